@@ -0,0 +1,245 @@
+/*!
+ * How well did you play? A look back over a finished game's guesses.
+ *
+ * The best you can do, when all you hear is "too big" or "too small", is a
+ * binary search: always guess the middle of what's left, and every answer
+ * rules out half of it. From 1 to 100 that never takes more than 7
+ * guesses, because 2^7 = 128 is the first power of two past 100.
+ *
+ * Against that, each guess can go wrong in one of three ways (a guess is
+ * only counted once, under the first of these that fits):
+ *
+ * ```text
+ * out of range   outside 1 to max, so it can't possibly be right
+ * repeated       the same as an earlier guess: you already knew the answer
+ * wasted         new, but an earlier "too big" or "too small" had ruled it out
+ * ```
+ *
+ * In the terminal game an out-of-range number never gets as far as being a
+ * guess (see read_guess() in ./game.rs), but it's still a mistake, so
+ * analyze_game() counts the game's rejected entries in with the rest.
+ * Entries that weren't numbers at all are counted too, apart from the
+ * mistakes: a typo says nothing about how well you played.
+ *
+ * Everything here is a plain function of the guesses, the secret and the
+ * max, so the tests below can check it without playing a game.
+ */
+use crate::game::Game;
+use common::Messages;
+use errors::GuessError;
+use std::cmp::Ordering;
+
+// the most guesses a binary search needs for numbers from 1 to `max`
+pub fn worst_case(max: u32) -> u32 {
+    // the number of bits in `max` is the smallest n with 2^n > max
+    u32::BITS - max.leading_zeros()
+}
+
+// how many guesses a binary search takes to find this particular secret
+pub fn binary_search_guesses(secret: u32, max: u32) -> u32 {
+    let (mut low, mut high) = (1, max);
+    let mut guesses = 0;
+    while low <= high {
+        guesses += 1;
+        let middle = low + (high - low) / 2;
+        match middle.cmp(&secret) {
+            Ordering::Less => low = middle + 1,
+            Ordering::Greater => high = middle - 1,
+            Ordering::Equal => break,
+        }
+    }
+    guesses
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Analysis {
+    pub guesses: u32,
+    // what a binary search would have taken, for this secret and at worst
+    pub binary_search: u32,
+    pub worst_case: u32,
+    pub out_of_range: u32,
+    pub repeated: u32,
+    pub wasted: u32,
+    // entries that weren't numbers, which aren't guesses or mistakes
+    pub not_a_number: u32,
+}
+
+pub fn analyze(history: &[u32], secret: u32, max: u32) -> Analysis {
+    let mut analysis = Analysis {
+        guesses: history.len() as u32,
+        binary_search: binary_search_guesses(secret, max),
+        worst_case: worst_case(max),
+        out_of_range: 0,
+        repeated: 0,
+        wasted: 0,
+        not_a_number: 0,
+    };
+    // what the answers so far have narrowed the secret down to
+    let (mut low, mut high) = (1, max);
+    for (i, &guess) in history.iter().enumerate() {
+        if guess < 1 || guess > max {
+            analysis.out_of_range += 1;
+        } else if history[..i].contains(&guess) {
+            analysis.repeated += 1;
+        } else if guess < low || guess > high {
+            analysis.wasted += 1;
+        }
+        match guess.cmp(&secret) {
+            Ordering::Less => low = low.max(guess + 1),
+            Ordering::Greater => high = high.min(guess - 1),
+            Ordering::Equal => {}
+        }
+    }
+    analysis
+}
+
+// The same for a whole game, with the entries it turned down as well
+pub fn analyze_game(game: &Game, max: u32) -> Analysis {
+    let mut analysis = analyze(game.history(), game.secret(), max);
+    for error in game.rejected() {
+        match error {
+            GuessError::NotANumber(_) => analysis.not_a_number += 1,
+            GuessError::TooLow(_) | GuessError::TooHigh(_) => analysis.out_of_range += 1,
+        }
+    }
+    analysis
+}
+
+impl Analysis {
+    pub fn mistakes(&self) -> u32 {
+        self.out_of_range + self.repeated + self.wasted
+    }
+
+    // A if you did as well as a binary search would have for this secret,
+    // without any mistakes (a lucky guess after a wasted one is still luck);
+    // B if no worse than its worst case, C within twice that, and D beyond
+    pub fn grade(&self) -> char {
+        if self.guesses <= self.binary_search && self.mistakes() == 0 {
+            'A'
+        } else if self.guesses <= self.worst_case {
+            'B'
+        } else if self.guesses <= self.worst_case * 2 {
+            'C'
+        } else {
+            'D'
+        }
+    }
+
+    // the report the game prints at the end, in the game's language (with
+    // a line for the typos, if there were any)
+    pub fn render(&self, messages: &Messages) -> String {
+        let mut lines = vec![
+            messages.format(
+                "analysis",
+                &[("guesses", &self.guesses), ("binary_search", &self.binary_search), ("worst_case", &self.worst_case)],
+            ),
+            messages.format(
+                "mistakes",
+                &[("out_of_range", &self.out_of_range), ("repeated", &self.repeated), ("wasted", &self.wasted)],
+            ),
+            messages.format("grade", &[("grade", &self.grade())]),
+        ];
+        if self.not_a_number > 0 {
+            lines.insert(2, messages.format("not_a_number_count", &[("count", &self.not_a_number)]));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::CATALOGS;
+
+    #[test]
+    fn binary_search_counts() {
+        assert_eq!(worst_case(100), 7);
+        assert_eq!(worst_case(127), 7);
+        assert_eq!(worst_case(128), 8);
+        assert_eq!(worst_case(1), 1);
+        assert_eq!(binary_search_guesses(50, 100), 1);
+        assert_eq!(binary_search_guesses(25, 100), 2);
+        assert_eq!(binary_search_guesses(1, 1), 1);
+        // never more than the worst case
+        assert!((1..=100).all(|secret| binary_search_guesses(secret, 100) <= 7));
+        assert!((1..=100).any(|secret| binary_search_guesses(secret, 100) == 7));
+    }
+
+    #[test]
+    fn a_perfect_game() {
+        let analysis = analyze(&[50, 25], 25, 100);
+        assert_eq!(
+            analysis,
+            Analysis {
+                guesses: 2,
+                binary_search: 2,
+                worst_case: 7,
+                out_of_range: 0,
+                repeated: 0,
+                wasted: 0,
+                not_a_number: 0
+            }
+        );
+        assert_eq!(analysis.grade(), 'A');
+    }
+
+    #[test]
+    fn each_kind_of_mistake() {
+        // 60 is too big, so 70 was already ruled out; 60 again is a repeat,
+        // and 0 and 101 are off the board altogether
+        let analysis = analyze(&[60, 70, 60, 0, 101, 30, 42], 42, 100);
+        assert_eq!((analysis.out_of_range, analysis.repeated, analysis.wasted), (2, 1, 1));
+        assert_eq!(analysis.mistakes(), 4);
+        // as quick as a binary search for 42, but not without mistakes
+        assert_eq!((analysis.guesses, analysis.binary_search), (7, 7));
+        assert_eq!(analysis.grade(), 'B');
+    }
+
+    #[test]
+    fn counting_up_is_slow_but_never_wasted() {
+        let history: Vec<u32> = (1..=26).collect();
+        let analysis = analyze(&history, 26, 100);
+        assert_eq!((analysis.out_of_range, analysis.repeated, analysis.wasted), (0, 0, 0));
+        assert_eq!(analysis.grade(), 'D');
+        assert_eq!(analyze(&history[..10], 10, 100).grade(), 'C');
+    }
+
+    #[test]
+    fn rejected_entries_count_apart_from_guesses() {
+        let mut game = Game::new(42);
+        game.reject(GuessError::TooHigh(500));
+        game.guess(50);
+        game.reject(GuessError::NotANumber(String::from("fourty")));
+        game.reject(GuessError::TooLow(0));
+        game.guess(42);
+        let analysis = analyze_game(&game, 100);
+        assert_eq!(analysis.guesses, 2);
+        assert_eq!((analysis.out_of_range, analysis.not_a_number), (2, 1));
+        // out of range is a mistake, but a typo isn't
+        assert_eq!(analysis.mistakes(), 2);
+        assert_eq!(analysis.grade(), 'B');
+
+        let mut typo = Game::new(50);
+        typo.reject(GuessError::NotANumber(String::from("5O")));
+        typo.guess(50);
+        assert_eq!(analyze_game(&typo, 100).grade(), 'A');
+    }
+
+    #[test]
+    fn reports() {
+        let analysis = analyze(&[60, 70, 60, 42], 42, 100);
+        assert_eq!(
+            analysis.render(&Messages::new(CATALOGS, "en")),
+            "You took 4 guesses; a binary search would have taken 7 (and never more than 7)\n\
+             Out of range: 0, repeated: 1, already ruled out: 1\n\
+             Grade: B"
+        );
+        assert_eq!(analysis.render(&Messages::new(CATALOGS, "es")).lines().last(), Some("Nota: B"));
+
+        let typos = Analysis { not_a_number: 2, ..analysis };
+        assert_eq!(
+            typos.render(&Messages::new(CATALOGS, "en")).lines().nth(2),
+            Some("Not a number: 2 (not counted as guesses)")
+        );
+    }
+}
@@ -0,0 +1,129 @@
+/*!
+ * The game itself, without any of the talking: a secret number, and how each
+ * guess compares to it.
+ *
+ * demo() in ./lib.rs does the reading and printing around this, and the
+ * `wasm` package does the same from JavaScript. Neither one has to know the
+ * rules, and this doesn't have to know where guesses come from.
+ *
+ * What someone types isn't always a guess. read_guess() sorts it out, with
+ * 13_errors' GuessError for the two ways it can go wrong: not a number at
+ * all, or a number outside the game. Those never reach guess(), but the
+ * game still keeps count of them, for the report at the end.
+ */
+use common::Rng;
+use errors::GuessError;
+use std::cmp::Ordering;
+
+pub struct Game {
+    secret: u32,
+    // every guess so far, in order, for ./analysis.rs to look back over
+    history: Vec<u32>,
+    // and every entry that wasn't one
+    rejected: Vec<GuessError>,
+}
+
+// What someone typed, as a guess for a game from 1 to `max`. (13_errors'
+// own Guess is always 1 to 100, and this game's max is a setting, so the
+// range check is done here, with the same errors Guess::try_new gives.)
+pub fn read_guess(text: &str, max: u32) -> Result<u32, GuessError> {
+    let text = text.trim();
+    // an i32, not a u32, so that "-5" is too low rather than not a number
+    let value: i32 = text.parse().map_err(|_| GuessError::NotANumber(text.to_string()))?;
+    if value < 1 {
+        Err(GuessError::TooLow(value))
+    } else if value as u32 > max {
+        Err(GuessError::TooHigh(value))
+    } else {
+        Ok(value as u32)
+    }
+}
+
+impl Game {
+    pub fn new(secret: u32) -> Game {
+        Game { secret, history: Vec::new(), rejected: Vec::new() }
+    }
+
+    // a secret number from 1 to `max`
+    pub fn random(rng: &mut impl Rng, max: u32) -> Game {
+        Game::new(rng.range(1, max as i32 + 1) as u32)
+    }
+
+    pub fn secret(&self) -> u32 {
+        self.secret
+    }
+
+    // Less means too small, Greater too big, and Equal is a win
+    pub fn guess(&mut self, guess: u32) -> Ordering {
+        self.history.push(guess);
+        guess.cmp(&self.secret)
+    }
+
+    // how many guesses so far, the winning one included
+    pub fn guesses(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    pub fn history(&self) -> &[u32] {
+        &self.history
+    }
+
+    // an entry read_guess() turned down: not a guess, so not counted as one
+    pub fn reject(&mut self, error: GuessError) {
+        self.rejected.push(error);
+    }
+
+    pub fn rejected(&self) -> &[GuessError] {
+        &self.rejected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SeededRng;
+
+    #[test]
+    fn guesses_compare_to_the_secret() {
+        let mut game = Game::new(42);
+        assert_eq!(game.guess(50), Ordering::Greater);
+        assert_eq!(game.guess(25), Ordering::Less);
+        assert_eq!(game.guess(42), Ordering::Equal);
+        assert_eq!(game.guesses(), 3);
+        assert_eq!(game.history(), [50, 25, 42]);
+    }
+
+    #[test]
+    fn reading_guesses() {
+        assert_eq!(read_guess(" 42\n", 100), Ok(42));
+        assert_eq!(read_guess("1", 100), Ok(1));
+        assert_eq!(read_guess("100", 100), Ok(100));
+        assert_eq!(read_guess("0", 100), Err(GuessError::TooLow(0)));
+        assert_eq!(read_guess("-5", 100), Err(GuessError::TooLow(-5)));
+        assert_eq!(read_guess("101", 100), Err(GuessError::TooHigh(101)));
+        assert_eq!(read_guess("fifty", 100), Err(GuessError::NotANumber(String::from("fifty"))));
+        assert_eq!(read_guess("", 100), Err(GuessError::NotANumber(String::new())));
+        // too big for an i32 is too big to be a number at all, as with Guess
+        assert_eq!(read_guess("99999999999", 100), Err(GuessError::NotANumber(String::from("99999999999"))));
+        // the range is the game's, not Guess's 1 to 100
+        assert_eq!(read_guess("500", 1000), Ok(500));
+        assert_eq!(read_guess("11", 10), Err(GuessError::TooHigh(11)));
+    }
+
+    #[test]
+    fn rejected_entries_are_not_guesses() {
+        let mut game = Game::new(42);
+        game.reject(GuessError::TooHigh(500));
+        game.guess(42);
+        assert_eq!(game.guesses(), 1);
+        assert_eq!(game.rejected(), [GuessError::TooHigh(500)]);
+    }
+
+    #[test]
+    fn a_seed_picks_the_same_secret() {
+        let secret = Game::random(&mut SeededRng::new(42), 100).secret();
+        assert!((1..=100).contains(&secret));
+        assert_eq!(Game::random(&mut SeededRng::new(42), 100).secret(), secret);
+        assert_eq!(Game::random(&mut SeededRng::new(42), 1).secret(), 1);
+    }
+}
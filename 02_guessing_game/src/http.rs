@@ -0,0 +1,324 @@
+/*!
+ * The game over HTTP, so it can be played with curl:
+ *
+ * ```text
+ * cargo run -p guessing_game -- --http          # then, in another terminal:
+ * curl localhost:7879/new
+ * curl localhost:7879/guess/50                  # {"guess":50,"result":"too_big",...}
+ * curl localhost:7879/state
+ * ```
+ *
+ * Every answer is JSON (common/src/json.rs), errors included, which come
+ * back as `{"error": "..."}` with a 4xx status. A guess that isn't one
+ * (`/guess/ten`, or `/guess/500` in a game up to 100) is turned down the
+ * way the terminal game turns it down, with game::read_guess(), and
+ * counted the same way in the grade at the end.
+ *
+ * The HTTP is the same hand-rolled kind as 28_web_server's: a TcpListener,
+ * the first line of each request, and a response written out by hand. One
+ * thing is different on purpose. That server answers on a pool of threads,
+ * and here there's only the one: every request reads or changes the same
+ * game, so they'd all be queueing for the same Mutex anyway. Taking them
+ * one at a time means no Mutex at all. The price is that one slow client
+ * holds up everyone behind it, so no client gets long: READ_TIMEOUT to send
+ * its request, and MAX_HEAD bytes to send it in.
+ *
+ * `respond` does all the deciding and none of the networking, which is
+ * what the tests below call; `serve` is the networking around it.
+ */
+use crate::analysis;
+use crate::explain;
+use crate::game::{self, Game};
+use crate::messages;
+use crate::settings::Settings;
+use common::{Json, Messages, Rng};
+use std::cmp::Ordering;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+// the book's server has 7878, and both can run at once
+pub const DEFAULT_PORT: u16 = 7879;
+
+// how long a client has to send its request, before it's hung up on
+pub const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+// and how much of it is read: the request line and headers, which for curl
+// are a hundred bytes or so
+pub const MAX_HEAD: u64 = 8 * 1024;
+
+pub struct Server {
+    settings: Settings,
+    // None until the first /new
+    game: Option<Game>,
+    // for the errors, in the game's language (DEMO_LANG, as in the terminal)
+    messages: Messages,
+}
+
+impl Server {
+    pub fn new(settings: Settings) -> Server {
+        Server { settings, game: None, messages: Messages::from_env(messages::CATALOGS) }
+    }
+
+    // Answer one request line (e.g. "GET /guess/50 HTTP/1.1") with a status
+    // line and a JSON body. POST works as well as GET, for anyone who'd
+    // rather not change things with a GET.
+    pub fn respond(&mut self, rng: &mut impl Rng, request_line: &str) -> (&'static str, Json) {
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next()) {
+            (Some(method), Some(path)) => (method, path),
+            _ => return error("HTTP/1.1 400 BAD REQUEST", format!("can't read {:?}", request_line)),
+        };
+        if method != "GET" && method != "POST" {
+            return error("HTTP/1.1 405 METHOD NOT ALLOWED", format!("{} isn't allowed: use GET or POST", method));
+        }
+        // nothing here takes a ?query, so it's ignored rather than a 404
+        let path = path.split('?').next().unwrap_or("");
+
+        match path {
+            "/new" => {
+                let game = match self.settings.secret {
+                    Some(secret) => Game::new(secret),
+                    None => Game::random(rng, self.settings.max),
+                };
+                self.game = Some(game);
+                ("HTTP/1.1 200 OK", self.state())
+            }
+            "/state" => match self.game {
+                Some(_) => ("HTTP/1.1 200 OK", self.state()),
+                None => no_game(),
+            },
+            _ => match path.strip_prefix("/guess/") {
+                Some(number) => self.guess(number),
+                None => error("HTTP/1.1 404 NOT FOUND", format!("no such endpoint: {}", path)),
+            },
+        }
+    }
+
+    fn guess(&mut self, number: &str) -> (&'static str, Json) {
+        let game = match self.game.as_mut() {
+            Some(game) if won(game) => {
+                return error("HTTP/1.1 409 CONFLICT", String::from("this game is over: start another with /new"))
+            }
+            Some(game) => game,
+            None => return no_game(),
+        };
+        // not a guess, so not counted as one, but kept for the grade
+        let guess = match game::read_guess(number, self.settings.max) {
+            Ok(guess) => guess,
+            Err(e) => {
+                let message = explain(&self.messages, &e, self.settings.max);
+                game.reject(e);
+                return error("HTTP/1.1 400 BAD REQUEST", message);
+            }
+        };
+        let result = match game.guess(guess) {
+            Ordering::Less => "too_small",
+            Ordering::Greater => "too_big",
+            Ordering::Equal => "correct",
+        };
+        let body = Json::object([
+            ("guess", Json::from(guess)),
+            ("result", Json::from(result)),
+            ("guesses", Json::from(game.guesses())),
+        ]);
+        ("HTTP/1.1 200 OK", body)
+    }
+
+    // The game so far. The secret (and the grade from ./analysis.rs) stay
+    // null until it's been guessed: /state is no place to cheat from.
+    fn state(&self) -> Json {
+        let game = match &self.game {
+            Some(game) => game,
+            None => return Json::Null,
+        };
+        let over = won(game);
+        let grade = if over {
+            let report = analysis::analyze_game(game, self.settings.max);
+            Some(report.grade().to_string())
+        } else {
+            None
+        };
+        Json::object([
+            ("max", Json::from(self.settings.max)),
+            ("guesses", Json::from(game.guesses())),
+            ("history", Json::array(game.history().iter().copied())),
+            ("rejected", Json::from(game.rejected().len())),
+            ("won", Json::from(over)),
+            ("secret", Json::from(if over { Some(game.secret()) } else { None })),
+            ("grade", Json::from(grade)),
+        ])
+    }
+}
+
+fn won(game: &Game) -> bool {
+    game.history().last() == Some(&game.secret())
+}
+
+fn error(status_line: &'static str, message: String) -> (&'static str, Json) {
+    (status_line, Json::object([("error", Json::from(message))]))
+}
+
+fn no_game() -> (&'static str, Json) {
+    error("HTTP/1.1 409 CONFLICT", String::from("no game yet: start one with /new"))
+}
+
+// Read one request, and write back the response. Unlike 28_web_server we
+// read the headers too (and ignore them): closing a socket with unread
+// data in it can reset the connection before curl has seen the answer.
+pub fn handle_connection(server: &mut Server, rng: &mut impl Rng, mut stream: TcpStream) {
+    // without this, a client that connects and says nothing would wait
+    // forever, and so would everyone after it
+    if let Err(e) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+        eprintln!("Failed to set a timeout: {}", e);
+        return;
+    }
+    let mut lines = BufReader::new((&stream).take(MAX_HEAD)).lines();
+    let request_line = match lines.next() {
+        Some(Ok(line)) => line,
+        // the client hung up (or sent garbage, or nothing in time) before
+        // saying anything
+        _ => return,
+    };
+    for line in lines {
+        match line {
+            Ok(line) if !line.is_empty() => {}
+            _ => break,
+        }
+    }
+
+    let (status_line, body) = server.respond(rng, &request_line);
+    let body = format!("{}\n", body);
+    let response = format!(
+        "{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        status_line,
+        body.len(),
+        body
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()) {
+        eprintln!("Failed to respond: {}", e);
+    }
+}
+
+// Answer requests one at a time, forever, or until `max_requests` of them
+// (which is how the tests, and `--max-requests`, get the server to stop)
+pub fn serve(listener: TcpListener, rng: &mut impl Rng, settings: Settings, max_requests: Option<usize>) {
+    let mut server = Server::new(settings);
+    for stream in listener.incoming().take(max_requests.unwrap_or(usize::MAX)) {
+        match stream {
+            Ok(stream) => handle_connection(&mut server, rng, stream),
+            Err(e) => eprintln!("Connection failed: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SeededRng;
+
+    fn server(secret: u32) -> Server {
+        // in English, whatever DEMO_LANG says
+        let messages = Messages::new(messages::CATALOGS, "en");
+        Server { settings: Settings { max: 100, secret: Some(secret) }, game: None, messages }
+    }
+
+    fn ask(server: &mut Server, request_line: &str) -> (&'static str, String) {
+        let (status, body) = server.respond(&mut SeededRng::new(1), request_line);
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn a_game_over_http() {
+        let mut server = server(42);
+        assert_eq!(
+            ask(&mut server, "GET /new HTTP/1.1"),
+            ("HTTP/1.1 200 OK", String::from(r#"{"max":100,"guesses":0,"history":[],"rejected":0,"won":false,"secret":null,"grade":null}"#))
+        );
+        assert_eq!(
+            ask(&mut server, "GET /guess/50 HTTP/1.1").1,
+            r#"{"guess":50,"result":"too_big","guesses":1}"#
+        );
+        assert_eq!(
+            ask(&mut server, "POST /guess/25 HTTP/1.1").1,
+            r#"{"guess":25,"result":"too_small","guesses":2}"#
+        );
+        assert_eq!(
+            ask(&mut server, "GET /guess/42 HTTP/1.1").1,
+            r#"{"guess":42,"result":"correct","guesses":3}"#
+        );
+        assert_eq!(
+            ask(&mut server, "GET /state HTTP/1.1").1,
+            r#"{"max":100,"guesses":3,"history":[50,25,42],"rejected":0,"won":true,"secret":42,"grade":"A"}"#
+        );
+    }
+
+    #[test]
+    fn query_strings_are_ignored() {
+        let mut server = server(42);
+        assert_eq!(ask(&mut server, "GET /new?x=1 HTTP/1.1").0, "HTTP/1.1 200 OK");
+        assert_eq!(ask(&mut server, "GET /guess/42?from=curl HTTP/1.1").1, r#"{"guess":42,"result":"correct","guesses":1}"#);
+        assert_eq!(ask(&mut server, "GET /state? HTTP/1.1").0, "HTTP/1.1 200 OK");
+    }
+
+    #[test]
+    fn the_game_has_to_be_going() {
+        let mut server = server(7);
+        assert_eq!(ask(&mut server, "GET /state HTTP/1.1").0, "HTTP/1.1 409 CONFLICT");
+        assert_eq!(ask(&mut server, "GET /guess/7 HTTP/1.1").0, "HTTP/1.1 409 CONFLICT");
+        ask(&mut server, "GET /new HTTP/1.1");
+        assert_eq!(ask(&mut server, "GET /guess/7 HTTP/1.1").0, "HTTP/1.1 200 OK");
+        // and once it's won, it's over until the next /new
+        let (status, body) = ask(&mut server, "GET /guess/7 HTTP/1.1");
+        assert_eq!(status, "HTTP/1.1 409 CONFLICT");
+        assert!(body.contains("this game is over"), "{}", body);
+        ask(&mut server, "GET /new HTTP/1.1");
+        assert!(ask(&mut server, "GET /state HTTP/1.1").1.contains(r#""guesses":0"#));
+    }
+
+    #[test]
+    fn bad_requests_get_4xx_and_an_error() {
+        let mut server = server(7);
+        ask(&mut server, "GET /new HTTP/1.1");
+        assert_eq!(
+            ask(&mut server, "GET /guess/lots HTTP/1.1"),
+            ("HTTP/1.1 400 BAD REQUEST", String::from(r#"{"error":"\"lots\" isn't a number: try again"}"#))
+        );
+        assert_eq!(ask(&mut server, "GET /nope HTTP/1.1").0, "HTTP/1.1 404 NOT FOUND");
+        assert_eq!(ask(&mut server, "GET /nope?x=1 HTTP/1.1").0, "HTTP/1.1 404 NOT FOUND");
+        assert_eq!(ask(&mut server, "DELETE /new HTTP/1.1").0, "HTTP/1.1 405 METHOD NOT ALLOWED");
+        assert_eq!(ask(&mut server, "garbage").0, "HTTP/1.1 400 BAD REQUEST");
+        // none of which counted as a guess
+        assert!(ask(&mut server, "GET /state HTTP/1.1").1.contains(r#""guesses":0"#));
+    }
+
+    #[test]
+    fn guesses_outside_the_game_are_turned_down() {
+        let mut server = server(7);
+        ask(&mut server, "GET /new HTTP/1.1");
+        assert_eq!(
+            ask(&mut server, "GET /guess/500 HTTP/1.1"),
+            (
+                "HTTP/1.1 400 BAD REQUEST",
+                String::from(r#"{"error":"500 is too high: the secret number is from 1 to 100"}"#)
+            )
+        );
+        assert_eq!(ask(&mut server, "GET /guess/0 HTTP/1.1").0, "HTTP/1.1 400 BAD REQUEST");
+        ask(&mut server, "GET /guess/7 HTTP/1.1");
+        // counted as mistakes, as they are in the terminal, so no A
+        assert_eq!(
+            ask(&mut server, "GET /state HTTP/1.1").1,
+            r#"{"max":100,"guesses":1,"history":[7],"rejected":2,"won":true,"secret":7,"grade":"B"}"#
+        );
+    }
+
+    #[test]
+    fn without_a_secret_the_rng_picks_one() {
+        let mut server = Server::new(Settings { max: 10, secret: None });
+        server.respond(&mut SeededRng::new(42), "GET /new HTTP/1.1");
+        let secret = server.game.as_ref().unwrap().secret();
+        assert!((1..=10).contains(&secret));
+        assert_eq!(Game::random(&mut SeededRng::new(42), 10).secret(), secret);
+    }
+}
@@ -0,0 +1,169 @@
+// a guessing game
+use std::cmp::Ordering;
+use std::io::{self, BufRead};
+
+// what the game says, in English or Spanish: DEMO_LANG=es to switch
+mod messages;
+
+// the game without the talking, public so the `wasm` package can run it in
+// a browser (see wasm/src/lib.rs)
+pub mod game;
+use game::Game;
+
+// how well the game was played, looking back over its guesses
+pub mod analysis;
+
+// how big the game is, from examples.toml or the command line
+pub mod settings;
+
+// the game as a tiny JSON-over-HTTP server, for curl: main.rs's `--http`
+pub mod http;
+use settings::Settings;
+use common::{Config, Messages, Rng, ThreadRng};
+use errors::GuessError;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::interactive("guess", "Guess a number between 1 and 100", demo),
+];
+
+// (with the settings from examples.toml, if there is one: main.rs adds the
+// command line's on top)
+pub fn demo() {
+    match Config::load().and_then(|config| Settings::load(&config, &mut Vec::new())) {
+        Ok(settings) => {
+            play(&mut ThreadRng, &settings);
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
+// The game, with its secret number drawn from `rng`: demo() passes the
+// thread's generator, where DEMO_SEED=<number> makes it the same number
+// every time (see common/src/random.rs). True for a win, and false if the
+// guesses ran out first.
+pub fn play(rng: &mut impl Rng, settings: &Settings) -> bool {
+    play_from(rng, settings, &mut io::stdin().lock())
+}
+
+// The same, with the guesses read from `input` instead of the keyboard:
+// main.rs's `--guesses 50,25,37` plays a whole game from the command line.
+pub fn play_from(rng: &mut impl Rng, settings: &Settings, input: &mut impl BufRead) -> bool {
+    let messages = Messages::from_env(messages::CATALOGS);
+    println!("{}", messages.get("title"));
+
+    // the rules (and the count of guesses) live in ./game.rs
+    let mut game = match settings.secret {
+        Some(secret) => Game::new(secret),
+        None => Game::random(rng, settings.max),
+    };
+
+    // printing the secret number is useful during development,
+    // but does not make for the best gameplay
+    // println!("The secret number is {}", game.secret());
+
+    // a loop statement all by itself is infinite!
+    // this only ends when we reach our `break` statement below
+    // (or via CTRL-C, or by entering a non-number)
+    loop {
+        println!("{}", messages.get("ask"));
+
+        let mut guess = String::new();
+        // compiler infers that `guess` is a string because we gave it a string!
+        // all user input from stdin() is also a string
+
+        let bytes_read = input
+            .read_line(&mut guess)
+            .expect("Failed to read line");
+        // nb: read_line returns a Return object, which is a core Rust type
+        // it is an enum which is either OK or Err (short for Err)
+        // You can handle this much like we do with a JS promise... via
+        // attaching handlers. The .expect() handler will fire for an Err,
+        // If the enum is not Error, it must have been an OK value, and that
+        // OK value gets placed in the `guess` variable
+
+        // Reading zero bytes means there's no more input at all (CTRL-D, or
+        // the end of a file piped in), and no guess is ever coming, so quit
+        if bytes_read == 0 {
+            return false;
+        }
+
+        // (Now, back to the main flow of control after our Return detour)
+        // But wait, we generated an integer for `secret_number`, doh!
+        // so we must convert the guess from a string into a number
+        // The next line is looks like we are _redeclaring_ the variable,
+        // but Rust considers this an instance of "shadowing". Ok then!
+        let guess: u32 = match game::read_guess(&guess, settings.max) {
+            // it turns out that using expect() on a Return object is kind of 
+            // the lazy way out... or at best the quick and dirty way out
+            // The more-correct approach is to feed the Return object to a
+            // {match} statement, and then explicitly handle both the
+            // OK case and the Error case with actual branches, like so:   
+            Ok(num) => num, // okay means we should have a number, so resolve to it
+            Err(error) => {
+                // The Err is a GuessError (from 13_errors), which says *what*
+                // was wrong, so we can say so instead of silently asking
+                // again. The game keeps count of these for the report at the
+                // end, and the keyword {continue} keeps the loop going
+                // instead of crashing the program
+                println!("{}", explain(&messages, &error, settings.max));
+                game.reject(error);
+                continue;
+            }
+        };
+
+        println!("{}", messages.format("you_guessed", &[("guess", &guess)]));
+
+        match game.guess(guess) {
+            Ordering::Less => println!("{}", messages.get("too_small")),
+            Ordering::Greater => println!("{}", messages.get("too_big")),
+            Ordering::Equal => {
+                println!("{}", messages.get("you_win"));
+                break;
+            } // end Equal match clause
+        }// end entire match statement
+    } // end loop
+            
+    println!("{}", messages.get("congratulations"));
+
+    // and how that compares to the best possible play (see ./analysis.rs)
+    let report = analysis::analyze_game(&game, settings.max);
+    println!("{}", report.render(&messages));
+    true
+}// end program
+
+// what to tell someone whose entry wasn't a guess, in the game's language
+// (./http.rs says the same, in its error bodies)
+pub(crate) fn explain(messages: &Messages, error: &GuessError, max: u32) -> String {
+    match error {
+        GuessError::NotANumber(text) => messages.format("not_a_number", &[("text", text)]),
+        GuessError::TooLow(guess) => messages.format("too_low", &[("guess", guess), ("max", &max)]),
+        GuessError::TooHigh(guess) => messages.format("too_high", &[("guess", guess), ("max", &max)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_error_gets_its_own_message() {
+        let en = Messages::new(messages::CATALOGS, "en");
+        assert_eq!(explain(&en, &GuessError::NotANumber(String::from("ten")), 100), "\"ten\" isn't a number: try again");
+        assert_eq!(explain(&en, &GuessError::TooLow(0), 100), "0 is too low: the secret number is from 1 to 100");
+        assert_eq!(explain(&en, &GuessError::TooHigh(20), 10), "20 is too high: the secret number is from 1 to 10");
+        let es = Messages::new(messages::CATALOGS, "es");
+        assert_eq!(explain(&es, &GuessError::TooLow(-3), 100), "-3 es demasiado poco: el número secreto va de 1 a 100");
+    }
+
+    // a bad entry is explained and the game goes on, until the input runs
+    // out: an out-of-range number is never taken for the secret
+    #[test]
+    fn bad_entries_dont_end_the_game() {
+        let settings = Settings { max: 10, secret: Some(7) };
+        assert!(play_from(&mut common::SeededRng::new(1), &settings, &mut "eleven\n11\n0\n7\n".as_bytes()));
+        assert!(!play_from(&mut common::SeededRng::new(1), &settings, &mut "11\n-7\n".as_bytes()));
+    }
+}
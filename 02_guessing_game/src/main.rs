@@ -1,66 +1,73 @@
-// a guessing game
-use rand::Rng;
-use std::cmp::Ordering;
-use std::io;
+// The game lives in lib.rs, where the top-level `runner` crate can reach
+// it too. All main adds is the command line: `--max 1000` for a bigger
+// game, on top of whatever examples.toml says (see common/src/config.rs).
+//
+// For scripts, and tests/cli.rs, a game can be played with no keyboard at
+// all: `--secret 42` picks the number, and `--guesses 50,25,42` makes the
+// guesses, one after the other. The exit status says how it ended: 0 for a
+// win, 2 when the guesses ran out first, and 1 for a bad command line.
+//
+// Or `--http` serves the game to curl instead (see src/http.rs), on port
+// 7879 unless `--port` says otherwise, for as long as it's left running or
+// for `--max-requests` requests.
+use common::config::{take_flag, Config};
+use common::ThreadRng;
+use guessing_game::http;
+use guessing_game::settings::Settings;
+use std::net::TcpListener;
+use std::process;
 
 fn main() {
-    println!("Guess the number!");
+    let mut args: Vec<String> = std::env::args().collect();
+    if let Some(at) = args.iter().position(|arg| arg == "--http") {
+        args.remove(at);
+        serve_http(args);
+        return;
+    }
+    let loaded = take_flag(&mut args, "--guesses").and_then(|guesses| {
+        let settings = Config::load().and_then(|config| Settings::load(&config, &mut args))?;
+        Ok((guesses, settings))
+    });
+    let won = match loaded {
+        // one guess to a line, as if they'd been typed
+        Ok((Some(guesses), settings)) => {
+            let input = guesses.replace(',', "\n");
+            guessing_game::play_from(&mut ThreadRng, &settings, &mut input.as_bytes())
+        }
+        Ok((None, settings)) => guessing_game::play(&mut ThreadRng, &settings),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    if !won {
+        process::exit(2);
+    }
+}
 
-    let secret_number = rand::thread_rng().gen_range(1, 101);
+fn serve_http(mut args: Vec<String>) {
+    let (port, max_requests, settings) = match parse_http(&mut args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    // as in 28_web_server: a server that can't listen has nothing else to do
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+    println!("Listening on http://{} (try /new, /guess/50 and /state)", listener.local_addr().unwrap());
+    http::serve(listener, &mut ThreadRng, settings, max_requests);
+}
 
-    // printing the secret number is useful during development,
-    // but does not make for the best gameplay
-    // println!("The secret number is {}", secret_number);
-
-    // a loop statement all by itself is infinite!
-    // this only ends when we reach our `break` statement below
-    // (or via CTRL-C, or by entering a non-number)
-    loop {
-        println!("Please input your guess");
-
-        let mut guess = String::new();
-        // compiler infers that `guess` is a string because we gave it a string!
-        // all user input from stdin() is also a string
-
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
-        // nb: read_line returns a Return object, which is a core Rust type
-        // it is an enum which is either OK or Err (short for Err)
-        // You can handle this much like we do with a JS promise... via
-        // attaching handlers. The .expect() handler will fire for an Err,
-        // If the enum is not Error, it must have been an OK value, and that
-        // OK value gets placed in the `guess` variable
-
-        // (Now, back to the main flow of control after our Return detour)
-        // But wait, we generated an integer for `secret_number`, doh!
-        // so we must convert the guess from a string into a number
-        // The next line is looks like we are _redeclaring_ the variable,
-        // but Rust considers this an instance of "shadowing". Ok then!
-        let guess: u32 = match guess.trim().parse() {
-            // it turns out that using expect() on a Return object is kind of 
-            // the lazy way out... or at best the quick and dirty way out
-            // The more-correct approach is to feed the Return object to a
-            // {match} statement, and then explicitly handle both the
-            // OK case and the Error case with actual branches, like so:   
-            Ok(num) => num, // okay means we should have a number, so resolve to it
-            Err(_) => continue, // lazy but *explicit* handling of an error
-            // typical use of `_` for "a variable I don't care about",
-            // and then the language keyword {continue} to keep the loop going
-            // instead of crashing the program
-        };
-
-        println!("You guessed {}", guess);
-
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!");
-                break;
-            } // end Equal match clause
-        }// end entire match statement
-    } // end loop
-            
-    println!("Congratulations!");
-}// end program
+fn parse_http(args: &mut Vec<String>) -> Result<(u16, Option<usize>, Settings), String> {
+    let port = match take_flag(args, "--port")? {
+        Some(port) => port.parse().map_err(|_| format!("--port: '{}' is not a port number", port))?,
+        None => http::DEFAULT_PORT,
+    };
+    let max_requests = match take_flag(args, "--max-requests")? {
+        Some(max) => Some(max.parse().map_err(|_| format!("--max-requests: '{}' is not a number", max))?),
+        None => None,
+    };
+    let settings = Config::load().and_then(|config| Settings::load(&config, args))?;
+    Ok((port, max_requests, settings))
+}
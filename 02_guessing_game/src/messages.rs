@@ -0,0 +1,64 @@
+// Everything the game says, in each language it can say it in (see
+// common/src/i18n.rs). English comes first, because it's the fallback.
+use common::Catalog;
+
+pub static CATALOGS: &[Catalog] = &[
+    Catalog {
+        lang: "en",
+        messages: &[
+            ("title", "Guess the number!"),
+            ("ask", "Please input your guess"),
+            ("you_guessed", "You guessed {guess}"),
+            ("too_small", "Too small!"),
+            ("too_big", "Too big!"),
+            ("you_win", "You win!"),
+            ("congratulations", "Congratulations!"),
+            ("analysis", "You took {guesses} guesses; a binary search would have taken {binary_search} (and never more than {worst_case})"),
+            ("mistakes", "Out of range: {out_of_range}, repeated: {repeated}, already ruled out: {wasted}"),
+            ("not_a_number_count", "Not a number: {count} (not counted as guesses)"),
+            ("grade", "Grade: {grade}"),
+            ("not_a_number", "\"{text}\" isn't a number: try again"),
+            ("too_low", "{guess} is too low: the secret number is from 1 to {max}"),
+            ("too_high", "{guess} is too high: the secret number is from 1 to {max}"),
+        ],
+    },
+    Catalog {
+        lang: "es",
+        messages: &[
+            ("title", "¡Adivina el número!"),
+            ("ask", "Escribe tu número"),
+            ("you_guessed", "Has dicho {guess}"),
+            ("too_small", "¡Demasiado pequeño!"),
+            ("too_big", "¡Demasiado grande!"),
+            ("you_win", "¡Has ganado!"),
+            ("congratulations", "¡Enhorabuena!"),
+            ("analysis", "Has necesitado {guesses} intentos; una búsqueda binaria habría necesitado {binary_search} (y nunca más de {worst_case})"),
+            ("mistakes", "Fuera de rango: {out_of_range}, repetidos: {repeated}, ya descartados: {wasted}"),
+            ("not_a_number_count", "No eran números: {count} (no cuentan como intentos)"),
+            ("grade", "Nota: {grade}"),
+            ("not_a_number", "«{text}» no es un número: prueba otra vez"),
+            ("too_low", "{guess} es demasiado poco: el número secreto va de 1 a {max}"),
+            ("too_high", "{guess} es demasiado: el número secreto va de 1 a {max}"),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::i18n::missing_keys;
+    use common::Messages;
+
+    #[test]
+    fn every_language_has_every_message() {
+        for catalog in CATALOGS {
+            assert_eq!(missing_keys(CATALOGS, catalog.lang), Vec::<&str>::new(), "in {}", catalog.lang);
+        }
+    }
+
+    #[test]
+    fn guesses_are_echoed_in_either_language() {
+        assert_eq!(Messages::new(CATALOGS, "en").format("you_guessed", &[("guess", &42)]), "You guessed 42");
+        assert_eq!(Messages::new(CATALOGS, "es").format("you_guessed", &[("guess", &42)]), "Has dicho 42");
+    }
+}
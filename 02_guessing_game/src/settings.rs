@@ -0,0 +1,96 @@
+/*!
+ * How big the game is: the secret number is from 1 to `max`.
+ *
+ * 100, unless examples.toml says otherwise, unless `--max` says otherwise
+ * again (common/src/config.rs has the layers, and the file):
+ *
+ * ```text
+ * [guessing_game]
+ * max = 1000
+ * ```
+ *
+ * `--secret 42` skips the random number altogether, for scripts and tests
+ * that need to know the answer in advance. That one is command line only:
+ * a secret written in a settings file wouldn't stay secret for long.
+ */
+use common::config::{layered, take_flag, Config};
+
+pub const DEFAULT_MAX: u32 = 100;
+
+#[derive(Debug, PartialEq)]
+pub struct Settings {
+    pub max: u32,
+    // the secret number, when it isn't up to chance
+    pub secret: Option<u32>,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings { max: DEFAULT_MAX, secret: None }
+    }
+}
+
+// 0 would leave nothing to guess, and the top has to leave room for the +1
+// in common::Rng's `range(1, max + 1)`
+fn check_max(max: i64) -> Result<u32, String> {
+    if (1..i32::MAX as i64).contains(&max) {
+        Ok(max as u32)
+    } else {
+        Err(format!("the largest secret number can't be {}", max))
+    }
+}
+
+impl Settings {
+    pub fn load(config: &Config, args: &mut Vec<String>) -> Result<Settings, String> {
+        let file = config.integer("guessing_game", "max")?.map(check_max).transpose()?;
+        let command_line = match take_flag(args, "--max")? {
+            Some(max) => Some(check_max(max.parse().map_err(|_| format!("--max wants a number, not {}", max))?)?),
+            None => None,
+        };
+        let max = layered(DEFAULT_MAX, file, command_line);
+        let secret = match take_flag(args, "--secret")? {
+            Some(secret) => match secret.parse::<u32>() {
+                Ok(n) if (1..=max).contains(&n) => Some(n),
+                _ => return Err(format!("--secret wants a number from 1 to {}, not {}", max, secret)),
+            },
+            None => None,
+        };
+        Ok(Settings { max, secret })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn the_flag_beats_the_file_beats_the_default() {
+        let file = Config::parse("[guessing_game]\nmax = 1000").unwrap();
+        assert_eq!(Settings::load(&Config::default(), &mut args(&["game"])), Ok(Settings::default()));
+        assert_eq!(Settings::load(&file, &mut args(&["game"])).unwrap().max, 1000);
+        assert_eq!(Settings::load(&file, &mut args(&["game", "--max", "10"])).unwrap().max, 10);
+    }
+
+    #[test]
+    fn a_secret_from_the_command_line() {
+        let mut list = args(&["game", "--secret", "42", "--max", "50"]);
+        assert_eq!(Settings::load(&Config::default(), &mut list), Ok(Settings { max: 50, secret: Some(42) }));
+        assert_eq!(list, ["game"]);
+        // it has to be a number the game could have picked
+        assert!(Settings::load(&Config::default(), &mut args(&["--secret", "101"])).is_err());
+        assert!(Settings::load(&Config::default(), &mut args(&["--secret", "0"])).is_err());
+        assert!(Settings::load(&Config::default(), &mut args(&["--secret", "soon"])).is_err());
+    }
+
+    #[test]
+    fn nothing_to_guess_is_an_error() {
+        let file = Config::parse("[guessing_game]\nmax = 0").unwrap();
+        assert!(Settings::load(&file, &mut Vec::new()).is_err());
+        assert!(Settings::load(&Config::default(), &mut args(&["--max", "-5"])).is_err());
+        assert!(Settings::load(&Config::default(), &mut args(&["--max", "lots"])).is_err());
+    }
+}
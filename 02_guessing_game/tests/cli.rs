@@ -0,0 +1,85 @@
+/**
+ * The game played from the command line alone, with `--secret` and
+ * `--guesses` standing in for chance and the keyboard: what it prints, and
+ * the exit status it ends with (0 for a win, 2 for running out of guesses,
+ * 1 for a bad command line, as src/main.rs says).
+ *
+ * Cargo builds the binary before running integration tests, and tells us
+ * where it put it in the CARGO_BIN_EXE_<name> variable.
+ */
+use std::process::{Command, Output, Stdio};
+
+// no examples.toml, English, and nothing on stdin: a game that tried to
+// read the keyboard would see EOF straight away
+fn game(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_guessing_game"))
+        .args(args)
+        .env("DEMO_CONFIG", "")
+        .env("DEMO_LANG", "en")
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn a_win_exits_zero() {
+    let output = game(&["--secret", "42", "--guesses", "50,25,42"]);
+    assert_eq!(output.status.code(), Some(0));
+    let text = stdout(&output);
+    assert!(text.contains("You guessed 50\nToo big!"), "{}", text);
+    assert!(text.contains("You guessed 25\nToo small!"), "{}", text);
+    assert!(text.contains("You guessed 42\nYou win!\nCongratulations!"), "{}", text);
+    assert!(text.contains("You took 3 guesses"), "{}", text);
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn running_out_of_guesses_exits_two() {
+    let output = game(&["--secret", "42", "--guesses", "50,25"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(!stdout(&output).contains("You win!"), "{}", stdout(&output));
+    assert_eq!(stderr(&output), "");
+}
+
+// the same as typing them: a guess that isn't a number, or is off the
+// board, is explained and skipped, and only counted in the report
+#[test]
+fn guesses_that_arent_numbers_are_skipped() {
+    let output = game(&["--secret", "7", "--guesses", "seven,700,7"]);
+    assert_eq!(output.status.code(), Some(0));
+    let text = stdout(&output);
+    assert!(text.contains("\"seven\" isn't a number: try again"), "{}", text);
+    assert!(text.contains("700 is too high: the secret number is from 1 to 100"), "{}", text);
+    assert!(text.contains("You took 1 guesses"), "{}", text);
+    assert!(text.contains("Out of range: 1,"), "{}", text);
+    assert!(text.contains("Not a number: 1 (not counted as guesses)"), "{}", text);
+}
+
+#[test]
+fn the_secret_has_to_fit_the_game() {
+    let output = game(&["--secret", "500", "--guesses", "500"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), "--secret wants a number from 1 to 100, not 500\n");
+    // unless the game is bigger
+    let output = game(&["--max", "1000", "--secret", "500", "--guesses", "500"]);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn flags_without_values_exit_one() {
+    for flag in ["--secret", "--guesses", "--max"] {
+        let output = game(&[flag]);
+        assert_eq!(output.status.code(), Some(1), "{}", flag);
+        assert_eq!(stdout(&output), "", "{}", flag);
+        assert_eq!(stderr(&output), format!("{} needs a value\n", flag));
+    }
+}
@@ -0,0 +1,24 @@
+/**
+ * The game, played to the end, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+// DEMO_SEED fixes the secret number, but guessing every number in order
+// finds it whatever it is, so this test doesn't care which seed that is
+#[test]
+fn guesses_one_to_a_hundred() {
+    let guesses: Vec<String> = (1..=100).map(|n| n.to_string()).collect();
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_guessing_game"), DIR), &guesses.join("\n"));
+    golden::check(DIR, "demo", &output);
+}
+
+// without the EOF check, this would loop forever asking for a guess (and
+// running out of guesses without a win is exit status 2: see src/main.rs)
+#[test]
+fn quits_when_the_input_runs_out() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_guessing_game"), DIR), "");
+    assert_eq!(output, "Guess the number!\nPlease input your guess\n--- exit status: 2 ---\n");
+}
@@ -0,0 +1,107 @@
+/**
+ * End-to-end: serve the game for real and play it over HTTP, the way curl
+ * would (src/http.rs has the unit tests for each endpoint's answers).
+ *
+ * Binding to port 0 asks the OS for any free port, so this never clashes
+ * with a server already running on 7879.
+ */
+use common::SeededRng;
+use guessing_game::http::{serve, MAX_HEAD, READ_TIMEOUT};
+use guessing_game::settings::Settings;
+use std::io::prelude::*;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::Instant;
+
+// a server with a known secret, good for `requests` requests
+fn start_server(secret: u32, requests: usize) -> (SocketAddr, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let settings = Settings { max: 100, secret: Some(secret) };
+    let server = thread::spawn(move || serve(listener, &mut SeededRng::new(1), settings, Some(requests)));
+    (addr, server)
+}
+
+// send one request, and read the reply until the server hangs up
+fn fetch(addr: SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n", path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn body(response: &str) -> &str {
+    &response[response.find("\r\n\r\n").unwrap() + 4..]
+}
+
+#[test]
+fn a_game_played_over_http() {
+    let (addr, server) = start_server(37, 5);
+    assert!(fetch(addr, "/new").starts_with("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n"));
+    assert_eq!(body(&fetch(addr, "/guess/50")), "{\"guess\":50,\"result\":\"too_big\",\"guesses\":1}\n");
+    assert_eq!(body(&fetch(addr, "/guess/25")), "{\"guess\":25,\"result\":\"too_small\",\"guesses\":2}\n");
+    assert_eq!(body(&fetch(addr, "/guess/37")), "{\"guess\":37,\"result\":\"correct\",\"guesses\":3}\n");
+    let state = fetch(addr, "/state");
+    assert!(body(&state).contains("\"won\":true,\"secret\":37"), "{}", state);
+    // the server stops by itself after its five requests
+    server.join().unwrap();
+}
+
+#[test]
+fn errors_come_back_as_json_too() {
+    let (addr, server) = start_server(37, 2);
+    let response = fetch(addr, "/guess/37");
+    assert!(response.starts_with("HTTP/1.1 409 CONFLICT\r\n"), "{}", response);
+    assert_eq!(body(&response), "{\"error\":\"no game yet: start one with /new\"}\n");
+    let response = fetch(addr, "/nope");
+    assert!(response.starts_with("HTTP/1.1 404 NOT FOUND\r\n"), "{}", response);
+    server.join().unwrap();
+}
+
+#[test]
+fn content_length_matches_the_body() {
+    let (addr, server) = start_server(37, 1);
+    let response = fetch(addr, "/new");
+    let length: usize = response
+        .lines()
+        .find_map(|line| line.strip_prefix("Content-Length: "))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(body(&response).len(), length);
+    server.join().unwrap();
+}
+
+#[test]
+fn a_silent_client_only_holds_things_up_for_a_while() {
+    let (addr, server) = start_server(37, 2);
+    // connects, and then says nothing at all
+    let silent = TcpStream::connect(addr).unwrap();
+    let start = Instant::now();
+    let response = fetch(addr, "/new");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    assert!(start.elapsed() >= READ_TIMEOUT / 2, "answered too soon to have waited");
+    drop(silent);
+    server.join().unwrap();
+}
+
+#[test]
+fn an_endless_head_is_cut_off() {
+    let (addr, server) = start_server(37, 2);
+    let mut stream = TcpStream::connect(addr).unwrap();
+    // a request line and then header after header, well past the limit
+    write!(stream, "GET /new HTTP/1.1\r\n").unwrap();
+    let header = format!("X-Padding: {}\r\n", "x".repeat(1000));
+    for _ in 0..(2 * MAX_HEAD as usize / header.len()) {
+        if stream.write_all(header.as_bytes()).is_err() {
+            break;
+        }
+    }
+    let mut response = String::new();
+    // (the server may hang up with some of the headers unread, which can
+    // reset the connection: all that matters is that it answers the next)
+    let _ = stream.read_to_string(&mut response);
+    assert!(body(&fetch(addr, "/state")).contains("\"guesses\":0"));
+    server.join().unwrap();
+}
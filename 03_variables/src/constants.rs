@@ -0,0 +1,134 @@
+/*!
+ * Values that aren't variables at all: `const`, `static`, and a static
+ * that's only worked out the first time it's needed.
+ *
+ * ```text
+ * const MAX_POINTS: u32 = 100_000;   // pasted in wherever it's used
+ * static GREETING: &str = "Hello";   // one value, at one address, for the whole run
+ * static PRIMES: OnceLock<Vec<u32>>  // empty until the first get_or_init fills it in
+ * ```
+ *
+ * Both `const` and `static` need their type written out, and both have to
+ * be worked out at compile time, so neither one can call `Vec::new()`
+ * and push onto it. When a global needs real work to build, like the
+ * table of primes here, `std::sync::OnceLock` holds it instead: the first
+ * caller runs the code that fills it in, and every caller after that
+ * (on any thread) gets the same table back, without building it again.
+ */
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+// a const has no address of its own: each use is as if you'd typed 100_000
+pub const MAX_POINTS: u32 = 100_000;
+
+// a static lives in one place for the whole program, hence &'static str
+pub static GREETING: &str = "Hello from a static";
+
+// The primes are worked out up to here. It's a const because an array
+// length or a loop bound can use it, and nothing ever needs its address.
+pub const PRIME_LIMIT: u32 = 10_000;
+
+// A static can't be `mut` without `unsafe`, but an atomic can be changed
+// through a plain `&`, so it can count how many times the table was built.
+static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+static PRIMES: OnceLock<Vec<u32>> = OnceLock::new();
+
+// the sieve of Eratosthenes: cross off every multiple of every prime
+fn sieve(limit: u32) -> Vec<u32> {
+    BUILDS.fetch_add(1, Ordering::SeqCst);
+    let limit = limit as usize;
+    let mut is_prime = vec![true; limit + 1];
+    let mut primes = Vec::new();
+    for n in 2..=limit {
+        if is_prime[n] {
+            primes.push(n as u32);
+            for multiple in (n * n..=limit).step_by(n) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    primes
+}
+
+// every prime up to PRIME_LIMIT, built by whoever asks first
+fn primes() -> &'static [u32] {
+    PRIMES.get_or_init(|| sieve(PRIME_LIMIT))
+}
+
+// how many times the table has been built: 0 before anyone asks, and
+// never more than 1
+pub fn builds() -> usize {
+    BUILDS.load(Ordering::SeqCst)
+}
+
+// None when `n` is past the end of the table, rather than a wrong answer
+pub fn is_prime(n: u32) -> Option<bool> {
+    if n > PRIME_LIMIT {
+        return None;
+    }
+    Some(primes().binary_search(&n).is_ok())
+}
+
+// the first prime is 2, and there are 1,229 of them in the table
+pub fn nth_prime(n: usize) -> Option<u32> {
+    n.checked_sub(1).and_then(|index| primes().get(index).copied())
+}
+
+pub fn primes_below(n: u32) -> &'static [u32] {
+    let primes = primes();
+    &primes[..primes.partition_point(|&p| p < n)]
+}
+
+pub fn demo_constants() {
+    println!("MAX_POINTS is a const: {}", MAX_POINTS);
+    println!("GREETING is a static: {}", GREETING);
+    println!("Prime tables built so far: {}", builds());
+    println!("Primes below 30: {:?}", primes_below(30));
+    println!("Is 9973 prime? {:?}", is_prime(9973));
+    println!("The 1000th prime: {:?}", nth_prime(1000));
+    println!("Prime tables built so far: {}", builds());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn the_table_answers_questions() {
+        assert_eq!(primes_below(20), [2, 3, 5, 7, 11, 13, 17, 19]);
+        assert!(primes_below(2).is_empty());
+        assert_eq!(is_prime(2), Some(true));
+        assert_eq!(is_prime(1), Some(false));
+        assert_eq!(is_prime(PRIME_LIMIT), Some(false));
+        assert_eq!(is_prime(PRIME_LIMIT + 1), None);
+        assert_eq!(nth_prime(1), Some(2));
+        assert_eq!(nth_prime(0), None);
+        assert_eq!(nth_prime(1229), Some(9973));
+        assert_eq!(nth_prime(1230), None);
+    }
+
+    // These tests run on several threads at once, and share the one table,
+    // so whichever of them asks first builds it. Either way, it's once.
+    #[test]
+    fn the_table_is_built_exactly_once() {
+        let handles: Vec<_> = (0..8)
+            .map(|i| thread::spawn(move || (is_prime(7 + i), nth_prime(10), primes_below(100).len())))
+            .collect();
+        for handle in handles {
+            let (_, tenth, below_100) = handle.join().unwrap();
+            assert_eq!(tenth, Some(29));
+            assert_eq!(below_100, 25);
+        }
+        assert_eq!(builds(), 1);
+        primes_below(PRIME_LIMIT);
+        assert_eq!(builds(), 1);
+    }
+
+    #[test]
+    fn every_caller_gets_the_same_table() {
+        assert!(std::ptr::eq(primes(), primes()));
+        assert!(std::ptr::eq(primes_below(PRIME_LIMIT + 1), primes()));
+    }
+}
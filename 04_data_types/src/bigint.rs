@@ -0,0 +1,348 @@
+/*!
+ * Numbers too big for any integer type: a u128 tops out at 39 digits, and
+ * 100! (100 * 99 * ... * 1) has 158 of them.
+ *
+ * A BigUint keeps its number the way we write one down, as a row of digits,
+ * except that each "digit" is a whole u32, so it counts in base 2^32
+ * instead of base 10. The digits are stored least significant first, which
+ * puts the carries at the end of the Vec, where pushing them is cheap:
+ *
+ * ```text
+ * 2^32 + 5         digits [5, 1]           5 * 1 + 1 * 2^32
+ * 0                digits []               no digits at all, not [0]
+ * ```
+ *
+ * There are never any zero digits on the end (the most significant ones),
+ * so each number has exactly one way to be written down, and the derived
+ * `==` can just compare the Vecs.
+ *
+ * The arithmetic is the long addition and long multiplication from school.
+ * Each step works in u64, which is big enough to hold a u32 times a u32
+ * plus two u32 carries without overflowing (see ./overflow.rs for what
+ * happens when it isn't).
+ */
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+const BASE: u64 = 1 << 32;
+
+// the biggest power of ten in a u32, for going to and from decimal nine
+// digits at a time instead of one
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { digits: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    // drop any zero digits from the top, so there's one way to write each number
+    fn normalize(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+
+    // self * factor + carry, in place: the one step parsing needs
+    fn mul_add_small(&mut self, factor: u32, carry: u32) {
+        let mut carry = carry as u64;
+        for digit in &mut self.digits {
+            let product = *digit as u64 * factor as u64 + carry;
+            *digit = (product % BASE) as u32;
+            carry = product / BASE;
+        }
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    // self / divisor, in place, and the remainder: the one step printing needs
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        // long division starts from the top
+        for digit in self.digits.iter_mut().rev() {
+            let value = remainder * BASE + *digit as u64;
+            *digit = (value / divisor as u64) as u32;
+            remainder = value % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> BigUint {
+        let mut big = BigUint { digits: vec![n as u32, (n >> 32) as u32] };
+        big.normalize();
+        big
+    }
+}
+
+// Long addition: add the digits pairwise from the bottom, carrying a 1
+// into the next pair whenever a sum doesn't fit
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.digits.len() >= other.digits.len() { (self, other) } else { (other, self) };
+        let mut digits = Vec::with_capacity(long.digits.len() + 1);
+        let mut carry = 0u64;
+        for (i, &digit) in long.digits.iter().enumerate() {
+            let sum = digit as u64 + short.digits.get(i).copied().unwrap_or(0) as u64 + carry;
+            digits.push((sum % BASE) as u32);
+            carry = sum / BASE;
+        }
+        if carry > 0 {
+            digits.push(carry as u32);
+        }
+        BigUint { digits }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        &self + &other
+    }
+}
+
+// Long multiplication: every digit of one times every digit of the other,
+// each product landing i + j places up
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut digits = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.digits.iter().enumerate() {
+                // at most (2^32 - 1)^2 + 2 * (2^32 - 1), which is 2^64 - 1
+                let product = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = (product % BASE) as u32;
+                carry = product / BASE;
+            }
+            digits[i + other.digits.len()] = carry as u32;
+        }
+        let mut product = BigUint { digits };
+        product.normalize();
+        product
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+// More digits means bigger (there are no zeros on top to pad one out), and
+// with the same number of digits, the first one that differs from the top
+// decides it
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseBigUintError {
+    Empty,
+    // the first character that isn't 0 to 9
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseBigUintError::Empty => write!(f, "there's no number in an empty string"),
+            ParseBigUintError::InvalidDigit(c) => write!(f, "{:?} isn't a decimal digit", c),
+        }
+    }
+}
+
+impl Error for ParseBigUintError {}
+
+// Decimal text, nine digits at a time: shift what we have so far up by
+// 10^9, and add the next nine on
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(text: &str) -> Result<BigUint, ParseBigUintError> {
+        if text.is_empty() {
+            return Err(ParseBigUintError::Empty);
+        }
+        if let Some(c) = text.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(ParseBigUintError::InvalidDigit(c));
+        }
+        let mut n = BigUint::zero();
+        // the first chunk takes the odd digits over, so the rest are all nine long
+        let first = match text.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            odd => odd,
+        };
+        let mut start = 0;
+        let mut end = first;
+        while start < text.len() {
+            let chunk = &text[start..end];
+            n.mul_add_small(10u32.pow(chunk.len() as u32), chunk.parse().unwrap());
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(n)
+    }
+}
+
+// The reverse: divide by 10^9 until nothing's left, and the remainders are
+// the nine-digit chunks, bottom first
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad("0");
+        }
+        let mut n = self.clone();
+        let mut chunks = Vec::new();
+        while !n.is_zero() {
+            chunks.push(n.div_rem_small(DECIMAL_CHUNK));
+        }
+        // every chunk but the top one keeps its leading zeros
+        let mut text = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            text.push_str(&format!("{:09}", chunk));
+        }
+        f.pad(&text)
+    }
+}
+
+// n! = n * (n - 1) * ... * 1, and 0! is 1
+pub fn factorial(n: u32) -> BigUint {
+    (1..=n as u64).fold(BigUint::from(1), |product, k| product * BigUint::from(k))
+}
+
+pub fn demo_bigint() {
+    println!("--- Big Integer Demonstration Begins --- ");
+    println!("u128::MAX is {}, and has {} digits", u128::MAX, u128::MAX.to_string().len());
+    let hundred = factorial(100);
+    println!("100! is {}", hundred);
+    println!("which has {} digits", hundred.to_string().len());
+    let big: BigUint = "18446744073709551616".parse().unwrap();
+    println!("2^64 squared is {}", &big * &big);
+    println!("and 2^64 + 2^64 is {}", &big + &big);
+    println!("Is 100! bigger than 2^64? {}", hundred > big);
+    println!("\"12x4\" as a BigUint: {:?}", "12x4".parse::<BigUint>());
+    println!("--- Big Integer Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigUint {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn small_numbers_round_trip() {
+        for n in [0, 1, 9, 10, 999_999_999, 1_000_000_000, u32::MAX as u64, 1 << 32, u64::MAX] {
+            assert_eq!(BigUint::from(n).to_string(), n.to_string());
+            assert_eq!(big(&n.to_string()), BigUint::from(n));
+        }
+        assert_eq!(BigUint::from(0), BigUint::zero());
+        assert_eq!(BigUint::from((1 << 32) + 5).digits, [5, 1]);
+    }
+
+    #[test]
+    fn leading_zeros_make_no_difference() {
+        assert_eq!(big("000"), BigUint::zero());
+        assert_eq!(big("0000000000000000042"), BigUint::from(42));
+        // zeros in the middle of a chunk are kept, though
+        assert_eq!(big("1000000000000000000001").to_string(), "1000000000000000000001");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigUintError::Empty));
+        assert_eq!("12x4".parse::<BigUint>(), Err(ParseBigUintError::InvalidDigit('x')));
+        assert_eq!("-1".parse::<BigUint>(), Err(ParseBigUintError::InvalidDigit('-')));
+        assert_eq!(" 1".parse::<BigUint>(), Err(ParseBigUintError::InvalidDigit(' ')));
+        assert_eq!(ParseBigUintError::InvalidDigit('x').to_string(), "'x' isn't a decimal digit");
+    }
+
+    // anything that fits in a u128 should agree with the u128 answer
+    #[test]
+    fn arithmetic_agrees_with_u128() {
+        let values: [u64; 6] = [0, 1, 12_345, u32::MAX as u64, 1 << 32, u64::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigUint::from(a), BigUint::from(b));
+                assert_eq!((&x + &y).to_string(), (a as u128 + b as u128).to_string(), "{} + {}", a, b);
+                assert_eq!((&x * &y).to_string(), (a as u128 * b as u128).to_string(), "{} * {}", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{} vs {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn carries_ripple_all_the_way_up() {
+        let all_ones = big("340282366920938463463374607431768211455"); // u128::MAX
+        assert_eq!((&all_ones + &BigUint::from(1)).to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(
+            (&all_ones * &all_ones).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(big("100000000000000000000") > BigUint::from(u64::MAX));
+        assert!(BigUint::zero() < BigUint::from(1));
+        // same length: the top digit decides, even if the bottom says otherwise
+        assert!(BigUint::from((2 << 32) + 1) > BigUint::from((1 << 32) + 9));
+        assert_eq!(big("123456789012345678901234567890").cmp(&big("123456789012345678901234567890")), Ordering::Equal);
+    }
+
+    #[test]
+    fn factorials() {
+        assert_eq!(factorial(0), BigUint::from(1));
+        assert_eq!(factorial(20), BigUint::from(2_432_902_008_176_640_000));
+        assert_eq!(factorial(25).to_string(), "15511210043330985984000000");
+        assert_eq!(
+            factorial(100).to_string(),
+            "93326215443944152681699238856266700490715968264381621468592963895217599993229915608941463976156518286253697920827223758251185210916864000000000000000000000000"
+        );
+        assert_eq!(factorial(100).to_string().len(), 158);
+    }
+
+    #[test]
+    fn display_pads_like_a_number() {
+        assert_eq!(format!("{:>6}", BigUint::from(42)), "    42");
+        assert_eq!(format!("{:<3}|", BigUint::zero()), "0  |");
+    }
+}
@@ -0,0 +1,235 @@
+/*!
+ * Integers as rows of bits, rather than as numbers.
+ *
+ * Bit i of a number is the 2^i column of its binary form, counting from 0
+ * on the right. The four things to do with one bit are each a single
+ * operator, with a *mask* that has only that bit set (`1 << i`):
+ *
+ * ```text
+ * set     n | (1 << i)       OR turns it on, and leaves the rest alone
+ * clear   n & !(1 << i)      AND with everything *but* it turns it off
+ * toggle  n ^ (1 << i)       XOR flips it
+ * test    n & (1 << i) != 0  AND keeps it and nothing else: is anything left?
+ * ```
+ *
+ * (Rust's `!` on an integer flips every bit, where C has `~`.)
+ *
+ * A u64 is then a set of up to 64 small numbers, one bit each, and a Vec
+ * of them is a set of as many as you like: BitSet, below. Union is OR and
+ * intersection is AND, a whole word (64 members) at a time.
+ *
+ * Formatting shows the bits too: `{:b}` is binary, `{:x}` and `{:X}` hex,
+ * `{:o}` octal, and `#` adds the 0b / 0x / 0o prefix. demo_bits() has them
+ * side by side.
+ */
+use std::iter::FromIterator;
+
+const WORD_BITS: usize = 64;
+
+// The helpers take bit numbers from 0 to 63: shifting a u64 by 64 or more
+// panics in a debug build (see ./overflow.rs for its cousins)
+pub fn set_bit(n: u64, i: u32) -> u64 {
+    n | (1 << i)
+}
+
+pub fn clear_bit(n: u64, i: u32) -> u64 {
+    n & !(1 << i)
+}
+
+pub fn toggle_bit(n: u64, i: u32) -> u64 {
+    n ^ (1 << i)
+}
+
+pub fn test_bit(n: u64, i: u32) -> bool {
+    n & (1 << i) != 0
+}
+
+// A set of small numbers, one bit each: n is in the set when bit n % 64 of
+// word n / 64 is set. There are never any zero words on the end, so two
+// sets with the same members are always `==`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> BitSet {
+        BitSet { words: Vec::new() }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    // true if `n` wasn't already in the set, as with HashSet::insert
+    pub fn insert(&mut self, n: usize) -> bool {
+        let (word, bit) = (n / WORD_BITS, (n % WORD_BITS) as u32);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let was_there = test_bit(self.words[word], bit);
+        self.words[word] = set_bit(self.words[word], bit);
+        !was_there
+    }
+
+    // true if `n` was in the set
+    pub fn remove(&mut self, n: usize) -> bool {
+        let (word, bit) = (n / WORD_BITS, (n % WORD_BITS) as u32);
+        if !self.contains(n) {
+            return false;
+        }
+        self.words[word] = clear_bit(self.words[word], bit);
+        self.trim();
+        true
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        match self.words.get(n / WORD_BITS) {
+            Some(&word) => test_bit(word, (n % WORD_BITS) as u32),
+            None => false,
+        }
+    }
+
+    // how many members: count_ones() is a single instruction on most chips
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    // everything in either: the longer set's extra words come along as they are
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (long, short) = if self.words.len() >= other.words.len() { (self, other) } else { (other, self) };
+        let mut words = long.words.clone();
+        for (word, &theirs) in words.iter_mut().zip(&short.words) {
+            *word |= theirs;
+        }
+        BitSet { words }
+    }
+
+    // everything in both: past the shorter set's end, there's nothing in both
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        let words = self.words.iter().zip(&other.words).map(|(a, b)| a & b).collect();
+        let mut set = BitSet { words };
+        set.trim();
+        set
+    }
+
+    // the members, smallest first
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..WORD_BITS as u32).filter(move |&bit| test_bit(word, bit)).map(move |bit| i * WORD_BITS + bit as usize)
+        })
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(members: I) -> BitSet {
+        let mut set = BitSet::new();
+        for n in members {
+            set.insert(n);
+        }
+        set
+    }
+}
+
+pub fn demo_bits() {
+    println!("--- Bits Demonstration Begins --- ");
+    let n = 0b1010_u64;
+    println!("n = {} = {:#06b}", n, n);
+    println!("set bit 0:    {:#06b}", set_bit(n, 0));
+    println!("clear bit 1:  {:#06b}", clear_bit(n, 1));
+    println!("toggle bit 2: {:#06b}", toggle_bit(n, 2));
+    println!("is bit 3 set? {}", test_bit(n, 3));
+
+    for n in [255_u64, 4096, 0xDEAD_BEEF] {
+        println!("{:>10}: binary {:b}, hex {:x} / {:#X}, octal {:#o}", n, n, n, n, n);
+    }
+    // `-1` is all ones in two's complement, which {:b} shows as it is
+    println!("-1i8 in binary is {:b}", -1_i8);
+
+    let evens: BitSet = (0..100).step_by(2).collect();
+    let threes: BitSet = (0..100).step_by(3).collect();
+    let both: Vec<usize> = evens.intersection(&threes).iter().collect();
+    println!("evens and multiples of 3 under 100, in both: {:?}", both);
+    println!("in either: {} of them", evens.union(&threes).len());
+    println!("--- Bits Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_bits() {
+        assert_eq!(set_bit(0b1010, 0), 0b1011);
+        assert_eq!(set_bit(0b1010, 1), 0b1010);
+        assert_eq!(clear_bit(0b1010, 1), 0b1000);
+        assert_eq!(clear_bit(0b1010, 0), 0b1010);
+        assert_eq!(toggle_bit(0b1010, 3), 0b0010);
+        assert_eq!(toggle_bit(toggle_bit(0b1010, 5), 5), 0b1010);
+        assert!(test_bit(0b1010, 3) && !test_bit(0b1010, 2));
+        // the top bit is bit 63
+        assert_eq!(set_bit(0, 63), 1 << 63);
+        assert!(test_bit(u64::MAX, 63));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn bit_64_is_off_the_end() {
+        set_bit(0, 64);
+    }
+
+    #[test]
+    fn inserting_and_removing() {
+        let mut set = BitSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(200));
+        assert!(set.contains(3) && set.contains(200));
+        assert!(!set.contains(4) && !set.contains(10_000));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(200));
+        assert!(!set.remove(200));
+        // the words that held 200 are gone, so this is the same set as {3}
+        assert_eq!(set, [3].iter().copied().collect());
+        assert!(set.remove(3));
+        assert_eq!(set, BitSet::new());
+    }
+
+    #[test]
+    fn members_come_out_in_order() {
+        let set: BitSet = vec![130, 0, 64, 63, 5, 64].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 5, 63, 64, 130]);
+        assert_eq!(set.len(), 5);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let small: BitSet = [1, 2, 3].iter().copied().collect();
+        let big: BitSet = [3, 4, 100].iter().copied().collect();
+        assert_eq!(small.union(&big).iter().collect::<Vec<_>>(), [1, 2, 3, 4, 100]);
+        assert_eq!(big.union(&small), small.union(&big));
+        assert_eq!(small.intersection(&big).iter().collect::<Vec<_>>(), [3]);
+        // nothing in common past the first word, so that word is all that's left
+        let far: BitSet = [1, 100].iter().copied().collect();
+        assert_eq!(far.intersection(&small), [1].iter().copied().collect());
+        assert!(far.intersection(&BitSet::new()).is_empty());
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format!("{:b}", 10), "1010");
+        assert_eq!(format!("{:#06b}", 10), "0b1010");
+        assert_eq!(format!("{:08b}", 10), "00001010");
+        assert_eq!(format!("{:x} {:X} {:#x}", 3_735_928_559_u32, 255, 255), "deadbeef FF 0xff");
+        assert_eq!(format!("{:#o}", 8), "0o10");
+        assert_eq!(format!("{:b}", -1_i8), "11111111");
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+ * Converting between number types, without any surprises.
+ *
+ * `as` converts anything numeric into anything else numeric, and never
+ * fails, which is exactly the trouble with it: `300 as u8` is 44 (it keeps
+ * the low eight bits), `-1 as u8` is 255, and `f64::NAN as u8` is 0. All
+ * perfectly well defined, and almost never what you meant.
+ *
+ * `TryFrom` (and `try_into`, its mirror image) is the careful alternative:
+ * it returns a Result, with an Err for any value that won't fit. The
+ * functions here wrap it up with an error type that says what went wrong,
+ * and add the conversions the standard library leaves to `as`: floats to
+ * integers, with a choice of rounding, and parsing text.
+ */
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    // a number that the target type can't hold (as text, since it might have
+    // been an integer or a float)
+    OutOfRange { value: String, target: &'static str },
+    NotANumber,
+    Infinite,
+    // text that isn't a number at all
+    Unparsable(String),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConvertError::OutOfRange { value, target } => write!(f, "{} doesn't fit in a {}", value, target),
+            ConvertError::NotANumber => write!(f, "NaN isn't a number, so it can't be converted"),
+            ConvertError::Infinite => write!(f, "infinity doesn't fit in any integer"),
+            ConvertError::Unparsable(text) => write!(f, "{:?} isn't a number", text),
+        }
+    }
+}
+
+impl Error for ConvertError {}
+
+// TryFrom's own error (TryFromIntError) only says *that* it didn't fit
+pub fn to_u8(n: i64) -> Result<u8, ConvertError> {
+    u8::try_from(n).map_err(|_| ConvertError::OutOfRange { value: n.to_string(), target: "u8" })
+}
+
+pub fn to_i32(n: i64) -> Result<i32, ConvertError> {
+    i32::try_from(n).map_err(|_| ConvertError::OutOfRange { value: n.to_string(), target: "i32" })
+}
+
+// Which way a float with a fractional part goes, on its way to an integer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rounding {
+    // to the closest integer, with halves going away from zero: 2.5 -> 3
+    Nearest,
+    // towards minus infinity: -2.5 -> -3
+    Down,
+    // towards plus infinity: -2.5 -> -2
+    Up,
+    // just drop the fraction, which is what `as` does: -2.7 -> -2
+    TowardZero,
+}
+
+// A float as an i64, rounded as asked. NaN and the infinities are errors,
+// and so is anything too big, where `as` would quietly give i64::MAX.
+pub fn float_to_i64(x: f64, rounding: Rounding) -> Result<i64, ConvertError> {
+    if x.is_nan() {
+        return Err(ConvertError::NotANumber);
+    }
+    if x.is_infinite() {
+        return Err(ConvertError::Infinite);
+    }
+    let rounded = match rounding {
+        Rounding::Nearest => x.round(),
+        Rounding::Down => x.floor(),
+        Rounding::Up => x.ceil(),
+        Rounding::TowardZero => x.trunc(),
+    };
+    // i64::MAX as f64 rounds *up* to 2^63, which is already too big, hence
+    // `<` at the top; -2^63 is exactly i64::MIN, so `>=` at the bottom
+    if rounded >= i64::MIN as f64 && rounded < i64::MAX as f64 {
+        Ok(rounded as i64)
+    } else {
+        Err(ConvertError::OutOfRange { value: x.to_string(), target: "i64" })
+    }
+}
+
+// float_to_i64, then to_u8: the float has to round to 0..=255
+pub fn float_to_u8(x: f64, rounding: Rounding) -> Result<u8, ConvertError> {
+    float_to_i64(x, rounding).and_then(to_u8).map_err(|e| match e {
+        ConvertError::OutOfRange { .. } => ConvertError::OutOfRange { value: x.to_string(), target: "u8" },
+        other => other,
+    })
+}
+
+// Text to a u8, with surrounding whitespace allowed. "256" is out of range
+// rather than unparsable: it's a perfectly good number, just a big one.
+pub fn parse_u8(text: &str) -> Result<u8, ConvertError> {
+    let text = text.trim();
+    // one sign at most, and then at least one digit and nothing else
+    let digits = text.strip_prefix('-').or_else(|| text.strip_prefix('+')).unwrap_or(text);
+    let is_number = !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    match text.parse::<i64>() {
+        Ok(n) => to_u8(n),
+        // a number too long for an i64 is still a number
+        Err(_) if is_number => Err(ConvertError::OutOfRange { value: text.to_string(), target: "u8" }),
+        Err(_) => Err(ConvertError::Unparsable(text.to_string())),
+    }
+}
+
+// Text to an f64. Rust happily parses "NaN" and "inf", which is rarely
+// what someone typing a number meant, so those are errors here.
+pub fn parse_f64(text: &str) -> Result<f64, ConvertError> {
+    let text = text.trim();
+    let x: f64 = text.parse().map_err(|_: ParseFloatError| ConvertError::Unparsable(text.to_string()))?;
+    if x.is_nan() {
+        Err(ConvertError::NotANumber)
+    } else if x.is_infinite() {
+        Err(ConvertError::Infinite)
+    } else {
+        Ok(x)
+    }
+}
+
+// (clippy knows `f64::NAN as u8` is always 0, and flags it as a mistake.
+// Showing off that quiet 0 is the point, so we overrule it.)
+#[allow(clippy::cast_nan_to_int)]
+pub fn demo_convert() {
+    println!("--- Conversion Demonstration Begins --- ");
+    println!("300 as u8 is {}, but to_u8(300) is {:?}", 300_i64 as u8, to_u8(300));
+    println!("-1 as u8 is {}, but to_u8(-1) is {:?}", -1_i64 as u8, to_u8(-1));
+    println!("to_u8(255) is {:?}", to_u8(255));
+    for rounding in [Rounding::Nearest, Rounding::Down, Rounding::Up, Rounding::TowardZero] {
+        println!("-2.5 rounded {:?}: {:?}", rounding, float_to_i64(-2.5, rounding));
+    }
+    let nan = float_to_u8(f64::NAN, Rounding::Nearest);
+    println!("f64::NAN as u8 is {}, but float_to_u8(NaN) is {:?}", f64::NAN as u8, nan);
+    for text in [" 42 ", "256", "-3", "forty-two"] {
+        match parse_u8(text) {
+            Ok(n) => println!("parse_u8({:?}) = {}", text, n),
+            Err(e) => println!("parse_u8({:?}) failed: {}", text, e),
+        }
+    }
+    println!("--- Conversion Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn out_of_range(value: &str, target: &'static str) -> ConvertError {
+        ConvertError::OutOfRange { value: value.to_string(), target }
+    }
+
+    #[test]
+    fn u8_boundaries() {
+        assert_eq!(to_u8(0), Ok(0));
+        assert_eq!(to_u8(255), Ok(255));
+        assert_eq!(to_u8(256), Err(out_of_range("256", "u8")));
+        assert_eq!(to_u8(-1), Err(out_of_range("-1", "u8")));
+        assert_eq!(to_u8(i64::MIN), Err(out_of_range("-9223372036854775808", "u8")));
+    }
+
+    #[test]
+    fn i32_boundaries() {
+        assert_eq!(to_i32(i32::MAX as i64), Ok(i32::MAX));
+        assert_eq!(to_i32(i32::MIN as i64), Ok(i32::MIN));
+        assert!(to_i32(i32::MAX as i64 + 1).is_err());
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let all = |x: f64| {
+            [Rounding::Nearest, Rounding::Down, Rounding::Up, Rounding::TowardZero]
+                .iter()
+                .map(|&r| float_to_i64(x, r).unwrap())
+                .collect::<Vec<i64>>()
+        };
+        assert_eq!(all(2.5), [3, 2, 3, 2]);
+        assert_eq!(all(-2.5), [-3, -3, -2, -2]);
+        assert_eq!(all(-2.7), [-3, -3, -2, -2]);
+        assert_eq!(all(7.0), [7, 7, 7, 7]);
+    }
+
+    #[test]
+    fn floats_that_cant_be_integers() {
+        assert_eq!(float_to_i64(f64::NAN, Rounding::Nearest), Err(ConvertError::NotANumber));
+        assert_eq!(float_to_i64(f64::INFINITY, Rounding::Down), Err(ConvertError::Infinite));
+        assert_eq!(float_to_i64(f64::NEG_INFINITY, Rounding::Up), Err(ConvertError::Infinite));
+        assert!(float_to_i64(1e19, Rounding::Nearest).is_err());
+        assert!(float_to_i64(9_223_372_036_854_775_807.0, Rounding::Nearest).is_err());
+        assert_eq!(float_to_i64(-9_223_372_036_854_775_808.0, Rounding::Nearest), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn floats_to_u8() {
+        assert_eq!(float_to_u8(254.6, Rounding::Nearest), Ok(255));
+        assert_eq!(float_to_u8(255.5, Rounding::Nearest), Err(out_of_range("255.5", "u8")));
+        assert_eq!(float_to_u8(255.5, Rounding::Down), Ok(255));
+        assert_eq!(float_to_u8(-0.4, Rounding::Nearest), Ok(0));
+        assert_eq!(float_to_u8(-0.5, Rounding::Nearest), Err(out_of_range("-0.5", "u8")));
+        assert_eq!(float_to_u8(f64::NAN, Rounding::Nearest), Err(ConvertError::NotANumber));
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse_u8(" 42\n"), Ok(42));
+        assert_eq!(parse_u8("256"), Err(out_of_range("256", "u8")));
+        assert_eq!(parse_u8("-1"), Err(out_of_range("-1", "u8")));
+        assert_eq!(parse_u8("99999999999999999999"), Err(out_of_range("99999999999999999999", "u8")));
+        assert_eq!(parse_u8("4.2"), Err(ConvertError::Unparsable(String::from("4.2"))));
+        assert_eq!(parse_u8(""), Err(ConvertError::Unparsable(String::new())));
+        // a sign on its own, or more than one, isn't a number
+        for text in ["-", "+", "--5", "+-5", "-+"] {
+            assert_eq!(parse_u8(text), Err(ConvertError::Unparsable(text.to_string())), "{:?}", text);
+        }
+        assert_eq!(parse_u8("+99999999999999999999"), Err(out_of_range("+99999999999999999999", "u8")));
+        assert_eq!(parse_f64(" -2.5 "), Ok(-2.5));
+        assert_eq!(parse_f64("NaN"), Err(ConvertError::NotANumber));
+        assert_eq!(parse_f64("inf"), Err(ConvertError::Infinite));
+        assert!(parse_f64("two").is_err());
+    }
+
+    #[test]
+    fn errors_say_what_went_wrong() {
+        assert_eq!(to_u8(256).unwrap_err().to_string(), "256 doesn't fit in a u8");
+        assert_eq!(parse_u8("x").unwrap_err().to_string(), "\"x\" isn't a number");
+    }
+}
@@ -0,0 +1,23 @@
+/*!
+ * Exercises for chapter 4: your turn to write the data types.
+ *
+ * Check them with `cargo run -p runner -- verify 04` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+// Turn the pair around: (1, 'a') becomes ('a', 1).
+// Hint: tuple fields are `.0` and `.1`, or take the tuple apart with `let`.
+pub fn swap(pair: (i32, char)) -> (char, i32) {
+    todo!()
+}
+
+// The last element of a four-element array.
+pub fn last_of(a: [i32; 4]) -> i32 {
+    todo!()
+}
+
+// The average of three floats, e.g. [1.0, 2.0, 6.0] gives 3.0
+pub fn average(a: [f64; 3]) -> f64 {
+    todo!()
+}
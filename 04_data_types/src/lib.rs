@@ -0,0 +1,210 @@
+/**
+ * Rust offers the usual primitives: integers, floats, strings and booleans.
+ * 
+ * They also offer built-in _tuples_ for arbitrary small groups of data,
+ * and an array, which is defined by *both* type and length (as in golang).
+ * 
+ * So: no surprises regarding primitive data types at first glance. 
+ * 
+ * The only unusual thing is that there is *no* concept of a `nil` or `null`
+ * or `undefined` or `None`. All of those things are unsafe, and there is no
+ * corresponding type for them. Variables can be temporarily unitialized in
+ * your code flow, but the compiler will ensure that you never ever try to 
+ * access them while they are in that state. Therefore, there is no need for
+ * them to have a low-level language type of their own. 
+ * 
+ * The closest equivalent would be the `None` value of an `Option` enum.
+ * That enum is well-known, fully encapsulated, part of the standard library, 
+ * and even part of the *prelude* (so it is automatically imported and made
+ * available in every Rust file). Thus the *concept* of `None` or `nil` does 
+ * exist, as does the tool to deal with it. But it is not a primitive *type*. 
+ * Dealing with that concept at a higher level of abstraction is *safer*
+ * than having it running about as a primitive type, and that kind of safety
+ * is what Rust is all about.
+ */
+// as in most languages, strings come in two flavors, one primitive and
+// one higher level: `str` is the former and `String` is the latter
+pub fn string_flavors() -> (&'static str, String) {
+    // note that declaring a var of type `str` is wordier than you'd think!
+    // The extra `&` is required, not optional, because technically this next
+    // var is a __slice__ reference, aka a "reference to a string slice".
+    let s: &str = "foo"; 
+    // The compiler won't even let you declare `str` as a type... you will  
+    // that you must always use `&str`: (reminder: is a "string slice". This 
+    // will also be true when you declare types for string parameters and 
+    // return values in function signatures. It's always `&str`, not `str`.
+    // (Returning one needs a lifetime, too: `'static` says it lives as long
+    // as the program, which is true of any string literal.)
+
+    // As a result, "simple strings" are not really so simple in Rust as
+    // they are in other languages. Given the current state of unicode
+    // multibyte madness, it's reasonable to stop considering strings as
+    // something that should be simple. They're not simple at all!
+
+    // most of the time we want our strings to be *expandable*, which means we
+    // are really talking about a collection of char values. 
+    // For that, rust gives us the the String class
+    // Most other languages prefer their small-s string primitives, but not Rust!
+    // in Rust we're more likely to use the big-S String 
+    let big_s: String = String::from("bar");
+    // NB: String args and return value types can appear in function signatures
+    // as `&str`. So "string slice" is treated as a _superset_ of String.
+    (s, big_s)
+}
+
+// Rust has a `char` type, which is the *only* valid place for single quotes
+pub fn a_char() -> char {
+    let c: char = 'a';
+    c
+}
+
+// typed languages do love to get very persnickety about their numbers 
+pub fn one_meeelion() -> i32 {
+    let i_32 : i32 = 1_000_000; 
+    i_32
+}
+
+// everyone uses float64 instead of float32, because it is just as fast, 
+// and less prone to forehead-slapping floating-point-math errors
+// (clippy spots that this is *almost* std::f64::consts::PI, and refuses to
+// build it by default. Here, "almost" is the whole joke, so we overrule it.)
+#[allow(clippy::approx_constant)]
+pub fn close_enough_to_pi() -> f64 {
+    let f_64 : f64 = 3.1415927; 
+    f_64
+}
+
+// bool is either true or false
+// 0 is NOT the same as false, other numbers are NOT the same as true 
+// let oops : bool = 1; // compiler error
+pub fn really() -> bool {
+    let really : bool = true; 
+    // no truthiness means no boolean shortcuts!
+    // if 1 {  // compiler error
+    //    println!("truthiness forever!");
+    //}
+    really
+}
+
+// there is no nil, None, null, or undefined 
+
+// run offers tuples, which are super-lightweight and dynamic-feeling
+// in the context of this strongly-typed language
+pub fn a_tuple() -> (i32, f64, u8) {
+    let tup: (i32, f64, u8) = (500, 6.4, 1);
+    tup
+}
+
+// println! won't do any auto-formatting of your tuple though
+// println!("I am a tuple, hear me roar: {}", tup); // compiler error
+// so we have to take it apart ourselves, field by numbered field
+pub fn describe_tuple(tup: (i32, f64, u8)) -> String {
+    format!("({}, {}, {})", tup.0, tup.1, tup.2)
+}
+
+// everything worth knowing about an array, for the demo to print
+#[derive(Debug, PartialEq)]
+pub struct ArrayReport {
+    pub length: usize,
+    pub last: i32,
+}
+
+// finally, `arrays` are defined by both *type* and *length* (as in golang)
+// so the parameter type here says "exactly five i32s", no more and no less
+pub fn describe_array(a: [i32; 5]) -> ArrayReport {
+    let length = a.len(); 
+    // there are more-idiomatic helper methods for this, I'm sure
+    let last = a[length - 1];
+    ArrayReport { length, last }
+}
+
+// turning one number type into another, with a Result when it won't fit
+pub mod convert;
+
+// and what the arithmetic operators do when an answer won't fit
+pub mod overflow;
+
+// numbers too big for any of them: 100! has 158 digits
+pub mod bigint;
+
+// and numbers as rows of bits: masks, binary and hex, and a BitSet
+pub mod bits;
+
+// practice problems, compiled only with `--features exercises`
+#[cfg(feature = "exercises")]
+pub mod exercises;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("data_types", "Strings, numbers, chars, tuples and arrays", demo_data_types),
+    Demo::new("convert", "Checked conversions between number types", convert::demo_convert),
+    Demo::new("overflow", "Wrapping, saturating, checked and overflowing arithmetic", overflow::demo_overflow),
+    Demo::new("bigint", "Arbitrary-precision integers, up to 100!", bigint::demo_bigint),
+    Demo::new("bits", "Setting, clearing and testing bits, and a BitSet", bits::demo_bits),
+];
+
+pub fn demo() {
+    demo_data_types();
+    convert::demo_convert();
+    overflow::demo_overflow();
+    bigint::demo_bigint();
+    bits::demo_bits();
+}
+
+pub fn demo_data_types() {
+    let (s, big_s) = string_flavors();
+    println!("I am a small-s string: {}", s);
+    println!("I am a big-S String: {}", big_s);
+    println!("I am a char: {}", a_char());
+    println!("One MEEEELION dollars: ${}", one_meeelion());
+    println!("Close enough to get to Pluto!: {}", close_enough_to_pi());
+    println!("Rust prefers truth over truthiness: {}", really());
+    println!("I am a tuple, hear me roar: {}", describe_tuple(a_tuple()));
+
+    let report = describe_array([11, 22, 33, 44, 55]);
+    println!("I am an array of length {} ending in {}", report.length, report.last);
+
+    // the fact that the `type` is pinned to a specific *length* makes rust 
+    // arrays seem less practical than JS arrays or Python Lists. Most of the 
+    // time you will probably prefer to use the non-primitive, higher-level 
+    // Vector and Slice constructs instead of raw arrays. 
+
+    println!("Rust has all the other usual suspects (maps, etc) in the standard library");
+    println!("But none of them are _primitives_ per se")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_kinds_of_string() {
+        let (s, big_s) = string_flavors();
+        assert_eq!(s, "foo");
+        assert_eq!(big_s, String::from("bar"));
+        // a String and a &str compare equal if the text matches
+        assert_eq!(big_s, "bar");
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(a_char(), 'a');
+        assert_eq!(one_meeelion(), 1_000_000);
+        assert!((close_enough_to_pi() - std::f64::consts::PI).abs() < 1e-6);
+        assert!(really());
+    }
+
+    #[test]
+    fn tuples_are_described_field_by_field() {
+        assert_eq!(a_tuple(), (500, 6.4, 1));
+        assert_eq!(describe_tuple(a_tuple()), "(500, 6.4, 1)");
+    }
+
+    #[test]
+    fn arrays_know_their_length() {
+        assert_eq!(describe_array([11, 22, 33, 44, 55]), ArrayReport { length: 5, last: 55 });
+        assert_eq!(describe_array([0; 5]), ArrayReport { length: 5, last: 0 });
+    }
+}
@@ -0,0 +1,186 @@
+/*!
+ * What happens when a number doesn't fit: integer overflow.
+ *
+ * A u8 holds 0 to 255, so what is 250 + 10? Plain `+` gives a different
+ * answer depending on how the program was built: a debug build (plain
+ * `cargo run`, or `cargo test`) panics with "attempt to add with overflow",
+ * while a release build (`--release`) skips the check and wraps around to
+ * 4. Code that *expects* to overflow should say which answer it wants,
+ * with one of the four families of methods every integer type has:
+ *
+ * ```text
+ * 250u8.wrapping_add(10)      4            keep the low bits, like release builds
+ * 250u8.saturating_add(10)    255          stop at the edge
+ * 250u8.checked_add(10)       None         Some(sum) if it fit, None if not
+ * 250u8.overflowing_add(10)   (4, true)    the wrapped answer, and whether it wrapped
+ * ```
+ *
+ * The functions here do that for u8s (small enough to overflow easily), so
+ * the demo can put all four answers side by side.
+ */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+pub fn wrapping(op: Op, a: u8, b: u8) -> u8 {
+    match op {
+        Op::Add => a.wrapping_add(b),
+        Op::Sub => a.wrapping_sub(b),
+        Op::Mul => a.wrapping_mul(b),
+    }
+}
+
+pub fn saturating(op: Op, a: u8, b: u8) -> u8 {
+    match op {
+        Op::Add => a.saturating_add(b),
+        Op::Sub => a.saturating_sub(b),
+        Op::Mul => a.saturating_mul(b),
+    }
+}
+
+pub fn checked(op: Op, a: u8, b: u8) -> Option<u8> {
+    match op {
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+    }
+}
+
+pub fn overflowing(op: Op, a: u8, b: u8) -> (u8, bool) {
+    match op {
+        Op::Add => a.overflowing_add(b),
+        Op::Sub => a.overflowing_sub(b),
+        Op::Mul => a.overflowing_mul(b),
+    }
+}
+
+// Plain `+`, for comparison: in a debug build this panics when the sum
+// doesn't fit, and in a release build it wraps, just like `wrapping`
+pub fn raw_add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+// All four answers to one sum, side by side
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub op: Op,
+    pub a: u8,
+    pub b: u8,
+    pub wrapping: u8,
+    pub saturating: u8,
+    pub checked: Option<u8>,
+    pub overflowing: (u8, bool),
+}
+
+pub fn compare(op: Op, a: u8, b: u8) -> Comparison {
+    Comparison {
+        op,
+        a,
+        b,
+        wrapping: wrapping(op, a, b),
+        saturating: saturating(op, a, b),
+        checked: checked(op, a, b),
+        overflowing: overflowing(op, a, b),
+    }
+}
+
+// One line per comparison, in columns, under a header
+pub fn report(comparisons: &[Comparison]) -> String {
+    let mut lines = vec![format!(
+        "{:<12}{:<10}{:<12}{:<10}{}",
+        "u8 sum", "wrapping", "saturating", "checked", "overflowing"
+    )];
+    for c in comparisons {
+        lines.push(format!(
+            "{:<12}{:<10}{:<12}{:<10}{:?}",
+            format!("{} {} {}", c.a, c.op, c.b),
+            c.wrapping,
+            c.saturating,
+            format!("{:?}", c.checked),
+            c.overflowing
+        ));
+    }
+    lines.join("\n")
+}
+
+pub fn demo_overflow() {
+    println!("--- Overflow Demonstration Begins --- ");
+    let comparisons = [
+        compare(Op::Add, 100, 100),
+        compare(Op::Add, 250, 10),
+        compare(Op::Sub, 3, 5),
+        compare(Op::Mul, 16, 16),
+    ];
+    println!("{}", report(&comparisons));
+    println!("(and plain 250 + 10 panics in a debug build: see the tests)");
+    println!("--- Overflow Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_it_fits_they_all_agree() {
+        let c = compare(Op::Add, 100, 100);
+        assert_eq!((c.wrapping, c.saturating, c.checked, c.overflowing), (200, 200, Some(200), (200, false)));
+        assert_eq!(raw_add(100, 100), 200);
+    }
+
+    #[test]
+    fn when_it_overflows_they_all_differ() {
+        assert_eq!(
+            compare(Op::Add, 250, 10),
+            Comparison { op: Op::Add, a: 250, b: 10, wrapping: 4, saturating: 255, checked: None, overflowing: (4, true) }
+        );
+        let c = compare(Op::Sub, 3, 5);
+        assert_eq!((c.wrapping, c.saturating, c.checked, c.overflowing), (254, 0, None, (254, true)));
+        let c = compare(Op::Mul, 16, 16);
+        assert_eq!((c.wrapping, c.saturating, c.checked, c.overflowing), (0, 255, None, (0, true)));
+    }
+
+    #[test]
+    fn the_edges() {
+        assert_eq!(checked(Op::Add, 255, 0), Some(255));
+        assert_eq!(checked(Op::Add, 255, 1), None);
+        assert_eq!(checked(Op::Sub, 0, 0), Some(0));
+        assert_eq!(checked(Op::Sub, 0, 1), None);
+    }
+
+    // Overflow checks are on in debug builds, which is what `cargo test`
+    // makes, but off with `cargo test --release`: there, this would wrap to
+    // 0 instead, so the test only exists when the checks do
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn plain_addition_panics_in_debug_builds() {
+        raw_add(255, 1);
+    }
+
+    #[test]
+    fn reports_line_up() {
+        let text = report(&[compare(Op::Add, 250, 10), compare(Op::Sub, 3, 5)]);
+        assert_eq!(
+            text,
+            "u8 sum      wrapping  saturating  checked   overflowing\n\
+             250 + 10    4         255         None      (4, true)\n\
+             3 - 5       254       0           None      (254, true)"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+/**
+ * The checks for src/exercises.rs. Spoilers below!
+ *
+ * These only build with `--features exercises` (see Cargo.toml), so the
+ * ordinary `cargo test` never trips over the unfinished exercises. Each test
+ * is named after the exercise it checks, which is how `runner verify` tells
+ * which ones pass.
+ */
+use data_types::exercises;
+
+#[test]
+fn swap() {
+    assert_eq!(exercises::swap((1, 'a')), ('a', 1));
+    assert_eq!(exercises::swap((-7, 'z')), ('z', -7));
+}
+
+#[test]
+fn last_of() {
+    assert_eq!(exercises::last_of([1, 2, 3, 4]), 4);
+    assert_eq!(exercises::last_of([9, 0, 0, -1]), -1);
+}
+
+#[test]
+fn average() {
+    assert_eq!(exercises::average([1.0, 2.0, 6.0]), 3.0);
+    assert!((exercises::average([0.1, 0.2, 0.3]) - 0.2).abs() < 1e-9);
+}
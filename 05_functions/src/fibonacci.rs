@@ -0,0 +1,185 @@
+/*!
+ * The nth Fibonacci number, four ways: one of the exercises the book
+ * suggests at the end of its chapter 3.
+ *
+ * ```text
+ * n     0  1  2  3  4  5  6   7   8   9  10 ...
+ * F(n)  0  1  1  2  3  5  8  13  21  34  55 ...  each one the sum of the two before
+ * ```
+ *
+ * The definition is a recursive function, and `recursive` is exactly that,
+ * but it calls itself twice per call, so F(40) takes hundreds of millions
+ * of calls. `memoized` writes each answer down the first time and never
+ * works it out twice; `iterative` keeps just the last two; and `Fib` is an
+ * Iterator that hands the whole sequence out one number at a time.
+ * (benches/ times them against each other.)
+ *
+ * F(93) is the biggest that fits in a u64, so the first three panic past
+ * that in a debug build, like any other overflow (see 04_data_types's
+ * overflow.rs). `memoized` checks first, in any build: it recurses n calls
+ * deep before its first addition, so F(1_000_000) would run out of stack
+ * (which can't be caught, the way a panic can) long before it overflowed.
+ * `checked` uses a u128 and says None instead of panicking, which gets as
+ * far as F(186).
+ */
+
+// the biggest n whose F(n) fits in a u64
+pub const MAX_U64: u32 = 93;
+
+pub fn recursive(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => recursive(n - 1) + recursive(n - 2),
+    }
+}
+
+pub fn memoized(n: u32) -> u64 {
+    // the same limit as recursion.rs's MAX_DEPTH, only here the answer
+    // runs out long before the stack does
+    if n > MAX_U64 {
+        panic!("F({}) doesn't fit in a u64 (the biggest is F({}))", n, MAX_U64);
+    }
+    // memo[i] is F(i), once it's been worked out
+    fn fib(n: usize, memo: &mut Vec<Option<u64>>) -> u64 {
+        if let Some(known) = memo[n] {
+            return known;
+        }
+        let answer = if n < 2 { n as u64 } else { fib(n - 1, memo) + fib(n - 2, memo) };
+        memo[n] = Some(answer);
+        answer
+    }
+    let n = n as usize;
+    fib(n, &mut vec![None; n + 1])
+}
+
+// (F(0) is the one answer that isn't the sum of two others, and starting
+// the loop from F(1) means it never works out one more than it needs to,
+// which for F(93) would be an F(94) that doesn't fit)
+pub fn iterative(n: u32) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    let (mut previous, mut current) = (0u64, 1u64);
+    for _ in 1..n {
+        let next = previous + current;
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+// The Fibonacci numbers in order, from F(0), stopping at the last one that
+// fits in a u64 rather than overflowing.
+pub struct Fib {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fib {
+    pub fn new() -> Fib {
+        Fib { current: Some(0), next: Some(1) }
+    }
+}
+
+impl Default for Fib {
+    fn default() -> Fib {
+        Fib::new()
+    }
+}
+
+impl Iterator for Fib {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.current?;
+        let after = self.next.and_then(|next| current.checked_add(next));
+        self.current = self.next;
+        self.next = after;
+        Some(current)
+    }
+}
+
+// F(n) as a u128, or None if it doesn't fit in one: the same loop as
+// `iterative`, where `?` gives up as soon as an addition overflows.
+pub fn checked(n: u32) -> Option<u128> {
+    if n == 0 {
+        return Some(0);
+    }
+    let (mut previous, mut current) = (0u128, 1u128);
+    for _ in 1..n {
+        let next = previous.checked_add(current)?;
+        previous = current;
+        current = next;
+    }
+    Some(current)
+}
+
+pub fn demo_fibonacci() {
+    println!("The first 15, from Fib: {:?}", Fib::new().take(15).collect::<Vec<_>>());
+    println!(
+        "F(30), four ways: {} {} {} {:?}",
+        recursive(30),
+        memoized(30),
+        iterative(30),
+        checked(30)
+    );
+    println!("Fib stops at F({}) = {:?}", MAX_U64, Fib::new().last());
+    println!("checked(186) = {:?}", checked(186));
+    println!("checked(187) = {:?}", checked(187));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: [u64; 11] = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+
+    #[test]
+    fn the_first_few() {
+        for (n, &expected) in FIRST.iter().enumerate() {
+            let n = n as u32;
+            assert_eq!(recursive(n), expected);
+            assert_eq!(memoized(n), expected);
+            assert_eq!(iterative(n), expected);
+            assert_eq!(checked(n), Some(expected as u128));
+        }
+        assert!(Fib::new().take(11).eq(FIRST.iter().copied()));
+    }
+
+    #[test]
+    fn known_big_ones() {
+        assert_eq!(recursive(25), 75_025);
+        assert_eq!(memoized(50), 12_586_269_025);
+        assert_eq!(iterative(MAX_U64), 12_200_160_415_121_876_738);
+        assert_eq!(memoized(MAX_U64), iterative(MAX_U64));
+        assert_eq!(checked(100), Some(354_224_848_179_261_915_075));
+    }
+
+    #[test]
+    fn fib_stops_before_overflowing() {
+        assert_eq!(Fib::new().count(), MAX_U64 as usize + 1);
+        assert_eq!(Fib::new().last(), Some(iterative(MAX_U64)));
+        assert_eq!(Fib::new().nth(60), Some(iterative(60)));
+    }
+
+    #[test]
+    fn checked_says_none_past_the_end() {
+        assert_eq!(checked(186), Some(332_825_110_087_067_562_321_196_029_789_634_457_848));
+        assert_eq!(checked(187), None);
+        assert_eq!(checked(u32::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "F(1000000) doesn't fit in a u64 (the biggest is F(93))")]
+    fn memoized_panics_rather_than_running_out_of_stack() {
+        memoized(1_000_000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn u64s_overflow_past_93() {
+        iterative(MAX_U64 + 1);
+    }
+}
@@ -0,0 +1,222 @@
+/*!
+ * Two recursive functions, each written twice (once calling itself, once
+ * with a loop), and neither one allowed to panic.
+ *
+ * Recursion can fail in two ways. The answer can be too big for its type,
+ * which the `checked_` arithmetic methods catch (see 04_data_types's
+ * overflow.rs). Or the function can call itself so many times over that it
+ * runs out of stack, which nothing catches at all: the program just dies
+ * with "thread 'main' has overflowed its stack". So the recursive versions
+ * here count how deep they are, and give up with an Err at MAX_DEPTH,
+ * long before the stack would run out.
+ *
+ * Factorial is the gentle one: n! = n * (n - 1)!, so n! is n calls deep,
+ * and 34! is the biggest that fits in a u128. The recursive version only
+ * multiplies on its way back *up*, so factorial_recursive(1_000_000) would
+ * go a million calls deep before its first overflow. That's the depth
+ * limit's job.
+ *
+ * Ackermann's function is the wild one:
+ *
+ * ```text
+ * A(0, n) = n + 1
+ * A(m, 0) = A(m - 1, 1)
+ * A(m, n) = A(m - 1, A(m, n - 1))
+ * ```
+ *
+ * It always finishes, but A(3, n) is already 2^(n + 3) - 3, and A(4, 2)
+ * has 19,729 digits. It also can't be turned into a simple loop the way
+ * factorial can, because of the call inside a call. The iterative version
+ * keeps its own stack instead, in a Vec, which lives on the heap and can
+ * grow much larger than the thread's stack.
+ */
+use std::error::Error;
+use std::fmt;
+
+// how deep the recursive versions may go: well short of what a thread's
+// stack can take (a test's thread gets 2MB)
+pub const MAX_DEPTH: usize = 1_000;
+
+// and how big the iterative Ackermann's own stack may grow, which is only
+// limited by memory: a million u32s is 4MB
+pub const MAX_STACK: usize = 1_000_000;
+
+// the biggest n whose n! fits in a u128
+pub const MAX_FACTORIAL: u32 = 34;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathError {
+    // the answer doesn't fit in a u128
+    Overflow,
+    // getting to it would take more than `limit` levels
+    TooDeep { limit: usize },
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "the answer doesn't fit in a u128"),
+            MathError::TooDeep { limit } => write!(f, "the answer is more than {} levels deep", limit),
+        }
+    }
+}
+
+impl Error for MathError {}
+
+pub fn factorial_recursive(n: u32) -> Result<u128, MathError> {
+    // n! is exactly n calls deep, so there's no need to count
+    if n as usize > MAX_DEPTH {
+        return Err(MathError::TooDeep { limit: MAX_DEPTH });
+    }
+    match n {
+        0 => Ok(1),
+        _ => factorial_recursive(n - 1)?.checked_mul(n as u128).ok_or(MathError::Overflow),
+    }
+}
+
+// The loop multiplies from the bottom up, so it stops at the first
+// multiplication that overflows, and never needs a depth limit
+pub fn factorial_iterative(n: u32) -> Result<u128, MathError> {
+    let mut product: u128 = 1;
+    for k in 2..=n {
+        product = product.checked_mul(k as u128).ok_or(MathError::Overflow)?;
+    }
+    Ok(product)
+}
+
+pub fn ackermann_recursive(m: u32, n: u128) -> Result<u128, MathError> {
+    ackermann_to_depth(m, n, MAX_DEPTH)
+}
+
+// The same, with a depth limit of your own
+pub fn ackermann_to_depth(m: u32, n: u128, limit: usize) -> Result<u128, MathError> {
+    fn ackermann(m: u32, n: u128, depth: usize, limit: usize) -> Result<u128, MathError> {
+        if depth > limit {
+            return Err(MathError::TooDeep { limit });
+        }
+        match (m, n) {
+            (0, n) => n.checked_add(1).ok_or(MathError::Overflow),
+            (m, 0) => ackermann(m - 1, 1, depth + 1, limit),
+            (m, n) => {
+                let inner = ackermann(m, n - 1, depth + 1, limit)?;
+                ackermann(m - 1, inner, depth + 1, limit)
+            }
+        }
+    }
+    ackermann(m, n, 1, limit)
+}
+
+// The calls still to make, as a stack of their `m`s. Every call's answer
+// becomes the next one's `n`, so one `n` is all there is to keep: pop an
+// m, apply whichever rule fits, and push whatever calls that rule needs.
+// The answer is the `n` left when the stack is empty.
+pub fn ackermann_iterative(m: u32, n: u128) -> Result<u128, MathError> {
+    let mut stack = vec![m];
+    let mut n = n;
+    while let Some(m) = stack.pop() {
+        if m == 0 {
+            n = n.checked_add(1).ok_or(MathError::Overflow)?;
+        } else if n == 0 {
+            stack.push(m - 1);
+            n = 1;
+        } else {
+            // A(m - 1, A(m, n - 1)): the inner call goes on top, to go first
+            stack.push(m - 1);
+            stack.push(m);
+            n -= 1;
+        }
+        if stack.len() > MAX_STACK {
+            return Err(MathError::TooDeep { limit: MAX_STACK });
+        }
+    }
+    Ok(n)
+}
+
+pub fn demo_recursion() {
+    println!("--- Recursion Demonstration Begins --- ");
+    for n in [0, 5, 20, MAX_FACTORIAL, MAX_FACTORIAL + 1] {
+        println!("{}! = {:?}", n, factorial_iterative(n));
+    }
+    match factorial_recursive(1_000_000) {
+        Ok(answer) => println!("1000000! = {}", answer),
+        Err(e) => println!("1000000!, recursively: {}", e),
+    }
+    for m in 0..=3 {
+        let row: Vec<String> = (0..=5).map(|n| ackermann_iterative(m, n).unwrap().to_string()).collect();
+        println!("A({}, 0..=5) = {}", m, row.join(", "));
+    }
+    println!("A(4, 0) = {:?}", ackermann_recursive(4, 0));
+    // A(1, n) is just n + 2, but the way there is n + 1 calls deep
+    println!("A(1, 100000), recursively: {:?}", ackermann_recursive(1, 100_000));
+    println!("A(1, 100000), iteratively: {:?}", ackermann_iterative(1, 100_000));
+    println!("A(0, u128::MAX) = {:?}", ackermann_iterative(0, u128::MAX));
+    println!("--- Recursion Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_factorials() {
+        let known = [1, 1, 2, 6, 24, 120, 720, 5040];
+        for (n, &expected) in known.iter().enumerate() {
+            assert_eq!(factorial_recursive(n as u32), Ok(expected));
+            assert_eq!(factorial_iterative(n as u32), Ok(expected));
+        }
+        assert_eq!(factorial_iterative(20), Ok(2_432_902_008_176_640_000));
+    }
+
+    #[test]
+    fn the_overflow_boundary() {
+        let biggest = 295_232_799_039_604_140_847_618_609_643_520_000_000;
+        assert_eq!(factorial_recursive(MAX_FACTORIAL), Ok(biggest));
+        assert_eq!(factorial_iterative(MAX_FACTORIAL), Ok(biggest));
+        assert_eq!(factorial_recursive(MAX_FACTORIAL + 1), Err(MathError::Overflow));
+        assert_eq!(factorial_iterative(MAX_FACTORIAL + 1), Err(MathError::Overflow));
+        // 35 * 34! really doesn't fit
+        assert_eq!(biggest.checked_mul(35), None);
+    }
+
+    #[test]
+    fn deep_factorials_stop_before_the_stack_runs_out() {
+        assert_eq!(factorial_recursive(MAX_DEPTH as u32), Err(MathError::Overflow));
+        assert_eq!(factorial_recursive(MAX_DEPTH as u32 + 1), Err(MathError::TooDeep { limit: MAX_DEPTH }));
+        assert_eq!(factorial_recursive(u32::MAX), Err(MathError::TooDeep { limit: MAX_DEPTH }));
+        // the loop has no depth to run out of
+        assert_eq!(factorial_iterative(u32::MAX), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn ackermann_known_values() {
+        for n in 0..6 {
+            // A(1, n) = n + 2, A(2, n) = 2n + 3, A(3, n) = 2^(n + 3) - 3
+            for (m, expected) in [(1, n + 2), (2, 2 * n + 3), (3, (1 << (n + 3)) - 3)] {
+                assert_eq!(ackermann_recursive(m, n), Ok(expected), "A({}, {})", m, n);
+                assert_eq!(ackermann_iterative(m, n), Ok(expected), "A({}, {})", m, n);
+            }
+        }
+        assert_eq!(ackermann_recursive(0, 0), Ok(1));
+        assert_eq!(ackermann_iterative(4, 0), Ok(13));
+        assert_eq!(ackermann_iterative(3, 8), Ok(2045));
+    }
+
+    #[test]
+    fn ackermann_limits() {
+        assert_eq!(ackermann_recursive(0, u128::MAX), Err(MathError::Overflow));
+        assert_eq!(ackermann_iterative(0, u128::MAX), Err(MathError::Overflow));
+        // A(3, 5) = 253 is 255 calls deep at its deepest
+        assert_eq!(ackermann_to_depth(3, 5, 255), Ok(253));
+        assert_eq!(ackermann_to_depth(3, 5, 254), Err(MathError::TooDeep { limit: 254 }));
+        // A(1, n) is n + 1 calls deep: too deep to recurse, but not to loop
+        assert_eq!(ackermann_recursive(1, 20_000), Err(MathError::TooDeep { limit: MAX_DEPTH }));
+        assert_eq!(ackermann_iterative(1, 20_000), Ok(20_002));
+        assert_eq!(ackermann_iterative(1, MAX_STACK as u128), Err(MathError::TooDeep { limit: MAX_STACK }));
+    }
+
+    #[test]
+    fn errors_explain_themselves() {
+        assert_eq!(MathError::Overflow.to_string(), "the answer doesn't fit in a u128");
+        assert_eq!(MathError::TooDeep { limit: 10 }.to_string(), "the answer is more than 10 levels deep");
+    }
+}
@@ -0,0 +1,13 @@
+/**
+ * The demo's whole output, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn demo() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_functions"), DIR), "");
+    golden::check(DIR, "demo", &output);
+}
@@ -0,0 +1,24 @@
+/*!
+ * Exercises for chapter 6: branches and loops.
+ *
+ * Check them with `cargo run -p runner -- verify 06` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+// The classic: "Fizz" for multiples of 3, "Buzz" for multiples of 5,
+// "FizzBuzz" for multiples of both, and the number itself otherwise.
+pub fn fizzbuzz(n: u32) -> String {
+    todo!()
+}
+
+// 1 + 2 + ... + n, using a loop (any of the three kinds).
+pub fn sum_to(n: u32) -> u32 {
+    todo!()
+}
+
+// Count down from `from` to 1: count_down(3) is [3, 2, 1].
+// Hint: ranges have a `.rev()`.
+pub fn count_down(from: u32) -> Vec<u32> {
+    todo!()
+}
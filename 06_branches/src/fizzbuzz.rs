@@ -0,0 +1,97 @@
+/*!
+ * FizzBuzz, with the rules handed in instead of written into the branches.
+ *
+ * The usual FizzBuzz is an if / else if ladder, and every new rule means a
+ * new rung (and a new combined case: FizzBuzz, FizzBang, FizzBuzzBang...).
+ * Here the rules are data, a list of (divisor, word) pairs, and a number's
+ * answer is the words of every rule that divides it, in order:
+ *
+ * ```text
+ * rules [(3, "Fizz"), (5, "Buzz")]   9 -> Fizz   10 -> Buzz   15 -> FizzBuzz   7 -> 7
+ * ```
+ *
+ * so the combined cases take care of themselves. The branching that's left
+ * is the one `if` that decides whether any rule matched at all.
+ */
+
+pub struct FizzBuzz {
+    rules: Vec<(u32, String)>,
+}
+
+impl FizzBuzz {
+    // A rule with a divisor of 0 never matches: nothing is a multiple of 0
+    // (except 0, and `n % 0` would panic before we could find that out).
+    pub fn new(rules: Vec<(u32, String)>) -> FizzBuzz {
+        FizzBuzz { rules }
+    }
+
+    // Fizz on multiples of 3, Buzz on multiples of 5
+    pub fn classic() -> FizzBuzz {
+        FizzBuzz::new(vec![(3, String::from("Fizz")), (5, String::from("Buzz"))])
+    }
+
+    pub fn word_for(&self, n: u32) -> String {
+        let mut word = String::new();
+        for (divisor, rule_word) in &self.rules {
+            if n.checked_rem(*divisor) == Some(0) {
+                word.push_str(rule_word);
+            }
+        }
+        if word.is_empty() {
+            n.to_string()
+        } else {
+            word
+        }
+    }
+
+    // one answer per number, for `1..=15`, `90..100` or any other numbers
+    pub fn run(&self, numbers: impl IntoIterator<Item = u32>) -> Vec<String> {
+        numbers.into_iter().map(|n| self.word_for(n)).collect()
+    }
+}
+
+pub fn demo_fizzbuzz() {
+    println!("Classic FizzBuzz, 1 to 15: {}", FizzBuzz::classic().run(1..=15).join(" "));
+    let custom = FizzBuzz::new(vec![
+        (2, String::from("Fizz")),
+        (3, String::from("Buzz")),
+        (7, String::from("Bang")),
+    ]);
+    println!("With 2, 3 and 7 instead, 40 to 45: {}", custom.run(40..46).join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_classic_rules() {
+        assert_eq!(
+            FizzBuzz::classic().run(1..=15),
+            [
+                "1", "2", "Fizz", "4", "Buzz", "Fizz", "7", "8", "Fizz", "Buzz", "11", "Fizz", "13", "14",
+                "FizzBuzz"
+            ]
+        );
+        assert_eq!(FizzBuzz::classic().word_for(0), "FizzBuzz");
+        assert!(FizzBuzz::classic().run(5..5).is_empty());
+    }
+
+    #[test]
+    fn custom_rules_combine_in_order() {
+        let rules = FizzBuzz::new(vec![
+            (7, String::from("Bang")),
+            (2, String::from("Fizz")),
+            (3, String::from("Buzz")),
+        ]);
+        assert_eq!(rules.run(40..=42), ["Fizz", "41", "BangFizzBuzz"]);
+        // any numbers will do, not only ranges
+        assert_eq!(rules.run(vec![9, 14, 1]), ["Buzz", "BangFizz", "1"]);
+    }
+
+    #[test]
+    fn no_rules_or_a_zero_divisor_match_nothing() {
+        assert_eq!(FizzBuzz::new(Vec::new()).run(1..4), ["1", "2", "3"]);
+        assert_eq!(FizzBuzz::new(vec![(0, String::from("Never"))]).run(1..3), ["1", "2"]);
+    }
+}
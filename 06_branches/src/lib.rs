@@ -0,0 +1,261 @@
+/**
+ * Rust branches work like they do in most languages.
+ */
+use common::{Rng, ThreadRng};
+
+// a number from 0 to 9 for the demo to branch on, drawn from `rng`, so a
+// test can hand in a SeededRng and know what it's going to get
+pub fn pick_number(rng: &mut impl Rng) -> i32 {
+    rng.range(0, 10)
+}
+
+// the classic if / else if / else ladder
+pub fn describe_number(number: i32) -> String {
+    if number % 2 == 0 {
+        format!("{} is even", number)
+    } else if number == 5 {
+        format!("{} is equal to 5", number)
+    } else {
+        format!("{} is odd but not 5", number)
+    }
+
+    // !! rust does not support truthiness!!
+    // An {if} can *only* be followed by a bool!
+    // The following will not compile: 
+    //if number {
+    //    println!("This would work if rust supported truthiness");
+    //}
+}
+
+// if is an _expression_, which means you can use it for assignment
+// Remember that any *block* evaluates to its final expression 
+// (And if the final line is not an expression, you cannot use it 
+// in an assignment statement at all, because Rust simply does not 
+// do anything like {nil} as a primitive value! 
+pub fn parity(number: i32) -> &'static str {
+    let result = if number % 2 == 0 {
+        "even" // last line is expression sans semicolon, hence returned
+    } else {
+        "odd" // same
+    };
+    // both branches must have the same type! 
+    // if you change either "even" or "odd" to a number, you can't compile
+    result
+}
+
+// FizzBuzz, with the rules as data rather than as more branches
+pub mod fizzbuzz;
+
+// nested loops, and how to break out of the outer one
+pub mod maze;
+
+// a state machine, fed from a queue by `while let`
+pub mod turnstile;
+
+// practice problems, compiled only with `--features exercises`
+#[cfg(feature = "exercises")]
+pub mod exercises;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("branches", "if/else, loop, while and for", demo_branches),
+    Demo::new("fizzbuzz", "FizzBuzz with rules you choose", fizzbuzz::demo_fizzbuzz),
+    Demo::new("maze", "Loop labels, walking a maze", maze::demo_maze),
+    Demo::new("turnstile", "A state machine driven by while let", turnstile::demo_turnstile),
+];
+
+pub fn demo() {
+    demo_branches();
+    fizzbuzz::demo_fizzbuzz();
+    maze::demo_maze();
+    turnstile::demo_turnstile();
+}
+
+pub fn demo_branches() {
+    // random, unless DEMO_SEED is set (see common/src/random.rs)
+    let number = pick_number(&mut ThreadRng);
+
+    println!("{}", describe_number(number));
+    println!("That random number was {}", parity(number));
+
+    // the `loop` keyword runs forever, 
+    // so you'd better have a strategy to end it other than CTRL-C
+    let result = vanilla_loop();
+    println!("vanilla loop result was {} (expected 20)", result);
+
+    // the `while` keyword is mostly syntatic sugar for loop
+    let countdown = vanilla_while(3);
+    for call in &countdown.calls {
+        println!("{}", call);
+    }
+    println!("After the loop, number was {}", countdown.ended_at);
+
+    // the rust `for` loop is a smart iterator, instead of being index-based
+    let a = [10, 20, 30];
+    for (index, value) in while_indexed(&a) {
+        println!("(while) index {} has value {}", index, value);
+    }
+    for element in for_each_value(&a) {
+        println!("(iterator) the value is: {}", element);
+    }
+    for (i, item) in for_enumerated(&a) {
+        println!("(enumerator) index {} has value {}", i, item);
+    }
+
+    // finally, rust has easy access to numeric ranges  
+    print!("Let's print a few numbers using the .. range operator: ");
+    for number in for_range(1, 5) {
+        print!("{}", number); //
+    }
+    println!();
+    println!(" NB: `1..5` does not include 5!");
+}
+
+// demonstrate the loop keyword
+pub fn vanilla_loop() -> i32 {
+    let mut counter = 0; // `mut` means this is a _mutable_ variable
+    // which means it can be changed, as opposed to simply being shadowed.
+    // Shadowing is a mere parlor trick... true mutation requires {mut}. 
+
+    // again we see that a *block* is an *expression*, so it can be the
+    // value for an assignment statement, or (as here) the last thing in a
+    // function, which makes its value the function's return value
+    loop {
+        counter += 1;
+
+        if counter == 10 {
+            // a `loop` never ends until you call `break`
+            break counter * 2;
+        }
+    }
+}
+
+// what a countdown said, and where its counter ended up
+#[derive(Debug, PartialEq)]
+pub struct Countdown {
+    pub calls: Vec<String>,
+    pub ended_at: i32,
+}
+
+// demonstrate the while keyword
+pub fn vanilla_while(start: i32) -> Countdown {
+    let mut number = start; // again, mutability is strictly *opt-in*
+    let mut calls = Vec::new();
+
+    // {while} is syntactic sugar over {loop}, with easier termination
+    // You don't have to do your own if check within the loop, or call {break}
+    while number > 0 {
+        calls.push(format!("{}!", number));
+        number -= 1;
+    }
+    // on the other hand, you can't use a `while` block to assign,
+    // which was a nifty little fringe benefit of using `loop`
+
+    calls.push(String::from("LIFTOFF!!!"));
+    Countdown { calls, ended_at: number }
+}
+
+// index-based iteration will always be error prone, so the rust `for` 
+// keyword doesn't support it. You can of course use `while` to do your 
+// own brittle index-based iteration, like so: 
+pub fn while_indexed(a: &[i32; 3]) -> Vec<(usize, i32)> {
+    let mut pairs = Vec::new();
+    let mut index = 0;
+
+    while index < 3 {
+        pairs.push((index, a[index]));
+        index += 1;
+    }
+    pairs
+}
+
+// but idiomatic rust is to use `for` with an *iterator*
+// most collections can give you an iterator out of the box
+pub fn for_each_value(a: &[i32]) -> Vec<i32> {
+    let mut values = Vec::new();
+    for element in a.iter() {
+        values.push(*element);
+    }
+    values
+}
+
+// and if you need the index, you get an *enumerator* instead 
+pub fn for_enumerated(a: &[i32]) -> Vec<(usize, i32)> {
+    let mut pairs = Vec::new();
+    for (i, item) in a.iter().enumerate() {
+        pairs.push((i, *item));
+    }
+    pairs
+}
+
+// use '..' to create ranges... snazzy!
+pub fn for_range(start: i32, end: i32) -> Vec<i32> {
+    let mut numbers = Vec::new();
+    for number in start..end {
+        numbers.push(number);
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::SeededRng;
+
+    #[test]
+    fn a_seed_picks_the_same_number() {
+        let number = pick_number(&mut SeededRng::new(42));
+        assert!((0..10).contains(&number));
+        assert_eq!(pick_number(&mut SeededRng::new(42)), number);
+    }
+
+    #[test]
+    fn the_if_ladder() {
+        assert_eq!(describe_number(4), "4 is even");
+        assert_eq!(describe_number(5), "5 is equal to 5");
+        assert_eq!(describe_number(7), "7 is odd but not 5");
+        assert_eq!(describe_number(0), "0 is even");
+    }
+
+    #[test]
+    fn if_as_an_expression() {
+        assert_eq!(parity(2), "even");
+        assert_eq!(parity(3), "odd");
+        assert_eq!(parity(-3), "odd");
+    }
+
+    #[test]
+    fn loop_breaks_with_a_value() {
+        assert_eq!(vanilla_loop(), 20);
+    }
+
+    #[test]
+    fn while_counts_down() {
+        assert_eq!(vanilla_while(3), Countdown {
+            calls: vec![
+                String::from("3!"),
+                String::from("2!"),
+                String::from("1!"),
+                String::from("LIFTOFF!!!"),
+            ],
+            ended_at: 0,
+        });
+        assert_eq!(vanilla_while(0).calls, vec![String::from("LIFTOFF!!!")]);
+    }
+
+    #[test]
+    fn every_kind_of_for_sees_the_same_values() {
+        let a = [10, 20, 30];
+        assert_eq!(while_indexed(&a), for_enumerated(&a));
+        assert_eq!(for_each_value(&a), vec![10, 20, 30]);
+        assert_eq!(for_enumerated(&a), vec![(0, 10), (1, 20), (2, 30)]);
+    }
+
+    #[test]
+    fn ranges_leave_out_the_end() {
+        assert_eq!(for_range(1, 5), vec![1, 2, 3, 4]);
+        assert!(for_range(5, 5).is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+/*!
+ * Finding a way through a maze: a use for loop labels.
+ *
+ * A `break` or `continue` normally means the innermost loop. When loops are
+ * nested, a *label* (a name with a leading quote) lets you say which one:
+ *
+ * ```text
+ * 'rows: for row in grid {
+ *     for cell in row {
+ *         if found(cell) {
+ *             break 'rows;      // out of both loops, not just this one
+ *         }
+ *     }
+ * }
+ * ```
+ *
+ * The maze is text, `#` for walls, `.` for floor, `S` to start and `E` to
+ * end. The search keeps a *frontier* stack of squares still to look at,
+ * and `while let` keeps popping from it until it's empty (no way through)
+ * or the search breaks out early because it reached the end.
+ */
+
+pub type Square = (usize, usize);
+
+// the grid, one Vec of chars per line
+fn grid(maze: &str) -> Vec<Vec<char>> {
+    maze.lines().map(|line| line.chars().collect()).collect()
+}
+
+// Where `target` is, as (row, column). The first one found wins, and once
+// it's found there's no point finishing either loop.
+fn find(grid: &[Vec<char>], target: char) -> Option<Square> {
+    let mut found = None;
+    'rows: for (row, line) in grid.iter().enumerate() {
+        for (column, &cell) in line.iter().enumerate() {
+            if cell == target {
+                found = Some((row, column));
+                break 'rows;
+            }
+        }
+    }
+    found
+}
+
+// the squares next to `square`, up, down, left and right, that are in the
+// grid and not walls (lines may be different lengths)
+fn neighbours(grid: &[Vec<char>], (row, column): Square) -> Vec<Square> {
+    let mut open = Vec::new();
+    let candidates = [
+        row.checked_sub(1).map(|up| (up, column)),
+        Some((row + 1, column)),
+        column.checked_sub(1).map(|left| (row, left)),
+        Some((row, column + 1)),
+    ];
+    for (r, c) in candidates.iter().flatten().copied() {
+        match grid.get(r).and_then(|line| line.get(c)) {
+            Some('#') | None => continue,
+            Some(_) => open.push((r, c)),
+        }
+    }
+    open
+}
+
+// A path from S to E, both included, one step at a time. It's *a* path, not
+// necessarily the shortest (the search goes deep before it goes wide). None
+// if there's no S, no E, or no way from one to the other.
+pub fn solve(maze: &str) -> Option<Vec<Square>> {
+    let grid = grid(maze);
+    let start = find(&grid, 'S')?;
+    let end = find(&grid, 'E')?;
+
+    // how the search got to each square, so the path can be traced back
+    let mut came_from: Vec<Vec<Option<Square>>> = grid.iter().map(|line| vec![None; line.len()]).collect();
+    let mut visited: Vec<Vec<bool>> = grid.iter().map(|line| vec![false; line.len()]).collect();
+    let mut frontier = vec![start];
+    let mut reached = start == end;
+
+    'search: while let Some(square) = frontier.pop() {
+        if visited[square.0][square.1] {
+            continue;
+        }
+        visited[square.0][square.1] = true;
+        for next in neighbours(&grid, square) {
+            if visited[next.0][next.1] {
+                continue;
+            }
+            came_from[next.0][next.1] = Some(square);
+            if next == end {
+                reached = true;
+                // done with this square's neighbours *and* the whole search
+                break 'search;
+            }
+            frontier.push(next);
+        }
+    }
+    if !reached {
+        return None;
+    }
+
+    let mut path = vec![end];
+    let mut square = end;
+    while let Some(previous) = came_from[square.0][square.1] {
+        path.push(previous);
+        square = previous;
+    }
+    path.reverse();
+    Some(path)
+}
+
+// the maze again, with the path's floor squares marked
+pub fn draw(maze: &str, path: &[Square]) -> String {
+    let mut grid = grid(maze);
+    for &(row, column) in path {
+        if grid[row][column] == '.' {
+            grid[row][column] = '*';
+        }
+    }
+    let lines: Vec<String> = grid.iter().map(|line| line.iter().collect()).collect();
+    lines.join("\n")
+}
+
+pub const DEMO_MAZE: &str = "\
+#########
+#S..#...#
+##.##.#.#
+#..#..#.#
+#.##.##.#
+#....#.E#
+#########";
+
+pub fn demo_maze() {
+    match solve(DEMO_MAZE) {
+        Some(path) => {
+            println!("A way through the maze, {} steps long:", path.len() - 1);
+            println!("{}", draw(DEMO_MAZE, &path));
+        }
+        None => println!("No way through the maze"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every step is to a neighbouring square, and none of them are walls
+    fn is_a_walk(maze: &str, path: &[Square]) -> bool {
+        let grid = grid(maze);
+        let steps_ok = path.windows(2).all(|pair| {
+            let ((r1, c1), (r2, c2)) = (pair[0], pair[1]);
+            r1.abs_diff(r2) + c1.abs_diff(c2) == 1
+        });
+        steps_ok && path.iter().all(|&(r, c)| grid[r][c] != '#')
+    }
+
+    #[test]
+    fn finds_a_way_through() {
+        let path = solve(DEMO_MAZE).unwrap();
+        assert_eq!(path.first(), Some(&(1, 1)));
+        assert_eq!(path.last(), Some(&(5, 7)));
+        assert!(is_a_walk(DEMO_MAZE, &path));
+    }
+
+    #[test]
+    fn a_corridor_has_one_path() {
+        assert_eq!(solve("S..E"), Some(vec![(0, 0), (0, 1), (0, 2), (0, 3)]));
+        assert_eq!(solve("S\n.\nE"), Some(vec![(0, 0), (1, 0), (2, 0)]));
+        assert_eq!(draw("#S..E", &solve("#S..E").unwrap()), "#S**E");
+    }
+
+    #[test]
+    fn walls_in_the_way() {
+        assert_eq!(solve("S.#.E"), None);
+        assert_eq!(solve("S#\n#E"), None);
+    }
+
+    #[test]
+    fn needs_a_start_and_an_end() {
+        assert_eq!(solve("...E"), None);
+        assert_eq!(solve("S..."), None);
+        assert_eq!(solve(""), None);
+    }
+
+    #[test]
+    fn ragged_lines_are_fine() {
+        let maze = "S....\n#\n#.E";
+        assert_eq!(solve(maze), None);
+        let maze = "S.\n.#\n..E";
+        let path = solve(maze).unwrap();
+        assert!(is_a_walk(maze, &path));
+        assert_eq!(path.last(), Some(&(2, 2)));
+    }
+}
@@ -0,0 +1,156 @@
+/*!
+ * A coin-operated turnstile, the textbook state machine: a `match` on a
+ * (state, event) pair, one arm per transition.
+ *
+ * ```text
+ * state      event   next state   what happens
+ * Locked     Coin    Unlocked     it unlocks
+ * Locked     Push    Locked       it won't turn
+ * Unlocked   Coin    Unlocked     it keeps the coin (it was already unlocked)
+ * Unlocked   Push    Locked       someone goes through, and it locks again
+ * ```
+ *
+ * The events wait in a queue, and `while let Some(event) = queue.pop()`
+ * takes them one at a time until there are none left: the loop runs for
+ * as long as the pattern matches, and pop() gives None when it's empty.
+ *
+ * pop() takes from the *end* of a Vec, so the queue is kept back to front,
+ * with the next event last. (A VecDeque can take from either end, and
+ * would do just as well.)
+ */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Locked,
+    Unlocked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    Coin,
+    Push,
+}
+
+// what the person at the turnstile sees happen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Unlocks,
+    Blocks,
+    KeepsTheCoin,
+    LetsThrough,
+}
+
+// one line of the log: one event, and what it did
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry {
+    pub from: State,
+    pub event: Event,
+    pub to: State,
+    pub action: Action,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match self.action {
+            Action::Unlocks => "it unlocks",
+            Action::Blocks => "it won't turn",
+            Action::KeepsTheCoin => "it keeps the coin",
+            Action::LetsThrough => "someone goes through",
+        };
+        write!(f, "{:?} + {:?} -> {:?}: {}", self.from, self.event, self.to, action)
+    }
+}
+
+// the table above, as a match
+pub fn next(state: State, event: Event) -> (State, Action) {
+    match (state, event) {
+        (State::Locked, Event::Coin) => (State::Unlocked, Action::Unlocks),
+        (State::Locked, Event::Push) => (State::Locked, Action::Blocks),
+        (State::Unlocked, Event::Coin) => (State::Unlocked, Action::KeepsTheCoin),
+        (State::Unlocked, Event::Push) => (State::Locked, Action::LetsThrough),
+    }
+}
+
+// Every event in `events`, in order, starting from `start`: the log, one
+// entry per event
+pub fn run(start: State, events: &[Event]) -> Vec<Entry> {
+    // back to front, so that pop() hands out events[0] first
+    let mut queue: Vec<Event> = events.iter().rev().copied().collect();
+    let mut state = start;
+    let mut log = Vec::new();
+    while let Some(event) = queue.pop() {
+        let (to, action) = next(state, event);
+        log.push(Entry { from: state, event, to, action });
+        state = to;
+    }
+    log
+}
+
+pub fn demo_turnstile() {
+    println!("--- Turnstile Demonstration Begins --- ");
+    let events = [Event::Push, Event::Coin, Event::Coin, Event::Push, Event::Push];
+    for entry in run(State::Locked, &events) {
+        println!("{}", entry);
+    }
+    println!("--- Turnstile Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Action::*;
+    use Event::*;
+    use State::*;
+
+    fn entry(from: State, event: Event, to: State, action: Action) -> Entry {
+        Entry { from, event, to, action }
+    }
+
+    #[test]
+    fn pay_then_go() {
+        assert_eq!(
+            run(Locked, &[Coin, Push]),
+            [entry(Locked, Coin, Unlocked, Unlocks), entry(Unlocked, Push, Locked, LetsThrough)]
+        );
+    }
+
+    #[test]
+    fn pushing_without_paying() {
+        assert_eq!(
+            run(Locked, &[Push, Push, Coin]),
+            [
+                entry(Locked, Push, Locked, Blocks),
+                entry(Locked, Push, Locked, Blocks),
+                entry(Locked, Coin, Unlocked, Unlocks),
+            ]
+        );
+    }
+
+    #[test]
+    fn paying_twice_lets_one_through() {
+        let log = run(Locked, &[Coin, Coin, Push, Push]);
+        let actions: Vec<Action> = log.iter().map(|e| e.action).collect();
+        assert_eq!(actions, [Unlocks, KeepsTheCoin, LetsThrough, Blocks]);
+        assert_eq!(log.last().unwrap().to, Locked);
+    }
+
+    #[test]
+    fn each_entry_starts_where_the_last_ended() {
+        let log = run(Unlocked, &[Push, Coin, Push, Coin, Coin, Push, Push]);
+        assert_eq!(log.len(), 7);
+        assert_eq!(log[0].from, Unlocked);
+        assert!(log.windows(2).all(|pair| pair[0].to == pair[1].from));
+    }
+
+    #[test]
+    fn no_events_no_log() {
+        assert!(run(Locked, &[]).is_empty());
+    }
+
+    #[test]
+    fn log_lines() {
+        assert_eq!(entry(Locked, Coin, Unlocked, Unlocks).to_string(), "Locked + Coin -> Unlocked: it unlocks");
+        assert_eq!(run(Unlocked, &[Coin])[0].to_string(), "Unlocked + Coin -> Unlocked: it keeps the coin");
+    }
+}
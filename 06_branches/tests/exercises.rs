@@ -0,0 +1,29 @@
+/**
+ * The checks for src/exercises.rs. Spoilers below!
+ *
+ * These only build with `--features exercises` (see Cargo.toml), so the
+ * ordinary `cargo test` never trips over the unfinished exercises. Each test
+ * is named after the exercise it checks, which is how `runner verify` tells
+ * which ones pass.
+ */
+use branches::exercises;
+
+#[test]
+fn fizzbuzz() {
+    let answers: Vec<String> = (1..=15).map(exercises::fizzbuzz).collect();
+    assert_eq!(answers[..5], ["1", "2", "Fizz", "4", "Buzz"]);
+    assert_eq!(answers[14], "FizzBuzz");
+}
+
+#[test]
+fn sum_to() {
+    assert_eq!(exercises::sum_to(0), 0);
+    assert_eq!(exercises::sum_to(1), 1);
+    assert_eq!(exercises::sum_to(100), 5050);
+}
+
+#[test]
+fn count_down() {
+    assert_eq!(exercises::count_down(3), vec![3, 2, 1]);
+    assert!(exercises::count_down(0).is_empty());
+}
@@ -0,0 +1,222 @@
+/*!
+ * An arena: one owner for a whole crowd of values, which all live exactly
+ * as long as it does.
+ *
+ * The usual story is one owner per value, and a linked structure makes that
+ * awkward. Who owns a node that two others point at? In a ring, where the
+ * last node points back at the first, who owns whom? The answers elsewhere
+ * in the book are Box (one owner, so no sharing) or Rc and RefCell (shared
+ * owners, counted at run time, and a cycle of Rcs is never freed: see
+ * 21_smart_pointers).
+ *
+ * An arena answers differently: the *arena* owns every node, and the nodes
+ * only hold plain `&` references to each other. Those references borrow
+ * from the arena, so the borrow checker makes sure none of them outlives
+ * it, and when the arena goes, every node goes with it, at once, cycles
+ * and all. Nothing is counted, and nothing is freed one at a time.
+ *
+ * The trick is that alloc() takes `&self`, not `&mut self`:
+ *
+ * ```text
+ * let a = arena.alloc(1);
+ * let b = arena.alloc(2);   // with &mut self, this would end `a`'s borrow
+ * println!("{} {}", a, b);  // and this wouldn't compile
+ * ```
+ *
+ * So the arena needs interior mutability (21_smart_pointers again). Each
+ * slot is a OnceCell, which can be filled through a `&` exactly once, and
+ * hands out a `&` to what's in it forever after. The arena just keeps a
+ * "bump" index to the next empty slot, in a Cell. The slots are all made
+ * up front, so the Vec never reallocates and never moves a value out from
+ * under a reference; the price is a fixed capacity. (Arenas that grow, like
+ * the typed-arena crate's, need `unsafe` for that part: see 29_unsafe.)
+ */
+use std::cell::{Cell, OnceCell};
+
+pub struct Arena<T> {
+    slots: Vec<OnceCell<T>>,
+    // the next empty slot: everything before it is full
+    next: Cell<usize>,
+}
+
+impl<T> Arena<T> {
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena { slots: (0..capacity).map(|_| OnceCell::new()).collect(), next: Cell::new(0) }
+    }
+
+    // Move `value` into the arena, and borrow it back for as long as the
+    // arena lives. When it's full, the value comes back as the Err.
+    pub fn try_alloc(&self, value: T) -> Result<&T, T> {
+        let index = self.next.get();
+        let slot = match self.slots.get(index) {
+            Some(slot) => slot,
+            None => return Err(value),
+        };
+        self.next.set(index + 1);
+        // the slot is empty (the index only goes up), so this fills it
+        Ok(slot.get_or_init(|| value))
+    }
+
+    // The same, but it panics when full
+    pub fn alloc(&self, value: T) -> &T {
+        match self.try_alloc(value) {
+            Ok(value) => value,
+            Err(_) => panic!("the arena is full ({} values)", self.capacity()),
+        }
+    }
+
+    // how many values have been allocated
+    pub fn len(&self) -> usize {
+        self.next.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    // everything allocated so far, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.slots[..self.len()].iter().filter_map(OnceCell::get)
+    }
+}
+
+// A node that points at another node in the same arena. The Cell is there
+// so the link can be set *after* both ends exist, through a `&`: the only
+// way to close a ring.
+pub struct Node<'a> {
+    pub name: &'static str,
+    pub next: Cell<Option<&'a Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(name: &'static str) -> Node<'a> {
+        Node { name, next: Cell::new(None) }
+    }
+}
+
+// the names met following `next` from `start`, for at most `steps` nodes
+pub fn walk<'a>(start: &'a Node<'a>, steps: usize) -> Vec<&'static str> {
+    let mut names = Vec::new();
+    let mut node = Some(start);
+    while let Some(current) = node {
+        if names.len() == steps {
+            break;
+        }
+        names.push(current.name);
+        node = current.next.get();
+    }
+    names
+}
+
+// A ring of nodes, each pointing at the next, and the last back at the
+// first. With Rc that's a leak; here it's just references.
+pub fn ring<'a>(arena: &'a Arena<Node<'a>>, names: &[&'static str]) -> Option<&'a Node<'a>> {
+    let nodes: Vec<&Node> = names.iter().map(|&name| arena.alloc(Node::new(name))).collect();
+    for pair in nodes.windows(2) {
+        pair[0].next.set(Some(pair[1]));
+    }
+    let (first, last) = (nodes.first()?, nodes.last()?);
+    last.next.set(Some(first));
+    Some(first)
+}
+
+pub fn demo_arena() {
+    println!("--- Arena Demonstration Begins --- ");
+    let numbers = Arena::with_capacity(3);
+    let one = numbers.alloc(1);
+    let two = numbers.alloc(2);
+    // both borrows are still alive, though the arena changed in between
+    println!("allocated {} and {}: {} of {} slots used", one, two, numbers.len(), numbers.capacity());
+    numbers.alloc(3);
+    println!("and when it's full, try_alloc hands the value back: {:?}", numbers.try_alloc(4));
+
+    let nodes = Arena::with_capacity(4);
+    let north = ring(&nodes, &["north", "east", "south", "west"]).unwrap();
+    println!("round the ring twice: {}", walk(north, 8).join(" -> "));
+    println!("every node in the arena: {:?}", nodes.iter().map(|node| node.name).collect::<Vec<_>>());
+    println!("(and they all go at once, when `nodes` does)");
+    println!("--- Arena Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn counts_allocations() {
+        let arena = Arena::with_capacity(2);
+        assert!(arena.is_empty());
+        assert_eq!((arena.len(), arena.capacity()), (0, 2));
+        arena.alloc("a");
+        assert_eq!(arena.len(), 1);
+        arena.alloc("b");
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena.try_alloc("c"), Err("c"));
+        // a failed allocation doesn't count
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena.iter().copied().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the arena is full (1 values)")]
+    fn alloc_panics_when_full() {
+        let arena = Arena::with_capacity(1);
+        arena.alloc(1);
+        arena.alloc(2);
+    }
+
+    #[test]
+    fn earlier_borrows_survive_later_allocations() {
+        let arena = Arena::with_capacity(100);
+        let first = arena.alloc(String::from("first"));
+        let address = first as *const String;
+        let rest: Vec<&String> = (0..99).map(|i| arena.alloc(i.to_string())).collect();
+        // still the same String, in the same place
+        assert_eq!(first, "first");
+        assert_eq!(first as *const String, address);
+        assert_eq!(rest[98], "98");
+        assert!(std::ptr::eq(arena.iter().next().unwrap(), first));
+    }
+
+    #[test]
+    fn rings_go_round() {
+        let arena = Arena::with_capacity(3);
+        let a = ring(&arena, &["a", "b", "c"]).unwrap();
+        assert_eq!(walk(a, 7), ["a", "b", "c", "a", "b", "c", "a"]);
+        // a ring of one points at itself
+        let solo = Arena::with_capacity(1);
+        let only = ring(&solo, &["only"]).unwrap();
+        assert!(std::ptr::eq(only.next.get().unwrap(), only));
+        assert!(ring(&Arena::with_capacity(0), &[]).is_none());
+    }
+
+    #[test]
+    fn a_chain_ends() {
+        let arena = Arena::with_capacity(2);
+        let tail = arena.alloc(Node::new("tail"));
+        let head = arena.alloc(Node::new("head"));
+        head.next.set(Some(tail));
+        assert_eq!(walk(head, 10), ["head", "tail"]);
+    }
+
+    #[test]
+    fn everything_is_dropped_with_the_arena() {
+        let counter = Rc::new(());
+        {
+            let arena = Arena::with_capacity(5);
+            for _ in 0..5 {
+                arena.alloc(Rc::clone(&counter));
+            }
+            assert_eq!(Rc::strong_count(&counter), 6);
+            // a value the arena turned down comes back, and is dropped as usual
+            drop(arena.try_alloc(Rc::clone(&counter)));
+            assert_eq!(Rc::strong_count(&counter), 6);
+        }
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
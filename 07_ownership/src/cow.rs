@@ -0,0 +1,192 @@
+/*!
+ * When does a copy actually happen? One job, done three ways: trim the
+ * whitespace off a list of names.
+ *
+ * ```text
+ * fn trim_in_place(names: &mut Vec<String>)                  borrow it, change it
+ * fn trim_owned(names: Vec<String>) -> Vec<String>            take it, change it, give it back
+ * fn trim_cow(names: Cow<[String]>) -> Cow<[String]>          borrow it, and copy only if it must
+ * ```
+ *
+ * None of the three clones anything of its own accord. The difference is
+ * what the *caller* has to do if it wants to keep the original list as it
+ * was. With the first two, it has to clone the whole list up front, just in
+ * case, whether or not there turns out to be anything to trim.
+ *
+ * A `Cow` ("clone on write") is either Borrowed or Owned. trim_cow() starts
+ * with a Borrowed list, and only calls `to_mut()` when a name really needs
+ * trimming; `to_mut()` is the one place the clone happens, and it happens
+ * at most once. A list that was clean all along comes back still Borrowed,
+ * and nothing was copied at all.
+ *
+ * Each function says what it did in an Outcome, so compare() can put the
+ * three side by side, for a clean list and a messy one.
+ */
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outcome {
+    // how many names needed trimming
+    pub changed: usize,
+    // whether the list was copied to get there
+    pub cloned: bool,
+}
+
+fn needs_trimming(name: &str) -> bool {
+    name.trim().len() != name.len()
+}
+
+// (clippy would rather this took a `&mut [String]`, which would do just as
+// well, since it never adds or removes a name. It's a Vec here to match the
+// other two, so we overrule it.)
+#[allow(clippy::ptr_arg)]
+pub fn trim_in_place(names: &mut Vec<String>) -> Outcome {
+    let mut changed = 0;
+    for name in names.iter_mut() {
+        if needs_trimming(name) {
+            *name = name.trim().to_string();
+            changed += 1;
+        }
+    }
+    Outcome { changed, cloned: false }
+}
+
+// the list is ours now, so it can be changed where it is, and handed back
+pub fn trim_owned(mut names: Vec<String>) -> (Vec<String>, Outcome) {
+    let outcome = trim_in_place(&mut names);
+    (names, outcome)
+}
+
+pub fn trim_cow(mut names: Cow<[String]>) -> (Cow<[String]>, Outcome) {
+    let borrowed = matches!(names, Cow::Borrowed(_));
+    let mut changed = 0;
+    for i in 0..names.len() {
+        if needs_trimming(&names[i]) {
+            // the first time through, this clones a Borrowed list into an
+            // Owned one; after that, it's just a &mut into the copy
+            let trimmed = names[i].trim().to_string();
+            names.to_mut()[i] = trimmed;
+            changed += 1;
+        }
+    }
+    let cloned = borrowed && matches!(names, Cow::Owned(_));
+    (names, Outcome { changed, cloned })
+}
+
+// one line of the comparison: what it took to trim `names` *and* keep the
+// original as it was
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub approach: &'static str,
+    pub outcome: Outcome,
+}
+
+pub fn compare(names: &[String]) -> Vec<Row> {
+    // &mut and by-value both need a copy of their own to work on
+    let mut copy = names.to_vec();
+    let in_place = Outcome { cloned: true, ..trim_in_place(&mut copy) };
+    let (_, owned) = trim_owned(names.to_vec());
+    let owned = Outcome { cloned: true, ..owned };
+    // and the Cow just borrows
+    let (_, cow) = trim_cow(Cow::Borrowed(names));
+    vec![
+        Row { approach: "&mut Vec<String>", outcome: in_place },
+        Row { approach: "Vec<String>", outcome: owned },
+        Row { approach: "Cow<[String]>", outcome: cow },
+    ]
+}
+
+pub fn report(rows: &[Row]) -> String {
+    let mut lines = vec![format!("{:<18}{:<10}{}", "approach", "changed", "cloned")];
+    for row in rows {
+        lines.push(format!("{:<18}{:<10}{}", row.approach, row.outcome.changed, row.outcome.cloned));
+    }
+    lines.join("\n")
+}
+
+fn names(list: &[&str]) -> Vec<String> {
+    list.iter().map(|name| name.to_string()).collect()
+}
+
+pub fn demo_cow() {
+    println!("--- Clone on Write Demonstration Begins --- ");
+    let clean = names(&["Ada", "Grace", "Alan"]);
+    let messy = names(&["Ada", "  Grace", "Alan\n"]);
+    for (label, list) in [("clean", &clean), ("messy", &messy)] {
+        println!("Trimming the {} list {:?}, and keeping the original:", label, list);
+        println!("{}", report(&compare(list)));
+    }
+    let (trimmed, _) = trim_cow(Cow::Borrowed(&messy));
+    println!("the trimmed copy is {:?}, and the original is still {:?}", trimmed, messy);
+    println!("--- Clone on Write Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_three_trim_the_same() {
+        let messy = names(&[" a", "b", "c "]);
+        let mut in_place = messy.clone();
+        trim_in_place(&mut in_place);
+        let (owned, _) = trim_owned(messy.clone());
+        let (cow, _) = trim_cow(Cow::Borrowed(&messy));
+        assert_eq!(in_place, ["a", "b", "c"]);
+        assert_eq!(owned, in_place);
+        assert_eq!(cow.as_ref(), in_place.as_slice());
+    }
+
+    #[test]
+    fn a_clean_list_is_never_copied_by_cow() {
+        let clean = names(&["a", "b"]);
+        let (result, outcome) = trim_cow(Cow::Borrowed(&clean));
+        assert_eq!(outcome, Outcome { changed: 0, cloned: false });
+        // still pointing at the very same Strings
+        assert!(matches!(result, Cow::Borrowed(slice) if std::ptr::eq(slice, clean.as_slice())));
+    }
+
+    #[test]
+    fn a_messy_list_is_copied_once() {
+        let messy = names(&[" a ", " b ", "c"]);
+        let (result, outcome) = trim_cow(Cow::Borrowed(&messy));
+        assert_eq!(outcome, Outcome { changed: 2, cloned: true });
+        assert!(matches!(result, Cow::Owned(_)));
+        // and the original hasn't changed
+        assert_eq!(messy, [" a ", " b ", "c"]);
+    }
+
+    #[test]
+    fn an_owned_cow_needs_no_copy() {
+        let (result, outcome) = trim_cow(Cow::Owned(names(&[" a"])));
+        assert_eq!(outcome, Outcome { changed: 1, cloned: false });
+        assert_eq!(result.into_owned(), ["a"]);
+    }
+
+    #[test]
+    fn borrowing_and_owning_never_clone_by_themselves() {
+        let mut list = names(&[" a"]);
+        assert_eq!(trim_in_place(&mut list), Outcome { changed: 1, cloned: false });
+        let (_, outcome) = trim_owned(names(&[" a"]));
+        assert_eq!(outcome, Outcome { changed: 1, cloned: false });
+    }
+
+    #[test]
+    fn keeping_the_original_costs_a_clone_except_with_cow() {
+        let clean: Vec<bool> = compare(&names(&["a"])).iter().map(|row| row.outcome.cloned).collect();
+        assert_eq!(clean, [true, true, false]);
+        let messy: Vec<bool> = compare(&names(&["a "])).iter().map(|row| row.outcome.cloned).collect();
+        assert_eq!(messy, [true, true, true]);
+    }
+
+    #[test]
+    fn reports_line_up() {
+        assert_eq!(
+            report(&compare(&names(&["a", " b"]))),
+            "approach          changed   cloned\n\
+             &mut Vec<String>  1         true\n\
+             Vec<String>       1         true\n\
+             Cow<[String]>     1         true"
+        );
+    }
+}
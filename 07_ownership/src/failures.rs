@@ -0,0 +1,66 @@
+/*!
+ * The borrow rules, broken at run time: `cargo run -- --demo <name>` (see
+ * common/src/failures.rs).
+ *
+ * The commented-out lines in lib.rs can't be run at all: a second `&mut`
+ * next to a `&` is a *compile* error, so there's no program to run. But the
+ * same rule can be checked at run time instead, by `RefCell` (which chapter
+ * 21 covers properly). It hands out borrows from a `&` reference, counts
+ * them as it goes, and breaks the rule the only way left to it: by
+ * panicking, or, if you ask first with `try_borrow_mut`, by saying no.
+ */
+use common::Demo;
+use std::cell::RefCell;
+
+pub const FAILURES: &[Demo] = &[
+    Demo::new("two_mutable_borrows", "a second borrow_mut() while the first is alive panics", two_mutable_borrows),
+    Demo::new("read_while_writing", "borrow() during a borrow_mut() panics too", read_while_writing),
+    Demo::new("try_borrow", "try_borrow_mut() turns the panic into an Err", try_borrow),
+];
+
+// the runtime twin of `let ack = &mut clone; let wtf = &mut clone;`
+fn two_mutable_borrows() {
+    let clone = RefCell::new(String::from("Hello, world"));
+    let mut ack = clone.borrow_mut();
+    let mut wtf = clone.borrow_mut(); // panics: already mutably borrowed
+    ack.push('!');
+    wtf.push('?');
+}
+
+// and of `let ack = &mut bar;` next to the read-only `zug`
+fn read_while_writing() {
+    let bar = RefCell::new(String::from("Hello, world"));
+    let mut ack = bar.borrow_mut();
+    let zug = bar.borrow(); // panics: already mutably borrowed
+    ack.push('!');
+    println!("{}", zug);
+}
+
+fn try_borrow() {
+    let clone = RefCell::new(String::from("Hello, world"));
+    let ack = clone.borrow_mut();
+    match clone.try_borrow_mut() {
+        Ok(_) => println!("a second mutable borrow? that shouldn't happen"),
+        Err(e) => println!("second mutable borrow refused: {}", e),
+    }
+    // once the first borrow is gone, asking again works
+    drop(ack);
+    println!("after dropping the first borrow: {}", clone.try_borrow_mut().is_ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::failures::run;
+
+    #[test]
+    fn overlapping_borrows_panic() {
+        assert!(run(FAILURES, "two_mutable_borrows").unwrap_err().contains("already"));
+        assert!(run(FAILURES, "read_while_writing").unwrap_err().contains("already"));
+    }
+
+    #[test]
+    fn try_borrow_mut_does_not() {
+        assert_eq!(run(FAILURES, "try_borrow"), Ok(()));
+    }
+}
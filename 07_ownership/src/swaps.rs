@@ -0,0 +1,154 @@
+/*!
+ * Moving a value out from behind a `&mut`, without cloning it.
+ *
+ * A `&mut` lets you change a value, but not move it out: the owner is
+ * still going to want *something* there when the borrow ends.
+ *
+ * ```text
+ * fn drain(inbox: &mut Inbox) -> Vec<String> {
+ *     inbox.messages     // error: cannot move out of `inbox.messages`
+ * }
+ * ```
+ *
+ * `.clone()` would compile, but copies every message just to throw the
+ * originals away. `std::mem` has three ways out, which all leave something
+ * behind in exchange:
+ *
+ * ```text
+ * mem::take(&mut x)          x's value out, Default::default() left behind
+ * mem::replace(&mut x, y)    x's value out, y left behind
+ * mem::swap(&mut x, &mut y)  each gets the other's value
+ * ```
+ *
+ * None of them copy what's inside: a String or a Vec moves by copying its
+ * pointer, length and capacity, however much text or how many items it holds.
+ */
+use std::mem;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Inbox {
+    pub messages: Vec<String>,
+    pub status: String,
+}
+
+impl Inbox {
+    // every message so far, leaving the inbox empty (an empty Vec is
+    // Vec's Default, and doesn't allocate)
+    pub fn drain(&mut self) -> Vec<String> {
+        mem::take(&mut self.messages)
+    }
+
+    // a new status, handing back the old one
+    pub fn set_status(&mut self, status: &str) -> String {
+        mem::replace(&mut self.status, String::from(status))
+    }
+}
+
+// the two Strings trade places, whatever their lengths
+pub fn trade(left: &mut String, right: &mut String) {
+    mem::swap(left, right);
+}
+
+// A post moves from one state to the next, and each state owns its text.
+// With only a `&mut self`, the text can't be moved from one variant to the
+// other, but the whole post can be replaced, handing back the old one.
+#[derive(Debug, PartialEq)]
+pub enum Post {
+    Draft(String),
+    Published(String),
+    Deleted,
+}
+
+impl Post {
+    // a draft goes live with the very same String; anything else stays as it was
+    pub fn publish(&mut self) {
+        *self = match mem::replace(self, Post::Deleted) {
+            Post::Draft(text) => Post::Published(text),
+            other => other,
+        };
+    }
+
+    // whatever text there was, with the post left deleted
+    pub fn delete(&mut self) -> Option<String> {
+        match mem::replace(self, Post::Deleted) {
+            Post::Draft(text) | Post::Published(text) => Some(text),
+            Post::Deleted => None,
+        }
+    }
+}
+
+pub fn demo_swaps() {
+    let mut inbox = Inbox { messages: vec![String::from("hi"), String::from("lunch?")], status: String::from("away") };
+    let drained = inbox.drain();
+    println!("take: drained {:?}, leaving {:?}", drained, inbox.messages);
+    let old = inbox.set_status("back");
+    println!("replace: status was {:?}, is now {:?}", old, inbox.status);
+
+    let (mut left, mut right) = (String::from("left"), String::from("right"));
+    trade(&mut left, &mut right);
+    println!("swap: left is {:?} and right is {:?}", left, right);
+
+    let mut post = Post::Draft(String::from("Hello, world"));
+    post.publish();
+    println!("replace on an enum: {:?}", post);
+    let text = post.delete();
+    println!("deleted, giving back {:?} and leaving {:?}", text, post);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_leaves_the_default() {
+        let mut inbox = Inbox { messages: vec![String::from("a"), String::from("b")], status: String::from("on") };
+        let before = inbox.messages.as_ptr();
+        let drained = inbox.drain();
+        assert_eq!(drained, ["a", "b"]);
+        // the same buffer, moved rather than copied
+        assert_eq!(drained.as_ptr(), before);
+        assert!(inbox.messages.is_empty());
+        assert_eq!(inbox.status, "on");
+        assert!(inbox.drain().is_empty());
+    }
+
+    #[test]
+    fn replace_hands_back_the_old_value() {
+        let mut inbox = Inbox::default();
+        assert_eq!(inbox.set_status("busy"), "");
+        assert_eq!(inbox.status, "busy");
+        assert_eq!(inbox.set_status("free"), "busy");
+        assert_eq!(inbox.status, "free");
+    }
+
+    #[test]
+    fn swap_trades_places() {
+        let mut left = String::from("short");
+        let mut right = String::from("a good deal longer");
+        trade(&mut left, &mut right);
+        assert_eq!(left, "a good deal longer");
+        assert_eq!(right, "short");
+    }
+
+    #[test]
+    fn posts_move_their_text_between_states() {
+        let mut post = Post::Draft(String::from("news"));
+        let before = match &post {
+            Post::Draft(text) => text.as_ptr(),
+            _ => unreachable!(),
+        };
+        post.publish();
+        match &post {
+            Post::Published(text) => assert_eq!(text.as_ptr(), before),
+            other => panic!("expected a published post, got {:?}", other),
+        }
+        // publishing twice changes nothing
+        post.publish();
+        assert_eq!(post, Post::Published(String::from("news")));
+        assert_eq!(post.delete(), Some(String::from("news")));
+        assert_eq!(post, Post::Deleted);
+        assert_eq!(post.delete(), None);
+        post.publish();
+        assert_eq!(post, Post::Deleted);
+    }
+}
@@ -0,0 +1,26 @@
+/*!
+ * Exercises for chapter 8: slices.
+ *
+ * Check them with `cargo run -p runner -- verify 08` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+// The book's own exercise: everything up to the first space (or the whole
+// string, if there isn't one). Return a slice, not a new String!
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
+
+// The last `n` bytes of `s`, or all of it if it's shorter than that.
+// (Stick to plain ASCII text here: slicing through the middle of a
+// multi-byte character panics.)
+pub fn last_n(s: &str, n: usize) -> &str {
+    todo!()
+}
+
+// Everything except the first and last elements. Slices that are too
+// short to have a middle give back an empty slice.
+pub fn middle(a: &[i32]) -> &[i32] {
+    todo!()
+}
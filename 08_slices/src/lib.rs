@@ -0,0 +1,138 @@
+/**
+ * Slices are references to _subsets_ of *collections*. Whenever you have any
+ * sequential collection -- whether a true array or merely array-like, such as
+ * a String or Vector -- you can create and manipulate *slice references* to  
+ * your chosen subsets of that collection. 
+ * 
+ * Slices are a data type all their own, with some special rules for how they
+ * are defined and manipulated. Thus they were a bit too complex to include
+ * in the discussion of simple data types. At the same time, they are best
+ * understood as a language primitive, and not some kind of higher-level class
+ * from the standard library (e.g. like a Vector or the ubiquitous Option<T>).
+ * 
+ * A slice is a __compound__ data type, just like an array. Where an array is
+ * defined by its type and its length, a slice is defined by a collection, a
+ * start index, and an end index. Take away any of those three and it's not a
+ * valid slice any more. 
+ * 
+ * Hence this is the general form:
+ *        
+ *       &foo[2..5]
+ * 
+ * Where `foo` is a collection (which must have been defined before this line!),
+ * and the two indices live inside the square brackets. 
+ */
+// most of our examples will be with a big-S String. 
+// This is a classic example of an array-like collection.
+pub const QUICK_FOX: &str = "The quick brown fox";
+
+// next, we take a variety of slices from it. Each fn borrows the text and
+// hands back a slice of it, so nothing is copied along the way.
+// As always, `&` should be read as "a read-only reference"
+pub fn second_word(s: &str) -> &str {
+    &s[4..9]
+    // As usual, the start index is the first to be *included*
+    // and the last index is the first to be *excluded*
+}
+
+// you can *include* the final index by adding an `=`
+pub fn second_word_inclusive(s: &str) -> &str {
+    &s[4..=8]
+    // This looks awkward at first, but it's better than the alternative of
+    // fiddling with a `+ 1` or `-1` on those occasions when you need it. 
+}
+
+// as in Python, leaving off the first index is synonymous with 0
+pub fn first_word_both_ways(s: &str) -> [&str; 2] {
+    let the = &s[..3];
+    let other_the = &s[0..3];
+    [the, other_the]
+}
+
+// and leaving off the final index is synonymous with 'to the end'
+pub fn last_word_both_ways(s: &str) -> [&str; 2] {
+    let fox = &s[16..19]; // this way you have go *past* the length, yuck!
+    let fox2 = &s[16..]; // this way you don't even need to know the length
+    [fox, fox2]
+}
+
+// which means you can slice the whole thing super easily, 
+// without resorting to a clumsy reference to `.length` at the end!
+// (clippy points out that `s` already *is* that slice; we spell it out anyway)
+#[allow(clippy::redundant_slicing)]
+pub fn whole_thing(s: &str) -> &str {
+    &s[..] // slice of the whole thing, hooray
+}
+
+// finding words, rather than knowing where they are in advance
+pub mod words;
+pub use words::{first_word, nth_word, word_bounds};
+
+// slices of numbers, cut into overlapping windows or even chunks
+pub mod windows;
+pub use windows::{chunks_rounded, moving_average, windows_of};
+
+// and a type of our own that can be indexed and sliced like one
+pub mod track;
+pub use track::Track;
+
+// practice problems, compiled only with `--features exercises`
+#[cfg(feature = "exercises")]
+pub mod exercises;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("slices", "Slicing a String every which way", demo_strings),
+    Demo::new("windows", "Windows, chunks and a moving average over numbers", windows::demo_windows),
+    Demo::new("track", "Implementing Index for a type of our own", track::demo_track),
+];
+
+pub fn demo() {
+    demo_strings();
+    windows::demo_windows();
+    track::demo_track();
+}
+
+pub fn demo_strings() {
+    let s = String::from(QUICK_FOX);
+    println!("Various examples based on 's': {}", s);
+
+    println!("Second word via '&s[4..9]': {}", second_word(&s));
+    println!("Second word, via '&s[4..=8]': {}", second_word_inclusive(&s));
+    println!("Selected start via '&s[..3]' vs '&s[0..3]': {:?}", first_word_both_ways(&s));
+    println!("Selected end via '&s[16..19]' vs '&s[16..]': {:?}", last_word_both_ways(&s));
+    println!("Slice of the whole thing via '[..]': {:?}", whole_thing(&s));
+
+    // slicing where the words actually are (see ./words.rs)
+    let padded = "  The quick brown fox  ";
+    println!("Word bounds of {:?}: {:?}", padded, word_bounds(padded));
+    println!("First word: {:?}, third word: {:?}, tenth word: {:?}",
+        first_word(padded), nth_word(padded, 2), nth_word(padded, 9));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exclusive_and_inclusive_ranges_agree() {
+        assert_eq!(second_word(QUICK_FOX), "quick");
+        assert_eq!(second_word_inclusive(QUICK_FOX), "quick");
+    }
+
+    #[test]
+    fn open_ended_ranges() {
+        assert_eq!(first_word_both_ways(QUICK_FOX), ["The", "The"]);
+        assert_eq!(last_word_both_ways(QUICK_FOX), ["fox", "fox"]);
+        assert_eq!(whole_thing(QUICK_FOX), QUICK_FOX);
+    }
+
+    #[test]
+    fn slices_point_into_the_original() {
+        let s = String::from(QUICK_FOX);
+        // no copy was made: the slice starts four bytes into s itself
+        assert_eq!(second_word(&s).as_ptr(), s[4..].as_ptr());
+    }
+}
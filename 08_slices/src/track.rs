@@ -0,0 +1,184 @@
+/*!
+ * Indexing a type of our own: `track[3]` and `track[1..4]`, the way a Vec
+ * or a slice does it.
+ *
+ * The square brackets are the `Index` trait. `a[i]` is sugar for
+ * `*a.index(i)`: index() hands back a *reference* into the container, and
+ * the `*` is added for you. The index type is a type parameter, so one
+ * container can be indexed by several kinds of thing, each with its own
+ * impl and its own Output:
+ *
+ * ```text
+ * impl Index<usize> for Track          track[3]      Output = f64
+ * impl Index<Range<usize>> for Track   track[1..4]   Output = [f64], a slice
+ * ```
+ *
+ * A Track is a newtype, a tuple struct with one field, around a Vec<f64>:
+ * the readings along a walk, say, one per minute. Both impls just pass the
+ * index on to the Vec, which does the bounds checking (and the panicking,
+ * for an index off the end). So do windows() and chunks(), which hand out
+ * std's iterators over the Vec's slices (see ./windows.rs for how those
+ * work inside).
+ *
+ * Only `usize` and `Range` are implemented here, so `track[2..]` or
+ * `track[..=3]` won't compile: each kind of range is its own type, and
+ * each needs its own impl (or a single generic one, over SliceIndex, which
+ * is how Vec does it).
+ */
+use crate::windows::moving_average;
+use std::ops::{Index, Range};
+use std::slice::{Chunks, Windows};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track(pub Vec<f64>);
+
+impl Index<usize> for Track {
+    type Output = f64;
+
+    fn index(&self, i: usize) -> &f64 {
+        &self.0[i]
+    }
+}
+
+impl Index<Range<usize>> for Track {
+    type Output = [f64];
+
+    fn index(&self, range: Range<usize>) -> &[f64] {
+        &self.0[range]
+    }
+}
+
+impl Track {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Every run of `n` neighbouring readings. Like the slice method it
+    // passes the work to, it panics if `n` is 0.
+    pub fn windows(&self, n: usize) -> Windows<'_, f64> {
+        self.0.windows(n)
+    }
+
+    // The readings `size` at a time, with whatever's left over at the end
+    // in a shorter last chunk; panics if `size` is 0, like windows()
+    pub fn chunks(&self, size: usize) -> Chunks<'_, f64> {
+        self.0.chunks(size)
+    }
+
+    // A moving average: each reading replaced by the average of it and its
+    // n - 1 neighbours, which irons out the jitter and leaves n - 1 fewer.
+    // Unlike windows(), a window of 0 doesn't panic: it just leaves nothing.
+    pub fn smooth(&self, n: usize) -> Track {
+        if n == 0 {
+            return Track(Vec::new());
+        }
+        // one average per window, as in downsample() below
+        Track(self.windows(n).flat_map(|window| moving_average(window, window.len())).collect())
+    }
+
+    // One reading per `size`, the average of each chunk: a shorter track
+    // that keeps the overall shape. A size of 0 leaves nothing, as in smooth().
+    pub fn downsample(&self, size: usize) -> Track {
+        if size == 0 {
+            return Track(Vec::new());
+        }
+        // a window as long as the chunk has just the one average
+        Track(self.chunks(size).flat_map(|chunk| moving_average(chunk, chunk.len())).collect())
+    }
+}
+
+pub fn demo_track() {
+    let track = Track(vec![120.0, 126.0, 123.0, 131.0, 140.0, 137.0, 152.0, 149.0, 158.0]);
+    println!("Elevations along a walk: {:?}", track.0);
+    println!("track[4] is {}, and track[1..4] is {:?}", track[4], &track[1..4]);
+    println!("Smoothed over 3: {:?}", track.smooth(3).0);
+    println!("Downsampled by 4: {:?}", track.downsample(4).0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track() -> Track {
+        Track(vec![1.0, 2.0, 3.0, 4.0, 5.0])
+    }
+
+    #[test]
+    fn indexing_by_position() {
+        let track = track();
+        assert_eq!(track[0], 1.0);
+        assert_eq!(track[4], 5.0);
+        // `track[2]` is `*track.index(2)`
+        assert_eq!(*track.index(2), track[2]);
+    }
+
+    #[test]
+    fn indexing_by_range() {
+        let track = track();
+        assert_eq!(&track[1..3], &[2.0, 3.0]);
+        assert_eq!(track[0..5].len(), 5);
+        assert!(track[2..2].is_empty());
+        // a slice that points into the Track, not a copy
+        assert_eq!(track[3..5].as_ptr(), &track[3] as *const f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn past_the_end_panics() {
+        let _ = track()[5];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn a_range_past_the_end_panics() {
+        let _ = &track()[3..6];
+    }
+
+    #[test]
+    fn windows_and_chunks_match_the_vecs() {
+        let track = track();
+        assert!(track.windows(2).eq(track.0.windows(2)));
+        let chunks: Vec<&[f64]> = track.chunks(2).collect();
+        assert_eq!(chunks, [&[1.0, 2.0][..], &[3.0, 4.0], &[5.0]]);
+        assert_eq!(track.windows(6).count(), 0);
+    }
+
+    #[test]
+    fn smoothing() {
+        assert_eq!(track().smooth(2), Track(vec![1.5, 2.5, 3.5, 4.5]));
+        assert_eq!(track().smooth(5), Track(vec![3.0]));
+        // a window of 1 changes nothing
+        assert_eq!(track().smooth(1), track());
+        assert!(track().smooth(6).is_empty());
+        // a jagged track comes out flat
+        assert_eq!(Track(vec![0.0, 2.0, 0.0, 2.0, 0.0]).smooth(2), Track(vec![1.0; 4]));
+    }
+
+    #[test]
+    fn smoothing_a_slice_of_a_track() {
+        let track = track();
+        let middle = Track(track[1..4].to_vec());
+        assert_eq!(middle.smooth(2), Track(vec![2.5, 3.5]));
+        // the same as the middle of the whole track's smoothing
+        let whole = track.smooth(2);
+        assert_eq!(middle.smooth(2).0, &whole[1..3]);
+        assert_eq!(middle.smooth(2)[0], (track[1] + track[2]) / 2.0);
+    }
+
+    #[test]
+    fn downsampling() {
+        assert_eq!(track().downsample(2), Track(vec![1.5, 3.5, 5.0]));
+        assert_eq!(track().downsample(5), Track(vec![3.0]));
+        assert_eq!(Track(vec![]).downsample(3), Track(vec![]));
+    }
+
+    #[test]
+    fn zero_sized_windows_and_chunks_leave_nothing() {
+        assert!(track().smooth(0).is_empty());
+        assert!(track().downsample(0).is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+/*!
+ * Slices of numbers, not just of strings: windows and chunks.
+ *
+ * A *window* is a run of `n` neighbours, and the windows over a slice
+ * overlap, moving one element at a time:
+ *
+ * ```text
+ * [1, 2, 3, 4]  windows of 2:  [1, 2]  [2, 3]  [3, 4]
+ * [1, 2, 3, 4]  2 chunks:      [1, 2]  [3, 4]
+ * ```
+ *
+ * *Chunks* don't overlap. The standard library has both (`slice.windows(n)`
+ * and `slice.chunks(size)`); these are written out by hand to show that
+ * they're nothing but index arithmetic and `&s[start..end]`. Every window
+ * and chunk is a slice pointing into the original, so nothing is copied.
+ *
+ * They return iterators (`impl Iterator`), so the slices are made one at a
+ * time, only as they're asked for.
+ */
+
+// Every run of `n` neighbouring elements, in order. There are none at all if
+// `n` is 0 or bigger than the slice.
+pub fn windows_of<T>(slice: &[T], n: usize) -> impl Iterator<Item = &[T]> {
+    let count = if n == 0 || n > slice.len() { 0 } else { slice.len() - n + 1 };
+    (0..count).map(move |start| &slice[start..start + n])
+}
+
+// The slice cut into `count` chunks whose lengths differ by at most one
+// (the longer ones first), rather than `chunks`'s fixed size with a short
+// one left over at the end. 10 elements in 3 chunks are 4, 3 and 3 long.
+// With more chunks than elements, the extras are empty; 0 chunks are none.
+pub fn chunks_rounded<T>(slice: &[T], count: usize) -> impl Iterator<Item = &[T]> {
+    // (checked, because 0 chunks would be a division by zero)
+    let size = slice.len().checked_div(count).unwrap_or(0);
+    let extra = slice.len().checked_rem(count).unwrap_or(0);
+    (0..count).map(move |i| {
+        // the first `extra` chunks get one more element each
+        let start = i * size + i.min(extra);
+        let end = start + size + if i < extra { 1 } else { 0 };
+        &slice[start..end]
+    })
+}
+
+// The average of each window of `n` values: a smoothed-out version of the
+// sequence, with n - 1 fewer values in it
+pub fn moving_average(values: &[f64], n: usize) -> impl Iterator<Item = f64> + '_ {
+    windows_of(values, n).map(|window| window.iter().sum::<f64>() / window.len() as f64)
+}
+
+pub fn demo_windows() {
+    let temperatures = [12.0, 14.0, 19.0, 17.0, 15.0, 21.0, 24.0];
+    println!("Daily temperatures: {:?}", temperatures);
+    let windows: Vec<&[f64]> = windows_of(&temperatures, 3).collect();
+    println!("Windows of 3: {:?}", windows);
+    let averages: Vec<f64> = moving_average(&temperatures, 3).collect();
+    println!("Three-day moving average: {:?}", averages);
+    let chunks: Vec<&[f64]> = chunks_rounded(&temperatures, 3).collect();
+    println!("In 3 rounded chunks: {:?}", chunks);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_overlap() {
+        let windows: Vec<&[i32]> = windows_of(&[1, 2, 3, 4], 2).collect();
+        assert_eq!(windows, [&[1, 2][..], &[2, 3], &[3, 4]]);
+        // the same as the standard library's
+        let letters = ['a', 'b', 'c', 'd', 'e'];
+        assert!(windows_of(&letters, 3).eq(letters.windows(3)));
+    }
+
+    #[test]
+    fn too_big_or_empty_windows_are_none_at_all() {
+        assert_eq!(windows_of(&[1, 2, 3], 4).count(), 0);
+        assert_eq!(windows_of(&[1, 2, 3], 0).count(), 0);
+        assert_eq!(windows_of::<i32>(&[], 1).count(), 0);
+        assert_eq!(windows_of(&[1, 2, 3], 3).count(), 1);
+    }
+
+    #[test]
+    fn rounded_chunks_share_the_elements_out() {
+        let numbers: Vec<i32> = (1..=10).collect();
+        let lengths: Vec<usize> = chunks_rounded(&numbers, 3).map(|chunk| chunk.len()).collect();
+        assert_eq!(lengths, [4, 3, 3]);
+        // and between them, every element, in order
+        assert_eq!(chunks_rounded(&numbers, 3).flatten().copied().collect::<Vec<i32>>(), numbers);
+        let chunks: Vec<&[i32]> = chunks_rounded(&numbers, 5).collect();
+        assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5, 6], &[7, 8], &[9, 10]]);
+    }
+
+    #[test]
+    fn more_chunks_than_elements() {
+        let lengths: Vec<usize> = chunks_rounded(&[1, 2], 4).map(|chunk| chunk.len()).collect();
+        assert_eq!(lengths, [1, 1, 0, 0]);
+        assert_eq!(chunks_rounded(&[1, 2], 0).count(), 0);
+    }
+
+    #[test]
+    fn moving_averages() {
+        let averages: Vec<f64> = moving_average(&[1.0, 2.0, 3.0, 4.0], 2).collect();
+        assert_eq!(averages, [1.5, 2.5, 3.5]);
+        assert_eq!(moving_average(&[5.0], 1).collect::<Vec<f64>>(), [5.0]);
+        assert_eq!(moving_average(&[], 3).count(), 0);
+    }
+
+    #[test]
+    fn windows_point_into_the_original() {
+        let numbers = [1, 2, 3, 4];
+        assert_eq!(windows_of(&numbers, 2).nth(2).unwrap().as_ptr(), numbers[2..].as_ptr());
+    }
+}
@@ -0,0 +1,91 @@
+/*!
+ * Words, as slices of the text they came from.
+ *
+ * The book builds `first_word` up as its motivating example for slices
+ * (its first version returns an index, which goes stale the moment the
+ * String changes; a slice can't, because the borrow checker won't let the
+ * String change while the slice is around). ./exercises.rs asks you to
+ * write the book's version, which stops at the first space. Try that one
+ * before reading on!
+ *
+ * These are a little sturdier: a word is a run of anything but whitespace,
+ * so leading, trailing and doubled-up spaces (or tabs, or newlines) never
+ * produce empty words. Everything is worked out once, in `word_bounds`, as
+ * byte ranges, and the rest just slice with them.
+ */
+
+// Where each word starts and ends, as byte indices into `s`, so that
+// `&s[start..end]` is the word. (Byte indices, not char counts: that's what
+// slicing takes, and char_indices hands them out even for multi-byte text.)
+pub fn word_bounds(s: &str) -> Vec<(usize, usize)> {
+    let mut bounds = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(from), true) => {
+                bounds.push((from, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        bounds.push((from, s.len()));
+    }
+    bounds
+}
+
+// The `n`th word (counting from 0), if there are that many
+pub fn nth_word(s: &str, n: usize) -> Option<&str> {
+    word_bounds(s).get(n).map(|&(start, end)| &s[start..end])
+}
+
+// The first word, or "" if there are no words at all. The empty slice still
+// points into `s`, so the result borrows `s` either way. (The one in
+// ./exercises.rs is the book's, which stops at the first space, even a
+// leading one.)
+pub fn first_word(s: &str) -> &str {
+    nth_word(s, 0).unwrap_or(&s[s.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_are_byte_ranges() {
+        assert_eq!(word_bounds("The quick fox"), [(0, 3), (4, 9), (10, 13)]);
+        // "héllo" is six bytes long, because é takes two
+        assert_eq!(word_bounds("héllo world"), [(0, 6), (7, 12)]);
+    }
+
+    #[test]
+    fn leading_and_trailing_spaces_are_skipped() {
+        assert_eq!(first_word("   hello world  "), "hello");
+        assert_eq!(nth_word("   hello world  ", 1), Some("world"));
+        assert_eq!(nth_word("   hello world  ", 2), None);
+        assert_eq!(word_bounds("\thello \n  world\n"), [(1, 6), (10, 15)]);
+    }
+
+    #[test]
+    fn empty_and_blank_strings_have_no_words() {
+        assert!(word_bounds("").is_empty());
+        assert!(word_bounds("   ").is_empty());
+        assert_eq!(first_word(""), "");
+        assert_eq!(first_word("   "), "");
+        assert_eq!(nth_word("", 0), None);
+    }
+
+    #[test]
+    fn one_word_is_the_whole_string() {
+        assert_eq!(first_word("hello"), "hello");
+        assert_eq!(word_bounds("hello"), [(0, 5)]);
+    }
+
+    #[test]
+    fn words_point_into_the_original() {
+        let s = String::from("  The quick brown fox");
+        assert_eq!(nth_word(&s, 1).unwrap().as_ptr(), s[6..].as_ptr());
+    }
+}
@@ -0,0 +1,31 @@
+/**
+ * The checks for src/exercises.rs. Spoilers below!
+ *
+ * These only build with `--features exercises` (see Cargo.toml), so the
+ * ordinary `cargo test` never trips over the unfinished exercises. Each test
+ * is named after the exercise it checks, which is how `runner verify` tells
+ * which ones pass.
+ */
+use slices::exercises;
+
+#[test]
+fn first_word() {
+    assert_eq!(exercises::first_word("hello world"), "hello");
+    assert_eq!(exercises::first_word("single"), "single");
+    assert_eq!(exercises::first_word(""), "");
+}
+
+#[test]
+fn last_n() {
+    assert_eq!(exercises::last_n("The quick brown fox", 3), "fox");
+    assert_eq!(exercises::last_n("ox", 3), "ox");
+    assert_eq!(exercises::last_n("fox", 0), "");
+}
+
+#[test]
+fn middle() {
+    assert_eq!(exercises::middle(&[1, 2, 3, 4]), [2, 3]);
+    assert_eq!(exercises::middle(&[1, 2]), []);
+    assert!(exercises::middle(&[1]).is_empty());
+    assert!(exercises::middle(&[]).is_empty());
+}
@@ -0,0 +1,17 @@
+/**
+ * Formatting for Rectangle, in yet another file.
+ *
+ * `#[derive(Debug)]` gets us `{:?}` for free, but `{}` requires a manual
+ * implementation of the Display trait. A trait impl is just another kind of
+ * `impl` block, so it can live wherever we like, too.
+ *
+ * "Manual" doesn't have to mean typed out, though. All this impl does is
+ * drop two fields into a sentence, and `display_as!`, a macro from chapter
+ * 27, writes exactly that impl for us: the `impl fmt::Display for
+ * Rectangle` block is still here, it's just the macro's expansion now.
+ */
+use macros::display_as;
+
+use super::shape::Rectangle;
+
+display_as!(Rectangle, "{}x{} rectangle", width, height);
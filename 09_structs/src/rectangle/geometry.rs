@@ -0,0 +1,32 @@
+/**
+ * Geometry methods for Rectangle, layered on in a file of their own.
+ *
+ * This `impl` block is in a completely different file from the struct
+ * definition. All it needs is for the type to be in scope.
+ */
+use super::shape::Rectangle;
+
+// Methods are layered onto the struct *after the fact*, in an `impl` block.
+// The key word of interest here is __impl__; it can only ever be used *after*
+// the following type has been defined or otherwise brought into scope
+impl Rectangle {
+    // NB: the Python-esque '@self' reference is *not* optional!
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+    // we can now call foo.area() on Rectangle instances
+
+    // a utility method so one Rectangle can compare itself to another
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+
+    // a __static__ method for the Rectangle *namespace*
+    // This is not an _instance_ method: note the lack of `&self`!
+    // This must be invoked as `Rectangle::square(12)`
+    pub fn square(size: u32) -> Rectangle {
+        Rectangle { width: size, height: size }
+    }
+}
+// nb: the methods must be `pub` now! Back when everything lived in main.rs
+// they were all in the same module, so private was good enough.
@@ -0,0 +1,25 @@
+/**
+ * Barrel file for the rectangle module.
+ *
+ * Back in `main.rs` we claimed that multiple `impl` blocks are 100% A-OK,
+ * and that they can live "in whatever locations that you need". Talk is
+ * cheap, so this module proves it with code that actually compiles:
+ *
+ * - `shape.rs` holds the one-and-only struct *definition*
+ * - `geometry.rs` holds an `impl Rectangle` block with the math methods
+ * - `format.rs` holds a *trait* impl (Display) for pretty printing
+ *
+ * None of those files know (or care) about each other's impl blocks. The
+ * compiler stitches them all together into one Rectangle type. The only
+ * requirement is that each impl block can *see* the struct, which is what
+ * the `use super::shape::Rectangle` lines in the sibling files are for.
+ */
+mod shape;
+mod geometry;
+mod format;
+
+// re-export the struct so users can say `rectangle::Rectangle` instead of
+// the longer (and private!) `rectangle::shape::Rectangle`. The methods from
+// `geometry` and `format` come along for free: methods belong to the *type*,
+// not to the file they were written in.
+pub use self::shape::Rectangle;
@@ -0,0 +1,12 @@
+/**
+ * The core definition of Rectangle: data only, no methods.
+ *
+ * The fields are `pub` because the impl blocks live in *sibling* files.
+ * Privacy in Rust is per-module, so a private field here would be invisible
+ * to `geometry.rs` and `format.rs`, even though they are right next door.
+ */
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
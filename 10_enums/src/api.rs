@@ -0,0 +1,64 @@
+/**
+ * Errors from an imaginary web API, designed to grow over time.
+ *
+ * Normally, adding a variant to a public enum is a *breaking change*:
+ * every downstream `match` that listed all the variants stops compiling.
+ * Marking the enum `#[non_exhaustive]` is a promise up front that more
+ * variants may be coming. In exchange, the compiler makes every match
+ * *outside this crate* include a wildcard `_` arm, so that new variants
+ * can be added later without breaking anybody.
+ *
+ * Inside this crate nothing changes: we can still match exhaustively,
+ * and `status_code` below does exactly that.
+ */
+use std::fmt;
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    NotFound { resource: String },
+    Unauthorized,
+    RateLimited { retry_after_secs: u32 },
+    Server(u16),
+    // added in "version 2" of the API. Downstream code that was written
+    // before this existed still compiles, thanks to its wildcard arm.
+    Timeout,
+}
+
+impl ApiError {
+    // an exhaustive match is fine here, in the defining crate
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ApiError::NotFound { .. } => 404,
+            ApiError::Unauthorized => 401,
+            ApiError::RateLimited { .. } => 429,
+            ApiError::Server(code) => *code,
+            ApiError::Timeout => 504,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "API error {}", self.status_code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_codes() {
+        assert_eq!(ApiError::NotFound { resource: String::from("x") }.status_code(), 404);
+        assert_eq!(ApiError::Unauthorized.status_code(), 401);
+        assert_eq!(ApiError::RateLimited { retry_after_secs: 5 }.status_code(), 429);
+        assert_eq!(ApiError::Server(503).status_code(), 503);
+        assert_eq!(ApiError::Timeout.status_code(), 504);
+    }
+
+    #[test]
+    fn display_mentions_the_code() {
+        assert_eq!(ApiError::Unauthorized.to_string(), "API error 401");
+    }
+}
@@ -0,0 +1,168 @@
+/**
+ * A tiny message queue for the Message enum from `main.rs`.
+ *
+ * Enums really shine once you have a whole *pile* of them to deal with.
+ * A Vec<Message> can hold any mix of variants, because every variant has
+ * the same type (Message). Then a single `match` routes each one to the
+ * right handler. That is the skeleton of every event loop, message bus,
+ * and actor system you will ever meet, in any language.
+ *
+ * Note that this module never declares Message itself. A submodule can
+ * always look *up* into its ancestors, even at private items, so a plain
+ * `use super::Message` is all we need.
+ */
+use super::Message;
+
+// Which variant is this? A fieldless enum is handy for asking that question
+// without having to conjure up dummy associated values just to compare.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageKind {
+    Quit,
+    Move,
+    Write,
+    Color,
+}
+
+impl Message {
+    pub fn kind(&self) -> MessageKind {
+        // the `..` and `_` patterns ignore the associated values entirely
+        match self {
+            Message::Quit => MessageKind::Quit,
+            Message::Move { .. } => MessageKind::Move,
+            Message::Write(_) => MessageKind::Write,
+            Message::Color(..) => MessageKind::Color,
+        }
+    }
+}
+
+// How many of each variant did process_all() dispatch?
+#[derive(Debug, Default, PartialEq)]
+pub struct BusSummary {
+    pub quits: usize,
+    pub moves: usize,
+    pub writes: usize,
+    pub colors: usize,
+}
+
+impl BusSummary {
+    pub fn total(&self) -> usize {
+        self.quits + self.moves + self.writes + self.colors
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MessageBus {
+    queue: Vec<Message>,
+}
+
+impl MessageBus {
+    pub fn new() -> MessageBus {
+        MessageBus { queue: Vec::new() }
+    }
+
+    pub fn push(&mut self, message: Message) {
+        self.queue.push(message);
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    // borrow every queued message of one particular kind
+    pub fn filter(&self, kind: MessageKind) -> Vec<&Message> {
+        self.queue.iter().filter(|m| m.kind() == kind).collect()
+    }
+
+    // Empty the queue in FIFO order, dispatching each message via `match`.
+    // `drain(..)` hands us each message by value and leaves the Vec empty
+    // (but still allocated), so the bus can be re-used afterwards.
+    pub fn process_all(&mut self) -> BusSummary {
+        let mut summary = BusSummary::default();
+        for message in self.queue.drain(..) {
+            message.call();
+            match message {
+                Message::Quit => summary.quits += 1,
+                Message::Move { .. } => summary.moves += 1,
+                Message::Write(_) => summary.writes += 1,
+                Message::Color(..) => summary.colors += 1,
+            }
+        }
+        summary
+    }
+}
+
+pub fn demo_bus() {
+    println!("--- MessageBus Demonstration Begins --- ");
+    let mut bus = MessageBus::new();
+    bus.push(Message::Write(String::from("first")));
+    bus.push(Message::Move { x: 1, y: 2 });
+    bus.push(Message::Write(String::from("second")));
+    bus.push(Message::Color(0, 128, 255));
+    bus.push(Message::Quit);
+
+    println!("Queued {} messages, of which {} are Writes",
+             bus.len(), bus.filter(MessageKind::Write).len());
+    let summary = bus.process_all();
+    println!("Processed: {:?} (total {})", summary, summary.total());
+    println!("Anything left in the queue? {}", !bus.is_empty());
+    println!("--- MessageBus Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded_bus() -> MessageBus {
+        let mut bus = MessageBus::new();
+        bus.push(Message::Write(String::from("a")));
+        bus.push(Message::Quit);
+        bus.push(Message::Move { x: 3, y: 4 });
+        bus.push(Message::Write(String::from("b")));
+        bus.push(Message::Color(1, 2, 3));
+        bus.push(Message::Write(String::from("c")));
+        bus
+    }
+
+    #[test]
+    fn push_grows_the_queue() {
+        let mut bus = MessageBus::new();
+        assert!(bus.is_empty());
+        bus.push(Message::Quit);
+        assert_eq!(bus.len(), 1);
+    }
+
+    #[test]
+    fn process_all_counts_each_variant() {
+        let mut bus = loaded_bus();
+        let summary = bus.process_all();
+        assert_eq!(summary, BusSummary { quits: 1, moves: 1, writes: 3, colors: 1 });
+        assert_eq!(summary.total(), 6);
+    }
+
+    #[test]
+    fn process_all_empties_the_queue() {
+        let mut bus = loaded_bus();
+        bus.process_all();
+        assert!(bus.is_empty());
+        // and a second pass has nothing to do
+        assert_eq!(bus.process_all(), BusSummary::default());
+    }
+
+    #[test]
+    fn filter_keeps_order_and_variant() {
+        let bus = loaded_bus();
+        let writes = bus.filter(MessageKind::Write);
+        assert_eq!(writes, vec![
+            &Message::Write(String::from("a")),
+            &Message::Write(String::from("b")),
+            &Message::Write(String::from("c")),
+        ]);
+        assert_eq!(bus.filter(MessageKind::Move), vec![&Message::Move { x: 3, y: 4 }]);
+        // filtering only borrows, so nothing was removed
+        assert_eq!(bus.len(), 6);
+    }
+}
@@ -0,0 +1,141 @@
+/**
+ * The full US coin purse, plus a little arithmetic on top of it.
+ *
+ * `value_in_cents` over in `main.rs` *consumes* its coin (it takes `Coin`,
+ * not `&Coin`), which is fine for a one-off demo but useless once you have
+ * a whole handful of coins that you want to keep. So here we add a
+ * borrowing `.cents()` method, and build everything else on top of it.
+ */
+use super::{Coin, UsState};
+
+// every denomination, biggest first, which is exactly the order that the
+// greedy change-making algorithm wants to try them in
+const DENOMINATIONS: [u32; 6] = [100, 50, 25, 10, 5, 1];
+
+impl Coin {
+    // same match as value_in_cents, minus the printing and the ownership grab
+    pub fn cents(&self) -> u32 {
+        match self {
+            Coin::Penny => 1,
+            Coin::Nickel => 5,
+            Coin::Dime => 10,
+            Coin::Quarter(_) => 25,
+            Coin::HalfDollar => 50,
+            Coin::Dollar => 100,
+        }
+    }
+
+    // the inverse: which coin is worth exactly this many cents (if any)?
+    fn from_cents(cents: u32) -> Option<Coin> {
+        match cents {
+            1 => Some(Coin::Penny),
+            5 => Some(Coin::Nickel),
+            10 => Some(Coin::Dime),
+            // the state on the back of a quarter doesn't change its value,
+            // so the change drawer just hands out Alabama quarters
+            25 => Some(Coin::Quarter(UsState::Alabama)),
+            50 => Some(Coin::HalfDollar),
+            100 => Some(Coin::Dollar),
+            _ => None,
+        }
+    }
+}
+
+// add up a whole slice of coins without consuming any of them
+pub fn total_value(coins: &[Coin]) -> u32 {
+    coins.iter().map(|coin| coin.cents()).sum()
+}
+
+// Greedy change-making: always hand out the biggest coin that still fits.
+// For US denominations greedy is also *optimal* (fewest coins possible),
+// but that is a happy property of this particular coin system, not a law
+// of nature. Try it with 1, 3 and 4 cent coins to see greedy fall over.
+pub fn make_change(cents: u32) -> Vec<Coin> {
+    let mut remaining = cents;
+    let mut change = Vec::new();
+    for &denomination in DENOMINATIONS.iter() {
+        while remaining >= denomination {
+            // DENOMINATIONS only lists values that from_cents knows about
+            change.push(Coin::from_cents(denomination).unwrap());
+            remaining -= denomination;
+        }
+    }
+    change
+}
+
+// do these coins add up to precisely this amount, no more and no less?
+pub fn is_exact_change(coins: &[Coin], cents: u32) -> bool {
+    total_value(coins) == cents
+}
+
+pub fn demo_coins() {
+    println!("--- Coin Purse Demonstration Begins --- ");
+    let purse = vec![
+        Coin::Dollar,
+        Coin::HalfDollar,
+        Coin::Quarter(UsState::Colorado),
+        Coin::Dime,
+        Coin::Penny,
+    ];
+    println!("A purse of {:?} is worth {} cents", purse, total_value(&purse));
+
+    let change = make_change(187);
+    println!("Change for 187 cents: {:?}", change);
+    println!("Is that exact change? {}", is_exact_change(&change, 187));
+    println!("Would it cover 200 cents exactly? {}", is_exact_change(&change, 200));
+    println!("--- Coin Purse Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cents_agrees_with_every_variant() {
+        assert_eq!(Coin::Penny.cents(), 1);
+        assert_eq!(Coin::Nickel.cents(), 5);
+        assert_eq!(Coin::Dime.cents(), 10);
+        assert_eq!(Coin::Quarter(UsState::Alaska).cents(), 25);
+        assert_eq!(Coin::HalfDollar.cents(), 50);
+        assert_eq!(Coin::Dollar.cents(), 100);
+    }
+
+    #[test]
+    fn total_value_of_empty_purse_is_zero() {
+        assert_eq!(total_value(&[]), 0);
+    }
+
+    #[test]
+    fn total_value_adds_everything() {
+        let purse = [Coin::Dollar, Coin::Quarter(UsState::California), Coin::Penny];
+        assert_eq!(total_value(&purse), 126);
+    }
+
+    #[test]
+    fn make_change_is_greedy() {
+        assert_eq!(make_change(0), vec![]);
+        assert_eq!(make_change(41), vec![
+            Coin::Quarter(UsState::Alabama),
+            Coin::Dime,
+            Coin::Nickel,
+            Coin::Penny,
+        ]);
+        assert_eq!(make_change(250), vec![Coin::Dollar, Coin::Dollar, Coin::HalfDollar]);
+    }
+
+    #[test]
+    fn make_change_always_adds_up() {
+        for cents in 0..=500 {
+            let change = make_change(cents);
+            assert!(is_exact_change(&change, cents), "bad change for {}", cents);
+        }
+    }
+
+    #[test]
+    fn exact_change_rejects_wrong_totals() {
+        let coins = [Coin::Dime, Coin::Dime];
+        assert!(is_exact_change(&coins, 20));
+        assert!(!is_exact_change(&coins, 19));
+        assert!(!is_exact_change(&coins, 21));
+    }
+}
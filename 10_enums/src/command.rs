@@ -0,0 +1,178 @@
+/**
+ * Enums as the backbone of a command-line protocol.
+ *
+ * Any program that takes typed-in commands has the same shape: turn a line
+ * of text into *one of* a fixed set of commands, then act on it. "One of a
+ * fixed set" is exactly what an enum is. Once the text has been parsed into
+ * a Command, the rest of the program never looks at raw strings again; it
+ * just matches on variants, and the compiler makes sure none are forgotten.
+ *
+ * The interactive loop itself lives in `main.rs` (run `cargo run -- repl`).
+ * Everything here is plain functions over strings and a Vec, which means
+ * it is all testable without anybody typing anything.
+ */
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Add(String),
+    Remove(usize), // 1-based, matching the numbers that List prints
+    List,
+    Quit,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    BadIndex(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "please type a command (try 'help')"),
+            ParseError::UnknownCommand(c) => write!(f, "unknown command '{}' (try 'help')", c),
+            ParseError::MissingArgument(c) => write!(f, "'{}' needs an argument", c),
+            ParseError::UnexpectedArgument(a) => write!(f, "unexpected argument '{}'", a),
+            ParseError::BadIndex(i) => write!(f, "'{}' is not a positive item number", i),
+        }
+    }
+}
+
+pub const HELP: &str = "\
+commands:
+  add <item>     add an item to the list
+  remove <n>     remove item number n
+  list           show all items
+  help           show this message
+  quit           leave";
+
+impl Command {
+    // The first word picks the variant; whatever follows is its argument.
+    pub fn parse(line: &str) -> Result<Command, ParseError> {
+        let line = line.trim();
+        let (word, rest) = match line.find(char::is_whitespace) {
+            Some(i) => (&line[..i], line[i..].trim()),
+            None => (line, ""),
+        };
+
+        match word.to_lowercase().as_str() {
+            "" => Err(ParseError::Empty),
+            "add" if rest.is_empty() => Err(ParseError::MissingArgument("add")),
+            "add" => Ok(Command::Add(rest.to_string())),
+            "remove" | "rm" if rest.is_empty() => Err(ParseError::MissingArgument("remove")),
+            "remove" | "rm" => match rest.parse::<usize>() {
+                Ok(n) if n > 0 => Ok(Command::Remove(n)),
+                _ => Err(ParseError::BadIndex(rest.to_string())),
+            },
+            "list" | "ls" if rest.is_empty() => Ok(Command::List),
+            "quit" | "exit" if rest.is_empty() => Ok(Command::Quit),
+            "help" | "?" if rest.is_empty() => Ok(Command::Help),
+            // if we get here with one of those words, the guard failed,
+            // which means somebody tacked on an argument
+            "list" | "ls" | "quit" | "exit" | "help" | "?" => {
+                Err(ParseError::UnexpectedArgument(rest.to_string()))
+            }
+            _ => Err(ParseError::UnknownCommand(word.to_string())),
+        }
+    }
+}
+
+// Carry out one command against the list, returning the text to show the
+// user. (Quit has nothing to do here: stopping is the caller's job.)
+pub fn execute(command: &Command, items: &mut Vec<String>) -> String {
+    match command {
+        Command::Add(item) => {
+            items.push(item.clone());
+            format!("added '{}' as item {}", item, items.len())
+        }
+        Command::Remove(n) if *n <= items.len() => {
+            let removed = items.remove(n - 1);
+            format!("removed '{}'", removed)
+        }
+        Command::Remove(n) => format!("there is no item {}", n),
+        Command::List if items.is_empty() => String::from("(no items)"),
+        Command::List => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| format!("{}. {}", i + 1, item))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Command::Help => String::from(HELP),
+        Command::Quit => String::from("bye!"),
+    }
+}
+
+pub fn demo_command() {
+    println!("--- Command Demonstration Begins --- ");
+    let mut items = Vec::new();
+    for line in ["add milk", "add eggs", "list", "remove 1", "remove 7", "jump", "list"].iter() {
+        match Command::parse(line) {
+            Ok(command) => println!("> {}\n{}", line, execute(&command, &mut items)),
+            Err(e) => println!("> {}\nerror: {}", line, e),
+        }
+    }
+    println!("(run `cargo run -- repl` to type your own commands)");
+    println!("--- Command Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_variant() {
+        assert_eq!(Command::parse("add buy milk"), Ok(Command::Add(String::from("buy milk"))));
+        assert_eq!(Command::parse("remove 2"), Ok(Command::Remove(2)));
+        assert_eq!(Command::parse("rm 1"), Ok(Command::Remove(1)));
+        assert_eq!(Command::parse("list"), Ok(Command::List));
+        assert_eq!(Command::parse("ls"), Ok(Command::List));
+        assert_eq!(Command::parse("quit"), Ok(Command::Quit));
+        assert_eq!(Command::parse("exit"), Ok(Command::Quit));
+        assert_eq!(Command::parse("help"), Ok(Command::Help));
+        assert_eq!(Command::parse("?"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_whitespace() {
+        assert_eq!(Command::parse("  LIST \n"), Ok(Command::List));
+        assert_eq!(Command::parse("Add   Eggs  "), Ok(Command::Add(String::from("Eggs"))));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Command::parse(""), Err(ParseError::Empty));
+        assert_eq!(Command::parse("   "), Err(ParseError::Empty));
+        assert_eq!(Command::parse("jump"), Err(ParseError::UnknownCommand(String::from("jump"))));
+        assert_eq!(Command::parse("add"), Err(ParseError::MissingArgument("add")));
+        assert_eq!(Command::parse("remove"), Err(ParseError::MissingArgument("remove")));
+        assert_eq!(Command::parse("remove two"), Err(ParseError::BadIndex(String::from("two"))));
+        assert_eq!(Command::parse("remove 0"), Err(ParseError::BadIndex(String::from("0"))));
+        assert_eq!(Command::parse("list all"), Err(ParseError::UnexpectedArgument(String::from("all"))));
+    }
+
+    #[test]
+    fn execute_drives_the_list() {
+        let mut items = Vec::new();
+        assert_eq!(execute(&Command::List, &mut items), "(no items)");
+        assert_eq!(execute(&Command::Add(String::from("a")), &mut items), "added 'a' as item 1");
+        execute(&Command::Add(String::from("b")), &mut items);
+        execute(&Command::Add(String::from("c")), &mut items);
+        assert_eq!(execute(&Command::List, &mut items), "1. a\n2. b\n3. c");
+        assert_eq!(execute(&Command::Remove(2), &mut items), "removed 'b'");
+        assert_eq!(execute(&Command::Remove(3), &mut items), "there is no item 3");
+        assert_eq!(items, vec![String::from("a"), String::from("c")]);
+    }
+
+    #[test]
+    fn help_lists_every_command() {
+        let help = execute(&Command::Help, &mut Vec::new());
+        for word in ["add", "remove", "list", "help", "quit"].iter() {
+            assert!(help.contains(word), "help is missing {}", word);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+/**
+ * An event loop, the way GUI toolkits and games do it.
+ *
+ * The outside world (keyboard, window manager, clock) produces Events.
+ * They pile up in a queue, and the loop pulls them off one at a time and
+ * applies each one to the application's state. The loop itself is almost
+ * nothing: a `while let` around a `match`. All the knowledge lives in the
+ * Event enum and in the match arms.
+ */
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    KeyPress(char),
+    Resize { w: u32, h: u32 },
+    Tick,
+    Quit,
+}
+
+// everything the "application" knows about itself
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppState {
+    pub text: String,
+    pub width: u32,
+    pub height: u32,
+    pub ticks: u64,
+    pub running: bool,
+}
+
+impl AppState {
+    pub fn new(width: u32, height: u32) -> AppState {
+        AppState { text: String::new(), width, height, ticks: 0, running: true }
+    }
+
+    // one event, one state change
+    pub fn apply(&mut self, event: Event) {
+        match event {
+            // backspace deletes, everything else types a character
+            Event::KeyPress('\u{8}') => {
+                self.text.pop();
+            }
+            Event::KeyPress(c) => self.text.push(c),
+            Event::Resize { w, h } => {
+                self.width = w;
+                self.height = h;
+            }
+            Event::Tick => self.ticks += 1,
+            Event::Quit => self.running = false,
+        }
+    }
+}
+
+// A first-in, first-out queue. VecDeque is a Vec that is cheap to pop
+// from the *front*, which is exactly what a queue needs.
+#[derive(Debug, Default)]
+pub struct EventQueue {
+    events: VecDeque<Event>,
+}
+
+impl EventQueue {
+    pub fn new() -> EventQueue {
+        EventQueue { events: VecDeque::new() }
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.events.push_back(event);
+    }
+
+    pub fn poll(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+// Keep polling until a Quit arrives (or the queue runs dry), then hand back
+// the final state. Anything queued after the Quit is left untouched.
+pub fn run_until_quit(queue: &mut EventQueue, mut state: AppState) -> AppState {
+    while let Some(event) = queue.poll() {
+        state.apply(event);
+        if !state.running {
+            break;
+        }
+    }
+    state
+}
+
+pub fn demo_events() {
+    println!("--- Event Loop Demonstration Begins --- ");
+    let mut queue = EventQueue::new();
+    for c in "hi!".chars() {
+        queue.push(Event::KeyPress(c));
+    }
+    queue.push(Event::Tick);
+    queue.push(Event::Resize { w: 120, h: 40 });
+    queue.push(Event::Tick);
+    queue.push(Event::Quit);
+    queue.push(Event::KeyPress('x')); // never seen: we quit first
+    println!("{} events queued", queue.len());
+
+    let state = run_until_quit(&mut queue, AppState::new(80, 24));
+    println!("Final state: {:?}", state);
+    println!("{} event(s) left over after quitting (queue empty? {})",
+             queue.len(), queue.is_empty());
+    println!("--- Event Loop Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue_of(events: Vec<Event>) -> EventQueue {
+        let mut queue = EventQueue::new();
+        for event in events {
+            queue.push(event);
+        }
+        queue
+    }
+
+    #[test]
+    fn queue_is_fifo() {
+        let mut queue = queue_of(vec![Event::Tick, Event::Quit]);
+        assert_eq!(queue.poll(), Some(Event::Tick));
+        assert_eq!(queue.poll(), Some(Event::Quit));
+        assert_eq!(queue.poll(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn typing_and_backspace() {
+        let mut queue = queue_of(vec![
+            Event::KeyPress('c'),
+            Event::KeyPress('a'),
+            Event::KeyPress('r'),
+            Event::KeyPress('\u{8}'),
+            Event::KeyPress('t'),
+            Event::Quit,
+        ]);
+        let state = run_until_quit(&mut queue, AppState::new(10, 10));
+        assert_eq!(state.text, "cat");
+    }
+
+    #[test]
+    fn final_state_reflects_every_event() {
+        let mut queue = queue_of(vec![
+            Event::Tick,
+            Event::Resize { w: 100, h: 50 },
+            Event::Tick,
+            Event::Tick,
+            Event::KeyPress('x'),
+            Event::Quit,
+        ]);
+        let state = run_until_quit(&mut queue, AppState::new(80, 24));
+        assert_eq!(state, AppState {
+            text: String::from("x"),
+            width: 100,
+            height: 50,
+            ticks: 3,
+            running: false,
+        });
+    }
+
+    #[test]
+    fn stops_at_quit_and_leaves_the_rest() {
+        let mut queue = queue_of(vec![Event::Quit, Event::Tick, Event::Tick]);
+        let state = run_until_quit(&mut queue, AppState::new(1, 1));
+        assert_eq!(state.ticks, 0);
+        assert!(!state.running);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn empty_queue_keeps_running() {
+        let state = run_until_quit(&mut EventQueue::new(), AppState::new(1, 1));
+        assert_eq!(state, AppState::new(1, 1));
+    }
+}
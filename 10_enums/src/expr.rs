@@ -0,0 +1,237 @@
+/**
+ * A tiny arithmetic language: an expression tree (AST) built from an enum.
+ *
+ * `1 + 2 * 3` is not really a line of text to a compiler, it is a tree:
+ *
+ *          Add
+ *         /   \
+ *      Num 1   Mul
+ *             /   \
+ *          Num 2  Num 3
+ *
+ * Each node is one variant of Expr, and the branches hold *more* Exprs, so
+ * (just like List) the enum is recursive and needs a Box to have a size.
+ * Evaluating the tree and printing it are each one recursive `match`.
+ */
+use std::fmt;
+use std::ops;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Neg(Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    DivisionByZero,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Expr {
+    pub fn num(n: f64) -> Expr {
+        Expr::Num(n)
+    }
+
+    // Walk the tree bottom-up. The `?` means a division by zero anywhere
+    // down in the branches stops the whole evaluation.
+    pub fn eval(&self) -> Result<f64, EvalError> {
+        match self {
+            Expr::Num(n) => Ok(*n),
+            Expr::Add(a, b) => Ok(a.eval()? + b.eval()?),
+            Expr::Sub(a, b) => Ok(a.eval()? - b.eval()?),
+            Expr::Mul(a, b) => Ok(a.eval()? * b.eval()?),
+            Expr::Div(a, b) => {
+                let divisor = b.eval()?;
+                if divisor == 0.0 {
+                    Err(EvalError::DivisionByZero)
+                } else {
+                    Ok(a.eval()? / divisor)
+                }
+            }
+            Expr::Neg(a) => Ok(-a.eval()?),
+        }
+    }
+
+    // how tightly each kind of node binds, for deciding on parentheses
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) => 2,
+            Expr::Neg(_) => 3,
+            Expr::Num(_) => 4,
+        }
+    }
+}
+
+// The builder API: rather than writing Box::new over and over, overload the
+// ordinary operators so that building a tree looks like writing the maths.
+// `Expr::num(1.0) + Expr::num(2.0) * Expr::num(3.0)` even gets the normal
+// precedence for free, because Rust already knows `*` binds tighter than `+`.
+impl ops::Add for Expr {
+    type Output = Expr;
+    fn add(self, other: Expr) -> Expr {
+        Expr::Add(Box::new(self), Box::new(other))
+    }
+}
+
+impl ops::Sub for Expr {
+    type Output = Expr;
+    fn sub(self, other: Expr) -> Expr {
+        Expr::Sub(Box::new(self), Box::new(other))
+    }
+}
+
+impl ops::Mul for Expr {
+    type Output = Expr;
+    fn mul(self, other: Expr) -> Expr {
+        Expr::Mul(Box::new(self), Box::new(other))
+    }
+}
+
+impl ops::Div for Expr {
+    type Output = Expr;
+    fn div(self, other: Expr) -> Expr {
+        Expr::Div(Box::new(self), Box::new(other))
+    }
+}
+
+impl ops::Neg for Expr {
+    type Output = Expr;
+    fn neg(self) -> Expr {
+        Expr::Neg(Box::new(self))
+    }
+}
+
+// The pretty-printer: infix notation with only the parentheses that are
+// actually needed. A child needs brackets when it binds more loosely than
+// its parent, and also on the right of `-` and `/` when it binds equally,
+// since `a - (b - c)` is not the same as `a - b - c`.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (symbol, a, b) = match self {
+            Expr::Num(n) => return write!(f, "{}", n),
+            Expr::Neg(a) => {
+                return match **a {
+                    Expr::Num(n) if n >= 0.0 => write!(f, "-{}", a),
+                    _ => write!(f, "-({})", a),
+                };
+            }
+            Expr::Add(a, b) => ("+", a, b),
+            Expr::Sub(a, b) => ("-", a, b),
+            Expr::Mul(a, b) => ("*", a, b),
+            Expr::Div(a, b) => ("/", a, b),
+        };
+        let mine = self.precedence();
+        if a.precedence() < mine {
+            write!(f, "({})", a)?;
+        } else {
+            write!(f, "{}", a)?;
+        }
+        write!(f, " {} ", symbol)?;
+        let strict = symbol == "-" || symbol == "/";
+        if b.precedence() < mine || (strict && b.precedence() == mine) {
+            write!(f, "({})", b)
+        } else {
+            write!(f, "{}", b)
+        }
+    }
+}
+
+pub fn demo_expr() {
+    println!("--- Expression Tree Demonstration Begins --- ");
+    let n = Expr::num;
+    let exprs = vec![
+        n(1.0) + n(2.0) * n(3.0),
+        (n(1.0) + n(2.0)) * n(3.0),
+        n(10.0) - (n(4.0) - n(1.0)),
+        -(n(2.0) + n(3.0)) / n(4.0),
+        n(1.0) / (n(2.0) - n(2.0)),
+    ];
+    for expr in &exprs {
+        match expr.eval() {
+            Ok(value) => println!("{} = {}", expr, value),
+            Err(e) => println!("{} = error: {}", expr, e),
+        }
+    }
+    println!("The tree behind the first one: {:?}", exprs[0]);
+    println!("--- Expression Tree Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(x: f64) -> Expr {
+        Expr::num(x)
+    }
+
+    #[test]
+    fn builder_makes_the_same_tree_as_writing_it_out() {
+        let built = n(1.0) + n(2.0) * n(3.0);
+        let by_hand = Expr::Add(
+            Box::new(Expr::Num(1.0)),
+            Box::new(Expr::Mul(Box::new(Expr::Num(2.0)), Box::new(Expr::Num(3.0)))),
+        );
+        assert_eq!(built, by_hand);
+    }
+
+    #[test]
+    fn evaluates_each_operation() {
+        assert_eq!(n(7.0).eval(), Ok(7.0));
+        assert_eq!((n(7.0) + n(2.0)).eval(), Ok(9.0));
+        assert_eq!((n(7.0) - n(2.0)).eval(), Ok(5.0));
+        assert_eq!((n(7.0) * n(2.0)).eval(), Ok(14.0));
+        assert_eq!((n(7.0) / n(2.0)).eval(), Ok(3.5));
+        assert_eq!((-n(7.0)).eval(), Ok(-7.0));
+    }
+
+    #[test]
+    fn evaluates_nested_expressions() {
+        // ((2 + 3) * (10 - 4)) / -(1 + 2) = 30 / -3
+        let expr = ((n(2.0) + n(3.0)) * (n(10.0) - n(4.0))) / -(n(1.0) + n(2.0));
+        assert_eq!(expr.eval(), Ok(-10.0));
+    }
+
+    #[test]
+    fn division_by_zero_anywhere_is_an_error() {
+        assert_eq!((n(1.0) / n(0.0)).eval(), Err(EvalError::DivisionByZero));
+        // the zero only shows up after evaluating a subtree...
+        let sneaky = n(5.0) / (n(3.0) - n(3.0));
+        assert_eq!(sneaky.eval(), Err(EvalError::DivisionByZero));
+        // ...and the error bubbles up through the rest of the tree
+        let deep = n(1.0) + n(2.0) * sneaky;
+        assert_eq!(deep.eval(), Err(EvalError::DivisionByZero));
+    }
+
+    #[test]
+    fn prints_infix_with_minimal_parentheses() {
+        assert_eq!((n(1.0) + n(2.0) * n(3.0)).to_string(), "1 + 2 * 3");
+        assert_eq!(((n(1.0) + n(2.0)) * n(3.0)).to_string(), "(1 + 2) * 3");
+        assert_eq!((n(1.0) - n(2.0) - n(3.0)).to_string(), "1 - 2 - 3");
+        assert_eq!((n(1.0) - (n(2.0) - n(3.0))).to_string(), "1 - (2 - 3)");
+        assert_eq!((n(1.0) + (n(2.0) + n(3.0))).to_string(), "1 + 2 + 3");
+        assert_eq!((n(8.0) / (n(4.0) / n(2.0))).to_string(), "8 / (4 / 2)");
+        assert_eq!((n(0.5) * n(4.0)).to_string(), "0.5 * 4");
+    }
+
+    #[test]
+    fn prints_negation() {
+        assert_eq!((-n(3.0)).to_string(), "-3");
+        assert_eq!((-(n(1.0) + n(2.0))).to_string(), "-(1 + 2)");
+        assert_eq!((-(-n(3.0))).to_string(), "-(-3)");
+        assert_eq!((-n(-3.0)).to_string(), "-(-3)");
+        assert_eq!((n(2.0) * -n(3.0)).to_string(), "2 * -3");
+    }
+}
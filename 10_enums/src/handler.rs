@@ -0,0 +1,90 @@
+/**
+ * A downstream consumer of `enums::api::ApiError`.
+ *
+ * This module lives in the *binary* crate, while ApiError lives in the
+ * *library* crate (see `lib.rs`). Because ApiError is `#[non_exhaustive]`,
+ * the `_` arm in `describe` below is not optional. Delete it and the
+ * compiler refuses, even though every variant that exists today is listed:
+ *
+ *      error[E0004]: non-exhaustive patterns: `&_` not covered
+ *
+ * That is the whole point: the library author may add variants later, and
+ * this code has already said what to do about them.
+ */
+use enums::api::ApiError;
+
+// Turn an API error into a message for the user. This was "written" before
+// ApiError::Timeout existed, so Timeout lands in the wildcard arm.
+pub fn describe(err: &ApiError) -> String {
+    match err {
+        ApiError::NotFound { resource } => format!("{} does not exist", resource),
+        ApiError::Unauthorized => String::from("please log in first"),
+        ApiError::RateLimited { retry_after_secs } => {
+            format!("slow down! try again in {}s", retry_after_secs)
+        }
+        ApiError::Server(code) => format!("the server broke ({})", code),
+        // required: covers Timeout today, and whatever comes next tomorrow
+        other => format!("something unexpected happened: {}", other),
+    }
+}
+
+// should the caller try the request again?
+pub fn is_retryable(err: &ApiError) -> bool {
+    match err {
+        ApiError::RateLimited { .. } => true,
+        ApiError::Server(code) => *code >= 500,
+        // when in doubt about unknown variants, don't retry
+        _ => false,
+    }
+}
+
+pub fn demo_handler() {
+    println!("--- Non-Exhaustive Enum Demonstration Begins --- ");
+    let errors = vec![
+        ApiError::NotFound { resource: String::from("/users/42") },
+        ApiError::Unauthorized,
+        ApiError::RateLimited { retry_after_secs: 30 },
+        ApiError::Server(503),
+        ApiError::Timeout,
+    ];
+    for err in &errors {
+        println!("{} -> {} (retry? {})", err, describe(err), is_retryable(err));
+    }
+    println!("--- Non-Exhaustive Enum Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_known_variants() {
+        assert_eq!(
+            describe(&ApiError::NotFound { resource: String::from("/a") }),
+            "/a does not exist"
+        );
+        assert_eq!(describe(&ApiError::Unauthorized), "please log in first");
+        assert_eq!(
+            describe(&ApiError::RateLimited { retry_after_secs: 3 }),
+            "slow down! try again in 3s"
+        );
+        assert_eq!(describe(&ApiError::Server(500)), "the server broke (500)");
+    }
+
+    #[test]
+    fn newer_variants_fall_through_to_the_wildcard() {
+        assert_eq!(
+            describe(&ApiError::Timeout),
+            "something unexpected happened: API error 504"
+        );
+    }
+
+    #[test]
+    fn retry_policy() {
+        assert!(is_retryable(&ApiError::RateLimited { retry_after_secs: 1 }));
+        assert!(is_retryable(&ApiError::Server(502)));
+        assert!(!is_retryable(&ApiError::Server(418)));
+        assert!(!is_retryable(&ApiError::Unauthorized));
+        assert!(!is_retryable(&ApiError::Timeout));
+    }
+}
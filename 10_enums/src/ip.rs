@@ -0,0 +1,268 @@
+/**
+ * The Rust book's IpAddr example, grown up a little.
+ *
+ * The book uses IpAddr to show that each variant can hold *different* data:
+ * a V4 address is four small numbers, a V6 address is something else
+ * entirely. Here a V6 address is eight 16-bit segments, which makes it
+ * easy to compare and inspect (the book just stores a String).
+ *
+ * Since these addresses usually arrive as text, we also add a `parse`
+ * function. Parsing is a great fit for enums twice over: the *result* is an
+ * enum (which kind of address?), and so is the *error* (what went wrong?).
+ *
+ * (The standard library has a real `std::net::IpAddr` with the same shape.
+ * Use that one in real code!)
+ */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpAddr {
+    V4(u8, u8, u8, u8),
+    V6([u16; 8]),
+}
+
+// every distinct way that parsing can fail
+#[derive(Debug, PartialEq)]
+pub enum AddrError {
+    Empty,
+    WrongOctetCount(usize),
+    BadOctet(String),
+    WrongSegmentCount(usize),
+    BadSegment(String),
+    MultipleDoubleColons,
+}
+
+impl fmt::Display for AddrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddrError::Empty => write!(f, "empty address"),
+            AddrError::WrongOctetCount(n) => write!(f, "expected 4 octets, found {}", n),
+            AddrError::BadOctet(s) => write!(f, "'{}' is not an octet (0-255)", s),
+            AddrError::WrongSegmentCount(n) => write!(f, "expected 8 segments, found {}", n),
+            AddrError::BadSegment(s) => write!(f, "'{}' is not a hex segment", s),
+            AddrError::MultipleDoubleColons => write!(f, "'::' may only appear once"),
+        }
+    }
+}
+
+impl IpAddr {
+    // a colon means V6, otherwise we assume V4
+    pub fn parse(s: &str) -> Result<IpAddr, AddrError> {
+        let s = s.trim();
+        if s.is_empty() {
+            Err(AddrError::Empty)
+        } else if s.contains(':') {
+            parse_v6(s)
+        } else {
+            parse_v4(s)
+        }
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            IpAddr::V4(a, ..) => *a == 127,
+            IpAddr::V6(segments) => *segments == [0, 0, 0, 0, 0, 0, 0, 1],
+        }
+    }
+
+    // the private ranges: 10/8, 172.16/12 and 192.168/16 for V4,
+    // and the "unique local" fc00::/7 range for V6
+    pub fn is_private(&self) -> bool {
+        match *self {
+            IpAddr::V4(10, ..) => true,
+            IpAddr::V4(172, 16..=31, ..) => true, // a range inside a pattern!
+            IpAddr::V4(192, 168, ..) => true,
+            IpAddr::V4(..) => false,
+            IpAddr::V6(segments) => segments[0] & 0xfe00 == 0xfc00,
+        }
+    }
+}
+
+fn parse_v4(s: &str) -> Result<IpAddr, AddrError> {
+    let parts: Vec<&str> = s.split('.').collect();
+    if parts.len() != 4 {
+        return Err(AddrError::WrongOctetCount(parts.len()));
+    }
+    let mut octets = [0u8; 4];
+    for (i, part) in parts.iter().enumerate() {
+        // `parse::<u8>` already rejects anything over 255, but it would
+        // happily accept "+1", so insist on plain digits first
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AddrError::BadOctet(part.to_string()));
+        }
+        octets[i] = part.parse().map_err(|_| AddrError::BadOctet(part.to_string()))?;
+    }
+    Ok(IpAddr::V4(octets[0], octets[1], octets[2], octets[3]))
+}
+
+// Handles the full eight-segment form as well as the `::` shorthand,
+// which stands in for "as many zero segments as it takes to make eight".
+fn parse_v6(s: &str) -> Result<IpAddr, AddrError> {
+    let halves: Vec<&str> = s.split("::").collect();
+    let (head, tail) = match halves.len() {
+        1 => (parse_segments(halves[0])?, None),
+        2 => (parse_segments(halves[0])?, Some(parse_segments(halves[1])?)),
+        _ => return Err(AddrError::MultipleDoubleColons),
+    };
+
+    let mut segments = [0u16; 8];
+    match tail {
+        None => {
+            if head.len() != 8 {
+                return Err(AddrError::WrongSegmentCount(head.len()));
+            }
+            segments.copy_from_slice(&head);
+        }
+        Some(tail) => {
+            // `::` must replace at least one segment
+            if head.len() + tail.len() > 7 {
+                return Err(AddrError::WrongSegmentCount(head.len() + tail.len()));
+            }
+            segments[..head.len()].copy_from_slice(&head);
+            segments[8 - tail.len()..].copy_from_slice(&tail);
+        }
+    }
+    Ok(IpAddr::V6(segments))
+}
+
+// "2001:db8:0" -> [0x2001, 0xdb8, 0]; an empty string means no segments
+fn parse_segments(s: &str) -> Result<Vec<u16>, AddrError> {
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+    s.split(':')
+        .map(|seg| {
+            // as with parse_v4's octets, from_str_radix would take a "+1"
+            if seg.is_empty() || seg.len() > 4 || !seg.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(AddrError::BadSegment(seg.to_string()));
+            }
+            u16::from_str_radix(seg, 16).map_err(|_| AddrError::BadSegment(seg.to_string()))
+        })
+        .collect()
+}
+
+// V6 is printed in full (no `::` compression), which keeps this simple
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpAddr::V4(a, b, c, d) => write!(f, "{}.{}.{}.{}", a, b, c, d),
+            IpAddr::V6(segments) => {
+                let parts: Vec<String> = segments.iter().map(|s| format!("{:x}", s)).collect();
+                write!(f, "{}", parts.join(":"))
+            }
+        }
+    }
+}
+
+pub fn demo_ip() {
+    println!("--- IpAddr Demonstration Begins --- ");
+    // the book's original example: two variants holding different data
+    let home = IpAddr::V4(127, 0, 0, 1);
+    let loopback = IpAddr::V6([0, 0, 0, 0, 0, 0, 0, 1]);
+    println!("home: {}, loopback: {}", home, loopback);
+
+    for input in ["192.168.1.20", "8.8.8.8", "256.1.1.1", "fd00::7", "::1", "1::2::3"].iter() {
+        match IpAddr::parse(input) {
+            Ok(addr) => println!("{} -> {} (loopback? {}, private? {})",
+                                 input, addr, addr.is_loopback(), addr.is_private()),
+            Err(e) => println!("{} -> error: {}", input, e),
+        }
+    }
+    println!("--- IpAddr Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_v4() {
+        assert_eq!(IpAddr::parse("127.0.0.1"), Ok(IpAddr::V4(127, 0, 0, 1)));
+        assert_eq!(IpAddr::parse(" 255.255.255.255 "), Ok(IpAddr::V4(255, 255, 255, 255)));
+    }
+
+    #[test]
+    fn rejects_bad_v4() {
+        let table = [
+            ("", AddrError::Empty),
+            ("1.2.3", AddrError::WrongOctetCount(3)),
+            ("1.2.3.4.5", AddrError::WrongOctetCount(5)),
+            ("1.2.3.256", AddrError::BadOctet(String::from("256"))),
+            ("1.2..4", AddrError::BadOctet(String::from(""))),
+            ("1.+2.3.4", AddrError::BadOctet(String::from("+2"))),
+            ("a.b.c.d", AddrError::BadOctet(String::from("a"))),
+        ];
+        for (input, expected) in table.iter() {
+            assert_eq!(IpAddr::parse(input).as_ref(), Err(expected), "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn parses_v6() {
+        assert_eq!(IpAddr::parse("::1"), Ok(IpAddr::V6([0, 0, 0, 0, 0, 0, 0, 1])));
+        assert_eq!(IpAddr::parse("::"), Ok(IpAddr::V6([0; 8])));
+        assert_eq!(
+            IpAddr::parse("2001:db8::ff00:42"),
+            Ok(IpAddr::V6([0x2001, 0xdb8, 0, 0, 0, 0, 0xff00, 0x42]))
+        );
+        assert_eq!(
+            IpAddr::parse("1:2:3:4:5:6:7:8"),
+            Ok(IpAddr::V6([1, 2, 3, 4, 5, 6, 7, 8]))
+        );
+    }
+
+    #[test]
+    fn rejects_bad_v6() {
+        let table = [
+            ("1::2::3", AddrError::MultipleDoubleColons),
+            ("1:2:3", AddrError::WrongSegmentCount(3)),
+            ("1:2:3:4:5:6:7:8:9", AddrError::WrongSegmentCount(9)),
+            ("1:2:3:4::5:6:7:8", AddrError::WrongSegmentCount(8)),
+            ("12345::", AddrError::BadSegment(String::from("12345"))),
+            ("g::1", AddrError::BadSegment(String::from("g"))),
+            ("+1::", AddrError::BadSegment(String::from("+1"))),
+            ("::+ff", AddrError::BadSegment(String::from("+ff"))),
+            ("1:::2", AddrError::BadSegment(String::from(""))),
+        ];
+        for (input, expected) in table.iter() {
+            assert_eq!(IpAddr::parse(input).as_ref(), Err(expected), "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn loopback() {
+        assert!(IpAddr::V4(127, 0, 0, 1).is_loopback());
+        assert!(IpAddr::V4(127, 9, 9, 9).is_loopback());
+        assert!(!IpAddr::V4(128, 0, 0, 1).is_loopback());
+        assert!(IpAddr::parse("::1").unwrap().is_loopback());
+        assert!(!IpAddr::parse("::2").unwrap().is_loopback());
+    }
+
+    #[test]
+    fn private_ranges() {
+        let table = [
+            ("10.1.2.3", true),
+            ("172.15.0.1", false),
+            ("172.16.0.1", true),
+            ("172.31.255.255", true),
+            ("172.32.0.1", false),
+            ("192.168.0.1", true),
+            ("192.169.0.1", false),
+            ("8.8.8.8", false),
+            ("fc00::1", true),
+            ("fdab::1", true),
+            ("fe80::1", false),
+            ("2001:db8::1", false),
+        ];
+        for &(input, expected) in table.iter() {
+            assert_eq!(IpAddr::parse(input).unwrap().is_private(), expected, "input {}", input);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in ["10.0.0.1", "2001:db8:0:0:0:0:ff00:42"].iter() {
+            assert_eq!(IpAddr::parse(input).unwrap().to_string(), *input);
+        }
+    }
+}
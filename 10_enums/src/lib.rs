@@ -0,0 +1,333 @@
+/**
+ * Enums allow you to mix and match different data types, but as a set of
+ * __choices__, where each instance of the Enum is always one and only one
+ * of the available choices. Compare that to structs, which also allow you
+ * to mix different data types, but which require each instance to have one 
+ * value for each end every key within the struct definition. Both of these
+ * data structures allow the aggregation of mixed data types, but they do so
+ * in a way which is completely orthogonal to (or inverted from) the other.
+ * 
+ * Other languages (e.g. Python or Golang) have Enums whose choices are 
+ * limited to simple scalar values (sometimes even just numbers!). That 
+ * limits their  utility compared to Rust enums, which can use the entirety 
+ * of the Rust type system as building blocks, mixing and matching types as 
+ * needed. Rust enum choices can have no associated value, or be associated
+ * with a wrapped constant, or a wrapped variable, or a wrapped tuple, or 
+ * even a wrapped struct instance! This gives them tremendous flexibility 
+ * and power compared to enums based on a simple scalar constant range.
+ * 
+ * Because of this power, Rust Enums are _ubiquitous_. They are used to 
+ * robustly handle concerns which are very tricky in other languages, such 
+ * as safely managing the possibility of null values and errors, or routing 
+ * a variety of incoming argument types from a single function entry point.
+ * Enums are used _all over_ Rust, so you must get familiar with them! 
+ */
+
+// the bigger worked examples each live in a sibling file of their own
+mod bus;
+mod coins;
+mod command;
+mod events;
+mod expr;
+mod list;
+mod maybe;
+mod messages;
+mod patterns;
+mod states;
+mod traffic;
+mod value;
+
+// One demo needs a *crate boundary* to work: `#[non_exhaustive]` only
+// changes the rules for code in a *different* crate. Cargo treats
+// `src/lib.rs` and `src/main.rs` as two separate crates (the binary gets
+// to `use enums::...` just like any outside user would), so the `api`
+// module is public, and its consumer (./handler.rs) lives over in main.rs.
+pub mod api;
+
+// And one is public so that the `benches` package can time enum dispatch
+// against trait objects (see benches/src/lib.rs).
+pub mod shapes;
+
+// And the roman numerals are public for the `wasm` package, which converts
+// them in a browser (see wasm/src/lib.rs).
+pub mod roman;
+
+// And the address parser, so tests/fuzz.rs can throw junk at it.
+pub mod ip;
+
+// the menu entry type that every chapter's DEMOS table is made of
+use common::Demo;
+
+// Here's an Enum with four _choices_ (aka `variants`)
+// An instance of this enum will always be one and only one of these variants.
+// The associated values are _variables_ and not _constants_!
+// Enums are not *required* to have associated values: note that Quit has none.
+#[derive(Debug, PartialEq)] // so the tests in ./bus.rs can compare messages
+enum Message {
+    Quit,   // variant with no associated value
+    Move { x: i32, y: i32 }, // a struct-style variant
+    Write(String),   // variant with a single big-S string value
+    Color(i32, i32, i32), // a tuple-style variant
+}
+
+// Enums can use __impl__ blocks just like structs do. That means they are 
+// first-class participants in Rust polymorphism, hooray!  
+// However, note that while Message is a first-class *type*, each individual
+// variant is *not* a first-class type. That means you cannot have separate
+// implementations for each variant: the whole Enum gets the method, and you
+// must use a match statement inside the method to route the result. 
+// Fortunately, Match statements are awesome, so this is not a big limitation!
+impl Message {
+    fn call(&self) {
+        // The correct way to discriminate Enum variants is a `match` statement.
+        // This is the _only_ idiomatic way to do it!
+        match self {
+            // NB: it seems you have make *namespaced* references to the variants
+            // I wonder if I'm missing a terser way to do this?
+            Message::Quit => println!("I am a Quit"),
+            // NB#2: you must destructure x and y as 'x' and 'y'... there is no
+            // concise way to tive them different local names. After all, this 
+            // is the whole point of using a struct in the first place!
+            Message::Move{x, y} => println!("I am a Move: {}, {}", x, y),
+            // but a single-value variant can get any local name you like
+            Message::Write(foo) => println!("I am a Write: {}", foo),
+            // as can the sequential fields from a tuple variant
+            Message::Color(r, g, b) => println!("I am a Color: {}, {}, {}", r, g, b)
+        }
+    }
+}
+
+// The book's UsState stops after a handful of variants with a `--snip--`.
+// The un-snipped version (all 50 of them!) lives in ./states.rs
+use states::UsState;
+
+// enum of some coin types
+#[derive(Debug, Clone, PartialEq)]
+enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(UsState), // Quarter is the only variant with an associated value
+    HalfDollar,
+    Dollar,
+}
+
+fn value_in_cents(coin: Coin) -> u32 {
+    match coin {
+        Coin::Penny => {
+            // a match clause gets a full-blown block all its own,
+            // so you aren't limited to just returning one value
+            println!("Lucky penny!");
+            1
+        },
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        Coin::Quarter(state) => {
+            println!("State quarter from {:?}!", state);
+            25
+        },
+        Coin::HalfDollar => 50,
+        Coin::Dollar => 100,
+    }
+}
+
+
+// A tiny read-eval-print loop: read a line, parse it into a Command, act on
+// it, repeat. All the interesting decisions happen in ./command.rs, so this
+// loop is nothing but plumbing between stdin and the Command enum.
+pub fn repl() {
+    use std::io::{self, BufRead, Write};
+    use command::Command;
+    use common::Messages;
+
+    // what it says around the commands, in English or Spanish (DEMO_LANG=es)
+    let messages = Messages::from_env(messages::CATALOGS);
+    let mut items: Vec<String> = Vec::new();
+    println!("{}", messages.get("help"));
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout");
+
+        let mut line = String::new();
+        // zero bytes read means end of input (e.g. CTRL-D), so treat as quit
+        if stdin.lock().read_line(&mut line).expect("Failed to read line") == 0 {
+            break;
+        }
+        match Command::parse(&line) {
+            Ok(Command::Quit) => break,
+            Ok(cmd) => println!("{}", command::execute(&cmd, &mut items)),
+            Err(e) => println!("{}", messages.format("error", &[("error", &e)])),
+        }
+    }
+    println!("{}", messages.format("final_list", &[("items", &format!("{:?}", items))]));
+}
+
+// Message, Option<T>, Coin and the UsState quarters, all in this file
+pub fn demo_basics() {
+    let m_quit = Message::Quit;
+    let m_move = Message::Move{x:5, y:7}; // structs require explicit key & value! 
+    let m_write = Message::Write(String::from("hello"));
+    let m_change = Message::Color(255, 200, 100);
+    
+    m_quit.call();
+    m_move.call();
+    m_write.call();
+    m_change.call();
+
+    // in fact, we already met Enums long ago, wa back in the guessing_game 
+    // chapter, where we wrote `match` clauses based on the standard-library 
+    // Option<T> type. This Enum is so fundamental that it's practically a 
+    // language keyword. Any type at all can be wrapped in a // Option<T>.
+    // The only two variants are Option::Some(foo), where foo is a T instance,
+    // or Option::None, where None has no associated value. In Rust you never
+    // ever need to check for null||nil||undefined||None, but it is absolutely
+    // routine to write match clauses for Option::Some || Option::None.
+    
+    // Because Option<T> is so important, it is part of the "prelude", which
+    // means the rust compiler automatically makes it available even if you 
+    // don't ask for it by name. Hence you'll see code all over that has 
+    // match clauses for Some || None, without the namespacing of `Option::`.
+    // This is even more encouragement to think of them as language keywords!
+    // But they are not: they are plain old generic Enum variants. 
+    let s_five = Some(5); // the compiler auto-types this as an Option<i32>
+    let s_none : Option<i32> = None;
+    // and you cannot send an Option<T> straight to println!
+    // println!("S_five is: {}", s_five);
+    
+    fn maybe(candidate: Option<i32>) {
+        match candidate {
+            None => println!("Nuthin' to see here"),
+            Some(x) => println!("That's a {}", x)
+        }
+    }
+    maybe(s_five);
+    maybe(s_none);
+
+    // the compiler complains about every uninstantiated type, yeesh!
+    // and then it complains if they are instantiated and unused, double yeesh!
+    // But it won't complain if you instantiate them with underscores, ok then!
+    // Finally, note that we're going to feed these to functions that feed 
+    // their arguments to println!, which means we face the full fury of the
+    // borrow checker. Hence the borrow types based on `&`
+    let _alabama = &UsState::Alabama;
+    let _alaska = &UsState::Alaska;
+    let _california = &UsState::California;
+    let _colorado = &UsState::Colorado;
+
+    let c_penny = Coin::Penny;
+    let c_nickel = Coin::Nickel;
+    let c_dime = Coin::Dime;
+    let c_quarter = Coin::Quarter(UsState::California);
+    println!("A penny is worth: {}", value_in_cents(c_penny));
+    println!("A nickel is worth {} cents", value_in_cents(c_nickel));
+    println!("A dime is worth {} cents", value_in_cents(c_dime));
+    println!("A quarter is worth {} cents", value_in_cents(c_quarter));
+
+    // out of the box, all match statements must be __exhaustive__,
+    // meaning you need one arm clause for every single variant. 
+    // But sometimes you are genuinely only interested in a subset of
+    // all possible variants. There are two workarounds to help keep
+    // things concise in those situations. The first one is to use a
+    //final `_` as the last arm caluse; this will match everyone else.
+    fn is_california(candidate: &UsState) {
+        match candidate {
+            UsState::California => println!("California here I come!"),
+            // all other states match here
+            _ => println!("Just another state which is NOT California...")
+        }
+    }
+    is_california(_colorado);
+    is_california(_california);
+
+    // `if let` is the the second option, which comes at the problem from the
+    // opposite direction. Rather than saying "everything not named yet", it 
+    // homes in on a single valid candidate, and says "this and only this"
+    fn only_california(candidate: &UsState) {
+        // Here we have to specify both sides of the = test explicitly
+        // This is unlike `match`, where there's an implied = test 
+        // (Technically you could do any comparison that yields a true bool,
+        // remembering that there is no such thing as truthiness in Rust)
+        if let UsState::California = candidate {
+            println!("California here I come!!");
+        } else {
+            println!("Oh, it's some other state. Meh.");
+        }
+    }
+    only_california(_alabama);
+    only_california(_alaska);
+    only_california(_california);
+
+    // so by default, match is 100% exhaustive, which can be exhausting.
+    // Together, `_` and `if let` give you two ways to write more-concise
+    // match statements. One is in the spirit of "everything not forbidden
+    // is allowed", and the other in the spirit of "everything not allowed
+    // is forbidden".
+}
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+pub const DEMOS: &[Demo] = &[
+    Demo::new("basics", "Message, Option<T>, match, `_` and `if let`", demo_basics),
+    Demo::new("states", "All fifty states, with parsing and abbreviations", states::demo_states),
+    Demo::new("maybe", "Our own Option<T>", maybe::demo_maybe),
+    Demo::new("coins", "The rest of the coin purse", coins::demo_coins),
+    Demo::new("ip", "The book's IpAddr example, plus parsing", ip::demo_ip),
+    Demo::new("traffic", "A state machine: an enum plus a transition function", traffic::demo_traffic),
+    Demo::new("list", "An enum that contains itself, via a Box", list::demo_list),
+    Demo::new("value", "JSON in one enum, nested as deep as you like", value::demo_value),
+    Demo::new("command", "Parsing text into an enum", command::demo_command),
+    Demo::new("patterns", "Guards, `@`, ranges and other pattern syntax", patterns::demo_patterns),
+    Demo::new("shapes", "Enum dispatch and trait dispatch, side by side", shapes::demo_shapes),
+    Demo::new("events", "An event loop: a queue of enums and a `while let`", events::demo_events),
+    Demo::new("expr", "A recursive enum as an expression tree", expr::demo_expr),
+    Demo::new("roman", "Roman numerals: seven digits, seven variants", roman::demo_roman),
+    Demo::new("bus", "A queue of Message values, routed through one `match`", bus::demo_bus),
+    Demo::interactive("repl", "Type commands at a prompt and watch them parse", repl),
+];
+
+pub fn demo() {
+    // Message, Option<T> and the coin purse, right here in lib.rs
+    demo_basics();
+
+    // all fifty states, with parsing and abbreviations
+    states::demo_states();
+
+    // Option<T> is just an enum, so we can write our own
+    maybe::demo_maybe();
+
+    // the rest of the coin purse lives in ./coins.rs
+    coins::demo_coins();
+
+    // the book's IpAddr example, plus parsing
+    ip::demo_ip();
+
+    // a state machine is just an enum plus a transition function
+    traffic::demo_traffic();
+
+    // an enum that contains itself (via a Box)
+    list::demo_list();
+
+    // JSON in one enum, nested as deep as you like
+    value::demo_value();
+
+    // parsing text into an enum, as every command-line tool must
+    command::demo_command();
+
+    // the fancier corners of pattern syntax: guards, `@`, ranges and more
+    patterns::demo_patterns();
+
+    // enum dispatch and trait dispatch, side by side
+    shapes::demo_shapes();
+
+    // an event loop: a queue of enums and a `while let`
+    events::demo_events();
+
+    // a recursive enum as an expression tree
+    expr::demo_expr();
+
+    // seven digits, seven variants
+    roman::demo_roman();
+
+    // finally, a whole queue of Message values, routed through one `match`
+    bus::demo_bus();
+}
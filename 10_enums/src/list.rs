@@ -0,0 +1,162 @@
+/**
+ * A recursive enum: the classic "cons list" from Lisp, built with Box.
+ *
+ * A naive definition does not compile:
+ *
+ *      enum List { Cons(i32, List), Nil } // error: recursive type has infinite size
+ *
+ * The compiler needs to know how big a List is, and a List that contains a
+ * List that contains a List... has no finite size. The fix is indirection:
+ * a `Box<List>` is just a pointer to a List on the heap, and a pointer has
+ * a known, fixed size no matter what it points at.
+ */
+use std::mem;
+
+#[derive(Debug, PartialEq)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+// pull in the variant names so we can write `Cons` instead of `List::Cons`
+use self::List::{Cons, Nil};
+
+impl List {
+    pub fn new() -> List {
+        Nil
+    }
+
+    // Put a new value at the head of the list. We can't move out of `*self`
+    // while we only have a `&mut` to it, so mem::replace swaps a Nil in
+    // temporarily and hands us the old list by value.
+    pub fn push_front(&mut self, value: i32) {
+        let old = mem::replace(self, Nil);
+        *self = Cons(value, Box::new(old));
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { next: self }
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Nil
+    }
+
+    pub fn sum(&self) -> i32 {
+        self.iter().sum()
+    }
+}
+
+impl Default for List {
+    fn default() -> List {
+        List::new()
+    }
+}
+
+// Borrowing iterator: just a reference to whichever node comes next
+pub struct Iter<'a> {
+    next: &'a List,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        match self.next {
+            Cons(value, rest) => {
+                self.next = rest;
+                Some(*value)
+            }
+            Nil => None,
+        }
+    }
+}
+
+// The compiler-generated drop for a recursive type is itself recursive:
+// dropping the head drops its Box, which drops the next node, and so on.
+// A long enough list blows the stack! So we unlink the nodes one at a
+// time in a loop instead, and each node is dropped with an empty tail.
+// (Careful: `current` is a List too, so dropping it runs this very
+// function again. That's why Nil must do nothing at all in here.)
+impl Drop for List {
+    fn drop(&mut self) {
+        if let Cons(_, next) = self {
+            let mut current = mem::replace(&mut **next, Nil);
+            while let Cons(_, next) = &mut current {
+                current = mem::replace(&mut **next, Nil);
+            }
+        }
+    }
+}
+
+pub fn demo_list() {
+    println!("--- Cons List Demonstration Begins --- ");
+    let mut list = List::new();
+    println!("A brand new list is empty: {}", list.is_empty());
+    for n in 1..=4 {
+        list.push_front(n);
+    }
+    println!("Our list: {:?}", list);
+    println!("Values in order: {:?}", list.iter().collect::<Vec<i32>>());
+    println!("It has {} items which add up to {}", list.len(), list.sum());
+    println!("--- Cons List Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_list_is_empty() {
+        let list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.sum(), 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn push_front_builds_cons_cells() {
+        let mut list = List::new();
+        list.push_front(2);
+        list.push_front(1);
+        assert_eq!(list, Cons(1, Box::new(Cons(2, Box::new(Nil)))));
+    }
+
+    #[test]
+    fn iterates_from_the_front() {
+        let mut list = List::new();
+        for n in 0..5 {
+            list.push_front(n);
+        }
+        assert_eq!(list.iter().collect::<Vec<i32>>(), vec![4, 3, 2, 1, 0]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.sum(), 10);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn iteration_only_borrows() {
+        let mut list = List::new();
+        list.push_front(7);
+        let first: Vec<i32> = list.iter().collect();
+        let second: Vec<i32> = list.iter().collect();
+        assert_eq!(first, second);
+    }
+
+    // with the default recursive drop, this overflows the (2MB) test stack
+    #[test]
+    fn dropping_a_long_list_does_not_overflow() {
+        let mut list = List::new();
+        for n in 0..1_000_000 {
+            list.push_front(n % 10);
+        }
+        assert_eq!(list.len(), 1_000_000);
+        assert_eq!(list.sum(), 4_500_000);
+        drop(list);
+    }
+}
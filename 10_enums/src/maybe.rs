@@ -0,0 +1,305 @@
+/**
+ * Option<T>, rebuilt by hand as Maybe<T>.
+ *
+ * Way back in main.rs we said that Option<T> is "practically a language
+ * keyword", but that it is really just a plain old generic enum. Here is
+ * the proof: a from-scratch copy with its most-used methods, written with
+ * nothing but `enum`, `impl` and `match`. No compiler magic required.
+ * (Well, one bit: `?` works through a trait, Try, that stable Rust won't
+ * let us implement. But `ok_or` turns a Maybe into a Result, and `?`
+ * works on those.)
+ *
+ * The variant names are borrowed from Haskell (Just/Nothing) so they can't
+ * be confused with the real Some/None from the prelude.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Maybe<T> {
+    Just(T),
+    Nothing,
+}
+
+use self::Maybe::{Just, Nothing};
+
+impl<T> Maybe<T> {
+    pub fn is_just(&self) -> bool {
+        match self {
+            Just(_) => true,
+            Nothing => false,
+        }
+    }
+
+    pub fn is_nothing(&self) -> bool {
+        !self.is_just()
+    }
+
+    // transform the value inside (if there is one); Nothing stays Nothing
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Maybe<U> {
+        match self {
+            Just(value) => Just(f(value)),
+            Nothing => Nothing,
+        }
+    }
+
+    // like map, but `f` itself might come up empty, so no Maybe<Maybe<U>>
+    pub fn and_then<U, F: FnOnce(T) -> Maybe<U>>(self, f: F) -> Maybe<U> {
+        match self {
+            Just(value) => f(value),
+            Nothing => Nothing,
+        }
+    }
+
+    // get the value out, with a fallback for Nothing
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Just(value) => value,
+            Nothing => default,
+        }
+    }
+
+    // the same, with the fallback only worked out when it's needed
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Just(value) => value,
+            Nothing => f(),
+        }
+    }
+
+    // turn "missing" into a proper error, so `?` can propagate it
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Just(value) => Ok(value),
+            Nothing => Err(err),
+        }
+    }
+
+    // and the lazy version, for an error that's costly to build (a format!)
+    pub fn ok_or_else<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E> {
+        match self {
+            Just(value) => Ok(value),
+            Nothing => Err(f()),
+        }
+    }
+
+    // keep the value only if it passes the test
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Maybe<T> {
+        match self {
+            Just(value) if predicate(&value) => Just(value),
+            _ => Nothing,
+        }
+    }
+
+    // this one if it's Just, otherwise the other one
+    pub fn or(self, other: Maybe<T>) -> Maybe<T> {
+        match self {
+            Just(value) => Just(value),
+            Nothing => other,
+        }
+    }
+
+    // both values as a pair, but only if both are there
+    pub fn zip<U>(self, other: Maybe<U>) -> Maybe<(T, U)> {
+        match (self, other) {
+            (Just(a), Just(b)) => Just((a, b)),
+            _ => Nothing,
+        }
+    }
+
+    // move the value out and leave Nothing behind (std::mem::replace, from
+    // 07_ownership's swaps.rs, does the work)
+    pub fn take(&mut self) -> Maybe<T> {
+        std::mem::replace(self, Nothing)
+    }
+
+    // borrow the contents instead of consuming the Maybe
+    pub fn as_ref(&self) -> Maybe<&T> {
+        match self {
+            Just(value) => Just(value),
+            Nothing => Nothing,
+        }
+    }
+
+    // a bridge to the real thing, for comparisons
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Just(value) => Some(value),
+            Nothing => None,
+        }
+    }
+}
+
+// and bridges both ways through the standard From trait, so that
+// `Maybe::from(some_option)` and `some_option.into()` work too
+impl<T> From<Option<T>> for Maybe<T> {
+    fn from(option: Option<T>) -> Maybe<T> {
+        match option {
+            Some(value) => Just(value),
+            None => Nothing,
+        }
+    }
+}
+
+impl<T> From<Maybe<T>> for Option<T> {
+    fn from(maybe: Maybe<T>) -> Option<T> {
+        maybe.into_option()
+    }
+}
+
+// A little workload to run through both types: parse a string, halve it
+// if it's even, then describe the result. Each step can come up empty.
+fn halve(n: i32) -> Option<i32> {
+    if n % 2 == 0 { Some(n / 2) } else { None }
+}
+
+fn maybe_halve(n: i32) -> Maybe<i32> {
+    if n % 2 == 0 { Just(n / 2) } else { Nothing }
+}
+
+pub fn with_option(input: &str) -> Result<String, String> {
+    input.trim().parse::<i32>().ok()
+        .and_then(halve)
+        .map(|n| n * 10)
+        .ok_or(format!("'{}' didn't survive", input))
+        .map(|n| format!("got {}", n))
+}
+
+// the exact same chain, link for link, with our home-made type
+pub fn with_maybe(input: &str) -> Result<String, String> {
+    let parsed: Maybe<i32> = input.trim().parse::<i32>().ok().into();
+    parsed
+        .and_then(maybe_halve)
+        .map(|n| n * 10)
+        .ok_or(format!("'{}' didn't survive", input))
+        .map(|n| format!("got {}", n))
+}
+
+pub fn demo_maybe() {
+    println!("--- Maybe<T> Demonstration Begins --- ");
+    let five = Just(5);
+    let nothing: Maybe<i32> = Nothing;
+    println!("{:?} doubled is {:?}", five, five.map(|n| n * 2));
+    println!("{:?} doubled is {:?}", nothing, nothing.map(|n| n * 2));
+    println!("{:?} or 0 is {}, {:?} or 0 is {}",
+             five, five.unwrap_or(0), nothing, nothing.unwrap_or(0));
+    println!("Is five just? {} Is nothing nothing? {}", five.is_just(), nothing.is_nothing());
+    println!("A borrowed look inside: {:?}", five.as_ref());
+    for input in ["8", "7", "pancakes"].iter() {
+        println!("{:>10}: Option says {:?}, Maybe says {:?}",
+                 input, with_option(input), with_maybe(input));
+    }
+    println!("Filtered to evens: {:?}, zipped with Just(\"five\"): {:?}",
+             five.filter(|n| n % 2 == 0), five.zip(Just("five")));
+    println!("And back to the real thing: {:?}", five.into_option());
+    println!("--- Maybe<T> Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates() {
+        assert!(Just(1).is_just());
+        assert!(!Just(1).is_nothing());
+        assert!(Maybe::<i32>::Nothing.is_nothing());
+    }
+
+    #[test]
+    fn map_and_and_then() {
+        assert_eq!(Just(3).map(|n| n + 1), Just(4));
+        assert_eq!(Nothing.map(|n: i32| n + 1), Nothing);
+        assert_eq!(Just(4).and_then(maybe_halve), Just(2));
+        assert_eq!(Just(3).and_then(maybe_halve), Nothing);
+        assert_eq!(Nothing.and_then(maybe_halve), Nothing);
+    }
+
+    #[test]
+    fn unwrap_or_and_ok_or() {
+        assert_eq!(Just("x").unwrap_or("y"), "x");
+        assert_eq!(Nothing.unwrap_or("y"), "y");
+        assert_eq!(Just(1).ok_or("missing"), Ok(1));
+        assert_eq!(Maybe::<i32>::Nothing.ok_or("missing"), Err("missing"));
+    }
+
+    #[test]
+    fn lazy_fallbacks_only_run_for_nothing() {
+        assert_eq!(Just(1).unwrap_or_else(|| panic!("not needed")), 1);
+        assert_eq!(Nothing.unwrap_or_else(|| 2), 2);
+        assert_eq!(Just(1).ok_or_else(|| -> String { panic!("not needed") }), Ok(1));
+        assert_eq!(Maybe::<i32>::Nothing.ok_or_else(|| format!("no {}", "value")), Err(String::from("no value")));
+    }
+
+    // ok_or is what lets a Maybe take part in `?`
+    fn first_even(values: &[i32]) -> Result<i32, String> {
+        let first: Maybe<&i32> = values.first().into();
+        let value = first.ok_or("empty")?;
+        let even = Just(*value).filter(|n| n % 2 == 0).ok_or("odd")?;
+        Ok(even)
+    }
+
+    #[test]
+    fn ok_or_with_the_question_mark() {
+        assert_eq!(first_even(&[4, 1]), Ok(4));
+        assert_eq!(first_even(&[3]), Err(String::from("odd")));
+        assert_eq!(first_even(&[]), Err(String::from("empty")));
+    }
+
+    #[test]
+    fn filter() {
+        assert_eq!(Just(4).filter(|n| n % 2 == 0), Just(4));
+        assert_eq!(Just(3).filter(|n| n % 2 == 0), Nothing);
+        assert_eq!(Nothing.filter(|n: &i32| n % 2 == 0), Nothing);
+    }
+
+    #[test]
+    fn or_and_zip() {
+        assert_eq!(Just(1).or(Just(2)), Just(1));
+        assert_eq!(Nothing.or(Just(2)), Just(2));
+        assert_eq!(Maybe::<i32>::Nothing.or(Nothing), Nothing);
+        assert_eq!(Just(1).zip(Just("one")), Just((1, "one")));
+        assert_eq!(Just(1).zip(Maybe::<&str>::Nothing), Nothing);
+        assert_eq!(Maybe::<i32>::Nothing.zip(Just("one")), Nothing);
+    }
+
+    #[test]
+    fn take_leaves_nothing() {
+        let mut slot = Just(String::from("moved"));
+        assert_eq!(slot.take(), Just(String::from("moved")));
+        assert_eq!(slot, Nothing);
+        assert_eq!(slot.take(), Nothing);
+    }
+
+    #[test]
+    fn from_and_into_option() {
+        assert_eq!(Maybe::from(Some(1)), Just(1));
+        assert_eq!(Maybe::<i32>::from(None), Nothing);
+        let option: Option<i32> = Just(1).into();
+        assert_eq!(option, Some(1));
+        let round_trip: Option<&str> = Maybe::from(Some("there")).into();
+        assert_eq!(round_trip, Some("there"));
+    }
+
+    #[test]
+    fn as_ref_does_not_consume() {
+        let name = Just(String::from("Ferris"));
+        assert_eq!(name.as_ref().map(|s| s.len()), Just(6));
+        // still ours
+        assert_eq!(name, Just(String::from("Ferris")));
+    }
+
+    #[test]
+    fn behaves_exactly_like_option() {
+        for n in -5..=5 {
+            assert_eq!(maybe_halve(n).into_option(), halve(n));
+            assert_eq!(maybe_halve(n).map(|x| x * 3).into_option(), halve(n).map(|x| x * 3));
+            assert_eq!(maybe_halve(n).unwrap_or(99), halve(n).unwrap_or(99));
+            let even = |x: &i32| x % 2 == 0;
+            assert_eq!(maybe_halve(n).filter(even).into_option(), halve(n).filter(even));
+            assert_eq!(maybe_halve(n).or(Just(0)).into_option(), halve(n).or(Some(0)));
+            assert_eq!(maybe_halve(n).zip(Just(n)).into_option(), halve(n).zip(Some(n)));
+            assert_eq!(maybe_halve(n).ok_or(n), halve(n).ok_or(n));
+        }
+        for input in ["10", "3", "", "abc", " -4 "].iter() {
+            assert_eq!(with_maybe(input), with_option(input));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// What the REPL says around the commands, in each language it can say it in
+// (see common/src/i18n.rs). English comes first, because it's the fallback.
+//
+// The commands themselves stay English words (`add`, `remove`, ...), and so
+// do the parser's own error messages in ./command.rs: those are part of the
+// enum lesson, and its tests pin them down word for word.
+use common::Catalog;
+
+pub static CATALOGS: &[Catalog] = &[
+    Catalog {
+        lang: "en",
+        messages: &[
+            ("help", "\
+commands:
+  add <item>     add an item to the list
+  remove <n>     remove item number n
+  list           show all items
+  help           show this message
+  quit           leave"),
+            ("error", "error: {error}"),
+            ("final_list", "Final list: {items}"),
+        ],
+    },
+    Catalog {
+        lang: "es",
+        messages: &[
+            ("help", "\
+órdenes:
+  add <cosa>     añade una cosa a la lista
+  remove <n>     quita la cosa número n
+  list           muestra todas las cosas
+  help           muestra este mensaje
+  quit           salir"),
+            ("error", "error: {error}"),
+            ("final_list", "Lista final: {items}"),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::HELP;
+    use common::i18n::missing_keys;
+    use common::Messages;
+
+    #[test]
+    fn every_language_has_every_message() {
+        for catalog in CATALOGS {
+            assert_eq!(missing_keys(CATALOGS, catalog.lang), Vec::<&str>::new(), "in {}", catalog.lang);
+        }
+    }
+
+    #[test]
+    fn english_help_is_the_commands_help() {
+        assert_eq!(Messages::new(CATALOGS, "en").get("help"), HELP);
+    }
+}
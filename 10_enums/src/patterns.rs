@@ -0,0 +1,221 @@
+/**
+ * A gallery of the fancier things you can put on the left side of `=>`.
+ *
+ * So far every match arm has been a plain variant, maybe with some names
+ * bound to its contents. But patterns are a little language all their own:
+ *
+ * - match guards: `n if n < 0 => ...` adds an extra boolean test to an arm
+ * - or-patterns: `'a' | 'e' | 'i' => ...` lets one arm match several shapes
+ * - ranges: `90..=100 => ...` matches a whole span of numbers (or chars)
+ * - `@` bindings: `n @ 13..=19 => ...` tests a pattern *and* names the value
+ * - nested destructuring: reach right inside structs inside enums inside tuples
+ *
+ * Each one gets a small function that returns a value, so the tests at the
+ * bottom can pin down exactly what each pattern does.
+ */
+// match guards: the `if` runs only after the pattern itself has matched.
+// Arms are tried top to bottom, so order matters when guards overlap.
+pub fn classify_number(n: i32) -> &'static str {
+    match n {
+        0 => "zero",
+        n if n < 0 => "negative",
+        n if n % 2 == 0 => "positive even",
+        _ => "positive odd",
+    }
+}
+
+// or-patterns: one arm, several alternatives
+pub fn letter_kind(c: char) -> &'static str {
+    match c.to_ascii_lowercase() {
+        'a' | 'e' | 'i' | 'o' | 'u' => "vowel",
+        // a range, which we'll see more of in a second
+        'b'..='z' => "consonant",
+        _ => "not a letter",
+    }
+}
+
+// ranges in patterns work for integers...
+pub fn letter_grade(score: u32) -> char {
+    match score {
+        90..=100 => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        0..=59 => 'F',
+        // the compiler knows u32 goes higher than 100, so we must say
+        // what happens up there too
+        _ => '?',
+    }
+}
+
+// ...and for chars as well
+pub fn char_kind(c: char) -> &'static str {
+    match c {
+        'a'..='z' => "lowercase",
+        'A'..='Z' => "uppercase",
+        '0'..='9' => "digit",
+        ' ' | '\t' | '\n' => "whitespace",
+        _ => "other",
+    }
+}
+
+// `@` bindings: test against a range, but keep hold of the actual value.
+// Without the `@`, an arm like `13..=19` matches but gives us no name.
+pub fn describe_age(age: u32) -> String {
+    match age {
+        0 => String::from("newborn"),
+        years @ 1..=12 => format!("child of {}", years),
+        years @ 13..=19 => format!("teenager of {}", years),
+        years => format!("adult of {}", years),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub enum Segment {
+    Line { start: Point, end: Point },
+    Dot(Point),
+}
+
+// Nested destructuring: patterns can go as deep as the data does.
+// Literal values can appear anywhere inside, and `..` skips the rest.
+pub fn describe_segment(segment: &Segment) -> String {
+    match segment {
+        Segment::Dot(Point { x: 0, y: 0 }) => String::from("a dot at the origin"),
+        Segment::Dot(Point { x, y }) => format!("a dot at ({}, {})", x, y),
+        Segment::Line { start: Point { x: 0, y: 0 }, end: Point { x, y } } => {
+            format!("a line from the origin to ({}, {})", x, y)
+        }
+        // guards can look at anything the pattern bound
+        Segment::Line { start: Point { x: x1, .. }, end: Point { x: x2, .. } } if x1 == x2 => {
+            format!("a vertical line at x = {}", x1)
+        }
+        Segment::Line { start: Point { y: y1, .. }, end: Point { y: y2, .. } } if y1 == y2 => {
+            format!("a horizontal line at y = {}", y1)
+        }
+        Segment::Line { .. } => String::from("a slanted line"),
+    }
+}
+
+// All of the above at once: a tuple of Options, destructured in one go,
+// with or-patterns nested *inside* a variant, plus ranges and `@`.
+pub fn combine(pair: (Option<i32>, Option<i32>)) -> String {
+    match pair {
+        (None, None) => String::from("nothing"),
+        (Some(0), _) | (_, Some(0)) => String::from("has a zero"),
+        (Some(a @ 1..=9), Some(b @ 1..=9)) => format!("two digits: {}{}", a, b),
+        (Some(n), None) | (None, Some(n)) => format!("just {}", n),
+        (Some(a), Some(b)) if a == b => format!("a pair of {}s", a),
+        (Some(1 | -1), Some(_)) => String::from("starts with plus or minus one"),
+        (Some(a), Some(b)) => format!("{} and {}", a, b),
+    }
+}
+
+pub fn demo_patterns() {
+    println!("--- Pattern Gallery Demonstration Begins --- ");
+    for n in [-3, 0, 4, 7].iter() {
+        println!("{} is {}", n, classify_number(*n));
+    }
+    println!("vowels in 'Rustacean': {}",
+             "Rustacean".chars().filter(|c| letter_kind(*c) == "vowel").count());
+    println!("grades for 95, 83, 42: {} {} {}", letter_grade(95), letter_grade(83), letter_grade(42));
+    println!("'q' is {}, '7' is {}, '!' is {}", char_kind('q'), char_kind('7'), char_kind('!'));
+    println!("ages: {}, {}, {}", describe_age(8), describe_age(15), describe_age(40));
+    let line = Segment::Line { start: Point { x: 2, y: 1 }, end: Point { x: 2, y: 9 } };
+    println!("this segment is {}", describe_segment(&line));
+    println!("and this one is {}", describe_segment(&Segment::Dot(Point { x: 0, y: 0 })));
+    println!("(Some(4), Some(2)) is {}", combine((Some(4), Some(2))));
+    println!("--- Pattern Gallery Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guards() {
+        assert_eq!(classify_number(0), "zero");
+        assert_eq!(classify_number(-8), "negative");
+        assert_eq!(classify_number(8), "positive even");
+        assert_eq!(classify_number(9), "positive odd");
+    }
+
+    #[test]
+    fn or_patterns() {
+        assert_eq!(letter_kind('a'), "vowel");
+        assert_eq!(letter_kind('U'), "vowel");
+        assert_eq!(letter_kind('y'), "consonant");
+        assert_eq!(letter_kind('B'), "consonant");
+        assert_eq!(letter_kind('7'), "not a letter");
+    }
+
+    #[test]
+    fn integer_ranges_include_both_ends() {
+        assert_eq!(letter_grade(100), 'A');
+        assert_eq!(letter_grade(90), 'A');
+        assert_eq!(letter_grade(89), 'B');
+        assert_eq!(letter_grade(60), 'D');
+        assert_eq!(letter_grade(59), 'F');
+        assert_eq!(letter_grade(0), 'F');
+        assert_eq!(letter_grade(101), '?');
+    }
+
+    #[test]
+    fn char_ranges() {
+        assert_eq!(char_kind('m'), "lowercase");
+        assert_eq!(char_kind('Z'), "uppercase");
+        assert_eq!(char_kind('0'), "digit");
+        assert_eq!(char_kind('\t'), "whitespace");
+        assert_eq!(char_kind('é'), "other");
+    }
+
+    #[test]
+    fn at_bindings() {
+        assert_eq!(describe_age(0), "newborn");
+        assert_eq!(describe_age(1), "child of 1");
+        assert_eq!(describe_age(12), "child of 12");
+        assert_eq!(describe_age(13), "teenager of 13");
+        assert_eq!(describe_age(20), "adult of 20");
+    }
+
+    #[test]
+    fn nested_destructuring() {
+        let origin = Point { x: 0, y: 0 };
+        let p = |x, y| Point { x, y };
+        assert_eq!(describe_segment(&Segment::Dot(origin)), "a dot at the origin");
+        assert_eq!(describe_segment(&Segment::Dot(p(3, 4))), "a dot at (3, 4)");
+        assert_eq!(
+            describe_segment(&Segment::Line { start: origin, end: p(5, 5) }),
+            "a line from the origin to (5, 5)"
+        );
+        assert_eq!(
+            describe_segment(&Segment::Line { start: p(1, 2), end: p(1, 8) }),
+            "a vertical line at x = 1"
+        );
+        assert_eq!(
+            describe_segment(&Segment::Line { start: p(1, 2), end: p(6, 2) }),
+            "a horizontal line at y = 2"
+        );
+        assert_eq!(
+            describe_segment(&Segment::Line { start: p(1, 2), end: p(3, 4) }),
+            "a slanted line"
+        );
+    }
+
+    #[test]
+    fn everything_at_once() {
+        assert_eq!(combine((None, None)), "nothing");
+        assert_eq!(combine((Some(0), Some(5))), "has a zero");
+        assert_eq!(combine((None, Some(0))), "has a zero");
+        assert_eq!(combine((Some(4), Some(2))), "two digits: 42");
+        assert_eq!(combine((Some(12), None)), "just 12");
+        assert_eq!(combine((None, Some(-3))), "just -3");
+        assert_eq!(combine((Some(11), Some(11))), "a pair of 11s");
+        assert_eq!(combine((Some(-1), Some(50))), "starts with plus or minus one");
+        assert_eq!(combine((Some(20), Some(50))), "20 and 50");
+    }
+}
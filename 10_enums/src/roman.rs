@@ -0,0 +1,222 @@
+/**
+ * Roman numerals: seven digits, one enum.
+ *
+ * Roman numerals only have seven symbols, which makes them a perfect fit
+ * for an enum with one variant per symbol. The tricky part is subtractive
+ * notation: a smaller digit *in front of* a bigger one is subtracted, so
+ * IV is 4 and XC is 90, but only six such pairs are allowed (IV, IX, XL,
+ * XC, CD, CM). The largest number we can write is MMMCMXCIX = 3999.
+ *
+ * Both directions can fail, so each gets its own little error type.
+ */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RomanDigit {
+    I,
+    V,
+    X,
+    L,
+    C,
+    D,
+    M,
+}
+
+impl RomanDigit {
+    pub fn value(self) -> u32 {
+        match self {
+            RomanDigit::I => 1,
+            RomanDigit::V => 5,
+            RomanDigit::X => 10,
+            RomanDigit::L => 50,
+            RomanDigit::C => 100,
+            RomanDigit::D => 500,
+            RomanDigit::M => 1000,
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<RomanDigit> {
+        match c.to_ascii_uppercase() {
+            'I' => Some(RomanDigit::I),
+            'V' => Some(RomanDigit::V),
+            'X' => Some(RomanDigit::X),
+            'L' => Some(RomanDigit::L),
+            'C' => Some(RomanDigit::C),
+            'D' => Some(RomanDigit::D),
+            'M' => Some(RomanDigit::M),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RangeError {
+    pub value: u32,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} can't be written in Roman numerals (only 1 to 3999)", self.value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    InvalidChar(char),
+    // every digit was fine, but not in an order a Roman would write them,
+    // like IIII, VX or IC
+    NotCanonical(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "no numeral given"),
+            ParseError::InvalidChar(c) => write!(f, "'{}' is not a Roman digit", c),
+            ParseError::NotCanonical(s) => write!(f, "'{}' is not a well-formed numeral", s),
+        }
+    }
+}
+
+// Every value we're allowed to write, biggest first, with the six
+// subtractive pairs slotted in between the plain digits.
+const TABLE: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+// Greedy, just like making change: take the biggest piece that fits,
+// as many times as it fits, then move on to the next one.
+pub fn to_roman(n: u32) -> Result<String, RangeError> {
+    if !(1..=3999).contains(&n) {
+        return Err(RangeError { value: n });
+    }
+    let mut remaining = n;
+    let mut numeral = String::new();
+    for &(value, symbol) in TABLE.iter() {
+        while remaining >= value {
+            numeral.push_str(symbol);
+            remaining -= value;
+        }
+    }
+    Ok(numeral)
+}
+
+// Read right to left: a digit smaller than the one after it is subtracted,
+// anything else is added. That rule happily accepts nonsense like "IIII"
+// or "IM", so at the end we write the number back out and insist that we
+// get the very same numeral.
+pub fn from_roman(s: &str) -> Result<u32, ParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut digits = Vec::new();
+    for c in s.chars() {
+        match RomanDigit::from_char(c) {
+            Some(digit) => digits.push(digit),
+            None => return Err(ParseError::InvalidChar(c)),
+        }
+    }
+
+    let mut total = 0;
+    let mut biggest_so_far = 0;
+    for digit in digits.iter().rev() {
+        let value = digit.value();
+        if value < biggest_so_far {
+            total -= value as i64;
+        } else {
+            total += value as i64;
+            biggest_so_far = value;
+        }
+    }
+
+    let upper = s.to_ascii_uppercase();
+    let canonical = if total > 0 { to_roman(total as u32).ok() } else { None };
+    match canonical {
+        Some(ref written) if *written == upper => Ok(total as u32),
+        _ => Err(ParseError::NotCanonical(s.to_string())),
+    }
+}
+
+pub fn demo_roman() {
+    println!("--- Roman Numeral Demonstration Begins --- ");
+    for n in [1, 4, 9, 14, 40, 90, 400, 1994, 2024, 3999].iter() {
+        println!("{:>4} = {}", n, to_roman(*n).unwrap());
+    }
+    if let Err(e) = to_roman(4000) {
+        println!("to_roman(4000): {}", e);
+    }
+    for s in ["MCMXCIV", "xlii", "IIII", "MXQ", ""].iter() {
+        match from_roman(s) {
+            Ok(n) => println!("{:>9} = {}", s, n),
+            Err(e) => println!("{:>9} : {}", s, e),
+        }
+    }
+    println!("One digit up close: {:?} is worth {}", RomanDigit::D, RomanDigit::D.value());
+    println!("--- Roman Numeral Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digit_values() {
+        assert_eq!(RomanDigit::I.value(), 1);
+        assert_eq!(RomanDigit::M.value(), 1000);
+        assert_eq!(RomanDigit::from_char('l'), Some(RomanDigit::L));
+        assert_eq!(RomanDigit::from_char('Z'), None);
+    }
+
+    #[test]
+    fn subtractive_notation() {
+        let pairs = [(4, "IV"), (9, "IX"), (40, "XL"), (90, "XC"), (400, "CD"), (900, "CM")];
+        for &(n, s) in pairs.iter() {
+            assert_eq!(to_roman(n), Ok(s.to_string()));
+            assert_eq!(from_roman(s), Ok(n));
+        }
+        assert_eq!(to_roman(1994), Ok(String::from("MCMXCIV")));
+        assert_eq!(to_roman(3999), Ok(String::from("MMMCMXCIX")));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(to_roman(0), Err(RangeError { value: 0 }));
+        assert_eq!(to_roman(4000), Err(RangeError { value: 4000 }));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(from_roman(""), Err(ParseError::Empty));
+        assert_eq!(from_roman("XIZ"), Err(ParseError::InvalidChar('Z')));
+        for bad in ["IIII", "VV", "IC", "IM", "VX", "XM", "MMMM", "IIV"].iter() {
+            assert_eq!(from_roman(bad), Err(ParseError::NotCanonical(bad.to_string())));
+        }
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_surrounding_space() {
+        assert_eq!(from_roman(" mcmxciv "), Ok(1994));
+    }
+
+    #[test]
+    fn round_trip_every_number() {
+        for n in 1..=3999 {
+            let numeral = to_roman(n).unwrap();
+            assert_eq!(from_roman(&numeral), Ok(n), "{} wrote {}", n, numeral);
+        }
+    }
+}
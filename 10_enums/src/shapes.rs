@@ -0,0 +1,180 @@
+/**
+ * The same shapes, modelled two different ways: enum vs trait object.
+ *
+ * Enum dispatch: one type, a fixed list of variants, and every method is a
+ * `match`. Adding a new *operation* is easy (write one more match), but
+ * adding a new *shape* means editing every match in the program. On the
+ * upside, the compiler checks all of those matches for us.
+ *
+ * Trait dispatch: one trait, any number of types implementing it, stored
+ * behind `Box<dyn Shape>`. Adding a new *shape* is easy (a new struct and
+ * an impl, possibly in someone else's crate), but adding a new *operation*
+ * means touching every implementation.
+ *
+ * Neither is "better". They are mirror images, which is exactly why it is
+ * worth seeing them side by side. (The trait half is a sneak preview of
+ * `15_traits` and the book's chapter 17.)
+ */
+
+// ---- enum dispatch ----
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeEnum {
+    Circle { radius: f64 },
+    Rect { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+impl ShapeEnum {
+    pub fn area(&self) -> f64 {
+        match self {
+            ShapeEnum::Circle { radius } => std::f64::consts::PI * radius * radius,
+            ShapeEnum::Rect { width, height } => width * height,
+            ShapeEnum::Triangle { base, height } => 0.5 * base * height,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShapeEnum::Circle { .. } => "circle",
+            ShapeEnum::Rect { .. } => "rectangle",
+            ShapeEnum::Triangle { .. } => "triangle",
+        }
+    }
+}
+
+// a function consuming the enum version: a plain slice of values
+pub fn total_area_enum(shapes: &[ShapeEnum]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+// ---- trait dispatch ----
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> &'static str;
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Rect {
+    pub width: f64,
+    pub height: f64,
+}
+
+pub struct Triangle {
+    pub base: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+    fn name(&self) -> &'static str {
+        "circle"
+    }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+    fn name(&self) -> &'static str {
+        "rectangle"
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        0.5 * self.base * self.height
+    }
+    fn name(&self) -> &'static str {
+        "triangle"
+    }
+}
+
+// a function consuming the trait version: each shape is a different type,
+// so they have to live behind a pointer (Box) to share one Vec
+pub fn total_area_dyn(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+// convert from one world to the other, which the tests use to check that
+// both versions always agree
+pub fn to_trait_object(shape: ShapeEnum) -> Box<dyn Shape> {
+    match shape {
+        ShapeEnum::Circle { radius } => Box::new(Circle { radius }),
+        ShapeEnum::Rect { width, height } => Box::new(Rect { width, height }),
+        ShapeEnum::Triangle { base, height } => Box::new(Triangle { base, height }),
+    }
+}
+
+pub fn demo_shapes() {
+    println!("--- Shape Enum vs Trait Demonstration Begins --- ");
+    let as_enums = vec![
+        ShapeEnum::Circle { radius: 1.0 },
+        ShapeEnum::Rect { width: 2.0, height: 3.0 },
+        ShapeEnum::Triangle { base: 4.0, height: 5.0 },
+    ];
+    let as_traits: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle { radius: 1.0 }),
+        Box::new(Rect { width: 2.0, height: 3.0 }),
+        Box::new(Triangle { base: 4.0, height: 5.0 }),
+    ];
+    for (e, t) in as_enums.iter().zip(as_traits.iter()) {
+        println!("{:>9}: enum says {:.3}, {} trait object says {:.3}",
+                 e.name(), e.area(), t.name(), t.area());
+    }
+    println!("Total area: enum {:.3}, trait {:.3}",
+             total_area_enum(&as_enums), total_area_dyn(&as_traits));
+    let converted: Vec<Box<dyn Shape>> = as_enums.into_iter().map(to_trait_object).collect();
+    println!("Converted enums to trait objects: total {:.3}", total_area_dyn(&converted));
+    println!("--- Shape Enum vs Trait Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> Vec<ShapeEnum> {
+        vec![
+            ShapeEnum::Circle { radius: 0.0 },
+            ShapeEnum::Circle { radius: 2.5 },
+            ShapeEnum::Rect { width: 3.0, height: 4.0 },
+            ShapeEnum::Rect { width: 0.5, height: 10.0 },
+            ShapeEnum::Triangle { base: 6.0, height: 2.0 },
+        ]
+    }
+
+    #[test]
+    fn known_areas() {
+        assert_eq!(ShapeEnum::Rect { width: 3.0, height: 4.0 }.area(), 12.0);
+        assert_eq!(ShapeEnum::Triangle { base: 6.0, height: 2.0 }.area(), 6.0);
+        assert!((ShapeEnum::Circle { radius: 2.0 }.area() - 12.566).abs() < 1e-3);
+    }
+
+    #[test]
+    fn enum_and_trait_agree_on_every_shape() {
+        for shape in samples() {
+            let object = to_trait_object(shape);
+            assert_eq!(shape.area(), object.area());
+            assert_eq!(shape.name(), object.name());
+        }
+    }
+
+    #[test]
+    fn enum_and_trait_agree_on_totals() {
+        let enums = samples();
+        let objects: Vec<Box<dyn Shape>> = enums.iter().cloned().map(to_trait_object).collect();
+        assert_eq!(total_area_enum(&enums), total_area_dyn(&objects));
+    }
+
+    #[test]
+    fn empty_collections_have_no_area() {
+        assert_eq!(total_area_enum(&[]), 0.0);
+        assert_eq!(total_area_dyn(&[]), 0.0);
+    }
+}
@@ -0,0 +1,421 @@
+/**
+ * All fifty states, for all fifty state quarters.
+ *
+ * The book's version of UsState stops after four variants with a cheeky
+ * `// --snip--`. Here is the un-snipped version, plus the sort of helpers a
+ * real program would want: printable names, postal abbreviations, parsing
+ * from user input via the standard `FromStr` trait, and the year each
+ * state's quarter came out in the 1999-2008 "50 State Quarters" program.
+ *
+ * Writing out 50-arm `match` statements is tedious, but note what we get in
+ * return: if anyone ever adds a 51st variant, *every one* of these matches
+ * becomes a compiler error until it is handled. That is exhaustiveness
+ * checking earning its keep.
+ */
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsState {
+    Alabama,
+    Alaska,
+    Arizona,
+    Arkansas,
+    California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
+    NewYork,
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
+}
+
+impl UsState {
+    // enums don't come with a built-in way to list their variants, so
+    // we keep our own list (in alphabetical order)
+    pub const ALL: [UsState; 50] = [
+        UsState::Alabama,
+        UsState::Alaska,
+        UsState::Arizona,
+        UsState::Arkansas,
+        UsState::California,
+        UsState::Colorado,
+        UsState::Connecticut,
+        UsState::Delaware,
+        UsState::Florida,
+        UsState::Georgia,
+        UsState::Hawaii,
+        UsState::Idaho,
+        UsState::Illinois,
+        UsState::Indiana,
+        UsState::Iowa,
+        UsState::Kansas,
+        UsState::Kentucky,
+        UsState::Louisiana,
+        UsState::Maine,
+        UsState::Maryland,
+        UsState::Massachusetts,
+        UsState::Michigan,
+        UsState::Minnesota,
+        UsState::Mississippi,
+        UsState::Missouri,
+        UsState::Montana,
+        UsState::Nebraska,
+        UsState::Nevada,
+        UsState::NewHampshire,
+        UsState::NewJersey,
+        UsState::NewMexico,
+        UsState::NewYork,
+        UsState::NorthCarolina,
+        UsState::NorthDakota,
+        UsState::Ohio,
+        UsState::Oklahoma,
+        UsState::Oregon,
+        UsState::Pennsylvania,
+        UsState::RhodeIsland,
+        UsState::SouthCarolina,
+        UsState::SouthDakota,
+        UsState::Tennessee,
+        UsState::Texas,
+        UsState::Utah,
+        UsState::Vermont,
+        UsState::Virginia,
+        UsState::Washington,
+        UsState::WestVirginia,
+        UsState::Wisconsin,
+        UsState::Wyoming,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UsState::Alabama => "Alabama",
+            UsState::Alaska => "Alaska",
+            UsState::Arizona => "Arizona",
+            UsState::Arkansas => "Arkansas",
+            UsState::California => "California",
+            UsState::Colorado => "Colorado",
+            UsState::Connecticut => "Connecticut",
+            UsState::Delaware => "Delaware",
+            UsState::Florida => "Florida",
+            UsState::Georgia => "Georgia",
+            UsState::Hawaii => "Hawaii",
+            UsState::Idaho => "Idaho",
+            UsState::Illinois => "Illinois",
+            UsState::Indiana => "Indiana",
+            UsState::Iowa => "Iowa",
+            UsState::Kansas => "Kansas",
+            UsState::Kentucky => "Kentucky",
+            UsState::Louisiana => "Louisiana",
+            UsState::Maine => "Maine",
+            UsState::Maryland => "Maryland",
+            UsState::Massachusetts => "Massachusetts",
+            UsState::Michigan => "Michigan",
+            UsState::Minnesota => "Minnesota",
+            UsState::Mississippi => "Mississippi",
+            UsState::Missouri => "Missouri",
+            UsState::Montana => "Montana",
+            UsState::Nebraska => "Nebraska",
+            UsState::Nevada => "Nevada",
+            UsState::NewHampshire => "New Hampshire",
+            UsState::NewJersey => "New Jersey",
+            UsState::NewMexico => "New Mexico",
+            UsState::NewYork => "New York",
+            UsState::NorthCarolina => "North Carolina",
+            UsState::NorthDakota => "North Dakota",
+            UsState::Ohio => "Ohio",
+            UsState::Oklahoma => "Oklahoma",
+            UsState::Oregon => "Oregon",
+            UsState::Pennsylvania => "Pennsylvania",
+            UsState::RhodeIsland => "Rhode Island",
+            UsState::SouthCarolina => "South Carolina",
+            UsState::SouthDakota => "South Dakota",
+            UsState::Tennessee => "Tennessee",
+            UsState::Texas => "Texas",
+            UsState::Utah => "Utah",
+            UsState::Vermont => "Vermont",
+            UsState::Virginia => "Virginia",
+            UsState::Washington => "Washington",
+            UsState::WestVirginia => "West Virginia",
+            UsState::Wisconsin => "Wisconsin",
+            UsState::Wyoming => "Wyoming",
+        }
+    }
+
+    // the two-letter postal abbreviation
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            UsState::Alabama => "AL",
+            UsState::Alaska => "AK",
+            UsState::Arizona => "AZ",
+            UsState::Arkansas => "AR",
+            UsState::California => "CA",
+            UsState::Colorado => "CO",
+            UsState::Connecticut => "CT",
+            UsState::Delaware => "DE",
+            UsState::Florida => "FL",
+            UsState::Georgia => "GA",
+            UsState::Hawaii => "HI",
+            UsState::Idaho => "ID",
+            UsState::Illinois => "IL",
+            UsState::Indiana => "IN",
+            UsState::Iowa => "IA",
+            UsState::Kansas => "KS",
+            UsState::Kentucky => "KY",
+            UsState::Louisiana => "LA",
+            UsState::Maine => "ME",
+            UsState::Maryland => "MD",
+            UsState::Massachusetts => "MA",
+            UsState::Michigan => "MI",
+            UsState::Minnesota => "MN",
+            UsState::Mississippi => "MS",
+            UsState::Missouri => "MO",
+            UsState::Montana => "MT",
+            UsState::Nebraska => "NE",
+            UsState::Nevada => "NV",
+            UsState::NewHampshire => "NH",
+            UsState::NewJersey => "NJ",
+            UsState::NewMexico => "NM",
+            UsState::NewYork => "NY",
+            UsState::NorthCarolina => "NC",
+            UsState::NorthDakota => "ND",
+            UsState::Ohio => "OH",
+            UsState::Oklahoma => "OK",
+            UsState::Oregon => "OR",
+            UsState::Pennsylvania => "PA",
+            UsState::RhodeIsland => "RI",
+            UsState::SouthCarolina => "SC",
+            UsState::SouthDakota => "SD",
+            UsState::Tennessee => "TN",
+            UsState::Texas => "TX",
+            UsState::Utah => "UT",
+            UsState::Vermont => "VT",
+            UsState::Virginia => "VA",
+            UsState::Washington => "WA",
+            UsState::WestVirginia => "WV",
+            UsState::Wisconsin => "WI",
+            UsState::Wyoming => "WY",
+        }
+    }
+
+    // Quarters were released five per year, in order of statehood.
+    // Or-patterns (`a | b`) let one arm cover all five states at once.
+    pub fn quarter_year(&self) -> u32 {
+        match self {
+            UsState::Delaware
+            | UsState::Pennsylvania
+            | UsState::NewJersey
+            | UsState::Georgia
+            | UsState::Connecticut => 1999,
+            UsState::Massachusetts
+            | UsState::Maryland
+            | UsState::SouthCarolina
+            | UsState::NewHampshire
+            | UsState::Virginia => 2000,
+            UsState::NewYork
+            | UsState::NorthCarolina
+            | UsState::RhodeIsland
+            | UsState::Vermont
+            | UsState::Kentucky => 2001,
+            UsState::Tennessee
+            | UsState::Ohio
+            | UsState::Louisiana
+            | UsState::Indiana
+            | UsState::Mississippi => 2002,
+            UsState::Illinois
+            | UsState::Alabama
+            | UsState::Maine
+            | UsState::Missouri
+            | UsState::Arkansas => 2003,
+            UsState::Michigan
+            | UsState::Florida
+            | UsState::Texas
+            | UsState::Iowa
+            | UsState::Wisconsin => 2004,
+            UsState::California
+            | UsState::Minnesota
+            | UsState::Oregon
+            | UsState::Kansas
+            | UsState::WestVirginia => 2005,
+            UsState::Nevada
+            | UsState::Nebraska
+            | UsState::Colorado
+            | UsState::NorthDakota
+            | UsState::SouthDakota => 2006,
+            UsState::Montana
+            | UsState::Washington
+            | UsState::Idaho
+            | UsState::Wyoming
+            | UsState::Utah => 2007,
+            UsState::Oklahoma
+            | UsState::NewMexico
+            | UsState::Arizona
+            | UsState::Alaska
+            | UsState::Hawaii => 2008,
+        }
+    }
+
+    // was this state's quarter released after the given year?
+    pub fn quarter_minted_after(&self, year: u32) -> bool {
+        self.quarter_year() > year
+    }
+}
+
+// a typed error, so callers can tell *what* failed to parse
+#[derive(Debug, PartialEq)]
+pub struct ParseStateError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseStateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a US state name or abbreviation", self.input)
+    }
+}
+
+impl Error for ParseStateError {}
+
+// Implementing FromStr is what makes `"Ohio".parse::<UsState>()` work.
+// Both full names and abbreviations are accepted, in any letter case.
+impl FromStr for UsState {
+    type Err = ParseStateError;
+
+    fn from_str(s: &str) -> Result<UsState, ParseStateError> {
+        let wanted = s.trim();
+        UsState::ALL
+            .iter()
+            .find(|state| {
+                state.name().eq_ignore_ascii_case(wanted)
+                    || state.abbreviation().eq_ignore_ascii_case(wanted)
+            })
+            .copied()
+            .ok_or_else(|| ParseStateError { input: s.to_string() })
+    }
+}
+
+pub fn demo_states() {
+    println!("--- UsState Demonstration Begins --- ");
+    for input in ["ny", "Rhode Island", "Tx", "Puerto Rico"].iter() {
+        match input.parse::<UsState>() {
+            Ok(state) => println!("{:?} parses to {} ({}), quarter from {}",
+                                  input, state.name(), state.abbreviation(), state.quarter_year()),
+            Err(e) => println!("Oops: {}", e),
+        }
+    }
+    let late = UsState::ALL.iter().filter(|s| s.quarter_minted_after(2006)).count();
+    println!("{} of {} state quarters came out after 2006", late, UsState::ALL.len());
+    println!("--- UsState Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (input, expected state) pairs: names, abbreviations, odd casing
+    const GOOD_INPUTS: [(&str, UsState); 8] = [
+        ("Alabama", UsState::Alabama),
+        ("AL", UsState::Alabama),
+        ("new york", UsState::NewYork),
+        ("Ny", UsState::NewYork),
+        ("WEST VIRGINIA", UsState::WestVirginia),
+        ("wv", UsState::WestVirginia),
+        ("  Hawaii  ", UsState::Hawaii),
+        ("ak", UsState::Alaska),
+    ];
+
+    #[test]
+    fn parses_names_and_abbreviations() {
+        for &(input, expected) in GOOD_INPUTS.iter() {
+            assert_eq!(input.parse::<UsState>(), Ok(expected), "input was {:?}", input);
+        }
+    }
+
+    #[test]
+    fn rejects_non_states() {
+        for &input in ["", "Puerto Rico", "XX", "NewYork", "Californ"].iter() {
+            assert_eq!(
+                input.parse::<UsState>(),
+                Err(ParseStateError { input: input.to_string() })
+            );
+        }
+    }
+
+    #[test]
+    fn every_state_round_trips() {
+        for state in UsState::ALL.iter() {
+            assert_eq!(state.name().parse::<UsState>(), Ok(*state));
+            assert_eq!(state.abbreviation().parse::<UsState>(), Ok(*state));
+        }
+    }
+
+    #[test]
+    fn abbreviations_are_unique_two_letter_codes() {
+        let mut seen: Vec<&str> = UsState::ALL.iter().map(|s| s.abbreviation()).collect();
+        assert!(seen.iter().all(|a| a.len() == 2 && a.chars().all(|c| c.is_ascii_uppercase())));
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 50);
+    }
+
+    #[test]
+    fn quarter_years() {
+        let table = [
+            (UsState::Delaware, 1999),
+            (UsState::Virginia, 2000),
+            (UsState::California, 2005),
+            (UsState::Hawaii, 2008),
+        ];
+        for &(state, year) in table.iter() {
+            assert_eq!(state.quarter_year(), year);
+            assert!(state.quarter_minted_after(year - 1));
+            assert!(!state.quarter_minted_after(year));
+        }
+    }
+
+    #[test]
+    fn five_quarters_per_year() {
+        for year in 1999..=2008 {
+            let count = UsState::ALL.iter().filter(|s| s.quarter_year() == year).count();
+            assert_eq!(count, 5, "year {}", year);
+        }
+    }
+}
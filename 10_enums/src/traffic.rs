@@ -0,0 +1,121 @@
+/**
+ * A traffic light as a state machine.
+ *
+ * An enum is the natural way to model "exactly one of these states at a
+ * time". Each transition is just a function from one variant to the next,
+ * and because `match` is exhaustive, the compiler checks that we said what
+ * happens next for *every* state. Forget one and it won't build.
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrafficLight {
+    Red,
+    Green,
+    Yellow,
+}
+
+// one entry in the simulation log: we were in `from` for `seconds`,
+// and then switched to `to`
+#[derive(Debug, PartialEq)]
+pub struct Transition {
+    pub from: TrafficLight,
+    pub to: TrafficLight,
+    pub seconds: u32,
+}
+
+impl TrafficLight {
+    // Red -> Green -> Yellow -> Red, and round we go.
+    // Note that `self` is taken by value: TrafficLight is Copy, so this
+    // costs nothing, and it reads nicely as `light = light.next()`
+    pub fn next(self) -> TrafficLight {
+        match self {
+            TrafficLight::Red => TrafficLight::Green,
+            TrafficLight::Green => TrafficLight::Yellow,
+            TrafficLight::Yellow => TrafficLight::Red,
+        }
+    }
+
+    // how long the light stays in this state, in seconds
+    pub fn duration(self) -> u32 {
+        match self {
+            TrafficLight::Red => 30,
+            TrafficLight::Green => 25,
+            TrafficLight::Yellow => 5,
+        }
+    }
+}
+
+// Run the light through `cycles` full Red -> Green -> Yellow -> Red loops,
+// starting from Red, and return every transition along the way.
+pub fn run(cycles: u32) -> Vec<Transition> {
+    let mut log = Vec::new();
+    let mut light = TrafficLight::Red;
+    // three transitions make one full cycle
+    for _ in 0..cycles * 3 {
+        let next = light.next();
+        log.push(Transition { from: light, to: next, seconds: light.duration() });
+        light = next;
+    }
+    log
+}
+
+pub fn demo_traffic() {
+    println!("--- TrafficLight Demonstration Begins --- ");
+    let log = run(2);
+    for step in &log {
+        println!("{:?} for {}s, then {:?}", step.from, step.seconds, step.to);
+    }
+    let total: u32 = log.iter().map(|step| step.seconds).sum();
+    println!("Two full cycles take {} seconds", total);
+    println!("--- TrafficLight Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_follows_the_cycle() {
+        assert_eq!(TrafficLight::Red.next(), TrafficLight::Green);
+        assert_eq!(TrafficLight::Green.next(), TrafficLight::Yellow);
+        assert_eq!(TrafficLight::Yellow.next(), TrafficLight::Red);
+    }
+
+    #[test]
+    fn three_steps_return_to_start() {
+        for &light in [TrafficLight::Red, TrafficLight::Green, TrafficLight::Yellow].iter() {
+            assert_eq!(light.next().next().next(), light);
+        }
+    }
+
+    #[test]
+    fn zero_cycles_is_an_empty_log() {
+        assert!(run(0).is_empty());
+    }
+
+    #[test]
+    fn one_cycle_log() {
+        assert_eq!(run(1), vec![
+            Transition { from: TrafficLight::Red, to: TrafficLight::Green, seconds: 30 },
+            Transition { from: TrafficLight::Green, to: TrafficLight::Yellow, seconds: 25 },
+            Transition { from: TrafficLight::Yellow, to: TrafficLight::Red, seconds: 5 },
+        ]);
+    }
+
+    #[test]
+    fn every_transition_is_legal_and_chained() {
+        let log = run(5);
+        assert_eq!(log.len(), 15);
+        for step in &log {
+            assert_eq!(step.from.next(), step.to);
+            assert_eq!(step.seconds, step.from.duration());
+        }
+        // each step starts where the previous one finished
+        for pair in log.windows(2) {
+            assert_eq!(pair[0].to, pair[1].from);
+        }
+        // and we never go straight from Green back to Red
+        assert!(!log.iter().any(|s| s.from == TrafficLight::Green && s.to == TrafficLight::Red));
+        assert_eq!(log.last().unwrap().to, TrafficLight::Red);
+    }
+}
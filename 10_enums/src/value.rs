@@ -0,0 +1,217 @@
+/**
+ * A JSON-like Value, as one recursive enum.
+ *
+ * JSON has exactly six kinds of value, which makes it a perfect fit for an
+ * enum with six variants. Two of those variants (Array and Object) contain
+ * *more* Values, so this is a recursive type, just like the cons list. But
+ * here the recursion goes through a Vec instead of a Box: a Vec already
+ * keeps its contents on the heap, so the size of a Value stays finite.
+ *
+ * Object is a Vec of (key, value) pairs rather than a HashMap, so that
+ * keys keep the order they were written in when we print them back out.
+ */
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    // Follow a dotted path like "a.b.0" down into nested values.
+    // Each segment is an object key, or an array index if we are
+    // currently looking at an array. An empty path means "this value".
+    pub fn get_path(&self, path: &str) -> Option<&Value> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        let mut current = self;
+        for segment in path.split('.') {
+            current = match current {
+                Value::Object(pairs) => {
+                    pairs.iter().find(|(key, _)| key == segment).map(|(_, value)| value)?
+                }
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                // you can't look inside a scalar
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    // multi-line output with two-space indentation
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    // The recursive heart of the printer: scalars print themselves, and
+    // containers print their children one level deeper.
+    fn write_pretty(&self, out: &mut String, depth: usize) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(&b.to_string()),
+            Value::Number(n) => out.push_str(&n.to_string()),
+            Value::String(s) => out.push_str(&quote(s)),
+            // empty containers stay on one line
+            Value::Array(items) if items.is_empty() => out.push_str("[]"),
+            Value::Object(pairs) if pairs.is_empty() => out.push_str("{}"),
+            Value::Array(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&indent(depth + 1));
+                    item.write_pretty(out, depth + 1);
+                    if i + 1 < items.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&indent(depth));
+                out.push(']');
+            }
+            Value::Object(pairs) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    out.push_str(&indent(depth + 1));
+                    out.push_str(&quote(key));
+                    out.push_str(": ");
+                    value.write_pretty(out, depth + 1);
+                    if i + 1 < pairs.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&indent(depth));
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+// Wrap a string in double quotes, escaping the characters JSON insists on:
+// control characters without a short escape of their own become \uXXXX
+// (as in common/src/json.rs)
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// a small document to play with, shared by the demo and the tests
+fn sample() -> Value {
+    Value::Object(vec![
+        (String::from("name"), Value::String(String::from("Ferris"))),
+        (String::from("age"), Value::Number(7.0)),
+        (String::from("crab"), Value::Bool(true)),
+        (String::from("owner"), Value::Null),
+        (String::from("friends"), Value::Array(vec![
+            Value::Object(vec![
+                (String::from("name"), Value::String(String::from("Corro"))),
+                (String::from("tags"), Value::Array(vec![])),
+            ]),
+            Value::Number(2.5),
+        ])),
+    ])
+}
+
+pub fn demo_value() {
+    println!("--- Value Demonstration Begins --- ");
+    let doc = sample();
+    println!("{}", doc.pretty());
+    for path in ["name", "friends.0.name", "friends.1", "friends.9", "age.oops"].iter() {
+        match doc.get_path(path) {
+            Some(value) => println!("{} => {:?}", path, value),
+            None => println!("{} => (nothing there)", path),
+        }
+    }
+    println!("--- Value Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalars_print_on_one_line() {
+        assert_eq!(Value::Null.pretty(), "null");
+        assert_eq!(Value::Bool(false).pretty(), "false");
+        assert_eq!(Value::Number(42.0).pretty(), "42");
+        assert_eq!(Value::Number(-0.5).pretty(), "-0.5");
+        assert_eq!(Value::String(String::from("hi")).pretty(), "\"hi\"");
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        let v = Value::String(String::from("say \"hi\"\\\n"));
+        assert_eq!(v.pretty(), r#""say \"hi\"\\\n""#);
+        let v = Value::String(String::from("line\r\nbell\u{7}del\u{7f}"));
+        assert_eq!(v.pretty(), r#""line\r\nbell\u0007del\u007f""#);
+    }
+
+    #[test]
+    fn empty_containers() {
+        assert_eq!(Value::Array(vec![]).pretty(), "[]");
+        assert_eq!(Value::Object(vec![]).pretty(), "{}");
+    }
+
+    #[test]
+    fn nested_pretty_print() {
+        let expected = "\
+{
+  \"name\": \"Ferris\",
+  \"age\": 7,
+  \"crab\": true,
+  \"owner\": null,
+  \"friends\": [
+    {
+      \"name\": \"Corro\",
+      \"tags\": []
+    },
+    2.5
+  ]
+}";
+        assert_eq!(sample().pretty(), expected);
+    }
+
+    #[test]
+    fn get_path_walks_objects_and_arrays() {
+        let doc = sample();
+        assert_eq!(doc.get_path(""), Some(&doc));
+        assert_eq!(doc.get_path("age"), Some(&Value::Number(7.0)));
+        assert_eq!(doc.get_path("friends.0.name"), Some(&Value::String(String::from("Corro"))));
+        assert_eq!(doc.get_path("friends.1"), Some(&Value::Number(2.5)));
+        assert_eq!(doc.get_path("friends.0.tags"), Some(&Value::Array(vec![])));
+    }
+
+    #[test]
+    fn get_path_misses() {
+        let doc = sample();
+        assert_eq!(doc.get_path("nope"), None);
+        assert_eq!(doc.get_path("friends.2"), None);
+        assert_eq!(doc.get_path("friends.x"), None);
+        assert_eq!(doc.get_path("friends.-1"), None);
+        // scalars have no children
+        assert_eq!(doc.get_path("name.0"), None);
+        assert_eq!(doc.get_path("owner.anything"), None);
+    }
+}
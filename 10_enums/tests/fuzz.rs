@@ -0,0 +1,31 @@
+/**
+ * The chapter's two parsers, fed thousands of made-up inputs (see
+ * common/src/fuzz.rs): whatever they get, they answer with an Ok or one of
+ * their own error variants, and never panic.
+ */
+use common::fuzz;
+use enums::ip::IpAddr;
+use enums::roman::{from_roman, to_roman};
+
+#[test]
+fn ip_addresses() {
+    let corpus: &[&[u8]] = &[b"127.0.0.1", b"192.168.1.20", b"::1", b"fd00::7", b"2001:db8:0:0:0:0:0:1", b"1::2::3"];
+    fuzz::check("IpAddr::parse", corpus, |bytes| {
+        let text = String::from_utf8_lossy(bytes);
+        // and whatever it accepts, it prints as something it accepts again
+        if let Ok(addr) = IpAddr::parse(&text) {
+            assert_eq!(IpAddr::parse(&addr.to_string()), Ok(addr), "from {:?}", text);
+        }
+    });
+}
+
+#[test]
+fn roman_numerals() {
+    let corpus: &[&[u8]] = &[b"XIV", b"MCMXCIV", b"MMMCMXCIX", b"IIII", b"IC"];
+    fuzz::check("from_roman", corpus, |bytes| {
+        let text = String::from_utf8_lossy(bytes);
+        if let Ok(n) = from_roman(&text) {
+            assert_eq!(from_roman(&to_roman(n).unwrap()), Ok(n), "from {:?}", text);
+        }
+    });
+}
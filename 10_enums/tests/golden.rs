@@ -0,0 +1,20 @@
+/**
+ * The demos' output, and a session at the REPL, checked against
+ * tests/golden/ (the how and why are in common/src/golden.rs).
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn demo() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_enums"), DIR), "");
+    golden::check(DIR, "demo", &output);
+}
+
+#[test]
+fn repl() {
+    let typed = "add milk\nadd eggs\nlist\nremove 1\nremove 7\njump\nlist\nquit\n";
+    let output = golden::output(golden::command(env!("CARGO_BIN_EXE_enums"), DIR).arg("repl"), typed);
+    golden::check(DIR, "repl", &output);
+}
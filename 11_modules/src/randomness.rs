@@ -0,0 +1,90 @@
+/**
+ * A facade: the one file in this chapter that knows where randomness comes
+ * from. Everything else asks *this* module for a number, an id or a
+ * shuffle, and never names `common` (or the `rand` crate behind it).
+ *
+ * Why bother, when `common` is right there? Because dependencies change.
+ * rand has broken its API more than once (`gen_range(1, 101)` became
+ * `gen_range(1..101)`, `thread_rng` became `rng`), and when that happens,
+ * every file that called it directly has to change too. Behind a facade,
+ * one file changes and the rest of the crate never notices. The functions
+ * below are the whole of what the chapter needs, with names and argument
+ * types of our own choosing.
+ *
+ * The `pub use` line re-exports the generator types, so callers get them
+ * from here too: `crate::randomness::ThreadRng`, not `common::ThreadRng`.
+ * A re-export is how a module says "this is part of my API", even though
+ * it was defined somewhere else.
+ */
+pub use common::{Rng, SeededRng, ThreadRng};
+use std::ops::RangeInclusive;
+
+// a number in `range`, both ends included: random_in_range(rng, 1..=6) is a die
+pub fn random_in_range(rng: &mut impl Rng, range: RangeInclusive<i32>) -> i32 {
+  let (start, end) = (*range.start(), *range.end());
+  // Rng::range wants one past the end, and past i32::MAX there's no i32.
+  // So for a range that ends there, draw from one lower and add 1; and if
+  // it starts at i32::MIN too, it's every i32, which is any 32 bits at all.
+  match (end.checked_add(1), start.checked_sub(1)) {
+    (Some(high), _) => rng.range(start, high),
+    (None, Some(low)) => rng.range(low, end) + 1,
+    (None, None) => ((rng.range(0, 1 << 16) << 16) | rng.range(0, 1 << 16)) as u32 as i32,
+  }
+}
+
+// `length` random letters and digits, for telling things apart
+pub fn random_id(rng: &mut impl Rng, length: usize) -> String {
+  common::random_id_from(rng, length)
+}
+
+// Put `items` in a random order, every order equally likely (the
+// Fisher-Yates shuffle: swap each item with one at or before it)
+pub fn shuffle<T>(rng: &mut impl Rng, items: &mut [T]) {
+  for last in (1..items.len()).rev() {
+    let pick = random_in_range(rng, 0..=last as i32) as usize;
+    items.swap(pick, last);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ranges_include_both_ends() {
+    let mut rng = SeededRng::new(1);
+    let rolls: Vec<i32> = (0..600).map(|_| random_in_range(&mut rng, 1..=6)).collect();
+    assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+    assert!(rolls.contains(&1) && rolls.contains(&6));
+    assert_eq!(random_in_range(&mut rng, 4..=4), 4);
+  }
+
+  #[test]
+  fn ranges_can_end_at_i32_max() {
+    let mut rng = SeededRng::new(2);
+    assert_eq!(random_in_range(&mut rng, i32::MAX..=i32::MAX), i32::MAX);
+    let top: Vec<i32> = (0..100).map(|_| random_in_range(&mut rng, i32::MAX - 1..=i32::MAX)).collect();
+    assert!(top.contains(&(i32::MAX - 1)) && top.contains(&i32::MAX));
+    // and every i32 there is: both signs turn up
+    let any: Vec<i32> = (0..100).map(|_| random_in_range(&mut rng, i32::MIN..=i32::MAX)).collect();
+    assert!(any.iter().any(|&n| n < 0) && any.iter().any(|&n| n > 0));
+  }
+
+  #[test]
+  fn ids_come_from_the_generator() {
+    assert_eq!(random_id(&mut SeededRng::new(7), 12), random_id(&mut SeededRng::new(7), 12));
+    assert!(random_id(&mut SeededRng::new(7), 12).chars().all(|c| c.is_ascii_alphanumeric()));
+  }
+
+  #[test]
+  fn a_shuffle_keeps_every_item() {
+    let mut items: Vec<u32> = (0..20).collect();
+    shuffle(&mut SeededRng::new(3), &mut items);
+    assert_ne!(items, (0..20).collect::<Vec<u32>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    // and nothing to shuffle is no trouble
+    shuffle(&mut SeededRng::new(3), &mut [0u8; 0]);
+    shuffle(&mut SeededRng::new(3), &mut [1]);
+  }
+}
@@ -21,5 +21,5 @@
 // With the `pub`, other files can `use` it, based on the directory path
 // shenanigans described above. 
 pub fn clarinet(path: &str) {
-    println!("A clarinet goes tweet tweet! (via {} path)", path);
+    common::info!("A clarinet goes tweet tweet! (via {} path)", path);
 }
@@ -17,7 +17,7 @@
 // As mentioned above, if we *do* use `mod` internally, it creates a submodule.
 pub mod eggs {
     pub fn toast(path: &str) {
-        println!("I am Toast; hear me roar (via a {} path!)", path);
+        common::info!("I am Toast; hear me roar (via a {} path!)", path);
     }
 
     // this function demonstrates the use of `super::` 
@@ -34,6 +34,6 @@ pub mod eggs {
 
 // this fn is part of the spam module
 pub fn beans(msg: &str)  {
-    println!("Beans says: {}", msg);
+    common::info!("Beans says: {}", msg);
 }
 
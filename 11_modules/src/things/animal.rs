@@ -12,7 +12,7 @@ impl Animal {
         Animal {
             name: String::from(name),
             // note the use of `super` to reach up one tier in the hierarchy
-            id: super::get_id(8),
+            id: super::get_id(&mut super::ThreadRng, 8),
         }
     }
 }
@@ -12,7 +12,7 @@ impl Mineral {
     pub fn new(name: &str) -> Mineral {
         Mineral {
             name: String::from(name),
-            id: super::get_id(8),
+            id: super::get_id(&mut super::ThreadRng, 8),
         }
     }
 }
@@ -5,11 +5,16 @@
  * the directory, creating a one:one relationship between the directory and
  * this file, under the name of the directory. 
  * 
- * The first imports in this file will be from the external `rand` crate. 
- * We will use those functions down the road to generate uuid-like identifiers.
+ * The first import in this file is from this crate's own `randomness`
+ * module, which we will use down the road to generate uuid-like
+ * identifiers. (It used to be the `rand` crate, used directly, and then the
+ * external `common` crate, which lives in the `common/` directory at the top
+ * of the workspace and wraps rand up. Now ../randomness.rs wraps *that*, so
+ * this is the last time the source of our randomness moves this file.)
  * The general sequence for using an *external* library is as follows:
  * 
  * 1) add it to the [dependencies] section of the root `Cargo.toml` file
+ *    (from crates.io by version, or from disk by `path`, as here)
  * 2) _declare_ which specific aspects of the crate you will be using
  * 3) use it!
  * 
@@ -22,7 +27,7 @@
  * 2018, and assuming you are using `cargo`, you will never need to specify
  * `extern crate foo`)
  * 
- * After the rand-related imports, we define some sub-modules for this one.  
+ * After the import, we define some sub-modules for this one.  
  * All three of these references end in a semicolon instead of a block {}. 
  * Therefore, the compiler will look for all of them as  _peer files_. We are 
  * defining these as modules from *above*: when you look into these files 
@@ -30,9 +35,8 @@
  * words, each of these files "thinks" of itself as its own root. In Rust,
  * *modularity is in the eye of the beholder*. 
  */
-// note the shortcut form for >1 import from a library: {thread_rng, Rng}
-use rand::{thread_rng, Rng};
-use rand::distributions::Alphanumeric; // an Enum variant, presumably
+// a single function (and the generator types), by way of the facade
+use crate::randomness::{random_id, Rng, ThreadRng};
 
 pub mod animal;
 pub mod mineral;
@@ -53,7 +57,7 @@ pub fn assortment() -> [Thing; 3] {
 }
 
 pub fn greet() {
-  println!("Greetings from the things module ({})", &get_id(7));
+  common::info!("Greetings from the things module ({})", &get_id(&mut ThreadRng, 7));
 }
 
 // Get a uuid-like pseudorandom identifier
@@ -62,12 +66,10 @@ pub fn greet() {
 // whenever an entity is looking *downwards*, deeper into a module or submodule. 
 // But the submodules of this module *can* call it, because access is *public*
 // by default when a submodule looks *up* into its own module ancestry tree.
-fn get_id(length: usize) -> String {
-  let rand_string: String = thread_rng()  // rng for `Random Number Generator`
-        .sample_iter(&Alphanumeric)   // distributions enum, Alphanumeric variant
-        .take(length)  // take however many you like
-        .collect();    // and bundle them up together into a String
-  // ending with rand_string all by itself as an _expression_ has the same
-  // effect as saying `return rand_string;`, but it is more idiomatic in Rust
-  rand_string
+// (The actual randomness now comes from ../randomness.rs, but wrapping it
+// in a private fn keeps that lesson intact: `randomness::random_id` is pub
+// in its module, and `get_id` is still private in ours. The randomness
+// comes in as an argument, so a test can pass a SeededRng.)
+fn get_id(rng: &mut impl Rng, length: usize) -> String {
+  random_id(rng, length)
 }
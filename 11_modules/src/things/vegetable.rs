@@ -16,7 +16,7 @@ impl Vegetable {
     pub fn new(name: &str) -> Vegetable {
         Vegetable {
             name: String::from(name),
-            id: super::get_id(8),
+            id: super::get_id(&mut super::ThreadRng, 8),
         }
     }
 }
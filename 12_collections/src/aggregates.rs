@@ -0,0 +1,100 @@
+/*!
+ * Loops against iterator adaptors: is the abstraction really free?
+ *
+ * Rust's claim is that an iterator chain like
+ *
+ * ```text
+ * values.iter().filter(|&&v| v % 2 == 0).map(|&v| v * v).sum()
+ * ```
+ *
+ * compiles down to the same machine code as the loop you'd write by hand,
+ * a "zero-cost abstraction". If anything it can be *faster* than indexing,
+ * because `values[i]` checks that `i` is in bounds every time, and an
+ * iterator already knows it is.
+ *
+ * The three functions here all add up the squares of the even values, one
+ * with an index, one with `for ... in`, and one with the chain above, and
+ * the demo times them over a million values. Run it with
+ * `cargo run --release -p collections`: in a debug build nothing is
+ * optimized, so the chain's closures really are called one at a time and
+ * it loses. For steadier numbers, `cargo bench -p benches` times the same
+ * three functions with criterion.
+ */
+use common::{info, print_divider, time};
+
+pub fn indexed(values: &[i64]) -> i64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if values[i] % 2 == 0 {
+            total += values[i] * values[i];
+        }
+        i += 1;
+    }
+    total
+}
+
+pub fn for_in(values: &[i64]) -> i64 {
+    let mut total = 0;
+    for &value in values {
+        if value % 2 == 0 {
+            total += value * value;
+        }
+    }
+    total
+}
+
+pub fn chained(values: &[i64]) -> i64 {
+    values.iter().filter(|&&value| value % 2 == 0).map(|&value| value * value).sum()
+}
+
+// each way of adding up, with a name to print it under
+type Contender = (&'static str, fn(&[i64]) -> i64);
+
+const CONTENDERS: [Contender; 3] = [("index loop", indexed), ("for-in loop", for_in), ("iterator chain", chained)];
+
+pub fn demo_aggregates() {
+    print_divider();
+    info!("--- Loops vs Iterators Demonstration Begins --- ");
+
+    let values: Vec<i64> = (0..1_000_000).collect();
+    for (name, sum_of_squares) in CONTENDERS.iter() {
+        let (total, elapsed) = time(|| sum_of_squares(&values));
+        info!("{:<15} {} in {:?}", name, total, elapsed);
+    }
+
+    info!("--- Loops vs Iterators Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_three_agree() {
+        let values: Vec<i64> = (-10..=10).collect();
+        // 2 * (4 + 16 + 36 + 64 + 100)
+        assert_eq!(indexed(&values), 440);
+        assert_eq!(for_in(&values), 440);
+        assert_eq!(chained(&values), 440);
+    }
+
+    #[test]
+    fn odd_only_or_empty_is_zero() {
+        for values in [&[][..], &[1, 3, 5]] {
+            assert_eq!(indexed(values), 0);
+            assert_eq!(for_in(values), 0);
+            assert_eq!(chained(values), 0);
+        }
+    }
+
+    #[test]
+    fn a_million_values() {
+        let values: Vec<i64> = (0..1_000_000).collect();
+        let expected = chained(&values);
+        assert_eq!(expected, 166_666_166_667_000_000);
+        assert_eq!(indexed(&values), expected);
+        assert_eq!(for_in(&values), expected);
+    }
+}
@@ -0,0 +1,210 @@
+/*!
+ * A line-by-line diff of two strings, the way `diff` does it: find the
+ * longest common subsequence (LCS) of their lines, keep those, and
+ * everything else was either removed from the old text or added in the new.
+ *
+ * A subsequence keeps its order but can skip: the LCS of "ABCBDAB" and
+ * "BDCABA" is 4 long ("BCBA", among others). Finding it is the classic
+ * dynamic programming problem, and the table is a Vec of Vecs:
+ * `table[i][j]` is how long the LCS of `old[i..]` and `new[j..]` is. Each
+ * cell needs only the cells below and to its right, so filling it in from
+ * the bottom right corner means they're always ready:
+ *
+ * ```text
+ * old[i] == new[j]   table[i][j] = 1 + table[i + 1][j + 1]   keep the line
+ * otherwise          table[i][j] = max(table[i + 1][j],      drop old[i]
+ *                                      table[i][j + 1])      or new[j]
+ * ```
+ *
+ * Then one walk from the top left corner reads the diff back out, taking
+ * whichever step keeps the LCS as long as it can be. That's O(n * m) time
+ * and space for n and m lines, which is fine for the files people read,
+ * but not for a million lines each (real diff tools use Myers' algorithm,
+ * which is cleverer when the differences are few).
+ */
+use common::{info, print_divider};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change<'a> {
+    // in both
+    Same(&'a str),
+    // only in the old text
+    Removed(&'a str),
+    // only in the new text
+    Added(&'a str),
+}
+
+/// The changes that turn `old` into `new`, a line at a time. Where a line
+/// is replaced, its removal comes before its replacement, as in `diff`.
+///
+/// # Examples
+///
+/// ```
+/// use collections::diff::{diff, Change};
+///
+/// assert_eq!(
+///     diff("a\nb\nc", "a\nc\nd"),
+///     vec![Change::Same("a"), Change::Removed("b"), Change::Same("c"), Change::Added("d")]
+/// );
+/// ```
+pub fn diff<'a>(old: &'a str, new: &'a str) -> Vec<Change<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let table = lcs_table(&old, &new);
+
+    let mut changes = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            changes.push(Change::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            // dropping old[i] costs nothing (or ties), so it goes first
+            changes.push(Change::Removed(old[i]));
+            i += 1;
+        } else {
+            changes.push(Change::Added(new[j]));
+            j += 1;
+        }
+    }
+    // whatever is left of one side has nothing to match in the other
+    changes.extend(old[i..].iter().map(|line| Change::Removed(line)));
+    changes.extend(new[j..].iter().map(|line| Change::Added(line)));
+    changes
+}
+
+// `table[i][j]`: the length of the LCS of `old[i..]` and `new[j..]`. The
+// extra row and column are the empty suffixes, whose LCS is always 0.
+fn lcs_table(old: &[&str], new: &[&str]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            table[i][j] = if old[i] == new[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+    table
+}
+
+/// How many lines `old` and `new` have in common, in order.
+///
+/// # Examples
+///
+/// ```
+/// use collections::diff::common_lines;
+///
+/// assert_eq!(common_lines("a\nb\nc\nd", "b\nx\nd"), 2);
+/// ```
+pub fn common_lines(old: &str, new: &str) -> usize {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    lcs_table(&old, &new)[0][0]
+}
+
+/// The changes as `diff -u` shows its lines: a "+" for added, a "-" for
+/// removed and a space for the same, one line each.
+///
+/// # Examples
+///
+/// ```
+/// use collections::diff::{diff, render};
+///
+/// assert_eq!(render(&diff("frog\nbog", "frog\ndog")), " frog\n-bog\n+dog\n");
+/// ```
+pub fn render(changes: &[Change]) -> String {
+    changes
+        .iter()
+        .map(|change| match change {
+            Change::Same(line) => format!(" {}\n", line),
+            Change::Removed(line) => format!("-{}\n", line),
+            Change::Added(line) => format!("+{}\n", line),
+        })
+        .collect()
+}
+
+pub fn demo_diff() {
+    print_divider();
+    info!("--- Diff Demonstration Begins --- ");
+
+    let old = "I'm nobody! Who are you?\nAre you nobody, too?\nThen there's a pair of us\nDon't tell!";
+    let new = "I'm nobody! Who are you?\nAre you nobody, too?\nThen there's a pair of us\nThey'd banish us, you know.";
+    info!("{} lines in common", common_lines(old, new));
+    for line in render(&diff(old, new)).lines() {
+        info!("{}", line);
+    }
+
+    info!("--- Diff Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Change::*;
+
+    // put the old text back together from the diff, and the new one too
+    fn sides(changes: &[Change]) -> (Vec<String>, Vec<String>) {
+        let old = changes.iter().filter_map(|c| match c {
+            Same(line) | Removed(line) => Some(line.to_string()),
+            Added(_) => None,
+        });
+        let new = changes.iter().filter_map(|c| match c {
+            Same(line) | Added(line) => Some(line.to_string()),
+            Removed(_) => None,
+        });
+        (old.collect(), new.collect())
+    }
+
+    #[test]
+    fn identical_inputs() {
+        let text = "one\ntwo\nthree";
+        assert_eq!(diff(text, text), vec![Same("one"), Same("two"), Same("three")]);
+        assert_eq!(render(&diff(text, text)), " one\n two\n three\n");
+        assert!(diff("", "").is_empty());
+    }
+
+    #[test]
+    fn insertions() {
+        assert_eq!(diff("a\nc", "a\nb\nc"), vec![Same("a"), Added("b"), Same("c")]);
+        assert_eq!(diff("b", "a\nb\nc"), vec![Added("a"), Same("b"), Added("c")]);
+        assert_eq!(diff("", "a\nb"), vec![Added("a"), Added("b")]);
+    }
+
+    #[test]
+    fn deletions() {
+        assert_eq!(diff("a\nb\nc", "a\nc"), vec![Same("a"), Removed("b"), Same("c")]);
+        assert_eq!(diff("a\nb\nc", "b"), vec![Removed("a"), Same("b"), Removed("c")]);
+        assert_eq!(diff("a\nb", ""), vec![Removed("a"), Removed("b")]);
+    }
+
+    #[test]
+    fn replacements_remove_then_add() {
+        assert_eq!(diff("a\nb\nc", "a\nx\nc"), vec![Same("a"), Removed("b"), Added("x"), Same("c")]);
+        assert_eq!(render(&diff("old", "new")), "-old\n+new\n");
+    }
+
+    #[test]
+    fn the_textbook_example() {
+        // one letter per line, so the LCS is the classic one, 4 long
+        let a = "A\nB\nC\nB\nD\nA\nB";
+        let b = "B\nD\nC\nA\nB\nA";
+        assert_eq!(common_lines(a, b), 4);
+        let changes = diff(a, b);
+        assert_eq!(changes.iter().filter(|c| matches!(c, Same(_))).count(), 4);
+        let (old, new) = sides(&changes);
+        assert_eq!(old.join("\n"), a);
+        assert_eq!(new.join("\n"), b);
+    }
+
+    #[test]
+    fn whole_lines_only() {
+        // a trailing space makes it a different line
+        assert_eq!(diff("frog", "frog "), vec![Removed("frog"), Added("frog ")]);
+        // but a missing final newline doesn't (`lines` doesn't see it)
+        assert_eq!(diff("frog\n", "frog"), vec![Same("frog")]);
+    }
+}
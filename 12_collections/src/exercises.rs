@@ -0,0 +1,34 @@
+/*!
+ * Exercises for chapter 12: collections. The first three come straight from
+ * the end of the book's chapter 8.
+ *
+ * Check them with `cargo run -p runner -- verify 12` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+use std::collections::HashMap;
+
+// The middle value once the list is sorted, or None for an empty list.
+// (For an even number of values, take the lower of the two middles.)
+pub fn median(values: &[i32]) -> Option<i32> {
+    todo!()
+}
+
+// The value that shows up most often, or None for an empty list.
+// Ties go to the smallest of the tied values. Hint: a HashMap of counts.
+pub fn mode(values: &[i32]) -> Option<i32> {
+    todo!()
+}
+
+// Pig latin: "first" becomes "irst-fay", and a word starting with a vowel
+// just gets "-hay" on the end, so "apple" becomes "apple-hay".
+pub fn pig_latin(word: &str) -> String {
+    todo!()
+}
+
+// How many times each word appears, ignoring case. Words are whatever
+// `split_whitespace` gives you.
+pub fn word_counts(text: &str) -> HashMap<String, usize> {
+    todo!()
+}
@@ -5,27 +5,128 @@
  * them, wtf! I take great umbrage at this: hashmaps are the single greatest
  * data structure known to humanity, and they deserve better. 
  */
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::collections::hash_map::Entry;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+use common::{info, print_divider};
+
+// A HashMap has no order of its own, and the order it *does* iterate in
+// changes from run to run: its hasher is seeded at random, to make life hard
+// for anyone trying to feed it keys that all collide. So before printing one,
+// copy it into a BTreeMap, which always iterates in key order.
+
+/// The same entries as `map`, borrowed, in key order.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use collections::hashmaps::sorted;
+///
+/// let mut scores = HashMap::new();
+/// scores.insert("Red", 50);
+/// scores.insert("Blue", 101);
+/// // whatever order the HashMap was in today, this one is alphabetical
+/// assert_eq!(format!("{:?}", sorted(&scores)), r#"{"Blue": 101, "Red": 50}"#);
+/// ```
+pub fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> BTreeMap<&K, &V> {
+    map.iter().collect()
+}
+
+/// `items`, sorted into a Vec per key, where `key` says which key each one
+/// belongs under. Within each Vec, the items keep the order they came in.
+///
+/// # Examples
+///
+/// ```
+/// use collections::hashmaps::group_by;
+///
+/// let words = vec!["frog", "bog", "june", "fog", "jump"];
+/// let by_letter = group_by(words, |word| word.chars().next().unwrap());
+/// assert_eq!(by_letter[&'f'], vec!["frog", "fog"]);
+/// assert_eq!(by_letter[&'j'], vec!["june", "jump"]);
+/// assert_eq!(by_letter.len(), 3);
+/// ```
+pub fn group_by<T, K, I, F>(items: I, key: F) -> HashMap<K, Vec<T>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> K,
+{
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in items {
+        // the counting trick from the demo below, with a Vec instead of a
+        // number: the first item for a key makes its (empty) Vec
+        groups.entry(key(&item)).or_default().push(item);
+    }
+    groups
+}
+
+// index_by's complaint: two items had the same key
+#[derive(Debug, PartialEq)]
+pub struct DuplicateKey<K>(pub K);
+
+impl<K: fmt::Debug> fmt::Display for DuplicateKey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "more than one item has the key {:?}", self.0)
+    }
+}
+
+impl<K: fmt::Debug> Error for DuplicateKey<K> {}
+
+/// `items`, looked up by `key`, when every key should be different (an id,
+/// say). Two items with the same key are an error, not a quiet overwrite.
+///
+/// # Examples
+///
+/// ```
+/// use collections::hashmaps::{index_by, DuplicateKey};
+///
+/// let by_length = index_by(vec!["a", "bb", "ccc"], |s| s.len()).unwrap();
+/// assert_eq!(by_length[&2], "bb");
+/// assert_eq!(index_by(vec!["a", "bb", "cc"], |s| s.len()), Err(DuplicateKey(2)));
+/// ```
+pub fn index_by<T, K, I, F>(items: I, key: F) -> Result<HashMap<K, T>, DuplicateKey<K>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> K,
+{
+    let mut index = HashMap::new();
+    for item in items {
+        // `entry` again, but matched on by hand, because an Occupied entry
+        // is the error here rather than something to update
+        match index.entry(key(&item)) {
+            Entry::Occupied(taken) => return Err(DuplicateKey(taken.remove_entry().0)),
+            Entry::Vacant(space) => {
+                space.insert(item);
+            }
+        }
+    }
+    Ok(index)
+}
 
 // demo string-related code 
 pub fn demo_hashmaps () {
-    let divider = "///////////";
-
     // HashMaps are Rusts' canonical dictionary implementation
     
-    println!("{}", &divider);
-    println!("--- HashMap Demonstration Begins --- ");
+    print_divider();
+    info!("--- HashMap Demonstration Begins --- ");
 
     let mut scores1 = HashMap::new();
 
     scores1.insert(String::from("Blue"), 101);
     scores1.insert(String::from("Red"), 50);
-    println!("Scores: {:?}", scores1);
+    info!("Scores: {:?}", sorted(&scores1));
     
-    // iterate over keys and values in the map
-    println!("Scores1 keys and values:");
-    for (key, value) in &scores1 {
-      println!("... {}: {}", key, value);
+    // iterate over keys and values in the map (`for (key, value) in &scores1`
+    // works too, but in whatever order the map feels like today)
+    info!("Scores1 keys and values:");
+    for (key, value) in sorted(&scores1) {
+      info!("... {}: {}", key, value);
     }
 
     // here's an alternative initialization strategy, which is so verbose
@@ -34,18 +135,18 @@ pub fn demo_hashmaps () {
     let _scores = vec![42, 14];
     // now the verbosity happens!
     let scores2: HashMap<_, _> = teams.iter().zip(_scores.iter()).collect();
-    println!("Scores2: {:?}", scores2);
+    info!("Scores2: {:?}", sorted(&scores2));
 
     // access using `.get` with a _borrowed_ key reference
     let green_key = String::from("Green");
     // but you get Option<i32> back, not plain old <i32>, eek!
     let green_score = scores2.get(&green_key); // because the value might not exist!
     match green_score {
-      Some(score) => println!("Green score: {}", score),
-      None => println!("Green has no score")
+      Some(score) => info!("Green score: {}", score),
+      None => info!("Green has no score")
     }
     // confirm that nothing was consumed and no ownership changed
-    println!("Scores2: {:?}", scores2);
+    info!("Scores2: {:?}", sorted(&scores2));
 
     // Setting and inserting a value are both done via .insert()
     let mut colors = HashMap::new();
@@ -53,7 +154,7 @@ pub fn demo_hashmaps () {
     colors.insert(String::from("red"), 700);
     // but wait, 400 nanometers is indigo, not blue!
     colors.insert(String::from("blue"), 456); // that's more like it
-    println!("Colors and wavelengths: {:?}", colors);
+    info!("Colors and wavelengths: {:?}", sorted(&colors));
 
     // use .entry() to insert only if the key was unset before
     // then add a .or_insert() clause for the value to insert if so
@@ -61,7 +162,7 @@ pub fn demo_hashmaps () {
     colors.entry(String::from("blue")).or_insert(444);
     // but this call _will_ insert a new entry
     colors.entry(String::from("yellow")).or_insert(555);
-    println!("Colors and wavelengths: {:?}", colors);
+    info!("Colors and wavelengths: {:?}", sorted(&colors));
 
     // NB:  the .or_insert() clause returns a mutable borrow for the value,
     // so you can be more sophisticated about how you update it.
@@ -76,8 +177,103 @@ pub fn demo_hashmaps () {
       *count += 1;
     }
 
-    println!("word map for '{}': {:?}", text, words);
+    info!("word map for '{}': {:?}", text, sorted(&words));
+
+    info!("--- HashMap Demonstration Finish --- ");
+    print_divider();
+}
+
+// The book's suggested exercise for this chapter: a company directory,
+// people by department, each department's people listed alphabetically.
+// group_by does the sorting into departments, and index_by the lookups by
+// badge number (which had better be unique).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Employee {
+    pub badge: u32,
+    pub name: String,
+    pub department: String,
+}
+
+impl Employee {
+    pub fn new(badge: u32, name: &str, department: &str) -> Employee {
+        Employee { badge, name: name.to_string(), department: department.to_string() }
+    }
+}
+
+// each department's names, alphabetically, and the departments in order too
+pub fn departments(staff: &[Employee]) -> BTreeMap<&str, Vec<&str>> {
+    group_by(staff, |e| e.department.as_str())
+        .into_iter()
+        .map(|(department, people)| {
+            let mut names: Vec<&str> = people.iter().map(|e| e.name.as_str()).collect();
+            names.sort_unstable();
+            (department, names)
+        })
+        .collect()
+}
+
+pub fn demo_directory() {
+    print_divider();
+    info!("--- Company Directory Demonstration Begins --- ");
+
+    let staff = vec![
+        Employee::new(7, "Sally", "Engineering"),
+        Employee::new(3, "Amir", "Sales"),
+        Employee::new(12, "Ferris", "Engineering"),
+        Employee::new(5, "Bo", "Engineering"),
+        Employee::new(9, "Zelda", "Sales"),
+    ];
+    for (department, names) in departments(&staff) {
+        info!("{}: {}", department, names.join(", "));
+    }
+
+    match index_by(&staff, |e| e.badge) {
+        Ok(by_badge) => info!("Badge 12 is {}", by_badge[&12].name),
+        Err(e) => info!("Can't look up badges: {}", e),
+    }
+    // a new hire given someone else's badge
+    let mut muddled = staff.clone();
+    muddled.push(Employee::new(3, "Ada", "Research"));
+    match index_by(&muddled, |e| e.badge) {
+        Ok(by_badge) => info!("Badge 3 is {}", by_badge[&3].name),
+        Err(e) => info!("Can't look up badges: {}", e),
+    }
+
+    info!("--- Company Directory Demonstration Finish --- ");
+    print_divider();
+}
 
-    println!("--- HashMap Demonstration Finish --- ");
-    println!("{}", &divider)
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_keep_their_order() {
+        let groups = group_by(1..=10, |n| n % 3);
+        assert_eq!(sorted(&groups).into_iter().collect::<Vec<_>>(), [(&0, &vec![3, 6, 9]), (&1, &vec![1, 4, 7, 10]), (&2, &vec![2, 5, 8])]);
+        assert!(group_by(Vec::<i32>::new(), |n| *n).is_empty());
+    }
+
+    #[test]
+    fn one_big_group() {
+        let groups = group_by(vec!["a"; 5], |_| ());
+        assert_eq!(groups[&()].len(), 5);
+    }
+
+    #[test]
+    fn indexes_need_unique_keys() {
+        let index = index_by(vec![(1, "one"), (2, "two")], |pair| pair.0).unwrap();
+        assert_eq!(index[&1], (1, "one"));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index_by(vec![(1, "one"), (2, "two"), (1, "uno")], |pair| pair.0), Err(DuplicateKey(1)));
+        assert_eq!(DuplicateKey("x").to_string(), "more than one item has the key \"x\"");
+    }
+
+    #[test]
+    fn the_directory() {
+        let staff = vec![Employee::new(1, "Zed", "Ops"), Employee::new(2, "Al", "Dev"), Employee::new(3, "Bea", "Ops")];
+        let expected: BTreeMap<&str, Vec<&str>> = vec![("Dev", vec!["Al"]), ("Ops", vec!["Bea", "Zed"])].into_iter().collect();
+        assert_eq!(departments(&staff), expected);
+        assert!(departments(&[]).is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+/**
+ * There are a few collection types that are used so ubiquitously (across all
+ * languages, not just Rust!) that they have been made part of the standard
+ * library. Two of them (String and Vector) are even part of the _prelude_, 
+ * so you don't even need to do any import shenanigans to use them in any 
+ * particular file. The third -- HashMap -- is not part of the prelude, though
+ * *I* would have put it there if anyone had asked me! So none of those types
+ * are part of the lanuage core, but they are all readily available.
+ * 
+ * We'll do some quick demos of all three types here
+ */
+// The modules are public for the few helpers they hand out, each with a
+// `///` example that `cargo test` runs (see ../17_testing for doctests)
+pub mod vectors;
+pub mod strings;
+pub mod hashmaps;
+pub mod wordplay;
+pub mod lru;
+pub mod scheduler;
+pub mod aggregates;
+pub mod sorting;
+pub mod template;
+pub mod diff;
+
+// practice problems, compiled only with `--features exercises`
+#[cfg(feature = "exercises")]
+pub mod exercises;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("vectors", "Vec<T>: building, reading and iterating", vectors::demo_vectors),
+    Demo::new("strings", "String, &str and unicode", strings::demo_strings),
+    Demo::new("hashmaps", "HashMap: inserting, updating and counting", hashmaps::demo_hashmaps),
+    Demo::new("directory", "A company directory, with group_by and index_by", hashmaps::demo_directory),
+    Demo::new("wordplay", "Palindromes and anagrams, glyph by glyph", wordplay::demo_wordplay),
+    Demo::new("lru", "An LRU cache: a HashMap with a size limit", lru::demo_lru),
+    Demo::new("scheduler", "BinaryHeap: a priority queue of tasks", scheduler::demo_scheduler),
+    Demo::new("aggregates", "Index loops, for loops and iterator chains, timed", aggregates::demo_aggregates),
+    Demo::new("sorting", "Sorting, de-duplicating and merging, for any T: Ord", sorting::demo_sorting),
+    Demo::new("template", "Filling in a string's {placeholders} from a HashMap", template::demo_template),
+    Demo::new("diff", "A line-by-line diff, from a Vec of Vecs", diff::demo_diff),
+];
+
+pub fn demo() {
+
+    // use Vec<T> for arbitrary growable collections of any type T
+    vectors::demo_vectors();
+
+    // use Strings for collections of characters
+    strings::demo_strings();
+
+    // use HashMaps for... just about everything!
+    hashmaps::demo_hashmaps();
+
+    // and the chapter's exercise: people grouped by department
+    hashmaps::demo_directory();
+
+    // and glyphs again, for palindromes and anagrams
+    wordplay::demo_wordplay();
+
+    // a HashMap and a BTreeMap together make a cache that forgets
+    lru::demo_lru();
+
+    // use a BinaryHeap when you only ever want the biggest (or smallest) next
+    scheduler::demo_scheduler();
+
+    // and three ways to add up a Vec, against the clock
+    aggregates::demo_aggregates();
+
+    // sort + dedup for a sorted set, and merging two sorted lists in one pass
+    sorting::demo_sorting();
+
+    // a HashMap of values, poured into the {placeholders} in a string
+    template::demo_template();
+
+    // and a Vec of Vecs, for the longest common subsequence behind a diff
+    diff::demo_diff();
+}
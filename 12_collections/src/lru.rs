@@ -0,0 +1,306 @@
+/*!
+ * A least-recently-used cache: a HashMap with a size limit.
+ *
+ * When the cache is full and something new comes in, whatever was used
+ * longest ago goes out to make room. "Used" means inserted or read with
+ * `get`, so the things you keep asking for stay, and the rest drift to the
+ * back and fall off:
+ *
+ * ```text
+ * capacity 2:  insert a, insert b    [a, b]      (least recent first)
+ *              get a                 [b, a]      a is fresh again
+ *              insert c              [a, c]      b was the oldest, so b goes
+ * ```
+ *
+ * A HashMap alone can't say which entry is oldest, so there's a second
+ * collection for that: a BTreeMap from a use counter (a "tick", bumped on
+ * every use) to the key. A BTreeMap keeps its keys sorted, so its first
+ * entry is always the least recently used. Each use costs a lookup in both
+ * maps, O(log n) at worst, instead of scanning everything for the oldest.
+ */
+use common::{info, print_divider};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// A map that holds at most `capacity` entries, and makes room for new
+/// ones by evicting the least recently used.
+///
+/// # Examples
+///
+/// ```
+/// use collections::lru::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+/// cache.get(&"a"); // a is now the most recently used
+/// cache.insert("c", 3); // so b is the one to go
+/// assert_eq!(cache.get(&"b"), None);
+/// assert_eq!(cache.keys_by_recency(), [&"a", &"c"]);
+/// ```
+pub struct LruCache<K, V> {
+    capacity: usize,
+    // each value, with the tick it was last used at
+    entries: HashMap<K, (V, u64)>,
+    // tick -> key, oldest first
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    // A capacity of 0 is allowed, if not much use: everything inserted is
+    // evicted again straight away.
+    pub fn new(capacity: usize) -> LruCache<K, V> {
+        LruCache { capacity, entries: HashMap::new(), recency: BTreeMap::new(), tick: 0, on_evict: None }
+    }
+
+    /// Call `callback` with every entry that's evicted to make room, from
+    /// now on. (Not with entries taken out by `remove`, or values replaced
+    /// by `insert`: those are handed back to the caller instead.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::lru::LruCache;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let evicted = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&evicted);
+    /// let mut cache = LruCache::new(1);
+    /// cache.on_evict(move |key, value| log.borrow_mut().push((key, value)));
+    /// cache.insert("a", 1);
+    /// cache.insert("b", 2);
+    /// assert_eq!(*evicted.borrow(), [("a", 1)]);
+    /// ```
+    pub fn on_evict(&mut self, callback: impl FnMut(K, V) + 'static) {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    // the next tick, for an entry that's just been used
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    // move `key` to the back of the queue, if it's here at all
+    fn touch(&mut self, key: &K) -> bool {
+        let tick = self.next_tick();
+        match self.entries.get_mut(key) {
+            Some((_, last_used)) => {
+                let key = self.recency.remove(last_used).expect("every entry has a tick");
+                *last_used = tick;
+                self.recency.insert(tick, key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Insert `value` under `key`, as the most recently used entry. If
+    /// `key` was already there, its old value comes back; otherwise, the
+    /// least recently used entry is evicted if there's no room for one more.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.touch(&key) {
+            let (old, _) = self.entries.get_mut(&key).expect("touch found it");
+            return Some(std::mem::replace(old, value));
+        }
+        let tick = self.next_tick();
+        self.entries.insert(key.clone(), (value, tick));
+        self.recency.insert(tick, key);
+        while self.entries.len() > self.capacity {
+            self.evict_oldest();
+        }
+        None
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some((_, key)) = self.recency.pop_first() {
+            let (value, _) = self.entries.remove(&key).expect("every tick has an entry");
+            if let Some(callback) = &mut self.on_evict {
+                callback(key, value);
+            }
+        }
+    }
+
+    /// The value for `key`, which also makes it the most recently used.
+    /// (That's why this needs `&mut self`: a read changes the order.)
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self.touch(key) {
+            self.entries.get(key).map(|(value, _)| value)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        if self.touch(key) {
+            self.entries.get_mut(key).map(|(value, _)| value)
+        } else {
+            None
+        }
+    }
+
+    // the value for `key`, leaving the order as it was
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, tick) = self.entries.remove(key)?;
+        self.recency.remove(&tick);
+        Some(value)
+    }
+
+    // least recently used first, so the first key is the next to be evicted
+    pub fn keys_by_recency(&self) -> Vec<&K> {
+        self.recency.values().collect()
+    }
+}
+
+pub fn demo_lru() {
+    print_divider();
+    info!("--- LRU Cache Demonstration Begins --- ");
+
+    // the three most recently viewed pages, say
+    let mut pages = LruCache::new(3);
+    pages.on_evict(|url: &str, title: String| info!("evicted {} ({})", url, title));
+    pages.insert("/", String::from("Home"));
+    pages.insert("/about", String::from("About"));
+    pages.insert("/blog", String::from("Blog"));
+    info!("Cached, oldest first: {:?}", pages.keys_by_recency());
+
+    // reading the home page again saves it from being the next to go
+    info!("Home page from the cache: {:?}", pages.get(&"/"));
+    pages.insert("/contact", String::from("Contact"));
+    info!("Cached, oldest first: {:?}", pages.keys_by_recency());
+
+    info!("--- LRU Cache Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Evicted = Rc<RefCell<Vec<(&'static str, i32)>>>;
+
+    // a cache that writes down everything it evicts
+    fn logged(capacity: usize) -> (LruCache<&'static str, i32>, Evicted) {
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&evicted);
+        let mut cache = LruCache::new(capacity);
+        cache.on_evict(move |key, value| log.borrow_mut().push((key, value)));
+        (cache, evicted)
+    }
+
+    #[test]
+    fn holds_no_more_than_its_capacity() {
+        let (mut cache, evicted) = logged(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.len(), 2);
+        assert!(evicted.borrow().is_empty());
+        cache.insert("c", 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(*evicted.borrow(), [("a", 1)]);
+        assert_eq!(cache.keys_by_recency(), [&"b", &"c"]);
+        assert!(!cache.contains_key(&"a"));
+    }
+
+    #[test]
+    fn get_promotes_and_peek_does_not() {
+        let (mut cache, evicted) = logged(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.peek(&"a"), Some(&1));
+        assert_eq!(cache.keys_by_recency(), [&"a", &"b"]);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.keys_by_recency(), [&"b", &"a"]);
+        cache.insert("c", 3);
+        assert_eq!(*evicted.borrow(), [("b", 2)]);
+        assert_eq!(cache.get(&"b"), None);
+    }
+
+    #[test]
+    fn get_mut_promotes_too() {
+        let (mut cache, evicted) = logged(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        *cache.get_mut(&"a").unwrap() += 10;
+        cache.insert("c", 3);
+        assert_eq!(*evicted.borrow(), [("b", 2)]);
+        assert_eq!(cache.peek(&"a"), Some(&11));
+    }
+
+    #[test]
+    fn inserting_an_existing_key_replaces_without_evicting() {
+        let (mut cache, evicted) = logged(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.insert("a", 10), Some(1));
+        assert_eq!(cache.len(), 2);
+        assert!(evicted.borrow().is_empty());
+        // and the replaced key is the freshest now
+        assert_eq!(cache.keys_by_recency(), [&"b", &"a"]);
+    }
+
+    #[test]
+    fn remove_makes_room_without_the_callback() {
+        let (mut cache, evicted) = logged(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.remove(&"a"), Some(1));
+        assert_eq!(cache.remove(&"a"), None);
+        cache.insert("c", 3);
+        assert!(evicted.borrow().is_empty());
+        assert_eq!(cache.keys_by_recency(), [&"b", &"c"]);
+    }
+
+    #[test]
+    fn evictions_come_oldest_first() {
+        let (mut cache, evicted) = logged(3);
+        for (i, key) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+            cache.insert(key, i as i32);
+        }
+        assert_eq!(*evicted.borrow(), [("a", 0), ("b", 1)]);
+        assert_eq!(cache.keys_by_recency(), [&"c", &"d", &"e"]);
+    }
+
+    #[test]
+    fn no_capacity_keeps_nothing() {
+        let (mut cache, evicted) = logged(0);
+        assert_eq!(cache.insert("a", 1), None);
+        assert!(cache.is_empty());
+        assert_eq!(*evicted.borrow(), [("a", 1)]);
+        assert_eq!(cache.capacity(), 0);
+    }
+
+    #[test]
+    fn no_callback_is_fine() {
+        let mut cache = LruCache::new(1);
+        cache.insert(String::from("a"), vec![1]);
+        cache.insert(String::from("b"), vec![2]);
+        assert_eq!(cache.get(&String::from("b")), Some(&vec![2]));
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -1,26 +1,13 @@
-/**
- * There are a few collection types that are used so ubiquitously (across all
- * languages, not just Rust!) that they have been made part of the standard
- * library. Two of them (String and Vector) are even part of the _prelude_, 
- * so you don't even need to do any import shenanigans to use them in any 
- * particular file. The third -- HashMap -- is not part of the prelude, though
- * *I* would have put it there if anyone had asked me! So none of those types
- * are part of the lanuage core, but they are all readily available.
- * 
- * We'll do some quick demos of all three types here
- */
-mod vectors;
-mod strings;
-mod hashmaps;
-
+// The demos live in lib.rs, where the top-level `runner` crate can reach
+// them too; all that's left for main to do is call them. With
+// `--format json`, every message comes out as a JSON record instead
+// (see common/src/log.rs), and examples.toml can set that, and the log
+// level, for every run (see common/src/config.rs).
 fn main() {
-
-    // use Vec<T> for arbitrary growable collections of any type T
-    vectors::demo_vectors();
-
-    // use Strings for collections of characters
-    strings::demo_strings();
-
-    // use HashMaps for... just about everything!
-    hashmaps::demo_hashmaps();
+    let mut args: Vec<String> = std::env::args().collect();
+    if let Err(e) = common::Config::load().and_then(|config| common::log::configure(&config, &mut args)) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    collections::demo();
 }
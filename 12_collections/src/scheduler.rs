@@ -0,0 +1,139 @@
+/*!
+ * BinaryHeap: a priority queue, and a little task scheduler built on one.
+ *
+ * A BinaryHeap hands its items back biggest first, however they went in.
+ * It keeps them only *partly* sorted (a tree where every parent is bigger
+ * than its children), which is all it takes to find the biggest in O(1)
+ * and to push or pop in O(log n), for much less work than sorting.
+ *
+ * Biggest first is the only order it knows. For *smallest* first, wrap
+ * each item in `std::cmp::Reverse`, which flips its ordering around:
+ *
+ * ```text
+ * heap of 3, 1, 2             pops 3, 2, 1
+ * heap of Reverse(3), ...     pops Reverse(1), Reverse(2), Reverse(3)
+ * ```
+ *
+ * The scheduler's priorities work like "priority 1" on a bug tracker: the
+ * lower the number, the sooner it runs, hence the Reverse. Tasks with the
+ * same priority run in the order they were scheduled, which the heap can't
+ * promise by itself: each task also gets a sequence number, compared only
+ * when the priorities tie.
+ */
+use common::{info, print_divider};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+#[derive(Default)]
+pub struct Scheduler {
+    // (priority, sequence number) first, so that's what the heap compares
+    tasks: BinaryHeap<Reverse<(u32, u64, String)>>,
+    scheduled: u64,
+}
+
+impl Scheduler {
+    pub fn new() -> Scheduler {
+        Scheduler::default()
+    }
+
+    // 0 is the most urgent
+    pub fn schedule(&mut self, priority: u32, name: &str) {
+        self.tasks.push(Reverse((priority, self.scheduled, name.to_string())));
+        self.scheduled += 1;
+    }
+
+    // the task that would run next, without running it
+    pub fn peek(&self) -> Option<(u32, &str)> {
+        self.tasks.peek().map(|Reverse((priority, _, name))| (*priority, name.as_str()))
+    }
+
+    // Take the most urgent task off the queue and "run" it, which here just
+    // means handing back its name. None once there's nothing left to run.
+    pub fn run_next(&mut self) -> Option<String> {
+        self.tasks.pop().map(|Reverse((_, _, name))| name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+pub fn demo_scheduler() {
+    print_divider();
+    info!("--- BinaryHeap Demonstration Begins --- ");
+
+    let mut heap: BinaryHeap<i32> = vec![3, 1, 4, 1, 5, 9, 2, 6].into_iter().collect();
+    let popped: Vec<i32> = std::iter::from_fn(|| heap.pop()).collect();
+    info!("A max-heap pops biggest first: {:?}", popped);
+    let mut min_heap: BinaryHeap<Reverse<i32>> = vec![3, 1, 4].into_iter().map(Reverse).collect();
+    info!("With Reverse, smallest first: {:?}", min_heap.pop());
+
+    let mut scheduler = Scheduler::new();
+    scheduler.schedule(2, "write the docs");
+    scheduler.schedule(0, "fix the outage");
+    scheduler.schedule(1, "review the patch");
+    scheduler.schedule(2, "answer email");
+    info!("Next up: {:?}", scheduler.peek());
+    while let Some(task) = scheduler.run_next() {
+        info!("Running: {}", task);
+    }
+
+    info!("--- BinaryHeap Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_all(scheduler: &mut Scheduler) -> Vec<String> {
+        std::iter::from_fn(|| scheduler.run_next()).collect()
+    }
+
+    #[test]
+    fn most_urgent_first() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(5, "later");
+        scheduler.schedule(0, "now");
+        scheduler.schedule(3, "soon");
+        assert_eq!(scheduler.len(), 3);
+        assert_eq!(scheduler.peek(), Some((0, "now")));
+        assert_eq!(run_all(&mut scheduler), ["now", "soon", "later"]);
+        assert!(scheduler.is_empty());
+        assert_eq!(scheduler.run_next(), None);
+    }
+
+    #[test]
+    fn ties_run_in_the_order_they_were_scheduled() {
+        let mut scheduler = Scheduler::new();
+        // names that would sort the other way, if the names were compared
+        for name in ["c", "b", "a"] {
+            scheduler.schedule(1, name);
+        }
+        scheduler.schedule(0, "z");
+        assert_eq!(run_all(&mut scheduler), ["z", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn scheduling_between_runs() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(2, "first");
+        scheduler.schedule(2, "second");
+        assert_eq!(scheduler.run_next().as_deref(), Some("first"));
+        scheduler.schedule(1, "urgent");
+        scheduler.schedule(2, "third");
+        assert_eq!(run_all(&mut scheduler), ["urgent", "second", "third"]);
+    }
+
+    #[test]
+    fn reverse_turns_a_max_heap_into_a_min_heap() {
+        let mut max: BinaryHeap<i32> = vec![2, 7, 1].into_iter().collect();
+        let mut min: BinaryHeap<Reverse<i32>> = vec![2, 7, 1].into_iter().map(Reverse).collect();
+        assert_eq!((max.pop(), min.pop()), (Some(7), Some(Reverse(1))));
+        assert_eq!((max.pop(), min.pop()), (Some(2), Some(Reverse(2))));
+    }
+}
@@ -0,0 +1,170 @@
+/*!
+ * Sorting and de-duplicating, for any `T: Ord`.
+ *
+ * `Vec` already has the two halves of `sorted_unique`: `sort_unstable`,
+ * and `dedup`, which only removes duplicates that are *next to each
+ * other*. That's why the sort has to come first, and why the pair costs
+ * O(n log n) and no more than the sort alone: once equal values sit
+ * together, one pass drops the extras. Both work in place, in the Vec we
+ * were handed, so nothing is copied.
+ *
+ * ("Unstable" means equal values might swap places. For values that are
+ * equal *and* about to be de-duplicated, nobody can tell.)
+ *
+ * `merge_sorted` is the other half of merge sort: two lists that are each
+ * sorted already become one sorted list in a single pass, by taking
+ * whichever front value is smaller, over and over. That's O(n + m), where
+ * sorting the two glued together would be O((n + m) log(n + m)).
+ */
+use common::{info, print_divider};
+
+/// `values` in order, with each value just once.
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorting::sorted_unique;
+///
+/// assert_eq!(sorted_unique(vec![3, 1, 3, 2, 1]), vec![1, 2, 3]);
+/// assert_eq!(sorted_unique(vec!["frog", "bog", "frog"]), vec!["bog", "frog"]);
+/// ```
+pub fn sorted_unique<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+/// Two sorted lists as one sorted list, duplicates and all. When a value is
+/// in both, `a`'s copy comes first. If either list isn't sorted, neither
+/// is the answer (but it still has every value in it).
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorting::merge_sorted;
+///
+/// assert_eq!(merge_sorted(vec![1, 4, 9], vec![2, 4, 8, 16]), vec![1, 2, 4, 4, 8, 9, 16]);
+/// assert_eq!(merge_sorted(vec![], vec![5, 6]), vec![5, 6]);
+/// ```
+pub fn merge_sorted<T: Ord>(a: Vec<T>, b: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    // `peek` looks at the next value without taking it, so only the
+    // smaller of the two is taken each time round
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        let next = if y < x { b.next() } else { a.next() };
+        merged.extend(next);
+    }
+    // one of them has run out, so the rest of the other is all bigger
+    merged.extend(a);
+    merged.extend(b);
+    merged
+}
+
+pub fn demo_sorting() {
+    print_divider();
+    info!("--- Sort and Dedup Demonstration Begins --- ");
+
+    let words = vec!["to", "tell", "your", "name", "the", "livelong", "day", "to", "an", "admiring", "bog"];
+    let unique = sorted_unique(words.clone());
+    info!("{} words, {} of them different: {:?}", words.len(), unique.len(), unique);
+
+    let evens: Vec<u32> = (0..10).step_by(2).collect();
+    let squares: Vec<u32> = (0..4).map(|n| n * n).collect();
+    info!("Merging {:?} and {:?}: {:?}", evens, squares, merge_sorted(evens.clone(), squares.clone()));
+    // and merging then de-duplicating is a sorted set union
+    info!("Their union: {:?}", sorted_unique(merge_sorted(evens, squares)));
+
+    info!("--- Sort and Dedup Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The same pseudo-random numbers every run, from a linear congruential
+    // generator: good enough to shuffle test data, and nothing else
+    fn scrambled(len: usize, range: u64) -> Vec<u64> {
+        let mut state: u64 = 42;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 33) % range
+            })
+            .collect()
+    }
+
+    // the slow, obvious way, to check the fast ways against
+    fn sorted<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn sorted_unique_inputs() {
+        // already sorted, reversed, all the same, and empty
+        assert_eq!(sorted_unique((1..=5).collect::<Vec<_>>()), [1, 2, 3, 4, 5]);
+        assert_eq!(sorted_unique((1..=5).rev().collect::<Vec<_>>()), [1, 2, 3, 4, 5]);
+        assert_eq!(sorted_unique(vec![7; 1000]), [7]);
+        assert!(sorted_unique(Vec::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn duplicate_heavy_input() {
+        // 10,000 values, but only 10 different ones
+        let values = scrambled(10_000, 10);
+        assert_eq!(sorted_unique(values), (0..10).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn sorted_unique_of_owned_values() {
+        let words: Vec<String> = "b a c a b".split(' ').map(String::from).collect();
+        assert_eq!(sorted_unique(words), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn merge_inputs() {
+        // one entirely before the other, either way round
+        assert_eq!(merge_sorted(vec![1, 2, 3], vec![4, 5]), [1, 2, 3, 4, 5]);
+        assert_eq!(merge_sorted(vec![4, 5], vec![1, 2, 3]), [1, 2, 3, 4, 5]);
+        // interleaved, and with duplicates inside and between the two
+        assert_eq!(merge_sorted(vec![1, 1, 3, 5], vec![1, 2, 3, 3]), [1, 1, 1, 2, 3, 3, 3, 5]);
+        assert_eq!(merge_sorted(vec![2; 3], vec![2; 2]), [2; 5]);
+        assert!(merge_sorted(Vec::<u8>::new(), vec![]).is_empty());
+    }
+
+    // ordered by the number alone, so the letter shows whose copy is whose
+    #[derive(Debug, PartialEq, Eq)]
+    struct Tagged(u8, char);
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Tagged) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Tagged) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn merge_keeps_a_first_on_ties() {
+        let a = vec![Tagged(1, 'a'), Tagged(2, 'a')];
+        let b = vec![Tagged(1, 'b'), Tagged(3, 'b')];
+        let tags: Vec<char> = merge_sorted(a, b).iter().map(|t| t.1).collect();
+        assert_eq!(tags, ['a', 'b', 'a', 'b']);
+    }
+
+    #[test]
+    fn merge_agrees_with_sorting() {
+        let a = sorted(scrambled(500, 100));
+        let b = sorted(scrambled(300, 1000));
+        let mut both = a.clone();
+        both.extend(&b);
+        assert_eq!(merge_sorted(a, b), sorted(both));
+    }
+}
@@ -25,31 +25,35 @@
  * like a pretty defensible choice. 
  * 
  */
-// for randomization
-use rand::{thread_rng, Rng};
-use rand::distributions::Alphanumeric; // an Enum variant, presumably
+// for randomization and dividers, shared with the other chapters
+use common::{info, print_divider, random_id};
 // for unicode
 use unicode_segmentation::UnicodeSegmentation as Uni;
 
-// get random strings -- made public so others can use it also
-pub fn rand_str(length: usize) -> String {
-  let rand_string: String = thread_rng()  // rng for `Random Number Generator`
-        .sample_iter(&Alphanumeric)   // distributions enum, Alphanumeric variant
-        .take(length)  // take however many you like
-        .collect();    // and bundle them up together into a String
-  // ending with rand_string all by itself as an _expression_ has the same
-  // effect as saying `return rand_string;`, but it is more idiomatic in Rust
-  rand_string
+/// The glyphs in `s`: what a reader would count as its characters, each
+/// of which can be several `char`s, each of which can be several bytes.
+///
+/// # Examples
+///
+/// ```
+/// use collections::strings::glyphs;
+///
+/// let namaste = "नमस्ते";
+/// // the last glyph is a conjunct: स, a virama, त, and a vowel sign
+/// assert_eq!(glyphs(namaste), ["न", "म", "स्ते"]);
+/// assert_eq!(namaste.chars().count(), 6);
+/// assert_eq!(namaste.len(), 18);
+/// ```
+pub fn glyphs(s: &str) -> Vec<&str> {
+    Uni::graphemes(s, true).collect()
 }
 
 // demo string-related code 
 pub fn demo_strings () {
-    let divider = "///////////";
-
     // Strings are Rusts' growable character collection
     
-    println!("{}", &divider);
-    println!("--- String Demonstration Begins --- ");
+    print_divider();
+    info!("--- String Demonstration Begins --- ");
 
     // the data _variable_ is a "string slice", but we have initialized
     // it with a string *literal*. A string literal is not a variable!
@@ -70,12 +74,12 @@ pub fn demo_strings () {
     // given how aggressively the compiler auto-casts everything.
     // Still, I am wary of this issue... /suspicious
 
-    println!("Equal big-S strings? ({}, {}): {}", &str1, &str2, &str1 == &str2);
-    println!("Second big-S pair is also equal: {}", str3 == str4);
-    println!("Big-S equals string slice? ({}, {}): {}", &str1, &data, &str1 == &data);
+    info!("Equal big-S strings? ({}, {}): {}", &str1, &str2, &str1 == &str2);
+    info!("Second big-S pair is also equal: {}", str3 == str4);
+    info!("Big-S equals string slice? ({}, {}): {}", &str1, &data, &str1 == &data);
 
-    let rand1 = rand_str(11);
-    println!("A random string: {}", &rand1);
+    let rand1 = random_id(11);
+    info!("A random string: {}", &rand1);
 
     // Just as we saw with Vec<T>, a big-S String *can* be made mutable, 
     // and has a bunch of methods that *support* mutability,
@@ -87,7 +91,7 @@ pub fn demo_strings () {
     mut1.push_str(" -- "); // can push a string literal directly
     mut1.push_str(&rand1); // but must explicitly borrow a big-S string
     // nb: you could use .push() if the argument was a single char
-    println!("A mutable string: {}", &mut1);
+    info!("A mutable string: {}", &mut1);
     // note the compiler automagic: we can use {} instead of {:?} for big-s Strings
 
     // the '+' operator is overloaded for big-S string addition, 
@@ -100,7 +104,7 @@ pub fn demo_strings () {
     // whereas all the other big-S references must be borrows, 
     // and the string literals just slide on in there 
     let s4 = s1 + "-" + &s2 + "-" + &s3;
-    println!("+ operator overloading: {}", &s4);
+    info!("+ operator overloading: {}", &s4);
 
     // Alternatively, you can use the `format!` macro, which requires fewer
     // ownership shenanigans
@@ -109,7 +113,7 @@ pub fn demo_strings () {
     let b1 = String::from("200");
     let rgb = format!("rgb({}, {}, {})", r1, g1, b1);
     // all values are still owned by their original owners: 
-    println!("All values remain owned: {}, {}, {}, {}", r1, g1, b1, rgb);
+    info!("All values remain owned: {}, {}, {}, {}", r1, g1, b1, rgb);
 
     // NB: you cannot use array-notation access on strings (big-S or otherwise),
     // because it's the 21st century, and everything is unicode, baby!
@@ -119,8 +123,8 @@ pub fn demo_strings () {
     // Hence weirdness like this:
     let hola = String::from("hola"); // 4 glyphs, 4 bytes
     let russ = String::from("Здравствуйте"); // 12 glyphs, but how many bytes?
-    println!("{} is {} bytes long and it has 4 glyphs", hola, hola.len());
-    println!("But {} is {} bytes long, though it has 12 glyphs", russ, russ.len());
+    info!("{} is {} bytes long and it has 4 glyphs", hola, hola.len());
+    info!("But {} is {} bytes long, though it has 12 glyphs", russ, russ.len());
     // the rust book refers to a glyph as a "grapheme cluster"
     // that's a terrible choice for a term of art, even if it's in the specs
     // "Glyph" or "rune" (as in golang)) are both much better terms for this.
@@ -135,30 +139,32 @@ pub fn demo_strings () {
     let decoded = Uni::graphemes(russ.as_str(), true).collect::<Vec<&str>>();
     // also note the rather-ugly type `<Vec<&str>>`... yikes!
     // but this does what we want
-    println!("Unicode-Decoded Russ is now: {:?}", decoded);
-    println!("And we can *objectively* say that Russ has {} glyphs", decoded.len());
-    println!("Even though the original unicode has {} bytes", russ.len());
+    info!("Unicode-Decoded Russ is now: {:?}", decoded);
+    info!("And we can *objectively* say that Russ has {} glyphs", decoded.len());
+    info!("Even though the original unicode has {} bytes", russ.len());
 
     // back to built-in methods:
     // you can call .chars() on a string slice to get the individual bytes (u8 values),
     // and then do a `for ... in ...` over them 
     let kanji = String::from("नमस्ते");
-    println!("Using .chars() on kanji to get the individual bytes");
+    info!("Using .chars() on kanji to get the individual bytes");
+    let mut line = String::new();
     for c in kanji.chars() {
-      print!("...{}", c);
+      line.push_str(&format!("...{}", c));
     }
-    println!("... (done)");
+    info!("{}... (done)", line);
     // but again, that's not actually the recommended way to deal with unicode!
     // So that's a six-byte unicode string, but it has only four characters
-    let decoded_k = Uni::graphemes(kanji.as_str(), true).collect::<Vec<&str>>();
-    println!("Unicode-Decoded Kanji is now: {:?}", decoded_k);
-    println!("So now we see that the kanji var has {} glyphs", decoded_k.len());
-    println!("Even though the original unicode has a length of {}", kanji.len());
+    // (the same call as for russ above, wrapped up in a function for reuse)
+    let decoded_k = glyphs(&kanji);
+    info!("Unicode-Decoded Kanji is now: {:?}", decoded_k);
+    info!("So now we see that the kanji var has {} glyphs", decoded_k.len());
+    info!("Even though the original unicode has a length of {}", kanji.len());
     // note the 100% inconsistent API here: need to use .count() on result of .chars()
-    println!("And the original unicode has {} chars", kanji.chars().count());
+    info!("And the original unicode has {} chars", kanji.chars().count());
     // but you can use .len() on result of .bytes()
-    println!("And the original unicode has {} bytes", kanji.bytes().len());
+    info!("And the original unicode has {} bytes", kanji.bytes().len());
 
-    println!("--- String Demonstration Finish --- ");
-    println!("{}", &divider)
+    info!("--- String Demonstration Finish --- ");
+    print_divider();
 }
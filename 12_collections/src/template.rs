@@ -0,0 +1,198 @@
+/*!
+ * A very small template engine: fill in the `{name}`s in a string from a
+ * HashMap.
+ *
+ * ```text
+ * "Hello {name}, you have {count} messages"
+ *   + {"name": "Ferris", "count": 3}
+ *   = "Hello Ferris, you have 3 messages"
+ * ```
+ *
+ * The rules are `format!`'s: `{{` and `}}` are a literal `{` and `}`, and
+ * any other brace has to be part of a placeholder. The values can be
+ * anything that implements Display, so a count can stay a number.
+ *
+ * A name with no value isn't filled in with a blank, which would be easy
+ * to miss, but is an error. And not just the first one: the whole template
+ * is checked, and `MissingKey` lists every name that had no value, so they
+ * can all be put right in one go.
+ */
+use common::{info, print_divider};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::hash::Hash;
+
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    // every placeholder with no value, each once, in the order they appear
+    MissingKey(Vec<String>),
+    // a `{` with no `}` after it (the byte offset of the `{`)
+    Unclosed(usize),
+    // a `}` that doesn't close anything, and isn't doubled either
+    StrayBrace(usize),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemplateError::MissingKey(keys) => write!(f, "no value for {}", keys.join(", ")),
+            TemplateError::Unclosed(at) => write!(f, "the {{ at {} is never closed", at),
+            TemplateError::StrayBrace(at) => write!(f, "the }} at {} has no {{ (write }}}} for a literal one)", at),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// `template` with each `{name}` replaced by `values[name]`, and `{{` and
+/// `}}` by single braces.
+///
+/// The keys can be `&str` or `String` (anything that can be borrowed as a
+/// `str`, which is what lets a `&str` look up a `String` key).
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use collections::template::{render, TemplateError};
+///
+/// let mut values = HashMap::new();
+/// values.insert("name", "Ferris");
+/// values.insert("count", "3");
+/// assert_eq!(
+///     render("Hello {name}, you have {count} messages", &values),
+///     Ok(String::from("Hello Ferris, you have 3 messages"))
+/// );
+/// assert_eq!(render("{{literal}}", &values), Ok(String::from("{literal}")));
+/// assert_eq!(
+///     render("{greeting}, {name}! {mood}?", &values),
+///     Err(TemplateError::MissingKey(vec![String::from("greeting"), String::from("mood")]))
+/// );
+/// ```
+pub fn render<K, V>(template: &str, values: &HashMap<K, V>) -> Result<String, TemplateError>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: Display,
+{
+    let mut output = String::with_capacity(template.len());
+    let mut missing: Vec<String> = Vec::new();
+    // char_indices, so the errors can say *where*
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((at, c)) = chars.next() {
+        match c {
+            '{' if chars.next_if(|&(_, next)| next == '{').is_some() => output.push('{'),
+            '}' if chars.next_if(|&(_, next)| next == '}').is_some() => output.push('}'),
+            '}' => return Err(TemplateError::StrayBrace(at)),
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '}')) => break,
+                        Some((_, c)) => name.push(c),
+                        None => return Err(TemplateError::Unclosed(at)),
+                    }
+                }
+                let name = name.trim();
+                match values.get(name) {
+                    // writing to a String can't fail
+                    Some(value) => write!(output, "{}", value).unwrap(),
+                    None if !missing.iter().any(|m| m == name) => missing.push(name.to_string()),
+                    None => {}
+                }
+            }
+            c => output.push(c),
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(output)
+    } else {
+        Err(TemplateError::MissingKey(missing))
+    }
+}
+
+pub fn demo_template() {
+    print_divider();
+    info!("--- Template Demonstration Begins --- ");
+
+    let template = "Hello {name}, you have {count} messages";
+    let mut values: HashMap<&str, Box<dyn Display>> = HashMap::new();
+    values.insert("name", Box::new("Ferris"));
+    values.insert("count", Box::new(3));
+    info!("{:?} -> {:?}", template, render(template, &values));
+
+    let literal = "Braces are {{doubled}} to keep them: {name}";
+    info!("{:?} -> {:?}", literal, render(literal, &values));
+
+    // every missing name, not just the first
+    for broken in ["{greeting}, {name}! You have {count} {things}", "Hello {name", "Hello name}"] {
+        match render(broken, &values) {
+            Ok(text) => info!("{:?} -> {:?}", broken, text),
+            Err(e) => info!("{:?} -> error: {}", broken, e),
+        }
+    }
+
+    info!("--- Template Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> HashMap<String, String> {
+        let mut values = HashMap::new();
+        values.insert(String::from("name"), String::from("Ferris"));
+        values.insert(String::from("count"), String::from("3"));
+        values
+    }
+
+    #[test]
+    fn placeholders_are_filled_in() {
+        assert_eq!(render("Hello {name}, you have {count} messages", &values()).unwrap(), "Hello Ferris, you have 3 messages");
+        assert_eq!(render("{name}{name}", &values()).unwrap(), "FerrisFerris");
+        // spaces inside the braces don't count
+        assert_eq!(render("{ name }", &values()).unwrap(), "Ferris");
+    }
+
+    #[test]
+    fn no_placeholders_at_all() {
+        assert_eq!(render("", &values()).unwrap(), "");
+        assert_eq!(render("just text, ünïcödé too", &values()).unwrap(), "just text, ünïcödé too");
+    }
+
+    #[test]
+    fn doubled_braces_are_literal() {
+        assert_eq!(render("{{name}}", &values()).unwrap(), "{name}");
+        assert_eq!(render("{{{name}}}", &values()).unwrap(), "{Ferris}");
+        assert_eq!(render("}}{{", &values()).unwrap(), "}{");
+    }
+
+    #[test]
+    fn values_can_be_numbers() {
+        let mut counts = HashMap::new();
+        counts.insert("apples", 3);
+        counts.insert("pears", 0);
+        assert_eq!(render("{apples} apples, {pears} pears", &counts).unwrap(), "3 apples, 0 pears");
+    }
+
+    #[test]
+    fn every_missing_key_is_listed_once() {
+        let error = render("{a} {name} {b} {a} {}", &values()).unwrap_err();
+        assert_eq!(error, TemplateError::MissingKey(vec![String::from("a"), String::from("b"), String::new()]));
+        assert_eq!(error.to_string(), "no value for a, b, ");
+    }
+
+    #[test]
+    fn unbalanced_braces() {
+        assert_eq!(render("Hello {name", &values()), Err(TemplateError::Unclosed(6)));
+        assert_eq!(render("Hello name}", &values()), Err(TemplateError::StrayBrace(10)));
+        // a { inside a placeholder is just part of its (missing) name
+        assert_eq!(render("{a{b}", &values()), Err(TemplateError::MissingKey(vec![String::from("a{b")])));
+        // and the offsets are bytes, so é counts 2
+        assert_eq!(render("é}", &values()), Err(TemplateError::StrayBrace(2)));
+    }
+}
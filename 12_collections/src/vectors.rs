@@ -2,25 +2,48 @@
  * Vectors are a much closer analogue to Python's Lists or Javascript's arrays
  * than a Rust Array is. 
  */
-pub fn demo_vectors() {
-    let divider = "///////////";
+use common::{info, print_divider, time, timed};
+
+/// The squares of `0..count`, one push at a time, into a Vec that either
+/// starts empty and grows (`presized == false`), or starts out big enough.
+///
+/// # Examples
+///
+/// ```
+/// use collections::vectors::squares;
+///
+/// let grown = squares(5, false);
+/// let sized = squares(5, true);
+/// // the same contents either way: only the allocations differ
+/// assert_eq!(grown, [0, 1, 4, 9, 16]);
+/// assert_eq!(grown, sized);
+/// assert!(sized.capacity() >= 5);
+/// ```
+pub fn squares(count: u64, presized: bool) -> Vec<u64> {
+    let mut v = if presized { Vec::with_capacity(count as usize) } else { Vec::new() };
+    for n in 0..count {
+        v.push(n * n);
+    }
+    v
+}
 
+pub fn demo_vectors() {
     // Vectors are Rusts' growable generic collection
     // The library provides Vec<T>, where T can be any type you like
     
-    println!("{}", &divider);
-    println!("--- Vector Demonstration Begins --- ");
+    print_divider();
+    info!("--- Vector Demonstration Begins --- ");
 
     // Instantiate a Vector from an array literal with the `vec!` macro:
     let v1 = vec![1, 3, 5, 7];
-    println!("Vector from array literal: {:?}", &v1);
+    info!("Vector from array literal: {:?}", &v1);
     // The above example uses Rust type inference. But if you declare an empty 
     // Vector and do not populate it on the same line, then the compiler cannot 
     // do type inference, and you might want to declare the type explicitly.
     let v2: Vec<i32> = Vec::new(); 
     // it is perfectly legit -- and not at all uncommon -- to start with an 
     // empty Vector, so this will probably come up pretty often in practice.
-    println!("Empty vector (type i32): {:?}", &v2);
+    info!("Empty vector (type i32): {:?}", &v2);
 
     // both of the above vectors are *immutable*. Don't be fooled by the idea
     // that Vectors are "growable".  A Vector is growable if it was declared
@@ -35,34 +58,34 @@ pub fn demo_vectors() {
     v3.push(7);
     
     // do Vectors support value equality out of the box?
-    println!("Is v1 ({:?}) value equal to v3 ({:?}? {}", &v1, &v3, v1 == v3);
+    info!("Is v1 ({:?}) value equal to v3 ({:?}? {}", &v1, &v3, v1 == v3);
 
     // Now do a further mutation:
     v3.push(9);
     // and try it again
-    println!("Is v1 ({:?}) value equal to v3 ({:?}? {}", &v1, &v3, v1 == v3);
+    info!("Is v1 ({:?}) value equal to v3 ({:?}? {}", &v1, &v3, v1 == v3);
     // (I am a little surprised again that the borrow checker does not complain here)
 
     // array-like access works
     let val1 = &v1[0]; 
-    println!("First element of v3: {}", val1);
+    info!("First element of v3: {}", val1);
     // but runs the risk of panic! if you go out of range
     //let val2 = &v1[1001]; // runtime panic (not a compiler error!)
     // so use `get()`, which returns an Option<&T>
     let val3 = v1.get(1001); // an Option<&i32>, which will be None
 
     match val3 {
-        Some(foo) => println!("WTF, how did we get {}", foo),
-        None => println!("Yep, we expected None and that's just what we got")
+        Some(foo) => info!("WTF, how did we get {}", foo),
+        None => info!("Yep, we expected None and that's just what we got")
     }
 
     // you can _make_ an immutable borrow reference without the compiler complaining
     let _valx = &v3[0]; // compiler doesn't complain about this
     // and you can still _push_ afterwards without the compiler complaining either
     v3.push(42);
-    // but you can no longer *use* the _valx reference in the println! macro
+    // but you can no longer *use* the _valx reference in the info! macro
     // without triggering a compiler error about crossed streams
-    // println!("Why is the borrow checker letting us off so easily? {:?}", _valx);
+    // info!("Why is the borrow checker letting us off so easily? {:?}", _valx);
 
     // tl;dr: the borrow checker is pretty lax about sequential declarations that
     // happen to cross the streams. It's only when you try to transfer one of the
@@ -72,13 +95,22 @@ pub fn demo_vectors() {
     // iterate over a Vector with `for ... in ...`
     // but be sure to iterate over a _reference_ to the Vector unless you want
     // to transfer ownership (in which case the original will be gone, buh-bye!)
-    print!("v3 has");
+    // (a log message is a whole line, so we build the line up first)
+    let mut line = String::from("v3 has");
     for i in &v3 {
-        print!("...{}", i);
+        line.push_str(&format!("...{}", i));
     }
-    println!("");
-    println!("And it's still valid: {:?}", &v3);
+    info!("{}", line);
+    info!("And it's still valid: {:?}", &v3);
+
+    // growing a Vector one push at a time means it sometimes has to move to
+    // a bigger allocation. If you know the final size up front, with_capacity
+    // grabs it all at once. (Don't read much into the numbers from a debug
+    // build: there, the difference is often lost in the noise.)
+    let (grown, grow_time) = time(|| squares(100_000, false));
+    let sized = timed("Filling a pre-sized Vector", || squares(100_000, true));
+    info!("Growing one push at a time took {:?}; same contents? {}", grow_time, grown == sized);
 
-    println!("--- Vector Demonstration Finish --- ");
-    println!("{}", &divider)
+    info!("--- Vector Demonstration Finish --- ");
+    print_divider();
 }
\ No newline at end of file
@@ -0,0 +1,142 @@
+/*!
+ * Palindromes and anagrams, the grown-up way: by glyph, not by byte.
+ *
+ * The textbook palindrome check is `s == s.chars().rev().collect()`, and it
+ * goes wrong as soon as a letter is more than one `char`. An "é" can be
+ * written as an "e" followed by a combining accent (U+0301), and reversing
+ * the chars moves the accent onto whatever came *before* the "e". Working
+ * with grapheme clusters (the glyphs from ./strings.rs) keeps each letter
+ * in one piece.
+ *
+ * Both checks ignore case, spaces and punctuation, so "A man, a plan, a
+ * canal: Panama!" counts. They don't fold one way of writing "é" into the
+ * other, though: that's Unicode *normalization*, which needs the
+ * unicode-normalization crate, so "é" and "e" + U+0301 are different glyphs
+ * here, even though they look the same.
+ *
+ * Right-to-left scripts (Hebrew, Arabic) need nothing special: a string
+ * holds its text in reading order, whichever direction it's displayed in.
+ */
+use common::{info, print_divider};
+use unicode_segmentation::UnicodeSegmentation as Uni;
+
+// the glyphs that are letters or digits, lowercased
+fn letters(s: &str) -> Vec<String> {
+    Uni::graphemes(s, true)
+        .filter(|glyph| glyph.chars().next().is_some_and(char::is_alphanumeric))
+        .map(|glyph| glyph.to_lowercase())
+        .collect()
+}
+
+/// Whether `s` reads the same backwards, glyph by glyph, ignoring case,
+/// spaces and punctuation. Text with no letters in it at all doesn't count.
+///
+/// # Examples
+///
+/// ```
+/// use collections::wordplay::is_palindrome;
+///
+/// assert!(is_palindrome("A man, a plan, a canal: Panama!"));
+/// // the accent stays on its own letter, written either way
+/// assert!(is_palindrome("été"));
+/// assert!(is_palindrome("e\u{301}te\u{301}"));
+/// assert!(!is_palindrome("palindrome"));
+/// ```
+pub fn is_palindrome(s: &str) -> bool {
+    let letters = letters(s);
+    !letters.is_empty() && letters.iter().eq(letters.iter().rev())
+}
+
+/// Whether `a` and `b` use exactly the same letters, ignoring case, spaces
+/// and punctuation.
+///
+/// # Examples
+///
+/// ```
+/// use collections::wordplay::are_anagrams;
+///
+/// assert!(are_anagrams("Dormitory", "dirty room!"));
+/// assert!(are_anagrams("Résumé", "mésuré"));
+/// assert!(!are_anagrams("resume", "résumé"));
+/// ```
+pub fn are_anagrams(a: &str, b: &str) -> bool {
+    let (mut a, mut b) = (letters(a), letters(b));
+    a.sort();
+    b.sort();
+    a == b
+}
+
+pub fn demo_wordplay() {
+    print_divider();
+    info!("--- Wordplay Demonstration Begins --- ");
+    for phrase in ["Was it a car or a cat I saw?", "e\u{301}te\u{301}", "עבע", "palindrome"] {
+        // reversing chars leaves a combining accent on the wrong letter
+        let by_char: String = phrase.chars().rev().collect();
+        info!("{:?} is a palindrome: {} (reversed by char: {:?})", phrase, is_palindrome(phrase), by_char);
+    }
+    for (a, b) in [("Listen", "Silent"), ("Résumé", "mésuré"), ("Dormitory", "dirty room!"), ("cat", "act!!x")] {
+        info!("{:?} and {:?} are anagrams: {}", a, b, are_anagrams(a, b));
+    }
+    info!("--- Wordplay Demonstration Finish --- ");
+    print_divider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_palindromes() {
+        assert!(is_palindrome("racecar"));
+        assert!(is_palindrome("Never odd or even."));
+        assert!(is_palindrome("12321"));
+        assert!(is_palindrome("a"));
+        assert!(!is_palindrome("ab"));
+        assert!(!is_palindrome(""));
+        assert!(!is_palindrome("?! ..."));
+    }
+
+    #[test]
+    fn accented_palindromes() {
+        // precomposed and combining accents, and a capital that lowercases
+        assert!(is_palindrome("Ésé"));
+        assert!(is_palindrome("a\u{301}ba\u{301}"));
+        // the accent belongs to the first a and not the last, so no
+        assert!(!is_palindrome("a\u{301}ba"));
+        // a char-by-char check would see a, U+301, b, a, U+301 and say no
+        let by_char: Vec<char> = "a\u{301}ba\u{301}".chars().collect();
+        assert!(!by_char.iter().eq(by_char.iter().rev()));
+    }
+
+    #[test]
+    fn right_to_left_and_mixed_palindromes() {
+        // Hebrew, in reading order, with the spaces ignored
+        assert!(is_palindrome("אבא"));
+        assert!(is_palindrome("ילד כ דלי"));
+        // Arabic with its vowel marks, which are combining characters
+        assert!(is_palindrome("سَلَسَ"));
+        assert!(!is_palindrome("سَلَس"));
+        // left-to-right and right-to-left mixed, as stored
+        assert!(is_palindrome("ab אבא ba"));
+        assert!(!is_palindrome("ab אבא ab"));
+    }
+
+    #[test]
+    fn anagrams() {
+        assert!(are_anagrams("Listen", "Silent"));
+        assert!(are_anagrams("The eyes", "They see!"));
+        assert!(are_anagrams("", "..."));
+        assert!(!are_anagrams("cat", "cart"));
+        // the same letters, but not the same number of each
+        assert!(!are_anagrams("aab", "abb"));
+    }
+
+    #[test]
+    fn accented_and_mixed_anagrams() {
+        assert!(are_anagrams("Résumé", "ÉMÉRUS"));
+        assert!(are_anagrams("e\u{301}a", "ae\u{301}"));
+        // an accented letter isn't the plain one
+        assert!(!are_anagrams("é", "e"));
+        assert!(are_anagrams("אב cd", "dc בא"));
+    }
+}
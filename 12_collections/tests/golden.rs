@@ -0,0 +1,21 @@
+/**
+ * The demo's whole output, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn demo() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_collections"), DIR), "");
+    golden::check(DIR, "demo", &output);
+}
+
+// the same run, as one JSON record per message
+#[test]
+fn demo_as_json() {
+    let mut command = golden::command(env!("CARGO_BIN_EXE_collections"), DIR);
+    let output = golden::output(command.args(["--format", "json"]), "");
+    golden::check(DIR, "demo_as_json", &output);
+}
@@ -0,0 +1,82 @@
+/*!
+ * Every failure that demo() keeps commented out, runnable without editing
+ * anything: `cargo run -- --demo <name>` (see common/src/failures.rs).
+ *
+ * The panicking ones are the same calls demo() tells you to uncomment. The
+ * two propagation ones don't panic at all: they hand back an Err, and what
+ * happens to it is up to us, so we print it and carry on. That difference
+ * is the whole chapter in miniature.
+ */
+use crate::{
+    demo_io_simple, demo_result_expect, demo_result_smarter, eek, read_username_terse,
+    read_username_verbose, Guess,
+};
+use common::{info, Demo};
+
+pub const FAILURES: &[Demo] = &[
+    Demo::new("custom_panic", "panic! on purpose", custom_panic),
+    Demo::new("eek", "index past the end of a Vec", eek_panic),
+    Demo::new("io_simple", "open a missing file, and panic about it", demo_io_simple),
+    Demo::new("io_smarter", "create the file if it's missing (then trip over it)", demo_result_smarter),
+    Demo::new("expect", "expect() with a message of our own", demo_result_expect),
+    Demo::new("propagate_manually", "an Err handed back by match and return", propagate_manually),
+    Demo::new("propagate_tersely", "an Err handed back by ?", propagate_tersely),
+    Demo::new("guess_too_low", "Guess::new(-1) refuses", guess_too_low),
+    Demo::new("guess_too_high", "Guess::new(2000) refuses", guess_too_high),
+];
+
+fn custom_panic() {
+    panic!("Something has gone terribly terribly wrong");
+}
+
+fn eek_panic() {
+    let x = eek();
+    info!("never printed: {}", x);
+}
+
+fn propagate_manually() {
+    match read_username_verbose() {
+        Ok(name) => info!("somehow users.txt exists, and says: {}", name),
+        Err(e) => info!("read_username_verbose() handed back an Err: {}", e),
+    }
+}
+
+fn propagate_tersely() {
+    match read_username_terse() {
+        Ok(name) => info!("somehow users.txt exists, and says: {}", name),
+        Err(e) => info!("read_username_terse() handed back an Err: {}", e),
+    }
+}
+
+fn guess_too_low() {
+    let g = Guess::new(-1);
+    info!("never printed: {}", g.value());
+}
+
+fn guess_too_high() {
+    let g = Guess::new(2000);
+    info!("never printed: {}", g.value());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::failures::run;
+
+    #[test]
+    fn panics_are_caught() {
+        assert_eq!(run(FAILURES, "custom_panic"), Err(String::from("Something has gone terribly terribly wrong")));
+        assert!(run(FAILURES, "eek").unwrap_err().contains("index out of bounds"));
+        assert_eq!(run(FAILURES, "guess_too_high"), Err(String::from("Guess value must be between 1 and 100, got 2000.")));
+    }
+
+    #[test]
+    fn propagated_errors_are_not_panics() {
+        let (result, logged) = common::capture(|| run(FAILURES, "propagate_manually"));
+        assert_eq!(result, Ok(()));
+        assert!(logged.starts_with("read_username_verbose() handed back an Err"));
+        let (result, logged) = common::capture(|| run(FAILURES, "propagate_tersely"));
+        assert_eq!(result, Ok(()));
+        assert!(logged.starts_with("read_username_terse() handed back an Err"));
+    }
+}
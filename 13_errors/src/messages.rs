@@ -0,0 +1,51 @@
+// What the errors demo says, in each language it can say it in (see
+// common/src/i18n.rs). English comes first, because it's the fallback.
+use common::Catalog;
+
+pub static CATALOGS: &[Catalog] = &[
+    Catalog {
+        lang: "en",
+        messages: &[
+            ("intro", " ... Error demo module: all demos commented out by default ..."),
+            ("custom_panic", "Demo a custom panic! (uncomment next line to see)"),
+            ("native_panic", "Demo a native panic! (uncomment next line to see)"),
+            ("io_simple", "Demo a simple IO error (uncomment next line to see"),
+            ("io_smarter", "Demo a smarter IO error (uncomment next line to see"),
+            ("expect", "Demo the .expect() helper (uncomment next line to see"),
+            ("propagate_manually", "Demo manual error propagation (uncomment next line to see"),
+            ("propagate_tersely", "Demo terse error propagation with `?` (uncomment next line to see"),
+            ("guess_value", "Guess value is: {value}"),
+            ("try_the_flag", "Or see any of them without editing: cargo run -- --demo <name> (--demo alone lists them)"),
+            ("complete", "... Error demo module complete ..."),
+        ],
+    },
+    Catalog {
+        lang: "es",
+        messages: &[
+            ("intro", " ... Módulo de errores: todas las demos están comentadas de entrada ..."),
+            ("custom_panic", "Demo de un panic! propio (descomenta la línea siguiente para verlo)"),
+            ("native_panic", "Demo de un panic! nativo (descomenta la línea siguiente para verlo)"),
+            ("io_simple", "Demo de un error de E/S sencillo (descomenta la línea siguiente para verlo)"),
+            ("io_smarter", "Demo de un error de E/S más listo (descomenta la línea siguiente para verlo)"),
+            ("expect", "Demo del método .expect() (descomenta la línea siguiente para verlo)"),
+            ("propagate_manually", "Demo de propagar errores a mano (descomenta la línea siguiente para verlo)"),
+            ("propagate_tersely", "Demo de propagar errores con `?` (descomenta la línea siguiente para verlo)"),
+            ("guess_value", "El valor de Guess es: {value}"),
+            ("try_the_flag", "O míralas sin tocar el código: cargo run -- --demo <nombre> (--demo a secas las lista)"),
+            ("complete", "... Módulo de errores terminado ..."),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::i18n::missing_keys;
+
+    #[test]
+    fn every_language_has_every_message() {
+        for catalog in CATALOGS {
+            assert_eq!(missing_keys(CATALOGS, catalog.lang), Vec::<&str>::new(), "in {}", catalog.lang);
+        }
+    }
+}
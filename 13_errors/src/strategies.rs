@@ -0,0 +1,271 @@
+/*!
+ * What to do when one of many things fails, four ways, with the cost of each
+ * written down.
+ *
+ * The rest of the chapter handles one Result at a time. Real work is more
+ * often a list of them: a hundred lines to parse, a dozen files to read. The
+ * choices are the same few every time:
+ *
+ * ```text
+ * FailFast        stop at the first error (what `?` in a loop does)
+ * CollectAll      keep going, and report every error at the end
+ * Retry           try each failure again, in case it was a hiccup
+ * DefaultOnError  keep going, with a stand-in value for each failure
+ * ```
+ *
+ * `run` does the same work under any of them, and the `Metrics` it hands
+ * back put numbers on the trade-offs: fail-fast skips work, retrying costs
+ * time (and is wasted on errors that will never go away), and a default
+ * keeps the results lined up with the inputs at the price of making bad
+ * data look like good data.
+ */
+use common::{info, time};
+use std::collections::HashSet;
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    FailFast,
+    CollectAll,
+    // up to `attempts` more tries for each failure, `backoff` apart
+    Retry { attempts: u32, backoff: Duration },
+    DefaultOnError,
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Strategy::FailFast => write!(f, "fail fast"),
+            Strategy::CollectAll => write!(f, "collect all"),
+            Strategy::Retry { attempts, .. } => write!(f, "retry (x{})", attempts),
+            Strategy::DefaultOnError => write!(f, "default on error"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    pub successes: usize,
+    // inputs given up on, whatever happened to them afterwards
+    pub failures: usize,
+    // tries after the first, whether they worked or not
+    pub retries: usize,
+    // inputs never tried at all, because fail-fast had already stopped
+    pub skipped: usize,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ok, {} failed, {} retries, {} skipped in {:.2?}",
+            self.successes, self.failures, self.retries, self.skipped, self.elapsed
+        )
+    }
+}
+
+pub struct Outcome<U, E> {
+    // one per success, in order; with DefaultOnError, one per input
+    pub values: Vec<U>,
+    // every error given up on (fail-fast only ever has one)
+    pub errors: Vec<E>,
+    pub metrics: Metrics,
+}
+
+// Run `work` over every input under `strategy`. The default for
+// DefaultOnError is U's own Default: 0, "", an empty Vec and so on.
+pub fn run<T, U, E>(strategy: Strategy, inputs: &[T], mut work: impl FnMut(&T) -> Result<U, E>) -> Outcome<U, E>
+where
+    U: Default,
+{
+    let ((values, errors, mut metrics), elapsed) = time(|| {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        let mut metrics = Metrics::default();
+
+        for (at, input) in inputs.iter().enumerate() {
+            let mut result = work(input);
+            if let Strategy::Retry { attempts, backoff } = strategy {
+                for _ in 0..attempts {
+                    if result.is_ok() {
+                        break;
+                    }
+                    thread::sleep(backoff);
+                    metrics.retries += 1;
+                    result = work(input);
+                }
+            }
+
+            match result {
+                Ok(value) => {
+                    metrics.successes += 1;
+                    values.push(value);
+                }
+                Err(e) => {
+                    metrics.failures += 1;
+                    errors.push(e);
+                    match strategy {
+                        Strategy::FailFast => {
+                            metrics.skipped = inputs.len() - at - 1;
+                            break;
+                        }
+                        Strategy::DefaultOnError => values.push(U::default()),
+                        Strategy::CollectAll | Strategy::Retry { .. } => {}
+                    }
+                }
+            }
+        }
+        (values, errors, metrics)
+    });
+    metrics.elapsed = elapsed;
+    Outcome { values, errors, metrics }
+}
+
+// Something to fail at: a sensor that isn't very good at its job. "12"
+// reads as 12, "?" is garbled and always will be, and "~7" times out the
+// first time it's asked, then reads as 7.
+#[derive(Debug, PartialEq)]
+pub enum ReadError {
+    Garbled(String),
+    TimedOut(String),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Garbled(reading) => write!(f, "{} is garbled", reading),
+            ReadError::TimedOut(reading) => write!(f, "{} timed out", reading),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Sensor {
+    // the readings that have already had their time-out
+    timed_out: HashSet<String>,
+}
+
+impl Sensor {
+    pub fn new() -> Sensor {
+        Sensor::default()
+    }
+
+    pub fn read(&mut self, reading: &str) -> Result<i32, ReadError> {
+        let digits = match reading.strip_prefix('~') {
+            Some(_) if self.timed_out.insert(reading.to_string()) => {
+                return Err(ReadError::TimedOut(reading.to_string()));
+            }
+            Some(digits) => digits,
+            None => reading,
+        };
+        digits.parse().map_err(|_| ReadError::Garbled(reading.to_string()))
+    }
+}
+
+const READINGS: &[&str] = &["12", "~7", "?", "30", "~5"];
+
+// (everything through info!, and no dividers: `--format json` turns every
+// line this chapter prints into a record)
+pub fn demo_strategies() {
+    info!("--- Error Strategies Demonstration Begins --- ");
+
+    info!("readings: {:?}", READINGS);
+    let strategies = [
+        Strategy::FailFast,
+        Strategy::CollectAll,
+        Strategy::Retry { attempts: 2, backoff: Duration::from_millis(1) },
+        Strategy::DefaultOnError,
+    ];
+    for strategy in strategies.iter() {
+        // a fresh sensor each time, so every strategy meets the same time-outs
+        let mut sensor = Sensor::new();
+        let outcome = run(*strategy, READINGS, |reading| sensor.read(reading));
+        let errors: Vec<String> = outcome.errors.iter().map(|e| e.to_string()).collect();
+        info!("{:<17} {:?}, errors: {}", strategy.to_string(), outcome.values, errors.join(", "));
+        info!("{:<17} {}", "", outcome.metrics);
+    }
+
+    info!("--- Error Strategies Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_readings(strategy: Strategy) -> Outcome<i32, ReadError> {
+        let mut sensor = Sensor::new();
+        run(strategy, READINGS, |reading| sensor.read(reading))
+    }
+
+    const RETRY: Strategy = Strategy::Retry { attempts: 2, backoff: Duration::from_millis(0) };
+
+    #[test]
+    fn the_sensor_times_out_once() {
+        let mut sensor = Sensor::new();
+        assert_eq!(sensor.read("~7"), Err(ReadError::TimedOut(String::from("~7"))));
+        assert_eq!(sensor.read("~7"), Ok(7));
+        assert_eq!(sensor.read("?"), Err(ReadError::Garbled(String::from("?"))));
+        assert_eq!(sensor.read("-3"), Ok(-3));
+    }
+
+    #[test]
+    fn fail_fast_stops_at_the_first_error() {
+        let outcome = run_readings(Strategy::FailFast);
+        assert_eq!(outcome.values, [12]);
+        assert_eq!(outcome.errors, [ReadError::TimedOut(String::from("~7"))]);
+        let Metrics { successes, failures, retries, skipped, .. } = outcome.metrics;
+        assert_eq!((successes, failures, retries, skipped), (1, 1, 0, 3));
+    }
+
+    #[test]
+    fn collect_all_sees_every_error() {
+        let outcome = run_readings(Strategy::CollectAll);
+        assert_eq!(outcome.values, [12, 30]);
+        assert_eq!(outcome.errors.len(), 3);
+        let Metrics { successes, failures, retries, skipped, .. } = outcome.metrics;
+        assert_eq!((successes, failures, retries, skipped), (2, 3, 0, 0));
+    }
+
+    #[test]
+    fn retry_gets_past_hiccups_but_not_garbage() {
+        let outcome = run_readings(RETRY);
+        assert_eq!(outcome.values, [12, 7, 30, 5]);
+        assert_eq!(outcome.errors, [ReadError::Garbled(String::from("?"))]);
+        // one retry each for the time-outs, and both of them wasted on "?"
+        let Metrics { successes, failures, retries, skipped, .. } = outcome.metrics;
+        assert_eq!((successes, failures, retries, skipped), (4, 1, 4, 0));
+    }
+
+    #[test]
+    fn default_on_error_keeps_the_values_lined_up() {
+        let outcome = run_readings(Strategy::DefaultOnError);
+        assert_eq!(outcome.values, [12, 0, 0, 30, 0]);
+        assert_eq!(outcome.values.len(), READINGS.len());
+        assert_eq!(outcome.metrics.failures, 3);
+    }
+
+    #[test]
+    fn backoff_shows_up_in_the_elapsed_time() {
+        let backoff = Duration::from_millis(5);
+        let outcome = run_readings(Strategy::Retry { attempts: 1, backoff });
+        assert_eq!(outcome.metrics.retries, 3);
+        assert!(outcome.metrics.elapsed >= backoff * 3);
+    }
+
+    #[test]
+    fn nothing_to_do_is_no_trouble() {
+        let outcome = run(Strategy::FailFast, &[] as &[&str], |_| Err::<i32, ()>(()));
+        assert!(outcome.values.is_empty() && outcome.errors.is_empty());
+        assert_eq!(outcome.metrics.skipped, 0);
+    }
+
+    #[test]
+    fn metrics_read_as_a_sentence() {
+        let metrics = Metrics { successes: 4, failures: 1, retries: 4, skipped: 0, elapsed: Duration::from_micros(1500) };
+        assert_eq!(metrics.to_string(), "4 ok, 1 failed, 4 retries, 0 skipped in 1.50ms");
+        assert_eq!(RETRY.to_string(), "retry (x2)");
+    }
+}
@@ -0,0 +1,37 @@
+/**
+ * Guess::try_new and `parse::<Guess>()`, fed thousands of made-up inputs
+ * (see common/src/fuzz.rs). Guess::new panics on a bad value, on purpose;
+ * these two never should: every bad guess is a GuessError.
+ */
+use common::fuzz;
+use errors::{Guess, GuessError};
+
+#[test]
+fn try_new_takes_any_i32() {
+    let corpus: &[&[u8]] = &[&1i32.to_le_bytes(), &100i32.to_le_bytes(), &0i32.to_le_bytes(), &i32::MIN.to_le_bytes()];
+    fuzz::check("Guess::try_new", corpus, |bytes| {
+        // the first four bytes as a number, however many there are
+        let mut four = [0u8; 4];
+        for (to, from) in four.iter_mut().zip(bytes) {
+            *to = *from;
+        }
+        let value = i32::from_le_bytes(four);
+        match Guess::try_new(value) {
+            Ok(guess) => assert!((1..=100).contains(&guess.value())),
+            Err(GuessError::TooLow(n)) => assert!(n == value && n < 1),
+            Err(GuessError::TooHigh(n)) => assert!(n == value && n > 100),
+            Err(e) => panic!("try_new({}) said {:?}", value, e),
+        }
+    });
+}
+
+#[test]
+fn guesses_from_any_text() {
+    let corpus: &[&[u8]] = &[b"50", b" 1\n", b"100", b"-7", b"fifty", b"2147483648"];
+    fuzz::check("parse::<Guess>", corpus, |bytes| {
+        let text = String::from_utf8_lossy(bytes);
+        if let Ok(guess) = text.parse::<Guess>() {
+            assert_eq!(text.trim().parse::<i32>(), Ok(guess.value()));
+        }
+    });
+}
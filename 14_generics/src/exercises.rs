@@ -0,0 +1,22 @@
+/*!
+ * Exercises for chapter 14: generics.
+ *
+ * Check them with `cargo run -p runner -- verify 14` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+// `largest` from lib.rs, turned around. Assume the list isn't empty.
+pub fn smallest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    todo!()
+}
+
+// How many items in the list are equal to `target`?
+pub fn count_matching<T: PartialEq>(list: &[T], target: &T) -> usize {
+    todo!()
+}
+
+// A copy of the first item, or `default` if the list is empty.
+pub fn first_or<T: Clone>(list: &[T], default: T) -> T {
+    todo!()
+}
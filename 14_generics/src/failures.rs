@@ -0,0 +1,65 @@
+/*!
+ * Where `largest<T: PartialOrd>` goes wrong: `cargo run -- --demo <name>`
+ * (see common/src/failures.rs).
+ *
+ * The generic version compiles for any T that can be compared, but the
+ * compiler only checks the *types*. It can't know the slice won't be empty,
+ * and "can be compared" (PartialOrd) isn't the same as "always has an
+ * answer" (Ord): a float NaN is neither bigger nor smaller than anything.
+ */
+use crate::largest;
+use common::Demo;
+
+pub const FAILURES: &[Demo] = &[
+    Demo::new("largest_of_nothing", "largest(&[]) indexes past the end, and panics", largest_of_nothing),
+    Demo::new("largest_with_nan", "a NaN makes largest() answer nonsense, quietly", largest_with_nan),
+    Demo::new("largest_checked", "the Option-returning fix for both", largest_checked_demo),
+];
+
+fn largest_of_nothing() {
+    let empty: Vec<i32> = Vec::new();
+    println!("never printed: {}", largest(&empty));
+}
+
+// No panic here, which is worse: NaN > 1.0 is false, but so is 1.0 > NaN,
+// so whichever of them comes first "wins"
+fn largest_with_nan() {
+    println!("largest of [1.0, NaN, 3.0] is {}", largest(&[1.0, f64::NAN, 3.0]));
+    println!("largest of [NaN, 1.0, 3.0] is {}", largest(&[f64::NAN, 1.0, 3.0]));
+}
+
+// An Option covers the empty slice, and Ord rules out the NaNs: floats only
+// have PartialOrd, so this won't even compile for them
+pub fn largest_checked<T: Ord>(list: &[T]) -> Option<&T> {
+    list.iter().max()
+}
+
+fn largest_checked_demo() {
+    let empty: Vec<i32> = Vec::new();
+    println!("largest_checked(&[]) is {:?}", largest_checked(&empty));
+    println!("largest_checked(&[34, 50, 25]) is {:?}", largest_checked(&[34, 50, 25]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::failures::run;
+
+    #[test]
+    fn an_empty_list_panics() {
+        assert!(run(FAILURES, "largest_of_nothing").unwrap_err().contains("index out of bounds"));
+    }
+
+    #[test]
+    fn nan_order_matters() {
+        assert_eq!(*largest(&[1.0, f64::NAN, 3.0]), 3.0);
+        assert!(largest(&[f64::NAN, 1.0, 3.0]).is_nan());
+    }
+
+    #[test]
+    fn the_checked_version_never_panics() {
+        assert_eq!(largest_checked::<i32>(&[]), None);
+        assert_eq!(largest_checked(&[34, 50, 25]), Some(&50));
+        assert_eq!(run(FAILURES, "largest_checked"), Ok(()));
+    }
+}
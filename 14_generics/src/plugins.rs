@@ -0,0 +1,251 @@
+/*!
+ * Two ways to hold "one of several kinds of thing" in a single list, side
+ * by side: a closed enum, and an open `Vec<Box<dyn Plugin>>`.
+ *
+ * The plugins are tiny text filters, and each has the same three
+ * behaviors: a name, whether it wants a given text at all, and what it
+ * does to the text. The two versions hold the same three filters:
+ *
+ * ```text
+ * closed enum                       open trait objects
+ * ---------------------------------------------------------------------
+ * Builtin::Shout                    Box::new(Shout)
+ * every method is one `match`       every type has its own `impl Plugin`
+ * a new variant: the compiler       a new type: nothing else changes,
+ *   finds every match to update       and nothing checks it's handled
+ * only this file can add one        any crate can add one
+ * stored inline, no allocation      one Box each, and a vtable call
+ * ```
+ *
+ * Neither is better. An enum is the right call when *you* own the whole
+ * list (the compiler's exhaustiveness check is worth a lot), and a trait
+ * object when someone else needs to add to it. `impl Plugin for Builtin`
+ * at the bottom lets the closed set join an open list, too.
+ */
+use std::mem;
+
+// The open version: anything that implements this can be a plugin,
+// including types from crates that have never heard of this one
+pub trait Plugin {
+    fn name(&self) -> String;
+
+    // whether this plugin has anything to do with `text`; most do
+    fn accepts(&self, _text: &str) -> bool {
+        true
+    }
+
+    fn apply(&self, text: &str) -> String;
+}
+
+pub struct Shout;
+
+impl Plugin for Shout {
+    fn name(&self) -> String {
+        String::from("shout")
+    }
+
+    fn apply(&self, text: &str) -> String {
+        text.to_uppercase()
+    }
+}
+
+pub struct Reverse;
+
+impl Plugin for Reverse {
+    fn name(&self) -> String {
+        String::from("reverse")
+    }
+
+    fn apply(&self, text: &str) -> String {
+        text.chars().rev().collect()
+    }
+}
+
+pub struct Censor(pub String);
+
+impl Plugin for Censor {
+    fn name(&self) -> String {
+        format!("censor {}", self.0)
+    }
+
+    // a censor with nothing to censor stays out of the way
+    fn accepts(&self, text: &str) -> bool {
+        !self.0.is_empty() && text.contains(&self.0)
+    }
+
+    fn apply(&self, text: &str) -> String {
+        text.replace(&self.0, &"*".repeat(self.0.chars().count()))
+    }
+}
+
+// The closed version: these three, and no others, ever (unless this file
+// changes, and then every `match` below has to change with it)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Builtin {
+    Shout,
+    Reverse,
+    Censor(String),
+}
+
+impl Builtin {
+    pub fn name(&self) -> String {
+        match self {
+            Builtin::Shout => String::from("shout"),
+            Builtin::Reverse => String::from("reverse"),
+            Builtin::Censor(word) => format!("censor {}", word),
+        }
+    }
+
+    pub fn accepts(&self, text: &str) -> bool {
+        match self {
+            Builtin::Shout | Builtin::Reverse => true,
+            Builtin::Censor(word) => !word.is_empty() && text.contains(word.as_str()),
+        }
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Builtin::Shout => text.to_uppercase(),
+            Builtin::Reverse => text.chars().rev().collect(),
+            Builtin::Censor(word) => text.replace(word.as_str(), &"*".repeat(word.chars().count())),
+        }
+    }
+}
+
+// the closed set can always join the open one (never the other way round)
+impl Plugin for Builtin {
+    fn name(&self) -> String {
+        Builtin::name(self)
+    }
+
+    fn accepts(&self, text: &str) -> bool {
+        Builtin::accepts(self, text)
+    }
+
+    fn apply(&self, text: &str) -> String {
+        Builtin::apply(self, text)
+    }
+}
+
+// Run `text` through each plugin that accepts it, in order, and say which
+// ones did. The two are the same loop: only the type of the list differs.
+pub fn run_closed(plugins: &[Builtin], text: &str) -> (String, Vec<String>) {
+    let mut text = text.to_string();
+    let mut ran = Vec::new();
+    for plugin in plugins {
+        if plugin.accepts(&text) {
+            text = plugin.apply(&text);
+            ran.push(plugin.name());
+        }
+    }
+    (text, ran)
+}
+
+pub fn run_open(plugins: &[Box<dyn Plugin>], text: &str) -> (String, Vec<String>) {
+    let mut text = text.to_string();
+    let mut ran = Vec::new();
+    for plugin in plugins {
+        if plugin.accepts(&text) {
+            text = plugin.apply(&text);
+            ran.push(plugin.name());
+        }
+    }
+    (text, ran)
+}
+
+pub fn closed_set() -> Vec<Builtin> {
+    vec![Builtin::Censor(String::from("darn")), Builtin::Shout, Builtin::Reverse]
+}
+
+pub fn open_set() -> Vec<Box<dyn Plugin>> {
+    vec![Box::new(Censor(String::from("darn"))), Box::new(Shout), Box::new(Reverse)]
+}
+
+// A plugin this module doesn't define, the way another crate would add
+// one. There's no way to do the same for Builtin from outside this file.
+struct Exclaim;
+
+impl Plugin for Exclaim {
+    fn name(&self) -> String {
+        String::from("exclaim")
+    }
+
+    fn accepts(&self, text: &str) -> bool {
+        !text.ends_with('!')
+    }
+
+    fn apply(&self, text: &str) -> String {
+        format!("{}!", text)
+    }
+}
+
+pub fn demo_plugins() {
+    let text = "darn it";
+
+    let (closed, ran) = run_closed(&closed_set(), text);
+    println!("closed enum:   {:?} -> {:?} via {}", text, closed, ran.join(", "));
+    let (open, ran) = run_open(&open_set(), text);
+    println!("trait objects: {:?} -> {:?} via {}", text, open, ran.join(", "));
+
+    // only the open list can take a plugin nobody planned for
+    let mut extended = open_set();
+    extended.push(Box::new(Exclaim));
+    let (open, ran) = run_open(&extended, text);
+    println!("plus Exclaim:  {:?} -> {:?} via {}", text, open, ran.join(", "));
+
+    // and what each one costs to hold: the enum inline, the Box as a "fat"
+    // pointer (one word for the data, one for the vtable), on the heap
+    println!(
+        "size of a Builtin: {} bytes, of a Box<dyn Plugin>: {} bytes",
+        mem::size_of::<Builtin>(),
+        mem::size_of::<Box<dyn Plugin>>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_sets_agree() {
+        for text in ["darn it", "hello", "", "darn darn", "ünïcödé"].iter() {
+            assert_eq!(run_closed(&closed_set(), text), run_open(&open_set(), text), "for {:?}", text);
+        }
+        assert_eq!(run_closed(&closed_set(), "darn it").0, "TI ****");
+    }
+
+    #[test]
+    fn the_censor_only_runs_when_needed() {
+        let (text, ran) = run_open(&open_set(), "hello");
+        assert_eq!(text, "OLLEH");
+        assert_eq!(ran, ["shout", "reverse"]);
+        assert!(!Builtin::Censor(String::new()).accepts("anything"));
+        assert!(!Censor(String::new()).accepts("anything"));
+    }
+
+    #[test]
+    fn the_open_set_takes_new_plugins() {
+        let mut plugins = open_set();
+        plugins.push(Box::new(Exclaim));
+        assert_eq!(run_open(&plugins, "darn it"), (String::from("TI ****!"), vec![
+            String::from("censor darn"),
+            String::from("shout"),
+            String::from("reverse"),
+            String::from("exclaim"),
+        ]));
+        // an Exclaim never exclaims twice
+        assert!(!Exclaim.accepts("hi!"));
+        assert_eq!(run_open(&[Box::new(Exclaim) as Box<dyn Plugin>], "hi!").0, "hi!");
+    }
+
+    #[test]
+    fn builtins_can_join_an_open_list() {
+        let plugins: Vec<Box<dyn Plugin>> = closed_set().into_iter().map(|b| Box::new(b) as Box<dyn Plugin>).collect();
+        assert_eq!(run_open(&plugins, "darn it"), run_closed(&closed_set(), "darn it"));
+    }
+
+    #[test]
+    fn a_box_is_two_words() {
+        assert_eq!(mem::size_of::<Box<dyn Plugin>>(), 2 * mem::size_of::<usize>());
+    }
+}
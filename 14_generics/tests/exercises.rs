@@ -0,0 +1,28 @@
+/**
+ * The checks for src/exercises.rs. Spoilers below!
+ *
+ * These only build with `--features exercises` (see Cargo.toml), so the
+ * ordinary `cargo test` never trips over the unfinished exercises. Each test
+ * is named after the exercise it checks, which is how `runner verify` tells
+ * which ones pass.
+ */
+use generics::exercises;
+
+#[test]
+fn smallest() {
+    assert_eq!(exercises::smallest(&[34, 50, 25, 100, 65]), 25);
+    assert_eq!(exercises::smallest(&['y', 'm', 'z', 'a', 'q']), 'a');
+    assert_eq!(exercises::smallest(&[2.5, -1.0]), -1.0);
+}
+
+#[test]
+fn count_matching() {
+    assert_eq!(exercises::count_matching(&[1, 2, 1, 1], &1), 3);
+    assert_eq!(exercises::count_matching(&["a", "b"], &"c"), 0);
+}
+
+#[test]
+fn first_or() {
+    assert_eq!(exercises::first_or(&[String::from("hi")], String::from("none")), "hi");
+    assert_eq!(exercises::first_or(&[], 'x'), 'x');
+}
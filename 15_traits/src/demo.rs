@@ -0,0 +1,88 @@
+/**
+ * The demo itself: everything foo.rs's main() used to do, moved into the
+ * library so the top-level runner crate can call it as well.
+ *
+ * This is a *submodule* of lib.rs, so, just like foo.rs, it sees nothing
+ * from its parent until it asks. Here the names start with `crate::` (the
+ * root of *this* crate) instead of `traits_lib::` (what the crate is called
+ * from the outside), but the rule about traits is the same.
+ */
+// if we don't *explicitly* bring in the Summary trait, then nothing *related*
+// to the Summary trait will be visible inside this scope. That means we need
+// to use the trait if we want to use the *methods* from that trait. 
+use crate::Summary;  // now .summarize() is available
+
+pub fn demo() {
+    crate::greet();
+
+    // create a tweet
+    let tweet = crate::Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("a horse is a horse of course of course"),
+        reply: false,
+        retweet: false,
+    };
+
+    let article = crate::NewsArticle {
+        headline: String::from("Man Bites Dog"),
+        location: String::from("Albequerque"),
+        author: String::from("Fudd, E."),
+        content: String::from("Arf Arf"),
+    };
+
+    // nb: we had to explicitly `use crate::Summary` to enable the summarize()
+    // method on both structs. This is a good demonstration of the fact that the
+    // struct is *independent* of the trait: you can use one without the other!
+    println!("1 new tweet: {}", tweet.summarize());
+    println!("article: {}", article.summarize());
+
+    // NewsArticle has the default .more() implementation
+    println!("Article.more(): {}", article.more());
+    // but Tweet has a custom one
+    println!("Tweet.more(): {}", tweet.more());
+
+    // demo of both forms of notify
+    println!("Demo of 2 variants for using the Summary trait as a first-class type:");
+    crate::notify_a(&article);
+    crate::notify_b(&article);
+
+    // demo of the final and most-elegant version of `largest` from 14_generics:
+    let number_list = vec![34, 50, 25, 222, 65];
+    let result = crate::largest(&number_list);
+    println!("(generics + traits!) The largest number is {}", result);
+    println!("State of number list after the call: {:?}", number_list);
+
+    // nb: interestingly enough, we're still not far from panicky code!
+    // just send in an empty vector and our function panics
+    // let num_list_2: Vec<i32> = Vec::new(); // perfectly valid empty vector
+    // but our largest function makes a brittle reference to T[0]
+    // which triggers a panic on an empty vector!
+    // let result2 = crate::largest(&num_list_2);
+    // println!("Largest on empty list returns: {}", result2);
+    // So really, that method ought to either return a Result<T, E>,
+    // or it should *require* a second argument: the value to use when the
+    // function is called with an empty vector
+
+    // demo of the Pair struct
+    let nums = crate::Pair::new(5, 11);
+    // in this case T is a number, so it qualifies for this conditional method
+    nums.cmp_display();
+    // it also works with chars, which are of type PartialOrd
+    let chars = crate::Pair::new('a', 'z');
+    chars.cmp_display();
+    // but not two tweets, which are are neither Display nor PartialOrd
+    let tweet2 = crate::Tweet {
+        username: String::from("spam"),
+        content: String::from("eggs"),
+        reply: false,
+        retweet: false,
+    };
+    let tweets = crate::Pair::new(tweet, tweet2); // the _pair_ is valid
+    // but this Pair does *not* implement cmp_display!
+    // tweets.cmp_display(); // compiler error! The error message is:
+    // `no method named `cmp_display` found for type crate::Pair<crate::Tweet>`
+
+    // Tweets are Debug, though (by hand, in lib.rs), so a Pair of them is too
+    println!("{:?}", nums);
+    println!("{:#?}", tweets);
+}
@@ -7,12 +7,50 @@
 use std::fmt::Display;
 use std::fmt::Debug;
 
+// the demo that foo.rs runs (and the top-level runner, too)
+mod demo;
+pub use demo::demo;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("traits", "Summary, default methods, trait bounds and blanket impls", demo),
+];
+
 // sanity test to be used by other files
 pub fn greet() {
   println!("Hello From My Library!");
 }
 
-// A trait definition
+// A trait definition.
+//
+// The `///` comments from here on are *documentation* comments: `cargo doc`
+// turns them into web pages, and the code in their `# Examples` sections is
+// compiled and run by `cargo test`, as "doctests". (They're the one kind of
+// comment that can't quietly go stale.) A doctest is written from the
+// outside, like any other user of the library: it has to
+// `use traits_lib::...`, and it can only see what's `pub`.
+
+/// Something that can be boiled down to one line.
+///
+/// Implementors have to write `summarize`; `more` comes for free, but can
+/// be overridden too.
+///
+/// # Examples
+///
+/// ```
+/// use traits_lib::{Summary, Tweet};
+///
+/// let tweet = Tweet {
+///     username: String::from("horse_ebooks"),
+///     content: String::from("of course, as you probably already know, people"),
+///     reply: false,
+///     retweet: false,
+/// };
+/// assert_eq!(tweet.summarize(), "horse_ebooks: of course, as you probably already know, people");
+/// assert_eq!(tweet.more(), "(Tweet more...chirp chirp!)");
+/// ```
 pub trait Summary {
   // Functions inside a trait definition are signature-only: no body needed!
   // This is 100% standard for interfaces, which traits are +/- synonymous with
@@ -65,6 +103,47 @@ impl Summary for Tweet {
     }
 }
 
+// Debug is a trait like any other, and `#[derive(Debug)]` just writes the
+// impl for you. Writing it by hand lets you choose what it shows: here the
+// two bools become one readable `kind`. The `debug_struct` helper still
+// does the layout, for `{:?}` and the pretty `{:#?}` alike.
+
+/// Debug output with `reply` and `retweet` folded into one `kind`.
+///
+/// # Examples
+///
+/// ```
+/// use traits_lib::Tweet;
+///
+/// let tweet = Tweet {
+///     username: String::from("ferris"),
+///     content: String::from("hi"),
+///     reply: true,
+///     retweet: false,
+/// };
+/// assert_eq!(format!("{:?}", tweet), r#"Tweet { username: "ferris", content: "hi", kind: reply }"#);
+/// assert_eq!(
+///     format!("{:#?}", tweet),
+///     "Tweet {\n    username: \"ferris\",\n    content: \"hi\",\n    kind: reply,\n}"
+/// );
+/// ```
+impl Debug for Tweet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let kind = match (self.reply, self.retweet) {
+            (false, false) => "original",
+            (true, false) => "reply",
+            (false, true) => "retweet",
+            (true, true) => "retweeted reply",
+        };
+        f.debug_struct("Tweet")
+            .field("username", &self.username)
+            .field("content", &self.content)
+            // format_args! prints as-is, where a &str would get quotes
+            .field("kind", &format_args!("{}", kind))
+            .finish()
+    }
+}
+
 // traits are a first-class type, so they can appear in function signatures
 // Here's one form for using a trait inside a function signature: 
 pub fn notify_a(item: &impl Summary) {
@@ -98,7 +177,19 @@ pub fn fugly_fn<T, U>(t: T, u: U) -> i32
 // But the syntax is slightly different from a normal return
 // The signature in the next function specifies `-> impl Summary`, whereas you
 // might think you could simply specify `-> Summary`
-pub fn returns_summarizable() -> impl Summary { 
+
+/// Some `Summary`, of a type the caller never gets to name.
+///
+/// # Examples
+///
+/// ```
+/// use traits_lib::{returns_summarizable, Summary};
+///
+/// // all we know is that it implements Summary, so that's all we can use
+/// let item = returns_summarizable();
+/// assert!(item.summarize().starts_with("spammy_mc_spammer: "));
+/// ```
+pub fn returns_summarizable() -> impl Summary {
     Tweet {
         username: String::from("spammy_mc_spammer"),
         content: String::from("tweet tweet tweet tweet tweeeeeeeet"),
@@ -120,6 +211,25 @@ pub fn returns_summarizable() -> impl Summary {
 // we ought to be able to make largest() return a true `T` reference, so the 
 // caller owns it free and clear. And we can do exactly that, by specifying 
 // the Copy trait along with the `PartialOrd` trait. 
+
+/// The largest item in `list`, as a copy of its own.
+///
+/// # Panics
+///
+/// If `list` is empty, since there's no `list[0]` to start from.
+///
+/// # Examples
+///
+/// ```
+/// use traits_lib::largest;
+///
+/// assert_eq!(largest(&[34, 50, 25, 100, 65]), 100);
+/// assert_eq!(largest(&['y', 'm', 'a', 'q']), 'y');
+/// ```
+///
+/// ```should_panic
+/// traits_lib::largest::<i32>(&[]);
+/// ```
 pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
     let mut largest = list[0];
 
@@ -137,6 +247,8 @@ pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
 // Finally, you can do _conditional_ implementations for a type.
 // For example, consider this Pair struct, which works for any two instances
 // of the type T
+
+/// Two values of the same type.
 pub struct Pair<T> {
     x: T,
     y: T,
@@ -146,6 +258,8 @@ pub struct Pair<T> {
 // do any special checking on the type T
 impl<T> Pair<T> {
     // so ALL Pair<T> instances can be created with Pair<T>::new()
+
+    /// A pair of anything at all, even things that can't be compared.
     pub fn new(x: T, y: T) -> Self {
         Self {
             x,
@@ -161,12 +275,48 @@ impl<T> Pair<T> {
 // can call instance.cmp_display()
 impl<T: Display + PartialOrd> Pair<T> {
     // so an instance created with Pair::new(5, 11) could call this method
-    // but an instance created with Pair::new("foo", "bar") could not 
-    pub fn cmp_display(&self) {
+    // but an instance created with Pair::new(tweet1, tweet2) could not
+
+    /// Which member is larger, and what it is (`x` wins a tie).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use traits_lib::Pair;
+    ///
+    /// assert_eq!(Pair::new(5, 11).describe_largest(), "The largest member is y = 11");
+    /// assert_eq!(Pair::new('z', 'a').describe_largest(), "The largest member is x = z");
+    /// ```
+    pub fn describe_largest(&self) -> String {
         if self.x >= self.y {
-            println!("The largest member is x = {}", self.x);
+            format!("The largest member is x = {}", self.x)
         } else {
-            println!("The largest member is y = {}", self.y);
+            format!("The largest member is y = {}", self.y)
         }
     }
+
+    /// Print what `describe_largest` says.
+    pub fn cmp_display(&self) {
+        println!("{}", self.describe_largest());
+    }
+}
+
+// Debug can be conditional too: a Pair is Debug whenever its members are,
+// which now includes a Pair of Tweets. It prints as a plain list of the
+// two, courtesy of the `debug_list` helper.
+
+/// Debug output as a two-item list, `[x, y]`.
+///
+/// # Examples
+///
+/// ```
+/// use traits_lib::Pair;
+///
+/// assert_eq!(format!("{:?}", Pair::new(5, 11)), "[5, 11]");
+/// assert_eq!(format!("{:#?}", Pair::new('a', 'z')), "[\n    'a',\n    'z',\n]");
+/// ```
+impl<T: Debug> Debug for Pair<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list().entry(&self.x).entry(&self.y).finish()
+    }
 }
@@ -0,0 +1,173 @@
+/*!
+ * The lifetime elision rules from the appendix at the top of ./lib.rs, as
+ * code: each function below comes twice, once the way you'd write it, with
+ * the lifetimes left out ("elided"), and once with every lifetime the
+ * compiler fills in written out by hand. Both forms compile to the same
+ * thing, and the tests call both to prove it.
+ *
+ * The compiler applies three rules, in order, and gives up (asking you
+ * for annotations) if the output lifetimes still aren't settled:
+ *
+ * ```text
+ * 1. every reference argument gets a lifetime of its own:
+ *        fn f(x: &str, y: &str)       is  fn f<'a, 'b>(x: &'a str, y: &'b str)
+ * 2. if there's exactly one input lifetime, the outputs get it:
+ *        fn f(x: &str) -> &str        is  fn f<'a>(x: &'a str) -> &'a str
+ * 3. if one of the arguments is &self or &mut self, the outputs get its:
+ *        fn f(&self, y: &str) -> &str is  fn f<'s, 'b>(&'s self, y: &'b str) -> &'s str
+ * ```
+ *
+ * Two inputs, no self, and a reference coming out: none of the rules
+ * decide that one, so it's up to you (`longer` and `first_of`, below).
+ */
+
+// Rule 1 alone: two inputs, two lifetimes, and nothing to hand back, so
+// nothing more is needed
+pub fn same_length(x: &str, y: &str) -> bool {
+    x.len() == y.len()
+}
+
+// (clippy rightly calls these lifetimes needless, which is the whole point)
+#[allow(clippy::needless_lifetimes)]
+pub fn same_length_annotated<'a, 'b>(x: &'a str, y: &'b str) -> bool {
+    x.len() == y.len()
+}
+
+// Rule 2: one input lifetime, so the output borrows from it
+pub fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+#[allow(clippy::needless_lifetimes)]
+pub fn first_word_annotated<'a>(s: &'a str) -> &'a str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// Rule 3 lives on methods, so it needs a struct holding a reference
+#[derive(Debug)]
+pub struct Excerpt<'a> {
+    pub part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    // two inputs, but one is &self, so the output borrows from self and
+    // `announcement` can go away as soon as the call is over
+    pub fn announce(&self, announcement: &str) -> &str {
+        println!("Attention please: {}", announcement);
+        self.part
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    pub fn announce_annotated<'s, 'b>(&'s self, announcement: &'b str) -> &'s str {
+        println!("Attention please: {}", announcement);
+        self.part
+    }
+
+    // Rule 3 picks self's lifetime, which is the *shorter* one: the text
+    // outlives any Excerpt of it. When the caller needs the longer one,
+    // that's when you write 'a out yourself.
+    pub fn part_of_text(&self) -> &'a str {
+        self.part
+    }
+}
+
+// No rule applies: two input lifetimes, no self. This doesn't compile
+// without the 'a (the error is "missing lifetime specifier"):
+//
+//     pub fn longer(x: &str, y: &str) -> &str
+//
+// The only way to write it is with the annotations, which say that the
+// answer lives as long as the shorter-lived of x and y.
+pub fn longer<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() >= y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// Nor here, but only x's lifetime matters, so y can keep its own (elided)
+// one: a caller can pass a y that dies long before the answer does
+pub fn first_of<'a>(x: &'a str, y: &str) -> &'a str {
+    if y.is_empty() {
+        x
+    } else {
+        x.split(y).next().unwrap_or(x)
+    }
+}
+
+pub fn demo_elision() {
+    println!("rule 1: same_length(\"abc\", \"xyz\") = {}", same_length("abc", "xyz"));
+    println!("rule 2: first_word(\"hello lifetimes\") = {:?}", first_word("hello lifetimes"));
+
+    let text = String::from("Call me Ishmael. Some years ago...");
+    let part;
+    {
+        let excerpt = Excerpt { part: text.split('.').next().unwrap_or("") };
+        println!("rule 3: announce(...) = {:?}", excerpt.announce("an excerpt"));
+        // part_of_text() is tied to `text`, not to `excerpt`, so it can
+        // leave this block (excerpt.announce(..)'s answer couldn't)
+        part = excerpt.part_of_text();
+    }
+    println!("        and part_of_text() outlived its Excerpt: {:?}", part);
+
+    println!("no rule: longer(\"short\", \"longest\") = {:?}", longer("short", "longest"));
+    let result;
+    {
+        let separator = String::from(".");
+        result = first_of(&text, &separator);
+    }
+    println!("no rule: first_of(text, \".\") = {:?}, with \".\" long gone", result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_one() {
+        assert!(same_length("abc", "xyz"));
+        assert!(!same_length_annotated("abc", "wxyz"));
+        assert_eq!(same_length("", ""), same_length_annotated("", ""));
+    }
+
+    #[test]
+    fn rule_two() {
+        for s in ["hello lifetimes", "  padded", "", "one"].iter() {
+            assert_eq!(first_word(s), first_word_annotated(s));
+        }
+        assert_eq!(first_word("hello lifetimes"), "hello");
+        assert_eq!(first_word("   "), "");
+    }
+
+    #[test]
+    fn rule_three() {
+        let excerpt = Excerpt { part: "Call me Ishmael" };
+        // the announcement is a String that's dropped right after the call
+        assert_eq!(excerpt.announce(&String::from("now")), "Call me Ishmael");
+        assert_eq!(excerpt.announce_annotated(&String::from("now")), "Call me Ishmael");
+    }
+
+    #[test]
+    fn the_longer_lifetime_by_hand() {
+        let text = String::from("Some years ago");
+        let part = {
+            let excerpt = Excerpt { part: &text[..4] };
+            excerpt.part_of_text()
+        };
+        assert_eq!(part, "Some");
+    }
+
+    #[test]
+    fn no_rule_applies() {
+        assert_eq!(longer("short", "longest"), "longest");
+        assert_eq!(longer("tie", "TIE"), "tie");
+        let text = String::from("a,b,c");
+        let first = {
+            let comma = String::from(",");
+            first_of(&text, &comma)
+        };
+        assert_eq!(first, "a");
+        assert_eq!(first_of("abc", ""), "abc");
+    }
+}
@@ -6,11 +6,49 @@ pub fn echo(msg: &str) {
     println!("{}", msg);
 }
 
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("testing", "The library the tests exercise", demo),
+];
+
+// what main.rs runs (and so does the top-level runner). The real action in
+// this chapter is in the tests, which only `cargo test` runs.
+pub fn demo() {
+    echo("Hello Library");
+}
+
+// The `///` comments below are documentation, and their examples are tests
+// too: "doctests", which `cargo test` runs along with the rest (or, on their
+// own, `cargo test --doc`). Each one is compiled as a little program of its
+// own, outside this crate, so it only sees what's `pub`, and it has to
+// `use testing_lib::...` like any other user would. An example that
+// asserts what it shows can never drift away from what the code does.
+
+/// A friendly greeting for `name`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(testing_lib::greeting("Carol"), "Hello Carol!");
+/// ```
 pub fn greeting(name: &str) -> String {
     format!("Hello {}!", name)
 }
 
 // this can be tested by external code because it is `pub`
+
+/// `a`, plus two.
+///
+/// # Examples
+///
+/// ```
+/// use testing_lib::add_two;
+///
+/// assert_eq!(add_two(2), 4);
+/// assert_eq!(add_two(-2), 0);
+/// ```
 pub fn add_two(a: i32) -> i32 {
     internal_adder(a, 2)
 }
@@ -20,6 +58,7 @@ fn internal_adder(a: i32, b: i32) -> i32 {
     a + b
 }
 
+/// A rectangle, for fitting inside other rectangles.
 #[derive(Debug)]
 pub struct Rectangle {
     length: u32,
@@ -27,16 +66,54 @@ pub struct Rectangle {
 }
 
 impl Rectangle {
+    // the fields are private, so a doctest (which is outside the crate)
+    // needs a constructor to make one at all
+    pub fn new(length: u32, width: u32) -> Rectangle {
+        Rectangle { length, width }
+    }
+
+    /// Whether `other` fits strictly inside this one, without turning it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use testing_lib::Rectangle;
+    ///
+    /// let larger = Rectangle::new(8, 7);
+    /// let smaller = Rectangle::new(5, 1);
+    /// assert!(larger.can_hold(&smaller));
+    /// assert!(!smaller.can_hold(&larger));
+    /// // a rectangle can't hold itself: it has to be strictly bigger
+    /// assert!(!larger.can_hold(&Rectangle::new(8, 7)));
+    /// ```
     pub fn can_hold(&self, other: &Rectangle) -> bool {
         self.length > other.length && self.width > other.width
     }
 }
 
+/// A guess in the guessing game, which is always from 1 to 100.
 pub struct Guess {
     pub value: i32,
 }
 
 impl Guess {
+    /// A guess of `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is less than 1 or more than 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(testing_lib::Guess::new(50).value, 50);
+    /// ```
+    ///
+    /// A doctest can expect a panic, just like a `#[should_panic]` test:
+    ///
+    /// ```should_panic
+    /// testing_lib::Guess::new(200);
+    /// ```
     pub fn new(value: i32) -> Guess {
         if value < 1 {
             panic!("Guess value must be greater than or equal to 1, got {}.",
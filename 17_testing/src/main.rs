@@ -43,8 +43,8 @@
  * project instead if you like.  
  * 
  */
-use::mylib;
+use::testing_lib;
 
 fn main() {
-    mylib::echo("Hello Library");
+    testing_lib::demo();
 }
@@ -19,12 +19,12 @@
  * That should run all the tests in this file, and *only* the tests in this file. 
  * 
  */
-use mylib;
+use testing_lib;
 
 #[test]
 fn integration_experiment() {
     // this should be included when you run *all* tests
     // or when you run `cargo test integration`
     // or when you run `cargo test ex`
-    assert_eq!(4, mylib::add_two(2));
+    assert_eq!(4, testing_lib::add_two(2));
 }
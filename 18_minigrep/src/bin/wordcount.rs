@@ -0,0 +1,61 @@
+/**
+ * `wordcount`: a small `wc`, living in minigrep's package because it's the
+ * same sort of program (read some text, report on it). Cargo builds every
+ * file in src/bin/ as a binary of its own, and each one can `use` the
+ * package's library, just like main.rs does.
+ *
+ *      cargo run --bin wordcount -- poem.txt
+ *      cargo run --bin wordcount -- -m -                     (just stdin)
+ *
+ * The counting and the flags are in src/count.rs. Like wc, a file that
+ * can't be read is reported on stderr, the rest still get counted, and the
+ * exit status is 1 at the end; 0 means every file was counted.
+ */
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use minigrep::count::{Config, Counts};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let mut config = Config::new(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {}", err);
+        process::exit(1);
+    });
+    if config.files.is_empty() {
+        config.files.push(String::from("-"));
+    }
+
+    let mut total = Counts::default();
+    let mut failed = false;
+    for file in &config.files {
+        let read = if file == "-" {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes).map(|_| bytes)
+        } else {
+            fs::read(file)
+        };
+        match read {
+            Ok(bytes) => {
+                let counts = Counts::of(&bytes);
+                // stdin's row has no name on it, as in wc
+                let name = Some(file.as_str()).filter(|&name| name != "-");
+                println!("{}", config.row(&counts, name));
+                total += counts;
+            }
+            Err(e) => {
+                eprintln!("wordcount: {}: {}", file, e);
+                failed = true;
+            }
+        }
+    }
+    if config.files.len() > 1 {
+        println!("{}", config.row(&total, Some("total")));
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,188 @@
+/*!
+ * The logic behind the `wordcount` binary (src/bin/wordcount.rs): a small
+ * `wc`, which counts the lines, words and bytes in files or on stdin.
+ *
+ * ```text
+ * cargo run --bin wordcount -- poem.txt
+ * cargo run --bin wordcount -- -l -w poem.txt tests/fixtures/tree/README.md
+ * echo "héllo wörld" | cargo run --bin wordcount -- -m
+ * ```
+ *
+ * Same split as minigrep itself: the binary reads the files and prints,
+ * and everything that can be got wrong lives here, where it can be tested.
+ *
+ * wc's flags pick the columns, always in the same order whatever order
+ * they're given in (and with none, it's lines, words and bytes):
+ *
+ * ```text
+ * -l    lines (really newlines: a last line with no \n isn't counted)
+ * -w    words (runs of anything that isn't whitespace)
+ * -m    glyphs, i.e. grapheme clusters (see 12_collections/src/strings.rs)
+ * -c    bytes
+ * ```
+ *
+ * `wc -m` counts chars, which is one step short: "é" written as an "e" plus
+ * a combining accent is two chars, but one glyph, and one is what a reader
+ * would say. So -m counts the glyphs, with the same unicode-segmentation
+ * crate 12_collections uses.
+ *
+ * The input doesn't have to be UTF-8. Bytes and lines don't care, and for
+ * words and glyphs anything invalid counts as U+FFFD, the replacement
+ * character that `String::from_utf8_lossy` swaps in.
+ */
+use std::fmt::Write;
+use std::ops::AddAssign;
+use unicode_segmentation::UnicodeSegmentation as Uni;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub bytes: usize,
+    pub glyphs: usize,
+}
+
+impl Counts {
+    pub fn of(bytes: &[u8]) -> Counts {
+        let text = String::from_utf8_lossy(bytes);
+        Counts {
+            lines: bytes.iter().filter(|&&b| b == b'\n').count(),
+            words: text.split_whitespace().count(),
+            bytes: bytes.len(),
+            glyphs: Uni::graphemes(text.as_ref(), true).count(),
+        }
+    }
+}
+
+// so that `total += counts` adds up every column at once
+impl AddAssign for Counts {
+    fn add_assign(&mut self, other: Counts) {
+        self.lines += other.lines;
+        self.words += other.words;
+        self.bytes += other.bytes;
+        self.glyphs += other.glyphs;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub lines: bool,
+    pub words: bool,
+    pub bytes: bool,
+    pub glyphs: bool,
+    // no files at all means stdin, and so does "-"
+    pub files: Vec<String>,
+}
+
+impl Config {
+    // As with wc, flags can be run together ("-lw" is "-l -w"), and a lone
+    // "-" is a file name (stdin's), not a flag.
+    pub fn new(args: &[String]) -> Result<Config, &'static str> {
+        let mut config = Config { lines: false, words: false, bytes: false, glyphs: false, files: Vec::new() };
+        for arg in args.iter().skip(1) {
+            match arg.strip_prefix('-') {
+                Some(flags) if !flags.is_empty() => {
+                    for flag in flags.chars() {
+                        match flag {
+                            'l' => config.lines = true,
+                            'w' => config.words = true,
+                            'c' => config.bytes = true,
+                            'm' => config.glyphs = true,
+                            _ => return Err("unknown option (usage: wordcount [-l] [-w] [-c] [-m] [file...])"),
+                        }
+                    }
+                }
+                _ => config.files.push(arg.clone()),
+            }
+        }
+        if !(config.lines || config.words || config.bytes || config.glyphs) {
+            config.lines = true;
+            config.words = true;
+            config.bytes = true;
+        }
+        Ok(config)
+    }
+
+    // One line of the report: the chosen columns, each 8 wide like BSD wc's,
+    // then the name (if there is one: stdin's has none)
+    pub fn row(&self, counts: &Counts, name: Option<&str>) -> String {
+        let columns = [
+            (self.lines, counts.lines),
+            (self.words, counts.words),
+            (self.glyphs, counts.glyphs),
+            (self.bytes, counts.bytes),
+        ];
+        let mut row = String::new();
+        for (_, count) in columns.iter().filter(|(wanted, _)| *wanted) {
+            write!(row, "{:>8}", count).unwrap();
+        }
+        if let Some(name) = name {
+            write!(row, " {}", name).unwrap();
+        }
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn counts_like_wc() {
+        let counts = Counts::of(b"one two\n  three\n\nfour");
+        assert_eq!(counts, Counts { lines: 3, words: 4, bytes: 21, glyphs: 21 });
+        assert_eq!(Counts::of(b""), Counts::default());
+    }
+
+    #[test]
+    fn glyphs_are_not_chars() {
+        // "e" + a combining accent: 3 bytes, 2 chars, 1 glyph
+        let counts = Counts::of("e\u{301}".as_bytes());
+        assert_eq!((counts.bytes, counts.glyphs), (3, 1));
+        // and "\r\n" is one glyph too
+        assert_eq!(Counts::of(b"a\r\nb").glyphs, 3);
+    }
+
+    #[test]
+    fn invalid_utf8_still_counts() {
+        let counts = Counts::of(b"caf\xe9 au lait\n");
+        assert_eq!(counts, Counts { lines: 1, words: 3, bytes: 13, glyphs: 13 });
+    }
+
+    #[test]
+    fn totals_add_up() {
+        let mut total = Counts::of(b"a b\n");
+        total += Counts::of(b"c\n");
+        assert_eq!(total, Counts { lines: 2, words: 3, bytes: 6, glyphs: 6 });
+    }
+
+    #[test]
+    fn flags_pick_the_columns() {
+        let config = Config::new(&args(&["wordcount"])).unwrap();
+        assert!(config.lines && config.words && config.bytes && !config.glyphs);
+        assert!(config.files.is_empty());
+
+        let config = Config::new(&args(&["wordcount", "-m", "a.txt", "-lw", "-"])).unwrap();
+        assert!(config.lines && config.words && !config.bytes && config.glyphs);
+        assert_eq!(config.files, ["a.txt", "-"]);
+
+        assert!(Config::new(&args(&["wordcount", "-x"])).is_err());
+        assert!(Config::new(&args(&["wordcount", "-lx"])).is_err());
+    }
+
+    #[test]
+    fn rows_keep_wc_order() {
+        let counts = Counts { lines: 1, words: 22, bytes: 333, glyphs: 4444 };
+        let config = Config::new(&args(&["wordcount", "-c", "-l"])).unwrap();
+        assert_eq!(config.row(&counts, Some("x.txt")), "       1     333 x.txt");
+        let config = Config::new(&args(&["wordcount", "-m"])).unwrap();
+        assert_eq!(config.row(&counts, None), "    4444");
+        // -m before -c, as in wc, whichever comes first on the command line
+        let config = Config::new(&args(&["wordcount", "-c", "-m", "-w"])).unwrap();
+        assert_eq!(config.row(&counts, None), "      22    4444     333");
+    }
+}
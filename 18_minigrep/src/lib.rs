@@ -0,0 +1,313 @@
+/**
+ * All the logic for minigrep lives here, where the tests can get at it.
+ */
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+// the query is a (very) small regular expression: see pattern.rs
+pub mod pattern;
+// -r: every file in a directory tree
+pub mod walk;
+// the wordcount binary's logic (see src/bin/wordcount.rs)
+pub mod count;
+
+use pattern::Pattern;
+use walk::Searched;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub query: String,
+    pub filename: String,
+    pub case_sensitive: bool,
+    // -n: put each line's number in front of it
+    pub line_numbers: bool,
+    // -c: print how many lines matched, instead of the lines
+    pub count: bool,
+    // -r: `filename` is a directory, and every file under it gets searched
+    pub recursive: bool,
+}
+
+impl Config {
+    // args[0] is always the program's own name, so the real arguments
+    // start at index 1. Bad input is the user's fault, not a bug, so we
+    // return an Err instead of panicking.
+    //
+    // The flags can go anywhere, as with grep: what's left once they're
+    // taken out is the query and the filename, in that order.
+    pub fn new(args: &[String]) -> Result<Config, &'static str> {
+        let mut line_numbers = false;
+        let mut count = false;
+        let mut recursive = false;
+        let mut positional = Vec::new();
+        for arg in args.iter().skip(1) {
+            match arg.as_str() {
+                "-n" => line_numbers = true,
+                "-c" => count = true,
+                "-r" => recursive = true,
+                flag if flag.len() > 1 && flag.starts_with('-') => {
+                    return Err("unknown option (minigrep knows -n, -c and -r)");
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+        if positional.len() < 2 {
+            return Err("not enough arguments (usage: minigrep [-n] [-c] [-r] <query> <filename>)");
+        }
+
+        let query = positional[0].clone();
+        let filename = positional[1].clone();
+
+        // We only care whether the variable is *set*, not what it's set to,
+        // so `is_err()` is the whole test: an Err means "not set".
+        let case_sensitive = env::var("CASE_INSENSITIVE").is_err();
+
+        Ok(Config { query, filename, case_sensitive, line_numbers, count, recursive })
+    }
+}
+
+// `Box<dyn Error>` means "some kind of error, I'm not saying which". That
+// lets the `?` below pass along an io::Error without us having to wrap it.
+//
+// On success, how many lines matched: main.rs turns that into grep's exit
+// status, so a script can ask "was it there?" without reading the output.
+pub fn run(config: Config) -> Result<usize, Box<dyn Error>> {
+    let pattern = if config.case_sensitive {
+        Pattern::new(&config.query)
+    } else {
+        Pattern::case_insensitive(&config.query)
+    };
+    if config.recursive {
+        return run_recursive(&config, &pattern);
+    }
+
+    let contents = fs::read_to_string(&config.filename)?;
+    let results = search_numbered(&pattern, &contents);
+
+    if config.count {
+        println!("{}", results.len());
+    } else {
+        for (number, line) in &results {
+            if config.line_numbers {
+                println!("{}:{}", number, line);
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+
+    Ok(results.len())
+}
+
+// The same for every file under a directory, with each match prefixed by
+// the file it's in (grep's `path:line`, or `path:number:line` with -n).
+// A file that can't be read is reported and skipped, not fatal.
+fn run_recursive(config: &Config, pattern: &Pattern) -> Result<usize, Box<dyn Error>> {
+    let files = walk::files_under(Path::new(&config.filename))?;
+    let results = walk::search_files(pattern, &files, walk::available_threads());
+
+    let mut total = 0;
+    for (path, searched) in files.iter().zip(results) {
+        let lines = match searched {
+            Searched::Text(lines) => lines,
+            Searched::Binary => continue,
+            Searched::Failed(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                continue;
+            }
+        };
+        if config.count {
+            println!("{}:{}", path.display(), lines.len());
+        } else {
+            for (number, line) in &lines {
+                if config.line_numbers {
+                    println!("{}:{}:{}", path.display(), number, line);
+                } else {
+                    println!("{}:{}", path.display(), line);
+                }
+            }
+        }
+        total += lines.len();
+    }
+
+    Ok(total)
+}
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("minigrep", "Searching a poem, with and without case", demo),
+];
+
+// What the top-level runner shows for this chapter. It has no command line
+// to hand us, so this runs the searches from the top of main.rs against the
+// poem, which include_str! bakes into the program at compile time.
+pub fn demo() {
+    let poem = include_str!("../poem.txt");
+    for query in ["frog", "nobody"].iter() {
+        println!("Searching for '{}':", query);
+        for line in search(query, poem) {
+            println!("    {}", line);
+        }
+    }
+    println!("Searching for 'to', ignoring case:");
+    for line in search_case_insensitive("to", poem) {
+        println!("    {}", line);
+    }
+    println!("Searching for the pattern '^T.*s', with line numbers:");
+    for (number, line) in search_numbered(&Pattern::new("^T.*s"), poem) {
+        println!("    {}:{}", number, line);
+    }
+}
+
+// Every line that matches, with its line number (counting from 1, as
+// editors and grep -n do)
+pub fn search_numbered<'a>(pattern: &Pattern, contents: &'a str) -> Vec<(usize, &'a str)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| pattern.is_match(line))
+        .map(|(index, line)| (index + 1, line))
+        .collect()
+}
+
+// The lifetime says: the lines we hand back are slices of `contents`, so
+// they live exactly as long as `contents` does. (They have nothing to do
+// with `query`, which is why query gets no 'a.)
+//
+// (The book's version asks `line.contains(query)`. The query is a pattern
+// now, so it asks the Pattern instead; a query with no special characters
+// in it matches exactly the lines `contains` would.)
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let pattern = Pattern::new(query);
+    let mut results = Vec::new();
+
+    for line in contents.lines() {
+        if pattern.is_match(line) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+// Same idea, but lowercase both sides before comparing (the Pattern does
+// that part). Note that the lines we return are still the *original*
+// lines, not the lowercased ones.
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let pattern = Pattern::case_insensitive(query);
+    let mut results = Vec::new();
+
+    for line in contents.lines() {
+        if pattern.is_match(line) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn config_needs_two_arguments() {
+        assert!(Config::new(&args(&["minigrep"])).is_err());
+        assert!(Config::new(&args(&["minigrep", "query"])).is_err());
+    }
+
+    #[test]
+    fn config_reads_query_and_filename() {
+        let config = Config::new(&args(&["minigrep", "frog", "poem.txt", "extra"])).unwrap();
+        assert_eq!(config.query, "frog");
+        assert_eq!(config.filename, "poem.txt");
+        assert!(!config.line_numbers && !config.count);
+    }
+
+    #[test]
+    fn config_reads_flags_anywhere() {
+        let config = Config::new(&args(&["minigrep", "-n", "frog", "-c", "poem.txt"])).unwrap();
+        assert_eq!((config.query.as_str(), config.filename.as_str()), ("frog", "poem.txt"));
+        assert!(config.line_numbers && config.count && !config.recursive);
+        assert!(Config::new(&args(&["minigrep", "-r", "frog", "tests"])).unwrap().recursive);
+        // flags aren't arguments
+        assert!(Config::new(&args(&["minigrep", "-n", "frog"])).is_err());
+        assert!(Config::new(&args(&["minigrep", "-x", "frog", "poem.txt"])).is_err());
+        // but a lone "-" is a query like any other
+        assert_eq!(Config::new(&args(&["minigrep", "-", "poem.txt"])).unwrap().query, "-");
+    }
+
+    #[test]
+    fn numbered_results() {
+        let contents = "Rust:\nsafe, fast, productive.\nPick three.\nTrust me.";
+        assert_eq!(search_numbered(&Pattern::new("st"), contents), vec![(1, "Rust:"), (2, "safe, fast, productive."), (4, "Trust me.")]);
+        assert_eq!(search_numbered(&Pattern::new("^P"), contents), vec![(3, "Pick three.")]);
+    }
+
+    #[test]
+    fn case_sensitive() {
+        let query = "duct";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let query = "rUsT";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+        assert_eq!(
+            vec!["Rust:", "Trust me."],
+            search_case_insensitive(query, contents)
+        );
+    }
+
+    #[test]
+    fn no_match_is_an_empty_vec() {
+        assert!(search("monkey", "Rust:\nPick three.").is_empty());
+        assert!(search_case_insensitive("monkey", "").is_empty());
+    }
+
+    #[test]
+    fn run_reports_a_missing_file() {
+        let config = Config {
+            query: String::from("frog"),
+            filename: String::from("no_such_file.txt"),
+            case_sensitive: true,
+            line_numbers: false,
+            count: false,
+            recursive: false,
+        };
+        assert!(run(config).is_err());
+    }
+
+    #[test]
+    fn run_reads_the_poem() {
+        // `cargo test` runs from the package root, next to poem.txt
+        let config = Config {
+            query: String::from("frog"),
+            filename: String::from("poem.txt"),
+            case_sensitive: true,
+            line_numbers: true,
+            count: false,
+            recursive: false,
+        };
+        assert_eq!(run(config).unwrap(), 1);
+    }
+}
@@ -0,0 +1,56 @@
+/**
+ * The book's first real project: a stripped-down clone of `grep`.
+ *
+ *      cargo run -- frog poem.txt
+ *      CASE_INSENSITIVE=1 cargo run -- to poem.txt
+ *      cargo run -- -n '^To.*!$' poem.txt      (a pattern: see pattern.rs)
+ *      cargo run -- -c nobody poem.txt         (just count the matches)
+ *      cargo run -- -r frog tests/fixtures     (every file under a directory)
+ *
+ * The big lesson here is about *where code goes*. The book's advice for
+ * binary projects is to keep main.rs as small as possible:
+ *
+ * - main.rs collects the command line arguments, calls into the library,
+ *   and decides what to do if something goes wrong (print and exit)
+ * - lib.rs holds all the actual logic, which means all of it is testable,
+ *   because tests can't call `main()` but they *can* call library functions
+ *
+ * The other lesson is about the two output streams. Results go to stdout,
+ * and errors go to stderr via `eprintln!`. So you can redirect the results
+ * to a file and still see the errors on screen:
+ *
+ *      cargo run > output.txt              (error shows up in the terminal)
+ *      cargo run -- frog poem.txt > output.txt   (the match goes to the file)
+ *
+ * And like grep, the exit status says how it went, for scripts that only
+ * want to know: 0 when some line matched, 1 when none did, and 2 when
+ * there was a problem (bad arguments, or a file that couldn't be read).
+ * tests/cli.rs checks all three.
+ */
+use std::env;
+use std::process;
+
+use minigrep::Config;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    // unwrap_or_else: on Ok, hand back the Config; on Err, run the closure.
+    // `process::exit` stops right away with a non-zero status, without the
+    // extra noise that a panic would print.
+    let config = Config::new(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {}", err);
+        process::exit(2);
+    });
+
+    // run() hands back how many lines matched, and no matches at all is
+    // grep's status 1: not an error, but not a success either
+    match minigrep::run(config) {
+        Ok(0) => process::exit(1),
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Application error: {}", e);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+/**
+ * A very small regular expression matcher, written by hand.
+ *
+ * The real grep speaks regular expressions, and so (a little) does minigrep
+ * now. Four characters are special:
+ *
+ * ```text
+ * .     any one character              b.g     matches "bog", "big", "bag"
+ * *     the thing before it, 0+ times  bo*g    matches "bg", "bog", "boog"
+ * ^     (first) the start of the line  ^To     matches "To an admiring bog!"
+ * $     (last) the end of the line     bog!$   ditto
+ * ```
+ *
+ * Everything else matches itself, and so do `^` and `$` anywhere but the
+ * ends and `*` with nothing in front of it, which is what grep does too.
+ * There's no escaping, so there's no way to look for a literal `.`: that
+ * would be the next feature.
+ *
+ * The matching is the classic backtracking approach (from Kernighan and
+ * Pike's "The Practice of Programming"): try the pattern at each position
+ * in the line, and when a `*` could eat any number of characters, try each
+ * number in turn until the rest of the pattern fits.
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Atom {
+    Any,
+    Char(char),
+}
+
+impl Atom {
+    fn matches(self, c: char) -> bool {
+        match self {
+            Atom::Any => true,
+            Atom::Char(want) => want == c,
+        }
+    }
+}
+
+// one atom, and whether it has a * after it
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Token {
+    atom: Atom,
+    repeats: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    tokens: Vec<Token>,
+    at_start: bool,
+    at_end: bool,
+    ignore_case: bool,
+}
+
+impl Pattern {
+    // Every string is a valid pattern (the special characters just stop
+    // being special where they make no sense), so this can't fail
+    pub fn new(pattern: &str) -> Pattern {
+        let mut chars: Vec<char> = pattern.chars().collect();
+        let at_start = chars.first() == Some(&'^');
+        if at_start {
+            chars.remove(0);
+        }
+        let at_end = chars.last() == Some(&'$');
+        if at_end {
+            chars.pop();
+        }
+
+        let mut tokens: Vec<Token> = Vec::new();
+        for c in chars {
+            match (c, tokens.last_mut()) {
+                // "a**" is the same as "a*"
+                ('*', Some(last)) => last.repeats = true,
+                ('.', _) => tokens.push(Token { atom: Atom::Any, repeats: false }),
+                (c, _) => tokens.push(Token { atom: Atom::Char(c), repeats: false }),
+            }
+        }
+        Pattern { tokens, at_start, at_end, ignore_case: false }
+    }
+
+    // The same, but letters match whatever their case (CASE_INSENSITIVE)
+    pub fn case_insensitive(pattern: &str) -> Pattern {
+        Pattern { ignore_case: true, ..Pattern::new(&pattern.to_lowercase()) }
+    }
+
+    pub fn is_match(&self, line: &str) -> bool {
+        let text: Vec<char> = if self.ignore_case {
+            line.to_lowercase().chars().collect()
+        } else {
+            line.chars().collect()
+        };
+        if self.at_start {
+            return self.match_here(&self.tokens, &text);
+        }
+        // `..=`: an empty pattern (or one that's all stars) can match at
+        // the very end, after the last character
+        (0..=text.len()).any(|start| self.match_here(&self.tokens, &text[start..]))
+    }
+
+    // does `tokens` match at the beginning of `text`?
+    fn match_here(&self, tokens: &[Token], text: &[char]) -> bool {
+        let (token, rest) = match tokens.split_first() {
+            Some(split) => split,
+            None => return !self.at_end || text.is_empty(),
+        };
+        if token.repeats {
+            return self.match_star(token.atom, rest, text);
+        }
+        match text.split_first() {
+            Some((&c, text)) if token.atom.matches(c) => self.match_here(rest, text),
+            _ => false,
+        }
+    }
+
+    // `atom*` then `rest`: let the star take 0 characters, then 1, then 2,
+    // for as long as they're characters it can take
+    fn match_star(&self, atom: Atom, rest: &[Token], text: &[char]) -> bool {
+        let mut taken = 0;
+        loop {
+            if self.match_here(rest, &text[taken..]) {
+                return true;
+            }
+            match text.get(taken) {
+                Some(&c) if atom.matches(c) => taken += 1,
+                _ => return false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_characters_where_they_make_no_sense_are_literal() {
+        assert_eq!(Pattern::new("*a").tokens[0], Token { atom: Atom::Char('*'), repeats: false });
+        assert!(Pattern::new("a^b").is_match("a^b"));
+        assert!(Pattern::new("a$b").is_match("xa$by"));
+        assert!(!Pattern::new("a$b").is_match("ab"));
+    }
+
+    #[test]
+    fn double_stars_are_single_stars() {
+        assert_eq!(Pattern::new("a**b"), Pattern::new("a*b"));
+    }
+
+    #[test]
+    fn case_insensitive_patterns() {
+        assert!(Pattern::case_insensitive("^TO").is_match("to be"));
+        assert!(Pattern::case_insensitive("t.e$").is_match("THE"));
+        assert!(!Pattern::new("^TO").is_match("to be"));
+    }
+}
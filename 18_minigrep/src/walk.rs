@@ -0,0 +1,159 @@
+/**
+ * `minigrep -r`: search every file under a directory, like `grep -r`.
+ *
+ * Two jobs, kept apart:
+ *
+ * - finding the files: `fs::read_dir` lists one directory, so walking a
+ *   whole tree means calling it again for every directory inside, which
+ *   is a job for recursion
+ * - searching them: each file is searched on its own, so the files can be
+ *   shared out between threads (scoped threads, which can borrow the list
+ *   of files and the Pattern instead of needing their own copies)
+ *
+ * Not everything in a tree is text. A file with a NUL byte in it, or that
+ * isn't valid UTF-8, is taken to be binary and skipped, rather than being
+ * an error: `fs::read_to_string` would refuse it, and one picture in a
+ * directory shouldn't stop the search.
+ */
+use crate::pattern::Pattern;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+// what searching one file found
+#[derive(Debug)]
+pub enum Searched {
+    // the matching lines, with their line numbers (maybe none)
+    Text(Vec<(usize, String)>),
+    Binary,
+    Failed(io::Error),
+}
+
+// Every file under `root`, in sorted order so the output is the same every
+// time (read_dir promises no order at all). A `root` that's a file is just
+// itself. Symlinks are skipped, so a link back up the tree can't send us
+// round in circles forever.
+pub fn files_under(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if fs::metadata(root)?.is_dir() {
+        visit(root, &mut files)?;
+    } else {
+        files.push(root.to_path_buf());
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn visit(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            visit(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+// The file's contents, or None if they look binary
+pub fn read_text(path: &Path) -> io::Result<Option<String>> {
+    let bytes = fs::read(path)?;
+    if bytes.contains(&0) {
+        return Ok(None);
+    }
+    Ok(String::from_utf8(bytes).ok())
+}
+
+pub fn search_file(pattern: &Pattern, path: &Path) -> Searched {
+    match read_text(path) {
+        Ok(Some(contents)) => Searched::Text(
+            crate::search_numbered(pattern, &contents)
+                .into_iter()
+                .map(|(number, line)| (number, line.to_string()))
+                .collect(),
+        ),
+        Ok(None) => Searched::Binary,
+        Err(e) => Searched::Failed(e),
+    }
+}
+
+// Search `files` on up to `threads` threads, each taking its own run of
+// the list. The answers come back in the same order as `files`.
+pub fn search_files(pattern: &Pattern, files: &[PathBuf], threads: usize) -> Vec<Searched> {
+    if files.is_empty() {
+        return Vec::new();
+    }
+    let size = files.len().div_ceil(threads.max(1));
+    thread::scope(|s| {
+        let handles: Vec<thread::ScopedJoinHandle<Vec<Searched>>> = files
+            .chunks(size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(|path| search_file(pattern, path)).collect()))
+            .collect();
+        handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+    })
+}
+
+// as many threads as the machine has cores (or 1, if it won't say)
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tree")
+    }
+
+    fn names(files: &[PathBuf]) -> Vec<String> {
+        files.iter().map(|f| f.strip_prefix(tree()).unwrap().display().to_string()).collect()
+    }
+
+    #[test]
+    fn finds_every_file_in_order() {
+        let files = files_under(&tree()).unwrap();
+        assert_eq!(
+            names(&files),
+            ["README.md", "notes/frog.png", "notes/todo.txt", "poems/nobody.txt", "poems/short/haiku.txt"]
+        );
+    }
+
+    #[test]
+    fn a_file_is_its_own_tree() {
+        let file = tree().join("notes/todo.txt");
+        assert_eq!(files_under(&file).unwrap(), [file]);
+        assert!(files_under(&tree().join("no/such/dir")).is_err());
+    }
+
+    #[test]
+    fn binary_files_are_skipped() {
+        assert!(read_text(&tree().join("notes/frog.png")).unwrap().is_none());
+        assert!(matches!(search_file(&Pattern::new("frog"), &tree().join("notes/frog.png")), Searched::Binary));
+        assert!(read_text(&tree().join("notes/todo.txt")).unwrap().is_some());
+    }
+
+    #[test]
+    fn any_number_of_threads_finds_the_same() {
+        let files = files_under(&tree()).unwrap();
+        let pattern = Pattern::new("frog");
+        let summary = |results: Vec<Searched>| -> Vec<usize> {
+            results
+                .iter()
+                .map(|r| match r {
+                    Searched::Text(lines) => lines.len(),
+                    _ => 0,
+                })
+                .collect()
+        };
+        let expected = summary(search_files(&pattern, &files, 1));
+        assert_eq!(expected, [1, 0, 1, 1, 1]);
+        for threads in [0, 2, 3, 16] {
+            assert_eq!(summary(search_files(&pattern, &files, threads)), expected, "with {} threads", threads);
+        }
+        assert!(search_files(&pattern, &[], 4).is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+/**
+ * minigrep's exit status, run for real: 0 for a match, 1 for none, and 2
+ * for a problem, with the problem explained on stderr and nothing at all on
+ * stdout (tests/golden.rs has the full text of the rest).
+ *
+ * Cargo builds the binary before running integration tests, and tells us
+ * where it put it in the CARGO_BIN_EXE_<name> variable.
+ */
+use std::process::{Command, Output};
+
+// run from the package root, next to poem.txt, and case sensitive whatever
+// the shell says
+fn minigrep(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env_remove("CASE_INSENSITIVE")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn a_match_exits_zero() {
+    let output = minigrep(&["frog", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "How public, like a frog\n");
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn no_match_exits_one_quietly() {
+    let output = minigrep(&["monkey", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn case_insensitive_finds_more() {
+    let output = Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(["to", "poem.txt"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("CASE_INSENSITIVE", "1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output).lines().count(), 4, "{}", stdout(&output));
+}
+
+#[test]
+fn missing_arguments_exit_two_with_usage() {
+    for args in [&[][..], &["frog"][..]] {
+        let output = minigrep(args);
+        assert_eq!(output.status.code(), Some(2));
+        assert_eq!(stdout(&output), "");
+        assert!(stderr(&output).contains("usage: minigrep [-n] [-c] [-r] <query> <filename>"), "{}", stderr(&output));
+    }
+}
+
+#[test]
+fn a_missing_file_exits_two() {
+    let output = minigrep(&["frog", "no_such_file.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).starts_with("Application error: "), "{}", stderr(&output));
+}
+
+#[test]
+fn patterns_work_from_the_command_line() {
+    let output = minigrep(&["^Are.*too", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "Are you nobody, too?\n");
+}
+
+#[test]
+fn dash_n_numbers_the_lines() {
+    let output = minigrep(&["-n", "^To", "poem.txt"]);
+    assert_eq!(stdout(&output), "8:To tell your name the livelong day\n9:To an admiring bog!\n");
+}
+
+#[test]
+fn dash_c_only_counts() {
+    let output = minigrep(&["-c", "nobody", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "2\n");
+
+    // a count of zero is still printed, and still exits 1, as with grep
+    let output = minigrep(&["-c", "monkey", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "0\n");
+}
+
+#[test]
+fn unknown_options_exit_two() {
+    let output = minigrep(&["-v", "frog", "poem.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("unknown option"), "{}", stderr(&output));
+}
+
+#[test]
+fn dash_r_searches_a_whole_tree() {
+    let output = minigrep(&["-r", "frog", "tests/fixtures/tree"]);
+    assert_eq!(output.status.code(), Some(0));
+    // every text file, in order, and not the picture with "frog" in its bytes
+    assert_eq!(
+        stdout(&output),
+        "tests/fixtures/tree/README.md:unit tests in src/walk.rs. Every text file mentions a frog at least once.\n\
+         tests/fixtures/tree/notes/todo.txt:feed the frog\n\
+         tests/fixtures/tree/poems/nobody.txt:How public, like a frog\n\
+         tests/fixtures/tree/poems/short/haiku.txt:A frog jumps into the pond\n"
+    );
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn dash_r_with_line_numbers_and_counts() {
+    let output = minigrep(&["-r", "-n", "^To", "tests/fixtures/tree/poems"]);
+    assert_eq!(stdout(&output), "tests/fixtures/tree/poems/nobody.txt:3:To tell your name the livelong day\n");
+
+    let output = minigrep(&["-rc", "pond", "tests/fixtures/tree/poems"]);
+    assert_eq!(output.status.code(), Some(2), "flags don't combine: -rc is unknown");
+    let output = minigrep(&["-r", "-c", "pond", "tests/fixtures/tree/poems"]);
+    assert_eq!(
+        stdout(&output),
+        "tests/fixtures/tree/poems/nobody.txt:0\ntests/fixtures/tree/poems/short/haiku.txt:2\n"
+    );
+}
+
+#[test]
+fn dash_r_on_a_missing_directory_exits_two() {
+    let output = minigrep(&["-r", "frog", "tests/fixtures/nowhere"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("Application error: "), "{}", stderr(&output));
+}
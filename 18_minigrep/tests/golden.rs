@@ -0,0 +1,40 @@
+/**
+ * What minigrep prints, for a match, a case-insensitive match, a pattern
+ * with line numbers and a mistake, checked against tests/golden/ (the how
+ * and why are in common/src/golden.rs).
+ */
+use common::golden;
+use std::process::Command;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+// minigrep, with CASE_INSENSITIVE cleared in case it's set in the shell
+fn minigrep() -> Command {
+    let mut command = golden::command(env!("CARGO_BIN_EXE_minigrep"), DIR);
+    command.env_remove("CASE_INSENSITIVE");
+    command
+}
+
+#[test]
+fn frog() {
+    let output = golden::output(minigrep().args(["frog", "poem.txt"]), "");
+    golden::check(DIR, "frog", &output);
+}
+
+#[test]
+fn case_insensitive() {
+    let output = golden::output(minigrep().args(["to", "poem.txt"]).env("CASE_INSENSITIVE", "1"), "");
+    golden::check(DIR, "case_insensitive", &output);
+}
+
+#[test]
+fn line_numbers() {
+    let output = golden::output(minigrep().args(["-n", "o.*y", "poem.txt"]), "");
+    golden::check(DIR, "line_numbers", &output);
+}
+
+#[test]
+fn missing_arguments() {
+    let output = golden::output(&mut minigrep(), "");
+    golden::check(DIR, "missing_arguments", &output);
+}
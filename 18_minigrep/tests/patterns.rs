@@ -0,0 +1,76 @@
+/**
+ * The pattern matcher against a table of fixtures: a pattern, a line, and
+ * whether the one should match the other. Adding a case is adding a row.
+ */
+use minigrep::pattern::Pattern;
+
+const FIXTURES: &[(&str, &str, bool)] = &[
+    // plain text: the same as `contains`
+    ("frog", "How public, like a frog", true),
+    ("frog", "How public, like a Frog", false),
+    ("", "anything at all", true),
+    ("", "", true),
+    ("bog!", "To an admiring bog!", true),
+    // . is any one character, but it has to be *some* character
+    ("b.g", "bog", true),
+    ("b.g", "big bag", true),
+    ("b.g", "bg", false),
+    ("...", "ab", false),
+    ("...", "abc", true),
+    ("ü.c", "xüxc", true),
+    // * is the thing before it, any number of times (including none)
+    ("bo*g", "bg", true),
+    ("bo*g", "boooog", true),
+    ("bo*g", "bag", false),
+    ("a.*z", "a to z", true),
+    ("a.*z", "z to a", false),
+    (".*", "", true),
+    ("x*", "no x here", true),
+    // a * has to give back what the rest of the pattern needs
+    ("a*ab", "aaab", true),
+    ("a.*b.*c", "a-b-b-c", true),
+    ("a.*b.*c", "a-b-b-", false),
+    // ^ pins the match to the start of the line
+    ("^To", "To an admiring bog!", true),
+    ("^To", "How dreary to be somebody!", false),
+    ("^.o", "How", true),
+    ("^", "", true),
+    // $ pins it to the end
+    ("bog!$", "To an admiring bog!", true),
+    ("bog$", "To an admiring bog!", false),
+    ("o*$", "foo", true),
+    ("$", "", true),
+    // both: the whole line
+    ("^$", "", true),
+    ("^$", " ", false),
+    ("^a.*z$", "abcz", true),
+    ("^a.*z$", "abcz ", false),
+    ("^How.*!$", "How dreary to be somebody!", true),
+    // and where they make no sense, they're just characters
+    ("*", "a * b", true),
+    ("*", "no star", false),
+    ("a^b", "a^b", true),
+    ("a$b", "a$b", true),
+    ("a$b", "ab", false),
+];
+
+#[test]
+fn every_fixture() {
+    let failures: Vec<String> = FIXTURES
+        .iter()
+        .filter(|(pattern, line, expected)| Pattern::new(pattern).is_match(line) != *expected)
+        .map(|(pattern, line, expected)| format!("{:?} on {:?} should be {}", pattern, line, expected))
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+// with no special characters in it, a pattern is a plain substring search
+#[test]
+fn plain_patterns_agree_with_contains() {
+    let poem = include_str!("../poem.txt");
+    for query in ["nobody", "To", "you", "!", " ", "zebra"].iter() {
+        for line in poem.lines() {
+            assert_eq!(Pattern::new(query).is_match(line), line.contains(query), "{:?} on {:?}", query, line);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/**
+ * The wordcount binary, run for real: files, stdin, the flags, and what
+ * happens when a file isn't there. The expected numbers are what `wc` says
+ * about the same files.
+ */
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+// run from the package root, with `stdin` piped in
+fn wordcount(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordcount"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn one_file() {
+    let output = wordcount(&["poem.txt"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "       9      44     221 poem.txt\n");
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn several_files_and_a_total() {
+    let output = wordcount(&["-l", "-w", "tests/fixtures/tree/notes/todo.txt", "tests/fixtures/tree/poems/short/haiku.txt"], "");
+    assert_eq!(
+        stdout(&output),
+        "       2       6 tests/fixtures/tree/notes/todo.txt\n\
+         \x20      3      13 tests/fixtures/tree/poems/short/haiku.txt\n\
+         \x20      5      19 total\n"
+    );
+}
+
+#[test]
+fn stdin_has_no_name() {
+    let output = wordcount(&[], "one two\nthree\n");
+    assert_eq!(stdout(&output), "       2       3      14\n");
+
+    // "-" is stdin too, among the files
+    let output = wordcount(&["-c", "-", "poem.txt"], "four\n");
+    assert_eq!(stdout(&output), "       5\n     221 poem.txt\n     226 total\n");
+}
+
+#[test]
+fn dash_m_counts_glyphs() {
+    // "e" + a combining accent, "t", a one-char "é" and the newline: wc -m
+    // says 5 chars, but there are 4 glyphs in 7 bytes
+    let output = wordcount(&["-cm"], "e\u{301}t\u{e9}\n");
+    assert_eq!(stdout(&output), "       4       7\n");
+}
+
+#[test]
+fn a_missing_file_is_reported_and_the_rest_still_counted() {
+    let output = wordcount(&["-l", "no_such_file.txt", "poem.txt"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "       9 poem.txt\n       9 total\n");
+    assert!(stderr(&output).starts_with("wordcount: no_such_file.txt: "), "{}", stderr(&output));
+}
+
+#[test]
+fn unknown_flags_exit_one_with_usage() {
+    let output = wordcount(&["-q", "poem.txt"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).contains("usage: wordcount [-l] [-w] [-c] [-m] [file...]"), "{}", stderr(&output));
+}
@@ -0,0 +1,134 @@
+/**
+ * The book's Cacher, with its flaw fixed.
+ *
+ * The idea is memoization: wrap an expensive calculation in a struct, run
+ * it the first time somebody asks, and hand back the saved answer on every
+ * later call. The struct is generic over the closure type `F`, since every
+ * closure has its own unique, unnameable type.
+ *
+ * The book's version stores a single `Option<u32>`, so it remembers only
+ * the *first* answer, and then returns it no matter what you ask next:
+ *
+ *      let mut c = Cacher::new(|a| a);
+ *      c.value(1);   // 1
+ *      c.value(2);   // also 1 (!)
+ *
+ * Swapping the Option for a HashMap from argument to result fixes that,
+ * and making the key and value generic lets it cache any calculation.
+ */
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::thread;
+use std::time::Duration;
+
+pub struct Cacher<K, V, F>
+where
+    K: Eq + Hash,
+    F: Fn(K) -> V,
+{
+    calculation: F,
+    values: HashMap<K, V>,
+}
+
+impl<K, V, F> Cacher<K, V, F>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Fn(K) -> V,
+{
+    pub fn new(calculation: F) -> Cacher<K, V, F> {
+        Cacher { calculation, values: HashMap::new() }
+    }
+
+    // Look the argument up first, and only run the closure on a miss. The
+    // entry API makes that a single lookup. (Note the parentheses around
+    // `self.calculation`: without them Rust looks for a *method* named
+    // calculation, rather than calling the closure stored in the field.)
+    pub fn value(&mut self, arg: K) -> V {
+        let calculation = &self.calculation;
+        self.values
+            .entry(arg.clone())
+            .or_insert_with(|| (calculation)(arg))
+            .clone()
+    }
+
+    // how many distinct arguments we have answers for
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+pub fn demo_cacher() {
+    println!("--- Cacher Demonstration Begins --- ");
+    let mut slow_square = Cacher::new(|n: u64| {
+        println!("  (calculating {} squared slowly...)", n);
+        thread::sleep(Duration::from_millis(200));
+        n * n
+    });
+    println!("Empty before first use? {}", slow_square.is_empty());
+    for n in [3, 3, 4, 3, 4].iter() {
+        println!("{} squared is {}", n, slow_square.value(*n));
+    }
+    println!("Only {} actual calculations were needed", slow_square.len());
+
+    // any hashable key and clonable value will do
+    let mut shout = Cacher::new(|s: String| s.to_uppercase());
+    println!("{} / {}", shout.value(String::from("hi")), shout.value(String::from("bye")));
+    println!("--- Cacher Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn repeated_key_calls_closure_once() {
+        // a Cell lets an Fn closure keep count without needing `mut`
+        let calls = Cell::new(0);
+        let mut c = Cacher::new(|a: u32| {
+            calls.set(calls.get() + 1);
+            a * 10
+        });
+        assert_eq!(c.value(2), 20);
+        assert_eq!(c.value(2), 20);
+        assert_eq!(c.value(2), 20);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn distinct_keys_get_distinct_values() {
+        // this is the exact case the book's version gets wrong
+        let mut c = Cacher::new(|a: u32| a);
+        assert_eq!(c.value(1), 1);
+        assert_eq!(c.value(2), 2);
+        assert_eq!(c.len(), 2);
+    }
+
+    #[test]
+    fn mixed_keys_only_calculate_new_ones() {
+        let calls = Cell::new(0);
+        let mut c = Cacher::new(|a: i64| {
+            calls.set(calls.get() + 1);
+            -a
+        });
+        for &n in [1, 2, 1, 3, 2, 1].iter() {
+            assert_eq!(c.value(n), -n);
+        }
+        assert_eq!(calls.get(), 3);
+        assert_eq!(c.len(), 3);
+    }
+
+    #[test]
+    fn works_with_non_copy_keys_and_values() {
+        let mut c = Cacher::new(|s: String| vec![s.len(); 2]);
+        assert!(c.is_empty());
+        assert_eq!(c.value(String::from("abc")), vec![3, 3]);
+        assert_eq!(c.value(String::from("abc")), vec![3, 3]);
+        assert_eq!(c.len(), 1);
+    }
+}
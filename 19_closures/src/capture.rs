@@ -0,0 +1,56 @@
+/**
+ * Capturing by reference vs `move`.
+ *
+ * By default a closure captures as little as it can: a shared borrow if it
+ * only reads, a mutable borrow if it writes, and a move only if it has to.
+ * That keeps the original variables usable afterwards.
+ *
+ * The `move` keyword overrides that, and makes the closure take ownership
+ * of everything it uses. That's essential when the closure outlives the
+ * current scope, e.g. when it's returned from a function or handed to a
+ * new thread, since borrowed variables would be gone by then.
+ */
+use std::thread;
+
+// The closure we return uses `factor`, but `factor` dies when this function
+// returns. Without `move`, the compiler refuses: "closure may outlive the
+// current function, but it borrows `factor`".
+fn make_multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+    move |x| x * factor
+}
+
+pub fn demo_capture() {
+    println!("--- Closure Capture Demonstration Begins --- ");
+
+    // borrowed: we can still use `v` after defining and calling the closure
+    let v = vec![1, 2, 3];
+    let contains = |n| v.contains(&n);
+    println!("contains(2)? {}. And v is still ours: {:?}", contains(2), v);
+
+    // moved: after this, `w` belongs to the closure
+    let w: Vec<i32> = (4..=6).collect();
+    let owns_w = move |n| w.contains(&n);
+    println!("owns_w(7)? {}", owns_w(7));
+    // println!("{:?}", w); // error: borrow of moved value: `w`
+
+    // Copy types get copied rather than moved, so the original survives
+    let x = 4;
+    let equal_to_x = move |z| z == x;
+    println!("equal_to_x(4)? {}, and x is still {}", equal_to_x(4), x);
+
+    // a closure escaping its function
+    let triple = make_multiplier(3);
+    println!("triple(7) = {}", triple(7));
+
+    // and the classic reason for `move`: threads. The spawned thread might
+    // run longer than this function, so it must own its data. (To get the
+    // data back afterwards, the thread returns it when it finishes.)
+    let mut names = vec![String::from("Ferris")];
+    let handle = thread::spawn(move || {
+        names.push(String::from("Corro"));
+        names
+    });
+    println!("the thread handed back {:?}", handle.join().unwrap());
+
+    println!("--- Closure Capture Demonstration Finish --- ");
+}
@@ -0,0 +1,66 @@
+/**
+ * The three closure traits, from most to least permissive for the caller.
+ *
+ * When *writing* a function that takes a closure, ask for the loosest
+ * trait you can get away with: FnOnce accepts every closure, FnMut accepts
+ * Fn and FnMut closures, and Fn only accepts Fn closures. So a function
+ * that only calls its argument once should ask for FnOnce, and so on.
+ */
+// we call `f` twice, and never need it to change anything: Fn
+fn call_twice<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(f(x))
+}
+
+// we call `f` several times, and it's allowed to change its captures: FnMut
+fn call_n_times<F: FnMut()>(mut f: F, n: usize) {
+    for _ in 0..n {
+        f();
+    }
+}
+
+// we call `f` exactly once, so it may do anything, even consume captures
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// Plain functions implement all three traits too, so they can be passed
+// anywhere a closure is expected
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+pub fn demo_fn_traits() {
+    println!("--- Fn Traits Demonstration Begins --- ");
+
+    // type inference: the first call pins the types down for good
+    let add_one = |x| x + 1;
+    println!("add_one(5) = {}", add_one(5));
+    // add_one(5.0); // error: expected integer, found floating-point number
+
+    // the fully annotated form, which is legal but rarely needed
+    let add_two = |x: i32| -> i32 { x + 2 };
+    println!("add_two(5) = {}", add_two(5));
+
+    // Fn: `offset` is only read, so this closure is Fn
+    let offset = 10;
+    let add_offset = |x| x + offset;
+    println!("call_twice(add_offset, 1) = {}", call_twice(add_offset, 1));
+    println!("call_twice(double, 3) = {}", call_twice(double, 3));
+
+    // FnMut: `count` gets changed, so the closure itself must be `mut`
+    let mut count = 0;
+    let mut increment = || count += 1;
+    increment();
+    call_n_times(&mut increment, 4);
+    // the closure's borrow of `count` is over now, so we can read it again
+    println!("count after five increments: {}", count);
+
+    // FnOnce: `greeting` is moved *out* of the closure when it runs, so
+    // there's nothing left for a second call
+    let greeting = String::from("hello from a one-shot closure");
+    let consume = move || greeting;
+    println!("{}", call_once(consume));
+    // call_once(consume); // error: use of moved value: `consume`
+
+    println!("--- Fn Traits Demonstration Finish --- ");
+}
@@ -0,0 +1,45 @@
+/**
+ * Closures are anonymous functions that can *capture* their surroundings.
+ *
+ *      let add_one = |x| x + 1;
+ *
+ * The pipes hold the parameters, and the body follows. Types are usually
+ * inferred (unlike with `fn`, where they are mandatory), since closures are
+ * short-lived local helpers rather than part of anybody's public API.
+ *
+ * The big difference from a plain fn is that a closure can use variables
+ * from the scope where it was *defined*. How it uses them decides which of
+ * the three closure traits it implements:
+ *
+ * - Fn: only reads what it captured (can be called any number of times)
+ * - FnMut: changes what it captured (callable many times, but needs `mut`)
+ * - FnOnce: consumes what it captured (so it can only be called once)
+ *
+ * Every closure is at least FnOnce, and the compiler picks the most
+ * generous trait the body allows. We'll see all three, then the `move`
+ * keyword, and then put closures to work in a caching struct.
+ */
+mod fn_traits;
+mod capture;
+mod cacher;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("fn_traits", "Fn, FnMut and FnOnce, and functions that accept each", fn_traits::demo_fn_traits),
+    Demo::new("capture", "Borrowing vs `move`", capture::demo_capture),
+    Demo::new("cacher", "A struct that holds a closure and remembers its answers", cacher::demo_cacher),
+];
+
+pub fn demo() {
+
+    // Fn, FnMut and FnOnce, and functions that accept each of them
+    fn_traits::demo_fn_traits();
+
+    // borrowing vs `move`
+    capture::demo_capture();
+
+    // a struct that holds a closure and remembers its answers
+    cacher::demo_cacher();
+}
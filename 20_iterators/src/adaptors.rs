@@ -0,0 +1,85 @@
+/**
+ * Adaptors and consumers.
+ *
+ * An *adaptor* (map, filter, zip, skip, take...) wraps one iterator in
+ * another, and does nothing until asked. A *consumer* (sum, count, collect,
+ * max...) calls next() until it gets None, and so actually runs the chain.
+ *
+ * Forget the consumer and the compiler warns you: "iterators are lazy and
+ * do nothing unless consumed".
+ */
+// map then consume
+pub fn sum_of_squares(v: &[i32]) -> i32 {
+    v.iter().map(|x| x * x).sum()
+}
+
+// filter, map, then collect into a new Vec
+pub fn doubled_evens(v: &[i32]) -> Vec<i32> {
+    v.iter().filter(|x| *x % 2 == 0).map(|x| x * 2).collect()
+}
+
+// zip walks two iterators in lockstep, and stops when the shorter one does
+pub fn dot_product(a: &[i32], b: &[i32]) -> i32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+// a longer chain, read top to bottom like a recipe
+pub fn describe_scores(names: &[&str], scores: &[u32]) -> Vec<String> {
+    names
+        .iter()
+        .zip(scores.iter())
+        .filter(|(_, score)| **score >= 50)
+        .enumerate()
+        .map(|(rank, (name, score))| format!("{}. {} ({})", rank + 1, name, score))
+        .collect()
+}
+
+pub fn demo_adaptors() {
+    println!("--- Iterator Adaptors Demonstration Begins --- ");
+    let v = vec![1, 2, 3, 4, 5, 6];
+
+    // lazy: this line alone computes nothing at all
+    let _lazy = v.iter().map(|x| {
+        println!("  you will never see this line");
+        x + 1
+    });
+
+    println!("sum of squares of {:?} = {}", v, sum_of_squares(&v));
+    println!("evens, doubled: {:?}", doubled_evens(&v));
+    println!("dot product with [1, 0, 1] = {}", dot_product(&v, &[1, 0, 1]));
+    for line in describe_scores(&["Ann", "Bo", "Cy"], &[90, 30, 75]) {
+        println!("{}", line);
+    }
+    println!("--- Iterator Adaptors Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_and_sum() {
+        assert_eq!(sum_of_squares(&[1, 2, 3]), 14);
+        assert_eq!(sum_of_squares(&[]), 0);
+    }
+
+    #[test]
+    fn filter_map_collect() {
+        assert_eq!(doubled_evens(&[1, 2, 3, 4]), vec![4, 8]);
+        assert!(doubled_evens(&[1, 3]).is_empty());
+    }
+
+    #[test]
+    fn zip_stops_at_the_shorter_side() {
+        assert_eq!(dot_product(&[1, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(dot_product(&[1, 2, 3], &[10]), 10);
+    }
+
+    #[test]
+    fn a_whole_chain() {
+        assert_eq!(
+            describe_scores(&["Ann", "Bo", "Cy"], &[90, 30, 75]),
+            vec![String::from("1. Ann (90)"), String::from("2. Cy (75)")]
+        );
+    }
+}
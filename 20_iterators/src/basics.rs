@@ -0,0 +1,96 @@
+/**
+ * iter vs into_iter vs iter_mut.
+ *
+ * A Vec can give out its items three ways, which line up exactly with the
+ * three ways of handling any value in Rust:
+ *
+ * - iter():      shared references, `&T`    (look, don't touch)
+ * - iter_mut():  mutable references, `&mut T` (change them in place)
+ * - into_iter(): the items themselves, `T`  (take ownership, Vec is gone)
+ *
+ * A `for` loop calls into_iter() on whatever it's given, which is why
+ * `for x in v` consumes `v`, while `for x in &v` borrows it.
+ */
+// only looks, so the caller keeps the Vec
+pub fn total_length(words: &[String]) -> usize {
+    words.iter().map(|w| w.len()).sum()
+}
+
+// changes every item where it stands
+pub fn shout_all(words: &mut [String]) {
+    for word in words.iter_mut() {
+        *word = word.to_uppercase();
+    }
+}
+
+// takes the Vec and everything in it; the Strings move into the result
+pub fn join_owned(words: Vec<String>) -> String {
+    let mut joined = String::new();
+    for word in words.into_iter() {
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(&word);
+    }
+    joined
+}
+
+pub fn demo_basics() {
+    println!("--- Iterator Basics Demonstration Begins --- ");
+
+    // calling next() by hand, which is all a `for` loop really does.
+    // The iterator has to be `mut` because next() changes its position.
+    // (Arrays and slices have iter() too, not just Vecs.)
+    let v = [1, 2, 3];
+    let mut it = v.iter();
+    println!("next: {:?}, {:?}, {:?}, {:?}", it.next(), it.next(), it.next(), it.next());
+
+    let mut words = vec![String::from("hello"), String::from("iterators")];
+    println!("total length of {:?} is {}", words, total_length(&words));
+    shout_all(&mut words);
+    println!("after iter_mut: {:?}", words);
+    let joined = join_owned(words);
+    println!("after into_iter: {}", joined);
+    // println!("{:?}", words); // error: borrow of moved value: `words`
+
+    println!("--- Iterator Basics Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<String> {
+        vec![String::from("ab"), String::from("cde")]
+    }
+
+    #[test]
+    fn next_walks_then_stops() {
+        let v = [10, 20];
+        let mut it = v.iter();
+        assert_eq!(it.next(), Some(&10));
+        assert_eq!(it.next(), Some(&20));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn iter_borrows() {
+        let w = words();
+        assert_eq!(total_length(&w), 5);
+        assert_eq!(w.len(), 2);
+    }
+
+    #[test]
+    fn iter_mut_changes_in_place() {
+        let mut w = words();
+        shout_all(&mut w);
+        assert_eq!(w, vec![String::from("AB"), String::from("CDE")]);
+    }
+
+    #[test]
+    fn into_iter_consumes() {
+        assert_eq!(join_owned(words()), "ab cde");
+        assert_eq!(join_owned(Vec::new()), "");
+    }
+}
@@ -0,0 +1,86 @@
+/**
+ * Our own iterator: implement next(), get everything else for free.
+ *
+ * Counter counts from 1 to 5 and then stops. The `type Item` line is an
+ * *associated type*: it tells the trait what kind of thing next() returns.
+ */
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Counter {
+        Counter::new()
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < 5 {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+// The book's showpiece: pair each count with the next one along (skipping
+// the first), multiply the pairs, keep the multiples of three, add them up.
+// Not one of those methods was written by us.
+pub fn fancy_sum() -> u32 {
+    Counter::new()
+        .zip(Counter::new().skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum()
+}
+
+pub fn demo_counter() {
+    println!("--- Custom Iterator Demonstration Begins --- ");
+    for n in Counter::new() {
+        println!("counting: {}", n);
+    }
+    println!("collected: {:?}", Counter::default().collect::<Vec<u32>>());
+    println!("fancy sum: {}", fancy_sum());
+    println!("--- Custom Iterator Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calling_next_directly() {
+        let mut counter = Counter::new();
+
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), Some(3));
+        assert_eq!(counter.next(), Some(4));
+        assert_eq!(counter.next(), Some(5));
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn using_other_iterator_trait_methods() {
+        // (1*2) + (2*3) + (3*4) + (4*5), keeping only multiples of 3
+        assert_eq!(18, fancy_sum());
+    }
+
+    #[test]
+    fn provided_methods_work_too() {
+        assert_eq!(Counter::new().count(), 5);
+        assert_eq!(Counter::new().max(), Some(5));
+        assert_eq!(Counter::new().last(), Some(5));
+        assert_eq!(Counter::new().step_by(2).collect::<Vec<u32>>(), vec![1, 3, 5]);
+    }
+}
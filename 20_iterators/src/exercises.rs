@@ -0,0 +1,23 @@
+/*!
+ * Exercises for chapter 20: iterators. No `for` loops allowed!
+ *
+ * Check them with `cargo run -p runner -- verify 20` (see the README).
+ */
+// (the parameters sit unused until you write the bodies)
+#![allow(unused_variables)]
+
+// 1, 2, 3 gives 1 + 4 + 9 = 14. Hint: `map`, then `sum`.
+pub fn sum_of_squares(values: &[i32]) -> i32 {
+    todo!()
+}
+
+// Just the even numbers, in their original order.
+pub fn evens(values: &[i32]) -> Vec<i32> {
+    todo!()
+}
+
+// The longest word, or None if there are no words at all. When two words
+// tie, the first one wins.
+pub fn longest_word(text: &str) -> Option<&str> {
+    todo!()
+}
@@ -0,0 +1,176 @@
+/**
+ * Adding our own adaptors to *every* iterator, with an extension trait.
+ *
+ * We can't add methods to the standard library's Iterator trait, but we can
+ * write a trait of our own, with the new methods in it, and implement it
+ * for every type that implements Iterator, in one go:
+ *
+ *      impl<I: Iterator> MyIteratorExt for I {}
+ *
+ * That's a *blanket* impl. With the trait in scope (`use`), `.take_until()`
+ * works on a range, a Vec's iter(), a string's chars(), our own Counter:
+ * anything. The itertools crate is this same trick, with a hundred methods.
+ *
+ * Each method just wraps `self` in a struct, and the struct implements
+ * Iterator, with a next() that does the real work. That's exactly how the
+ * standard adaptors work too: `map` returns a `Map`, `filter` a `Filter`.
+ * And like theirs, ours are lazy, so nothing happens until something pulls.
+ */
+// the methods themselves have default bodies, so the blanket impl is empty
+pub trait MyIteratorExt: Iterator + Sized {
+    // Like take_while, but the other way round, and keeping the item that
+    // ends it: everything up to *and including* the first item `done` says
+    // yes to. Handy for "read up to the blank line".
+    fn take_until<P>(self, done: P) -> TakeUntil<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeUntil { iter: self, done, finished: false }
+    }
+
+    // The items two at a time, as tuples: (1, 2), (3, 4) and so on. A lone
+    // item left at the end has nothing to pair with, so it's dropped (like
+    // `chunks_exact` on a slice).
+    fn chunk_pairs(self) -> ChunkPairs<Self> {
+        ChunkPairs { iter: self }
+    }
+}
+
+impl<I: Iterator> MyIteratorExt for I {}
+
+pub struct TakeUntil<I, P> {
+    iter: I,
+    done: P,
+    // once the last item is out, stop for good, even if `iter` has more
+    finished: bool,
+}
+
+impl<I, P> Iterator for TakeUntil<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.finished {
+            return None;
+        }
+        let item = self.iter.next()?;
+        if (self.done)(&item) {
+            self.finished = true;
+        }
+        Some(item)
+    }
+
+    // anywhere from nothing to everything that's left
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+pub struct ChunkPairs<I> {
+    iter: I,
+}
+
+impl<I: Iterator> Iterator for ChunkPairs<I> {
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // `?` twice: no first item, or no second one, and we're done
+        let first = self.iter.next()?;
+        let second = self.iter.next()?;
+        Some((first, second))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (low / 2, high.map(|high| high / 2))
+    }
+}
+
+pub fn demo_extension() {
+    println!("--- Extension Trait Demonstration Begins --- ");
+    let lines = ["Subject: frogs", "From: a bog", "", "I'm nobody! Who are you?"];
+    let header: Vec<&&str> = lines.iter().take_until(|line| line.is_empty()).collect();
+    println!("up to the blank line: {:?}", header);
+    // an endless iterator is fine: take_until stops pulling
+    println!("until past 20: {:?}", (1..).map(|n| n * n).take_until(|&n| n > 20).collect::<Vec<u64>>());
+    println!("in pairs: {:?}", "frogs".chars().chunk_pairs().collect::<Vec<(char, char)>>());
+    let sums: Vec<u32> = crate::counter::Counter::new().chunk_pairs().map(|(a, b)| a + b).collect();
+    println!("Counter's pairs, added: {:?}", sums);
+    println!("--- Extension Trait Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::counter::Counter;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn take_until_keeps_the_last_item() {
+        assert_eq!((1..10).take_until(|&n| n == 3).collect::<Vec<i32>>(), [1, 2, 3]);
+        // never true: everything; true at once: just the first
+        assert_eq!(vec![1, 2].into_iter().take_until(|_| false).collect::<Vec<i32>>(), [1, 2]);
+        assert_eq!(vec![1, 2].into_iter().take_until(|_| true).collect::<Vec<i32>>(), [1]);
+        assert_eq!(std::iter::empty::<u8>().take_until(|_| true).count(), 0);
+    }
+
+    #[test]
+    fn take_until_on_other_sources() {
+        assert_eq!("I'm nobody!".chars().take_until(|c| c.is_whitespace()).collect::<String>(), "I'm ");
+        assert_eq!(Counter::new().take_until(|&n| n % 2 == 0).collect::<Vec<u32>>(), [1, 2]);
+        let queue: VecDeque<&str> = vec!["a", "stop", "b"].into_iter().collect();
+        assert_eq!(queue.iter().take_until(|s| **s == "stop").count(), 2);
+        // (1..) never ends, so this only finishes if take_until stops asking
+        assert_eq!((1..).take_until(|&n: &u64| n == 1000).last(), Some(1000));
+    }
+
+    #[test]
+    fn take_until_stays_finished() {
+        // the predicate is only asked about items that get handed out
+        let mut asked = 0;
+        {
+            let mut iter = (1..10).take_until(|&n| {
+                asked += 1;
+                n == 2
+            });
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.next(), Some(2));
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+        assert_eq!(asked, 2);
+    }
+
+    #[test]
+    fn chunk_pairs_drops_a_lone_last_item() {
+        assert_eq!((1..=4).chunk_pairs().collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+        assert_eq!((1..=5).chunk_pairs().collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+        assert_eq!((1..=1).chunk_pairs().count(), 0);
+        assert_eq!((1..=5).chunk_pairs().size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn chunk_pairs_on_other_sources() {
+        assert_eq!("abcd".chars().chunk_pairs().collect::<Vec<_>>(), [('a', 'b'), ('c', 'd')]);
+        assert_eq!(Counter::new().chunk_pairs().collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+        let words = vec![String::from("to"), String::from("an"), String::from("admiring")];
+        // owned items move into the pairs, no cloning needed
+        assert_eq!(words.into_iter().chunk_pairs().next(), Some((String::from("to"), String::from("an"))));
+        // and it chains with everything else
+        assert_eq!((1..).chunk_pairs().map(|(a, b)| a * b).take(3).collect::<Vec<u32>>(), [2, 12, 30]);
+    }
+
+    #[test]
+    fn both_together() {
+        let pairs: Vec<(u32, u32)> = (1..).chunk_pairs().take_until(|&(_, b)| b >= 6).collect();
+        assert_eq!(pairs, [(1, 2), (3, 4), (5, 6)]);
+    }
+}
@@ -0,0 +1,58 @@
+/**
+ * Iterators: a uniform way to walk through a series of items.
+ *
+ * We've been using them since the very first `for` loop, but never looked
+ * under the hood. There isn't much there! An iterator is anything that
+ * implements the Iterator trait, and the trait has exactly one required
+ * method:
+ *
+ *      fn next(&mut self) -> Option<Self::Item>;
+ *
+ * Each call hands back Some(item) until the series runs dry, and then None.
+ * Everything else (map, filter, zip, sum, collect, and dozens more) is a
+ * *provided* method built on top of next(), which every iterator gets for
+ * free.
+ *
+ * Iterators are also *lazy*: building a chain of adaptors does no work at
+ * all. Nothing happens until something at the end of the chain (a `for`
+ * loop, `sum`, `collect`...) starts pulling items through it.
+ */
+mod basics;
+mod adaptors;
+mod shoes;
+mod counter;
+// pub, so that other crates can `use iterators::extension::MyIteratorExt`
+pub mod extension;
+
+// practice problems, compiled only with `--features exercises`
+#[cfg(feature = "exercises")]
+pub mod exercises;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("basics", "The three ways to get an iterator out of a collection", basics::demo_basics),
+    Demo::new("adaptors", "Chaining adaptors together, and consuming the result", adaptors::demo_adaptors),
+    Demo::new("shoes", "A closure capturing its environment in a filter", shoes::demo_shoes),
+    Demo::new("counter", "Implementing Iterator ourselves", counter::demo_counter),
+    Demo::new("extension", "New adaptors for every iterator, with an extension trait", extension::demo_extension),
+];
+
+pub fn demo() {
+
+    // the three ways to get an iterator out of a collection
+    basics::demo_basics();
+
+    // chaining adaptors together, and consuming the result
+    adaptors::demo_adaptors();
+
+    // the book's example of a closure capturing its environment in a filter
+    shoes::demo_shoes();
+
+    // implementing Iterator ourselves
+    counter::demo_counter();
+
+    // and adaptors of our own, added to every iterator at once
+    extension::demo_extension();
+}
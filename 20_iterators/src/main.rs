@@ -0,0 +1,5 @@
+// The demos live in lib.rs, where the top-level `runner` crate can reach
+// them too; all that's left for main to do is call them.
+fn main() {
+    iterators::demo();
+}
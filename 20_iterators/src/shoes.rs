@@ -0,0 +1,57 @@
+/**
+ * The book's shoe store: filter with a closure that captures a variable.
+ *
+ * `shoe_size` is a plain function argument, but the closure passed to
+ * filter() can use it anyway, because closures capture their environment.
+ * A plain `fn` couldn't do that.
+ */
+#[derive(PartialEq, Debug)]
+pub struct Shoe {
+    pub size: u32,
+    pub style: String,
+}
+
+// into_iter takes ownership of the shoes, so the ones that pass the filter
+// can be moved into the result without any copying
+pub fn shoes_in_my_size(shoes: Vec<Shoe>, shoe_size: u32) -> Vec<Shoe> {
+    shoes.into_iter().filter(|s| s.size == shoe_size).collect()
+}
+
+fn sample_shoes() -> Vec<Shoe> {
+    vec![
+        Shoe { size: 10, style: String::from("sneaker") },
+        Shoe { size: 13, style: String::from("sandal") },
+        Shoe { size: 10, style: String::from("boot") },
+    ]
+}
+
+pub fn demo_shoes() {
+    println!("--- Shoe Filter Demonstration Begins --- ");
+    for shoe in shoes_in_my_size(sample_shoes(), 10) {
+        println!("size {} {}", shoe.size, shoe.style);
+    }
+    println!("--- Shoe Filter Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_by_size() {
+        let in_my_size = shoes_in_my_size(sample_shoes(), 10);
+
+        assert_eq!(
+            in_my_size,
+            vec![
+                Shoe { size: 10, style: String::from("sneaker") },
+                Shoe { size: 10, style: String::from("boot") },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_shoes_in_an_odd_size() {
+        assert!(shoes_in_my_size(sample_shoes(), 7).is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+/**
+ * The checks for src/exercises.rs. Spoilers below!
+ *
+ * These only build with `--features exercises` (see Cargo.toml), so the
+ * ordinary `cargo test` never trips over the unfinished exercises. Each test
+ * is named after the exercise it checks, which is how `runner verify` tells
+ * which ones pass.
+ */
+use iterators::exercises;
+
+#[test]
+fn sum_of_squares() {
+    assert_eq!(exercises::sum_of_squares(&[1, 2, 3]), 14);
+    assert_eq!(exercises::sum_of_squares(&[]), 0);
+}
+
+#[test]
+fn evens() {
+    assert_eq!(exercises::evens(&[1, 2, 3, 4, 6]), vec![2, 4, 6]);
+    assert!(exercises::evens(&[1, 3]).is_empty());
+}
+
+#[test]
+fn longest_word() {
+    assert_eq!(exercises::longest_word("a quick brown fox"), Some("quick"));
+    assert_eq!(exercises::longest_word("tie tie"), Some("tie"));
+    assert_eq!(exercises::longest_word("   "), None);
+}
@@ -0,0 +1,74 @@
+/**
+ * Box<T>: the simplest smart pointer.
+ *
+ * A Box is a pointer on the stack to a value on the heap. That's all. It
+ * has no runtime overhead beyond the allocation, and it's useful for:
+ *
+ * - values whose size can't be known at compile time (recursive types!)
+ * - big values you want to move around without copying all the bytes
+ * - trait objects, where you only care which trait a value implements
+ */
+
+// A recursive type: a List contains a List. Without the Box, Rust would
+// try to work out how big a List is, find a List inside it, and recurse
+// forever: "recursive type `List` has infinite size". A Box is always the
+// size of one pointer, which breaks the loop.
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use self::List::{Cons, Nil};
+
+// build Cons(1, Cons(2, ... Nil)) from a slice, back to front
+pub fn list_from(values: &[i32]) -> List {
+    let mut list = Nil;
+    for &v in values.iter().rev() {
+        list = Cons(v, Box::new(list));
+    }
+    list
+}
+
+// recursion follows the shape of the type
+pub fn sum(list: &List) -> i32 {
+    match list {
+        Cons(value, rest) => value + sum(rest),
+        Nil => 0,
+    }
+}
+
+pub fn demo_boxes() {
+    println!("--- Box Demonstration Begins --- ");
+    // a lone i32 on the heap: pointless, but it shows the syntax. A Box
+    // can be used (and printed) just like the value inside it.
+    let b = Box::new(5);
+    println!("b = {}", b);
+
+    let list = list_from(&[1, 2, 3]);
+    println!("list = {:?}", list);
+    println!("sum of list = {}", sum(&list));
+    println!("--- Box Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_in_order() {
+        match list_from(&[7, 8]) {
+            Cons(7, rest) => match *rest {
+                Cons(8, end) => assert!(matches!(*end, Nil)),
+                other => panic!("expected Cons(8, ..), got {:?}", other),
+            },
+            other => panic!("expected Cons(7, ..), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn sums() {
+        assert_eq!(sum(&list_from(&[1, 2, 3, 4])), 10);
+        assert_eq!(sum(&list_from(&[])), 0);
+    }
+}
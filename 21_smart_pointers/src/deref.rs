@@ -0,0 +1,87 @@
+/**
+ * Deref: making our own type work with `*`.
+ *
+ * MyBox<T> is a fake Box (it keeps its value on the stack, so it isn't
+ * really a Box at all), but once it implements Deref, Rust will happily
+ * treat it like a reference. Behind the scenes `*y` turns into
+ * `*(y.deref())`.
+ *
+ * Deref also unlocks *deref coercion*: when a function wants `&str` and we
+ * hand it `&MyBox<String>`, Rust calls deref() as many times as needed
+ * (MyBox<String> -> String -> str) to make the types line up. It all
+ * happens at compile time, so it costs nothing at runtime.
+ */
+use std::ops::{Deref, DerefMut};
+
+#[derive(Debug)]
+pub struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    // hands back a *reference*: we don't want to move the value out
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// the `&mut` twin, so that `*b = ...` and mutable coercion work too
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+pub fn hello(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+pub fn demo_deref() {
+    println!("--- Deref Demonstration Begins --- ");
+    let x = 5;
+    let y = MyBox::new(x);
+    // assert_eq!(5, y); // error: can't compare `{integer}` with `MyBox<{integer}>`
+    println!("x = {}, *y = {}, equal? {}", x, *y, x == *y);
+
+    // deref coercion: &MyBox<String> -> &String -> &str, all automatic
+    let m = MyBox::new(String::from("Rust"));
+    println!("{}", hello(&m));
+    // ...and without it, we'd be writing this mouthful instead:
+    println!("{}", hello(&(*m)[..]));
+
+    // methods of the inner value are reachable too, via the same coercion
+    let mut words = MyBox::new(vec!["smart"]);
+    words.push("pointers");
+    println!("{} words: {:?}", words.len(), *words);
+    println!("--- Deref Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_reaches_inside() {
+        let y = MyBox::new(5);
+        assert_eq!(5, *y);
+    }
+
+    #[test]
+    fn deref_coercion_to_str() {
+        let m = MyBox::new(String::from("Ferris"));
+        assert_eq!(hello(&m), "Hello, Ferris!");
+    }
+
+    #[test]
+    fn deref_mut_lets_us_assign_through_it() {
+        let mut b = MyBox::new(1);
+        *b += 41;
+        assert_eq!(*b, 42);
+    }
+}
@@ -0,0 +1,124 @@
+/**
+ * Drop: code that runs when a value goes out of scope.
+ *
+ * Rust inserts the call for us, so we never forget to free memory, close a
+ * file, or unlock a lock. Values are dropped in the *reverse* order they
+ * were created, like a stack of plates: last in, first out.
+ *
+ * We can't call `x.drop()` ourselves ("explicit destructor calls not
+ * allowed"), since Rust would then drop it a second time at the end of the
+ * scope. To get rid of something early, pass it to `std::mem::drop`, which
+ * is in the prelude as plain `drop(x)`. It takes ownership, and then simply
+ * lets the value go out of scope.
+ *
+ * To *watch* the drops happen in the tests, each value writes to a shared
+ * log. That log is an Rc<RefCell<..>>, which is a sneak preview of the two
+ * modules coming up after this one.
+ */
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub type DropLog = Rc<RefCell<Vec<String>>>;
+
+pub struct CustomSmartPointer {
+    pub data: String,
+    log: Option<DropLog>,
+}
+
+impl CustomSmartPointer {
+    pub fn new(data: &str) -> CustomSmartPointer {
+        CustomSmartPointer { data: data.to_string(), log: None }
+    }
+
+    // the same, but also record the drop in `log`
+    pub fn logged(data: &str, log: &DropLog) -> CustomSmartPointer {
+        CustomSmartPointer { data: data.to_string(), log: Some(Rc::clone(log)) }
+    }
+}
+
+impl Drop for CustomSmartPointer {
+    fn drop(&mut self) {
+        println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+        if let Some(log) = &self.log {
+            log.borrow_mut().push(self.data.clone());
+        }
+    }
+}
+
+pub fn new_log() -> DropLog {
+    Rc::new(RefCell::new(Vec::new()))
+}
+
+pub fn demo_drop() {
+    println!("--- Drop Demonstration Begins --- ");
+    {
+        let _c = CustomSmartPointer::new("my stuff");
+        let _d = CustomSmartPointer::new("other stuff");
+        println!("CustomSmartPointers created.");
+    } // <- d is dropped here, then c
+    println!("Out of the inner scope.");
+
+    let early = CustomSmartPointer::new("dropped early");
+    println!("Made `{}`, about to drop it.", early.data);
+    drop(early);
+    println!("CustomSmartPointer dropped before the end of main.");
+
+    // the same thing again, but keeping a record this time
+    let log = new_log();
+    {
+        let _first = CustomSmartPointer::logged("first", &log);
+        let _second = CustomSmartPointer::logged("second", &log);
+    }
+    println!("Drop order, as logged: {:?}", log.borrow());
+    println!("--- Drop Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_in_reverse_order_of_creation() {
+        let log = new_log();
+        {
+            let _a = CustomSmartPointer::logged("a", &log);
+            let _b = CustomSmartPointer::logged("b", &log);
+            let _c = CustomSmartPointer::logged("c", &log);
+            assert!(log.borrow().is_empty());
+        }
+        assert_eq!(*log.borrow(), vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn explicit_drop_goes_first() {
+        let log = new_log();
+        {
+            let a = CustomSmartPointer::logged("a", &log);
+            let _b = CustomSmartPointer::logged("b", &log);
+            drop(a);
+            assert_eq!(*log.borrow(), vec!["a"]);
+        }
+        assert_eq!(*log.borrow(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn moving_a_value_moves_its_drop_too() {
+        let log = new_log();
+        let kept;
+        {
+            let a = CustomSmartPointer::logged("a", &log);
+            kept = a; // ownership leaves the inner scope, so no drop here
+        }
+        assert!(log.borrow().is_empty());
+        drop(kept);
+        assert_eq!(*log.borrow(), vec!["a"]);
+    }
+
+    #[test]
+    fn unused_underscore_binding_drops_immediately() {
+        // `_` is not a variable at all, so nothing owns the value
+        let log = new_log();
+        let _ = CustomSmartPointer::logged("gone", &log);
+        assert_eq!(*log.borrow(), vec!["gone"]);
+    }
+}
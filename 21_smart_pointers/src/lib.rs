@@ -0,0 +1,62 @@
+/**
+ * Smart pointers: structs that act like references, but do more.
+ *
+ * A plain reference (`&T`) just points at data somebody else owns. A smart
+ * pointer *owns* the data it points to, and adds some behavior on top:
+ * Box puts its data on the heap, Rc counts its owners, RefCell checks the
+ * borrowing rules at runtime, and so on. We've already met two of them
+ * without calling them that: String and Vec<T>.
+ *
+ * What makes a struct a smart pointer is two traits:
+ *
+ * - Deref: lets `*p` reach the data inside, just like a reference
+ * - Drop: runs cleanup code when the pointer goes out of scope
+ *
+ * This package builds up from the simplest smart pointer to the fancier
+ * ones, one module at a time. Most of them only *use* std's pointers, but
+ * ./my_rc.rs builds its own Rc, to show there's no magic inside.
+ */
+mod boxes;
+mod deref;
+mod drop;
+mod my_rc;
+mod rc;
+mod refcell;
+mod tree;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("boxes", "Box<T>: the heap, and recursive types", boxes::demo_boxes),
+    Demo::new("deref", "Deref, MyBox<T> and deref coercion", deref::demo_deref),
+    Demo::new("drop", "Drop, and dropping things early", drop::demo_drop),
+    Demo::new("rc", "Rc<T>: shared ownership, with a count of the owners", rc::demo_rc),
+    Demo::new("my_rc", "MyRc<T>: an Rc of our own, with the unsafe showing", my_rc::demo_my_rc),
+    Demo::new("refcell", "RefCell<T>: borrowing rules, checked at runtime", refcell::demo_refcell),
+    Demo::new("tree", "Weak<T>: parents and children without a cycle", tree::demo_tree),
+];
+
+pub fn demo() {
+
+    // Box<T>: the heap, and recursive types
+    boxes::demo_boxes();
+
+    // Deref, our own MyBox<T>, and deref coercion
+    deref::demo_deref();
+
+    // Drop, and dropping things early
+    drop::demo_drop();
+
+    // Rc<T>: shared ownership, with a count of the owners
+    rc::demo_rc();
+
+    // MyRc<T>: the same counting, built by hand (the package's only unsafe)
+    my_rc::demo_my_rc();
+
+    // RefCell<T>: borrowing rules, checked at runtime instead
+    refcell::demo_refcell();
+
+    // Weak<T>: parents and children without a reference cycle
+    tree::demo_tree();
+}
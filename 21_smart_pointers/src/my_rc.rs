@@ -0,0 +1,169 @@
+/**
+ * MyRc<T>: Rc, taken apart to see how it works.
+ *
+ * An Rc is a pointer to one heap allocation holding two things: the value,
+ * and a count of how many Rcs point at it.
+ *
+ *      a ----\
+ *             +--> [ count: 2 | value ]
+ *      b ----/
+ *
+ * Three operations keep the count honest:
+ *
+ * - `new` puts the value on the heap with a count of 1
+ * - `clone` copies the *pointer*, and adds one to the count
+ * - `drop` takes one off, and whoever takes it to zero frees the allocation
+ *
+ * Nothing in safe Rust lets several owners share one heap allocation (that
+ * is, after all, exactly what ownership forbids), so this is the one module
+ * in the package with `unsafe` in it. Every `unsafe` block is the same
+ * promise, written out each time: the allocation is still alive, because
+ * the count says somebody (us) still owns it.
+ *
+ * The count lives in a Cell, so a `&MyRc` can change it: clone only gets
+ * `&self`. That, and the raw pointer, make MyRc neither Send nor Sync, so
+ * the compiler won't let one cross to another thread, where two threads
+ * bumping the same count at once could lose an update. The real Rc is
+ * single-threaded for the same reason, and Arc pays for atomics to lift it.
+ */
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+struct Inner<T> {
+    count: Cell<usize>,
+    value: T,
+}
+
+pub struct MyRc<T> {
+    ptr: NonNull<Inner<T>>,
+    // tells the compiler that a MyRc<T> owns a T (for the drop checker),
+    // which the raw pointer alone doesn't say
+    _owns: PhantomData<Inner<T>>,
+}
+
+impl<T> MyRc<T> {
+    pub fn new(value: T) -> MyRc<T> {
+        let inner = Box::new(Inner { count: Cell::new(1), value });
+        // leak hands the allocation over to us: Box won't free it now, and
+        // it's our job to turn it back into a Box when the count hits 0
+        MyRc { ptr: NonNull::from(Box::leak(inner)), _owns: PhantomData }
+    }
+
+    fn inner(&self) -> &Inner<T> {
+        // SAFETY: `self` is one of the owners the count is counting, so the
+        // count is at least 1 and the allocation hasn't been freed
+        unsafe { self.ptr.as_ref() }
+    }
+
+    // Rc's spelling: MyRc::strong_count(&a), not a.strong_count(), so that
+    // it can't be mistaken for a method of the T inside
+    pub fn strong_count(this: &MyRc<T>) -> usize {
+        this.inner().count.get()
+    }
+
+    // whether two MyRcs share one allocation (not whether the values are equal)
+    pub fn ptr_eq(this: &MyRc<T>, other: &MyRc<T>) -> bool {
+        this.ptr == other.ptr
+    }
+}
+
+impl<T> Clone for MyRc<T> {
+    fn clone(&self) -> MyRc<T> {
+        let count = &self.inner().count;
+        count.set(count.get() + 1);
+        MyRc { ptr: self.ptr, _owns: PhantomData }
+    }
+}
+
+impl<T> Drop for MyRc<T> {
+    fn drop(&mut self) {
+        let count = &self.inner().count;
+        count.set(count.get() - 1);
+        if count.get() == 0 {
+            // SAFETY: the pointer came from Box::leak in new(), and the count
+            // just reached zero, so no other MyRc is left to use it
+            unsafe { drop(Box::from_raw(self.ptr.as_ptr())) };
+        }
+    }
+}
+
+impl<T> Deref for MyRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+pub fn demo_my_rc() {
+    println!("--- MyRc Demonstration Begins --- ");
+    let a = MyRc::new(String::from("shared"));
+    println!("after creating a: count = {}", MyRc::strong_count(&a));
+    let b = MyRc::clone(&a);
+    println!("after cloning b: count = {}, same allocation? {}", MyRc::strong_count(&a), MyRc::ptr_eq(&a, &b));
+    {
+        let c = MyRc::clone(&b);
+        println!("after cloning c: count = {}, and c says {:?}", MyRc::strong_count(&a), *c);
+    }
+    println!("after c goes out of scope: count = {}", MyRc::strong_count(&a));
+    drop(b);
+    println!("after dropping b: count = {}", MyRc::strong_count(&a));
+    println!("--- MyRc Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drop::{new_log, CustomSmartPointer};
+
+    #[test]
+    fn counts_go_up_and_down() {
+        let a = MyRc::new(5);
+        assert_eq!(MyRc::strong_count(&a), 1);
+        let b = MyRc::clone(&a);
+        let c = b.clone();
+        assert_eq!(MyRc::strong_count(&a), 3);
+        drop(b);
+        assert_eq!(MyRc::strong_count(&c), 2);
+        assert_eq!(*a + *c, 10);
+    }
+
+    #[test]
+    fn clones_share_one_allocation() {
+        let a = MyRc::new(vec![1, 2, 3]);
+        let b = MyRc::clone(&a);
+        assert!(MyRc::ptr_eq(&a, &b));
+        // equal values, separate allocations
+        assert!(!MyRc::ptr_eq(&a, &MyRc::new(vec![1, 2, 3])));
+        // Deref, so the Vec's methods work straight through
+        assert_eq!(b.len(), 3);
+    }
+
+    #[test]
+    fn the_value_is_dropped_with_the_last_owner() {
+        let log = new_log();
+        let a = MyRc::new(CustomSmartPointer::logged("inside", &log));
+        let b = MyRc::clone(&a);
+        drop(a);
+        assert!(log.borrow().is_empty(), "dropped while b still owned it");
+        assert_eq!(b.data, "inside");
+        drop(b);
+        assert_eq!(log.borrow().len(), 1);
+    }
+
+    #[test]
+    fn every_value_is_dropped_exactly_once() {
+        let log = new_log();
+        {
+            let owners: Vec<MyRc<CustomSmartPointer>> = (0..3)
+                .map(|i| MyRc::new(CustomSmartPointer::logged(&i.to_string(), &log)))
+                .flat_map(|rc| vec![MyRc::clone(&rc), rc])
+                .collect();
+            assert_eq!(owners.len(), 6);
+            assert_eq!(MyRc::strong_count(&owners[0]), 2);
+        }
+        assert_eq!(log.borrow().len(), 3);
+    }
+}
@@ -0,0 +1,110 @@
+/**
+ * Rc<T>: more than one owner for the same value.
+ *
+ * Ownership says every value has exactly one owner. But some data really
+ * is shared, like a node in a graph with several edges pointing at it, and
+ * nobody can say in advance which owner will be the last to let go.
+ *
+ * Rc ("reference counted") keeps a count of its owners. `Rc::clone(&a)`
+ * doesn't copy the data at all, it just bumps the count; dropping an Rc
+ * knocks the count back down; and the data is freed when it hits zero.
+ * (Rc is for a single thread only. The thread-safe version is Arc.)
+ *
+ * The book's example: two lists, b and c, that share the same tail, a.
+ *
+ *      b: 3 --\
+ *              +--> a: 5 -> 10 -> Nil
+ *      c: 4 --/
+ */
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Rc<List>),
+    Nil,
+}
+
+use self::List::{Cons, Nil};
+
+// Walk the book's example step by step, noting how many owners `a` has
+// after each one. Returning the counts (rather than just printing them)
+// lets the tests check every number.
+pub fn shared_list_counts() -> Vec<(&'static str, usize)> {
+    let mut steps = Vec::new();
+
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    steps.push(("after creating a", Rc::strong_count(&a)));
+
+    // Rc::clone is the conventional spelling, rather than a.clone(), so a
+    // reader can tell at a glance that this is cheap: only a count goes up
+    let _b = Cons(3, Rc::clone(&a));
+    steps.push(("after creating b", Rc::strong_count(&a)));
+
+    {
+        let _c = Cons(4, Rc::clone(&a));
+        steps.push(("after creating c", Rc::strong_count(&a)));
+    }
+    steps.push(("after c goes out of scope", Rc::strong_count(&a)));
+
+    steps
+}
+
+// add up a list, following the shared Rc links
+pub fn sum(list: &List) -> i32 {
+    match list {
+        Cons(value, rest) => value + sum(rest),
+        Nil => 0,
+    }
+}
+
+pub fn demo_rc() {
+    println!("--- Rc Demonstration Begins --- ");
+    for (step, count) in shared_list_counts() {
+        println!("count {}: {}", step, count);
+    }
+
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    let b = Cons(3, Rc::clone(&a));
+    let c = Cons(4, Rc::clone(&a));
+    println!("a = {:?}", a);
+    println!("sums: a = {}, b = {}, c = {}", sum(&a), sum(&b), sum(&c));
+    println!("--- Rc Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_at_each_step() {
+        assert_eq!(
+            shared_list_counts(),
+            vec![
+                ("after creating a", 1),
+                ("after creating b", 2),
+                ("after creating c", 3),
+                ("after c goes out of scope", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_tail_is_not_copied() {
+        let a = Rc::new(Cons(1, Rc::new(Nil)));
+        let b = Cons(2, Rc::clone(&a));
+        if let Cons(_, tail) = &b {
+            // the very same allocation, not a copy of it
+            assert!(Rc::ptr_eq(tail, &a));
+        }
+        assert_eq!(sum(&b), 3);
+    }
+
+    #[test]
+    fn dropping_owners_brings_the_count_down() {
+        let a = Rc::new(Nil);
+        let owners: Vec<Rc<List>> = (0..5).map(|_| Rc::clone(&a)).collect();
+        assert_eq!(Rc::strong_count(&a), 6);
+        drop(owners);
+        assert_eq!(Rc::strong_count(&a), 1);
+    }
+}
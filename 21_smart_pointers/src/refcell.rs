@@ -0,0 +1,180 @@
+/**
+ * RefCell<T>: interior mutability, with the borrow rules checked at runtime.
+ *
+ * Normally the borrow checker enforces "one `&mut` OR any number of `&`"
+ * at compile time. RefCell enforces the very same rule, but while the
+ * program runs: `borrow()` hands out a shared borrow, `borrow_mut()` a
+ * mutable one, and breaking the rule is a panic instead of a compile error.
+ *
+ * Why give up the compile-time check? Because sometimes *we* know the code
+ * is fine but the compiler can't prove it. The book's example is a mock
+ * object in a test: the trait says `send(&self, ..)`, with an immutable
+ * self, but the mock wants to record every message it was sent.
+ *
+ * Combine it with Rc and we get Rc<RefCell<T>>: several owners, any of
+ * whom can change the shared value.
+ */
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub trait Messenger {
+    fn send(&self, msg: &str);
+}
+
+// Keeps an eye on a value against a maximum, and sends a warning through
+// whatever Messenger it was given as the value gets close to the limit.
+pub struct LimitTracker<'a, T: Messenger> {
+    messenger: &'a T,
+    value: usize,
+    max: usize,
+}
+
+impl<'a, T> LimitTracker<'a, T>
+where
+    T: Messenger,
+{
+    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+        LimitTracker { messenger, value: 0, max }
+    }
+
+    pub fn set_value(&mut self, value: usize) {
+        self.value = value;
+
+        let percentage_of_max = self.value as f64 / self.max as f64;
+
+        if percentage_of_max >= 1.0 {
+            self.messenger.send("Error: You are over your quota!");
+        } else if percentage_of_max >= 0.9 {
+            self.messenger.send("Urgent warning: You've used up over 90% of your quota!");
+        } else if percentage_of_max >= 0.75 {
+            self.messenger.send("Warning: You've used up over 75% of your quota!");
+        }
+    }
+}
+
+// The mock. Without the RefCell, `send` would need `&mut self` to push onto
+// the Vec, which would no longer match the trait. With it, `&self` is
+// enough, and borrow_mut() gets us write access on the inside.
+pub struct MockMessenger {
+    pub sent_messages: RefCell<Vec<String>>,
+}
+
+impl MockMessenger {
+    pub fn new() -> MockMessenger {
+        MockMessenger { sent_messages: RefCell::new(vec![]) }
+    }
+}
+
+impl Default for MockMessenger {
+    fn default() -> MockMessenger {
+        MockMessenger::new()
+    }
+}
+
+impl Messenger for MockMessenger {
+    fn send(&self, message: &str) {
+        self.sent_messages.borrow_mut().push(String::from(message));
+    }
+}
+
+// A real-ish messenger for the demo: it just prints.
+struct PrintMessenger;
+
+impl Messenger for PrintMessenger {
+    fn send(&self, message: &str) {
+        println!("  [message] {}", message);
+    }
+}
+
+// Rc<RefCell<T>>: one value, several owners, all of them allowed to change
+// it. Returns what each owner sees at the end.
+pub fn shared_mutation() -> (i32, i32, i32) {
+    let value = Rc::new(RefCell::new(5));
+    let a = Rc::clone(&value);
+    let b = Rc::clone(&value);
+
+    *a.borrow_mut() += 10;
+    *b.borrow_mut() *= 2;
+
+    let seen = (*value.borrow(), *a.borrow(), *b.borrow());
+    seen
+}
+
+pub fn demo_refcell() {
+    println!("--- RefCell Demonstration Begins --- ");
+    let printer = PrintMessenger;
+    let mut tracker = LimitTracker::new(&printer, 100);
+    for value in [50, 80, 95, 120].iter() {
+        println!("setting value to {}", value);
+        tracker.set_value(*value);
+    }
+
+    let mock = MockMessenger::new();
+    LimitTracker::new(&mock, 10).set_value(8);
+    println!("the mock recorded: {:?}", mock.sent_messages.borrow());
+
+    println!("every owner sees the same value: {:?}", shared_mutation());
+
+    // try_borrow_mut is the non-panicking version, which reports the clash
+    // as an Err instead (the tests show the panicking version)
+    let cell = RefCell::new(0);
+    let _held = cell.borrow();
+    println!("borrow_mut while borrowed: {:?}", cell.try_borrow_mut().map(|_| ()));
+    println!("--- RefCell Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_sends_an_over_75_percent_warning_message() {
+        let mock_messenger = MockMessenger::new();
+        let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+
+        limit_tracker.set_value(80);
+
+        assert_eq!(mock_messenger.sent_messages.borrow().len(), 1);
+    }
+
+    #[test]
+    fn each_threshold_sends_its_own_message() {
+        let mock = MockMessenger::new();
+        let mut tracker = LimitTracker::new(&mock, 100);
+        for value in [10, 75, 90, 100].iter() {
+            tracker.set_value(*value);
+        }
+        let sent = mock.sent_messages.borrow();
+        assert_eq!(sent.len(), 3);
+        assert!(sent[0].starts_with("Warning"));
+        assert!(sent[1].starts_with("Urgent"));
+        assert!(sent[2].starts_with("Error"));
+    }
+
+    #[test]
+    fn shared_owners_all_see_the_change() {
+        // (5 + 10) * 2, seen through all three handles
+        assert_eq!(shared_mutation(), (30, 30, 30));
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn two_mutable_borrows_panic_at_runtime() {
+        // Breaks the rules on purpose: two live mutable borrows of the same
+        // cell. This compiles just fine, and panics when it runs.
+        let cell = RefCell::new(vec![1, 2, 3]);
+        let mut first = cell.borrow_mut();
+        let mut second = cell.borrow_mut();
+        first.push(4);
+        second.push(5);
+    }
+
+    #[test]
+    fn shared_borrows_can_overlap() {
+        let cell = RefCell::new(String::from("hi"));
+        let one = cell.borrow();
+        let two = cell.borrow();
+        assert_eq!(*one, *two);
+        assert!(cell.try_borrow_mut().is_err());
+    }
+}
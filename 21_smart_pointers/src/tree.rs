@@ -0,0 +1,163 @@
+/**
+ * Weak<T>: pointing at something without keeping it alive.
+ *
+ * Rc has one big weakness: cycles. If a parent holds an Rc to its child,
+ * and the child holds an Rc back to its parent, neither count can ever
+ * reach zero, and both nodes leak. (Safe Rust promises no dangling
+ * pointers, but it does *not* promise no leaks!)
+ *
+ * The fix is to decide who owns whom. A parent owns its children (strong
+ * Rc links, going down), but a child merely *knows about* its parent (a
+ * Weak link, going up). Weak pointers bump a separate `weak_count`, which
+ * doesn't keep anything alive. To use one, call `upgrade()`, which gives
+ * back an Option<Rc<T>>: None if the value has already been dropped.
+ *
+ * Both link fields sit in a RefCell, so that nodes already shared through
+ * Rc can still have their family changed later.
+ */
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    pub parent: RefCell<Weak<Node>>,
+    pub children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        })
+    }
+
+    pub fn parent(&self) -> Option<Rc<Node>> {
+        self.parent.borrow().upgrade()
+    }
+
+    pub fn child_values(&self) -> Vec<i32> {
+        self.children.borrow().iter().map(|c| c.value).collect()
+    }
+}
+
+// Make `child` a child of `parent`: a strong link down, a weak link up.
+// A node only has one parent, so it leaves any old one first.
+pub fn attach(parent: &Rc<Node>, child: &Rc<Node>) {
+    if let Some(old) = child.parent() {
+        detach(&old, child);
+    }
+    parent.children.borrow_mut().push(Rc::clone(child));
+    *child.parent.borrow_mut() = Rc::downgrade(parent);
+}
+
+// Undo attach. Returns false if `child` wasn't one of `parent`'s children.
+pub fn detach(parent: &Rc<Node>, child: &Rc<Node>) -> bool {
+    let mut children = parent.children.borrow_mut();
+    match children.iter().position(|c| Rc::ptr_eq(c, child)) {
+        Some(i) => {
+            children.remove(i);
+            *child.parent.borrow_mut() = Weak::new();
+            true
+        }
+        None => false,
+    }
+}
+
+fn report(name: &str, node: &Rc<Node>) {
+    println!("{} strong = {}, weak = {}", name, Rc::strong_count(node), Rc::weak_count(node));
+}
+
+pub fn demo_tree() {
+    println!("--- Weak Tree Demonstration Begins --- ");
+    let leaf = Node::new(3);
+    report("leaf", &leaf);
+    println!("leaf parent = {:?}", leaf.parent().map(|p| p.value));
+
+    {
+        let branch = Node::new(5);
+        attach(&branch, &leaf);
+        println!("leaf parent = {:?}", leaf.parent().map(|p| p.value));
+        report("branch", &branch);
+        report("leaf", &leaf);
+    } // branch has no strong owners left, so it is dropped here
+
+    println!("leaf parent = {:?}", leaf.parent().map(|p| p.value));
+    report("leaf", &leaf);
+
+    let root = Node::new(1);
+    let (a, b) = (Node::new(2), Node::new(3));
+    attach(&root, &a);
+    attach(&root, &b);
+    attach(&a, &b); // b moves under a
+    println!("root's children: {:?}, a's children: {:?}",
+             root.child_values(), a.child_values());
+    println!("detached a from root? {}", detach(&root, &a));
+    println!("--- Weak Tree Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_follow_the_book() {
+        let leaf = Node::new(3);
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (1, 0));
+        {
+            let branch = Node::new(5);
+            attach(&branch, &leaf);
+            // branch: owned only by us, pointed at weakly by leaf
+            assert_eq!((Rc::strong_count(&branch), Rc::weak_count(&branch)), (1, 1));
+            // leaf: owned by us *and* by branch's children list
+            assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (2, 0));
+            assert_eq!(leaf.parent().unwrap().value, 5);
+        }
+        assert!(leaf.parent().is_none());
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (1, 0));
+    }
+
+    #[test]
+    fn detach_breaks_both_links() {
+        let parent = Node::new(1);
+        let child = Node::new(2);
+        attach(&parent, &child);
+        assert!(detach(&parent, &child));
+        assert!(parent.child_values().is_empty());
+        assert!(child.parent().is_none());
+        assert_eq!(Rc::strong_count(&child), 1);
+        // a second detach finds nothing to do
+        assert!(!detach(&parent, &child));
+    }
+
+    #[test]
+    fn reattaching_moves_the_child() {
+        let first = Node::new(1);
+        let second = Node::new(2);
+        let child = Node::new(3);
+        attach(&first, &child);
+        attach(&second, &child);
+        assert!(first.child_values().is_empty());
+        assert_eq!(second.child_values(), vec![3]);
+        assert_eq!(child.parent().unwrap().value, 2);
+        assert_eq!(Rc::strong_count(&child), 2);
+    }
+
+    #[test]
+    fn no_cycle_means_no_leak() {
+        // keep only Weak handles, then drop every strong one: if the
+        // parent/child links formed an Rc cycle, these would still upgrade
+        let (root_watch, child_watch) = {
+            let root = Node::new(1);
+            let child = Node::new(2);
+            let grandchild = Node::new(3);
+            attach(&root, &child);
+            attach(&child, &grandchild);
+            (Rc::downgrade(&root), Rc::downgrade(&grandchild))
+        };
+        assert!(root_watch.upgrade().is_none());
+        assert!(child_watch.upgrade().is_none());
+    }
+}
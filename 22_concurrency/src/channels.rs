@@ -0,0 +1,101 @@
+/**
+ * Channels: threads talking by sending each other values.
+ *
+ * `mpsc::channel()` gives back a pair: a transmitter `tx` and a receiver
+ * `rx`. mpsc stands for "multiple producer, single consumer": the tx end
+ * can be cloned as often as we like, but there's only ever one rx.
+ *
+ * Sending a value *moves* it into the channel, so the sender can't touch
+ * it afterwards, and there's no way for two threads to fight over it.
+ * The receiver can treat `rx` as an iterator, which keeps yielding values
+ * until every single tx has been dropped.
+ */
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// one producer, one message
+fn single_message() -> String {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let val = String::from("hi");
+        tx.send(val).unwrap();
+        // println!("val is {}", val); // error: borrow of moved value: `val`
+    });
+
+    // recv() blocks until a value arrives (or every sender is gone)
+    rx.recv().unwrap()
+}
+
+// One worker per job list, all sharing the same channel through cloned
+// transmitters. Each worker squares its numbers and sends back
+// (worker id, result) pairs as it goes. Results arrive in whatever order
+// the threads happen to run, so the caller gets them unsorted.
+pub fn square_workers(jobs: Vec<Vec<i32>>) -> Vec<(usize, i32)> {
+    let (tx, rx) = mpsc::channel();
+
+    for (id, job) in jobs.into_iter().enumerate() {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for n in job {
+                tx.send((id, n * n)).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+    }
+
+    // Drop our own original tx. Every worker holds a clone, so the channel
+    // closes when the last worker finishes. Forget this line and the loop
+    // below would wait forever for a message that never comes.
+    drop(tx);
+
+    rx.iter().collect()
+}
+
+pub fn demo_channels() {
+    println!("--- Channels Demonstration Begins --- ");
+    println!("Got: {}", single_message());
+
+    let jobs = vec![vec![1, 2, 3], vec![10, 20], vec![100]];
+    for (worker, result) in square_workers(jobs) {
+        println!("worker {} sent back {}", worker, result);
+    }
+    println!("--- Channels Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_message_arrives() {
+        assert_eq!(single_message(), "hi");
+    }
+
+    #[test]
+    fn collects_every_result_from_every_worker() {
+        let mut results = square_workers(vec![vec![1, 2, 3], vec![4], vec![], vec![5, 6]]);
+        results.sort();
+        assert_eq!(results, vec![(0, 1), (0, 4), (0, 9), (1, 16), (3, 25), (3, 36)]);
+    }
+
+    #[test]
+    fn each_worker_keeps_its_own_order() {
+        // across workers the order is anyone's guess, but one sender's
+        // messages always come out in the order it sent them
+        let results = square_workers(vec![(1..=20).collect(), (21..=40).collect()]);
+        for worker in 0..2 {
+            let mine: Vec<i32> = results.iter().filter(|r| r.0 == worker).map(|r| r.1).collect();
+            let mut sorted = mine.clone();
+            sorted.sort();
+            assert_eq!(mine.len(), 20);
+            assert_eq!(mine, sorted);
+        }
+    }
+
+    #[test]
+    fn no_workers_means_no_results() {
+        assert!(square_workers(vec![]).is_empty());
+    }
+}
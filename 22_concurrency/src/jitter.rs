@@ -0,0 +1,96 @@
+/**
+ * Shaking threads up, to test them: a repeatable way to make threads give
+ * up their turn at awkward moments.
+ *
+ * A race that loses one increment in a million runs is no use in a test
+ * that runs ten times. What helps is making threads switch over *more
+ * often*, and especially in the middle of something: between reading a
+ * counter and writing it back, say. `thread::yield_now()` does that, by
+ * telling the OS "let someone else run now, if anyone's waiting".
+ *
+ * Each thread gets its own `Pauses` from a seeded `Jitter`, and asks it
+ * `maybe_yield()` wherever a switch would be most awkward. Which calls
+ * yield is decided by the seed, so a failing seed yields in the same places
+ * again. (What the OS does with the yields isn't ours to fix, so a seed
+ * makes a failure much more likely to come back, not certain.)
+ *
+ * `Jitter::NONE` never yields at all, which is what the demos use.
+ */
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jitter {
+    seed: u64,
+    // yield on about one call in this many (0 for never)
+    one_in: u64,
+}
+
+impl Jitter {
+    pub const NONE: Jitter = Jitter { seed: 0, one_in: 0 };
+
+    // (only the tests ever want any)
+    #[cfg(test)]
+    pub fn new(seed: u64, one_in: u64) -> Jitter {
+        Jitter { seed, one_in }
+    }
+
+    // the pauses for thread number `index`, different for every thread
+    pub fn pauses(&self, index: usize) -> Pauses {
+        // splitmix64's mixing step, so that seeds 1 and 2 (and threads 1
+        // and 2) start nowhere near each other
+        let mut state = self.seed ^ (index as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Pauses { state: state | 1, one_in: self.one_in }
+    }
+}
+
+pub struct Pauses {
+    state: u64,
+    one_in: u64,
+}
+
+impl Pauses {
+    // xorshift: a few shifts and xors make the next pseudo-random number
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    // Yield (or not), and say which
+    pub fn maybe_yield(&mut self) -> bool {
+        if self.one_in == 0 || !self.next().is_multiple_of(self.one_in) {
+            return false;
+        }
+        thread::yield_now();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decisions(jitter: Jitter, index: usize) -> Vec<bool> {
+        let mut pauses = jitter.pauses(index);
+        (0..200).map(|_| pauses.maybe_yield()).collect()
+    }
+
+    #[test]
+    fn the_same_seed_yields_in_the_same_places() {
+        let jitter = Jitter::new(7, 3);
+        assert_eq!(decisions(jitter, 0), decisions(jitter, 0));
+        assert_ne!(decisions(jitter, 0), decisions(jitter, 1));
+        assert_ne!(decisions(jitter, 0), decisions(Jitter::new(8, 3), 0));
+    }
+
+    #[test]
+    fn about_one_in_n() {
+        let yields = decisions(Jitter::new(1, 4), 0).iter().filter(|&&y| y).count();
+        assert!((25..=75).contains(&yields), "{} of 200", yields);
+        assert!(decisions(Jitter::NONE, 0).iter().all(|&y| !y));
+        assert!(decisions(Jitter::new(1, 1), 0).iter().all(|&y| y));
+    }
+}
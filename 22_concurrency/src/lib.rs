@@ -0,0 +1,64 @@
+/**
+ * Fearless concurrency!
+ *
+ * Running several things at once has always been a minefield: two threads
+ * writing the same data (a race condition), two threads each waiting for
+ * the other (a deadlock), and bugs that show up once in a thousand runs.
+ *
+ * Rust's claim is that the same ownership and type rules we've been
+ * fighting with all along turn most of those bugs into *compile errors*.
+ * A thread can't borrow data that might die before it does, and data can
+ * only be shared between threads if its type says that's safe.
+ *
+ * Two styles get covered here:
+ *
+ * - message passing: threads own their data and send it to each other
+ *   over channels ("share memory by communicating"), even in a pipeline
+ *   of them
+ * - shared state: several threads, one piece of data, and a lock
+ *
+ * And scoped threads, which can borrow the caller's data outright, and put
+ * to work in ./parallel.rs on one big Vec.
+ */
+mod threads;
+mod channels;
+mod pipeline;
+mod shared_state;
+mod scoped;
+// seeded thread switching, for the tests (see shared_state.rs and pipeline.rs)
+mod jitter;
+// pub, so that the benches package can time it against 15_traits' largest
+pub mod parallel;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("threads", "spawn, join and move", threads::demo_threads),
+    Demo::new("channels", "mpsc channels, with one producer and then several", channels::demo_channels),
+    Demo::new("pipeline", "three stages joined by bounded sync_channels", pipeline::demo_pipeline),
+    Demo::new("shared_state", "Arc<Mutex<T>>, and atomics", shared_state::demo_shared_state),
+    Demo::new("scoped", "thread::scope: workers borrowing a local Vec", scoped::demo_scoped),
+    Demo::new("parallel", "largest and sum, split over several threads", parallel::demo_parallel),
+];
+
+pub fn demo() {
+
+    // spawn, join and move
+    threads::demo_threads();
+
+    // mpsc channels, with one producer and then several
+    channels::demo_channels();
+
+    // generator -> transformer -> aggregator, with backpressure
+    pipeline::demo_pipeline();
+
+    // Arc<Mutex<T>>, and atomics as a lock-free alternative
+    shared_state::demo_shared_state();
+
+    // thread::scope, and threads that borrow instead of owning
+    scoped::demo_scoped();
+
+    // the same trick on a million numbers, and whether it pays
+    parallel::demo_parallel();
+}
@@ -0,0 +1,131 @@
+/**
+ * Splitting one big job over every core: `largest` and a sum, in parallel.
+ *
+ * The recipe is the one from ./scoped.rs, with a second step on the end:
+ *
+ *      [ ........ one big slice ........ ]
+ *      [ chunk ][ chunk ][ chunk ][ chunk ]    one scoped thread each
+ *          |        |        |        |
+ *         p1       p2       p3       p4        a partial answer each
+ *          \_______\________/_______/
+ *                      |
+ *                   answer                     and the same job, on the parts
+ *
+ * That last step only works because largest and sum don't care how the
+ * input is grouped: the largest of the chunks' largests is the largest of
+ * the lot, and likewise for sums. (An average, for one, would need more
+ * care.) Each chunk gets the very same `largest` from 15_traits, so the
+ * only thing new here is the fanning out.
+ *
+ * Threads aren't free. Starting one costs some microseconds, which buys a
+ * lot of comparisons, so for small slices the single-threaded version
+ * wins outright. `cargo bench -p benches` has the numbers for a big one.
+ */
+use crate::scoped::chunk_size;
+use common::time;
+use std::iter::Sum;
+use std::thread;
+use traits_lib::largest;
+
+// as many threads as the machine has cores (or 1, if it won't say)
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// Run `job` on each chunk on a thread of its own, and hand back the partial
+// answers in chunk order. `job` is shared by all the threads, so it has to
+// be Sync, and the answers cross back to this thread, so they must be Send.
+fn per_chunk<T, U, F>(list: &[T], threads: usize, job: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&[T]) -> U + Sync,
+{
+    let job = &job;
+    thread::scope(|s| {
+        let handles: Vec<thread::ScopedJoinHandle<U>> = list
+            .chunks(chunk_size(list.len(), threads))
+            .map(|chunk| s.spawn(move || job(chunk)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+// Like `largest`, this panics on an empty slice: there's no answer to give
+pub fn par_largest<T: PartialOrd + Copy + Send + Sync>(list: &[T]) -> T {
+    par_largest_on(list, available_threads())
+}
+
+pub fn par_largest_on<T: PartialOrd + Copy + Send + Sync>(list: &[T], threads: usize) -> T {
+    largest(&per_chunk(list, threads, largest))
+}
+
+pub fn par_sum<T: Copy + Send + Sync + Sum>(list: &[T]) -> T {
+    par_sum_on(list, available_threads())
+}
+
+pub fn par_sum_on<T: Copy + Send + Sync + Sum>(list: &[T], threads: usize) -> T {
+    per_chunk(list, threads, |chunk| chunk.iter().copied().sum()).into_iter().sum()
+}
+
+// a million numbers in a scrambled (but repeatable) order
+pub fn big_list() -> Vec<u64> {
+    (0..1_000_000).map(|i| i * 7_919 % 1_000_003).collect()
+}
+
+pub fn demo_parallel() {
+    println!("--- Parallel Demonstration Begins --- ");
+    let numbers = big_list();
+
+    let (one, elapsed) = time(|| largest(&numbers));
+    println!("largest on one thread: {} in {:.2?}", one, elapsed);
+    let (four, elapsed) = time(|| par_largest_on(&numbers, 4));
+    println!("largest on four threads: {} in {:.2?}", four, elapsed);
+
+    let (one, elapsed) = time(|| numbers.iter().sum::<u64>());
+    println!("sum on one thread: {} in {:.2?}", one, elapsed);
+    let (four, elapsed) = time(|| par_sum_on(&numbers, 4));
+    println!("sum on four threads: {} in {:.2?}", four, elapsed);
+    println!("--- Parallel Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_largest_matches_largest() {
+        let numbers = big_list();
+        let expected = largest(&numbers);
+        for threads in [1, 2, 3, 7, 16] {
+            assert_eq!(par_largest_on(&numbers, threads), expected, "with {} threads", threads);
+        }
+        assert_eq!(par_largest(&numbers), expected);
+        // and for any PartialOrd + Copy, as with largest
+        assert_eq!(par_largest(&['y', 'm', 'a', 'q']), 'y');
+        assert_eq!(par_largest_on(&[0.5, -1.0, 2.5, 2.0], 3), 2.5);
+    }
+
+    #[test]
+    fn par_sum_matches_sum() {
+        let numbers = big_list();
+        let expected: u64 = numbers.iter().sum();
+        for threads in [1, 2, 3, 7, 16] {
+            assert_eq!(par_sum_on(&numbers, threads), expected, "with {} threads", threads);
+        }
+        assert_eq!(par_sum::<i32>(&[]), 0);
+        assert_eq!(par_sum_on(&[-3, 3, 9], 0), 9);
+    }
+
+    #[test]
+    fn more_threads_than_items() {
+        assert_eq!(par_largest_on(&[4, 9, 2], 10), 9);
+        assert_eq!(par_sum_on(&[4, 9, 2], 10), 15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn par_largest_of_nothing() {
+        par_largest::<i32>(&[]);
+    }
+}
@@ -0,0 +1,209 @@
+/**
+ * A pipeline: three threads in a row, joined by channels.
+ *
+ *      generator --> transformer --> aggregator
+ *      1, 2, 3...    squares each    adds them up
+ *
+ * Each stage owns the receiving end of the channel before it and the
+ * sending end of the one after, and knows nothing else about the others.
+ * When the generator runs out, it drops its sender; that ends the
+ * transformer's `for` loop, which drops *its* sender, and so on down the
+ * line, so the whole pipeline shuts itself down in order.
+ *
+ * The channels are `mpsc::sync_channel(capacity)` rather than the
+ * `channel()` of ./channels.rs. Those have no limit: a fast generator and a
+ * slow aggregator would pile up every number in between in memory. A sync
+ * channel holds at most `capacity` values, and `send` *blocks* once it's
+ * full, until the next stage takes one. That's backpressure: the slowest
+ * stage sets the pace for everyone upstream. (With a capacity of 0, every
+ * send waits for its receive, a "rendezvous".)
+ */
+use crate::jitter::{Jitter, Pauses};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Arc;
+use std::thread;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub sum: u64,
+    pub largest: Option<u64>,
+    // the most numbers that were ever somewhere between the generator and
+    // the aggregator at once, which backpressure keeps small
+    pub most_in_flight: usize,
+    // whether every square arrived after the one before it: channels are
+    // first in, first out, so with one thread per stage they always should
+    pub in_order: bool,
+}
+
+// Counts the numbers in the pipeline: up when the generator is about to
+// send one, down when the aggregator has received one. (Counting up *after*
+// the send would race the aggregator, which can count a number off before
+// the generator has counted it on.)
+#[derive(Default)]
+struct InFlight {
+    now: AtomicUsize,
+    most: AtomicUsize,
+}
+
+impl InFlight {
+    fn sent(&self) {
+        let now = self.now.fetch_add(1, Ordering::SeqCst) + 1;
+        self.most.fetch_max(now, Ordering::SeqCst);
+    }
+
+    fn received(&self) {
+        self.now.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// (each stage takes a chance to be switched out between steps, for the
+// tests: see ./jitter.rs)
+fn generator(limit: u64, tx: SyncSender<u64>, in_flight: Arc<InFlight>, mut pauses: Pauses) {
+    for n in 1..=limit {
+        pauses.maybe_yield();
+        in_flight.sent();
+        // blocks here whenever the transformer has fallen behind
+        tx.send(n).unwrap();
+    }
+}
+
+fn transformer(rx: Receiver<u64>, tx: SyncSender<u64>, mut pauses: Pauses) {
+    for n in rx {
+        pauses.maybe_yield();
+        tx.send(n * n).unwrap();
+    }
+}
+
+fn aggregator(rx: Receiver<u64>, in_flight: Arc<InFlight>, mut pauses: Pauses) -> Summary {
+    let mut summary = Summary { count: 0, sum: 0, largest: None, most_in_flight: 0, in_order: true };
+    for n in rx {
+        in_flight.received();
+        pauses.maybe_yield();
+        // squares of 1, 2, 3... only ever go up
+        if summary.largest.is_some_and(|largest| n <= largest) {
+            summary.in_order = false;
+        }
+        summary.count += 1;
+        summary.sum += n;
+        summary.largest = summary.largest.max(Some(n));
+    }
+    summary.most_in_flight = in_flight.most.load(Ordering::SeqCst);
+    summary
+}
+
+// The sum of the squares of 1..=limit, worked out by the three stages,
+// with `capacity` slots in each of the two channels between them
+pub fn run(limit: u64, capacity: usize) -> Summary {
+    run_jittered(limit, capacity, Jitter::NONE)
+}
+
+pub(crate) fn run_jittered(limit: u64, capacity: usize, jitter: Jitter) -> Summary {
+    let (numbers_tx, numbers_rx) = mpsc::sync_channel(capacity);
+    let (squares_tx, squares_rx) = mpsc::sync_channel(capacity);
+    let in_flight = Arc::new(InFlight::default());
+
+    let counter = Arc::clone(&in_flight);
+    let (first, second, third) = (jitter.pauses(0), jitter.pauses(1), jitter.pauses(2));
+    let generating = thread::spawn(move || generator(limit, numbers_tx, counter, first));
+    let transforming = thread::spawn(move || transformer(numbers_rx, squares_tx, second));
+    let aggregating = thread::spawn(move || aggregator(squares_rx, in_flight, third));
+
+    generating.join().unwrap();
+    transforming.join().unwrap();
+    aggregating.join().unwrap()
+}
+
+// How many numbers can be in flight at once: one the generator is waiting
+// to send, a full channel either side of the transformer, one in the
+// transformer's hands, and one the aggregator has received but not yet
+// counted off
+pub fn in_flight_bound(capacity: usize) -> usize {
+    2 * capacity + 3
+}
+
+pub fn demo_pipeline() {
+    println!("--- Pipeline Demonstration Begins --- ");
+    let summary = run(100, 4);
+    println!(
+        "squares of 1..=100: {} of them, adding up to {}, the largest {:?}",
+        summary.count, summary.sum, summary.largest
+    );
+    println!(
+        "never more than {} in flight, with 4 slots per channel: {}",
+        in_flight_bound(4),
+        summary.most_in_flight <= in_flight_bound(4)
+    );
+    println!("--- Pipeline Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_final_aggregate() {
+        let summary = run(100, 4);
+        assert_eq!(summary.count, 100);
+        // 1² + 2² + ... + n² = n(n + 1)(2n + 1) / 6
+        assert_eq!(summary.sum, 100 * 101 * 201 / 6);
+        assert_eq!(summary.largest, Some(10_000));
+    }
+
+    #[test]
+    fn any_capacity_gets_the_same_answer() {
+        let expected = run(500, 16);
+        for capacity in [0, 1, 2, 100, 1_000] {
+            let summary = run(500, capacity);
+            assert_eq!((summary.count, summary.sum, summary.largest), (expected.count, expected.sum, expected.largest));
+        }
+    }
+
+    #[test]
+    fn backpressure_bounds_what_is_in_flight() {
+        for capacity in [0, 1, 3] {
+            let summary = run(2_000, capacity);
+            assert!(
+                summary.most_in_flight <= in_flight_bound(capacity),
+                "{} in flight with a capacity of {}",
+                summary.most_in_flight,
+                capacity
+            );
+        }
+    }
+
+    #[test]
+    fn nothing_in_nothing_out() {
+        assert_eq!(run(0, 2), Summary { count: 0, sum: 0, largest: None, most_in_flight: 0, in_order: true });
+    }
+
+    // whatever order the stages happen to run in, the answer, the order
+    // and the bound all hold
+    #[test]
+    fn ordered_aggregation_under_jitter() {
+        let expected = 300 * 301 * 601 / 6;
+        for seed in 0..10 {
+            for capacity in [0, 1, 4] {
+                let summary = run_jittered(300, capacity, Jitter::new(seed, 3));
+                assert!(summary.in_order, "seed {}, capacity {}", seed, capacity);
+                assert_eq!((summary.count, summary.sum), (300, expected));
+                assert!(summary.most_in_flight <= in_flight_bound(capacity));
+            }
+        }
+    }
+
+    // much longer: `cargo test -p concurrency -- --ignored`
+    #[test]
+    #[ignore]
+    fn stress_ordered_aggregation() {
+        for seed in 0..300 {
+            for capacity in [0, 1, 2, 8, 64] {
+                let summary = run_jittered(5_000, capacity, Jitter::new(seed, 2));
+                assert!(summary.in_order, "seed {}, capacity {}", seed, capacity);
+                assert_eq!(summary.count, 5_000);
+                assert!(summary.most_in_flight <= in_flight_bound(capacity), "seed {}, capacity {}", seed, capacity);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/**
+ * Scoped threads: borrowing from the stack, with no Arc and no move.
+ *
+ * `thread::spawn` insists on `'static` closures, because a spawned thread
+ * may outlive the function that started it. That's why parallel_sum in
+ * ./threads.rs copies every chunk into a Vec of its own before handing it
+ * over. `thread::scope` makes a narrower promise instead: every thread
+ * spawned inside the scope is joined before `scope` returns. So the
+ * compiler knows the threads can't outlive anything declared *outside* the
+ * scope, and they're allowed to borrow it, even mutably:
+ *
+ *      let mut v = vec![...];
+ *      thread::scope(|s| {
+ *          s.spawn(|| ...reads &v...);    // borrows, doesn't move
+ *      });                                // every thread joined here
+ *      v.push(...);                       // and v is still ours
+ *
+ * The borrow rules still hold across threads: any number of threads can
+ * share `&v`, or each can have `&mut` to its own piece (chunks_mut hands
+ * out pieces that don't overlap), but never both at once.
+ */
+use std::thread;
+
+// the plain, one-thread answer, to check the others against
+pub fn sequential_sum(v: &[i64]) -> i64 {
+    v.iter().sum()
+}
+
+// the chunk size that splits `len` items over `threads` threads (at least 1)
+pub(crate) fn chunk_size(len: usize, threads: usize) -> usize {
+    len.div_ceil(threads.max(1)).max(1)
+}
+
+// One thread per chunk, each one *borrowing* its chunk of `v`: no copies.
+// The handles come back in chunk order, so the partial sums do too.
+pub fn chunked_sums(v: &[i64], threads: usize) -> Vec<i64> {
+    thread::scope(|s| {
+        let handles: Vec<thread::ScopedJoinHandle<i64>> = v
+            .chunks(chunk_size(v.len(), threads))
+            .map(|chunk| s.spawn(move || chunk.iter().sum()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+pub fn scoped_sum(v: &[i64], threads: usize) -> i64 {
+    chunked_sums(v, threads).iter().sum()
+}
+
+// The same fan-out with `&mut`: each thread gets a chunk of its own to
+// change, and after the scope, `v` is back in the caller's hands.
+pub fn scale_in_place(v: &mut [i64], factor: i64, threads: usize) {
+    let size = chunk_size(v.len(), threads);
+    thread::scope(|s| {
+        for chunk in v.chunks_mut(size) {
+            s.spawn(move || {
+                for x in chunk.iter_mut() {
+                    *x *= factor;
+                }
+            });
+        }
+    });
+}
+
+pub fn demo_scoped() {
+    println!("--- Scoped Threads Demonstration Begins --- ");
+    let mut numbers: Vec<i64> = (1..=100).collect();
+    println!("partial sums from 4 borrowing threads: {:?}", chunked_sums(&numbers, 4));
+    println!("scoped: {}, sequential: {}", scoped_sum(&numbers, 4), sequential_sum(&numbers));
+
+    scale_in_place(&mut numbers, 10, 4);
+    // still ours: nothing was moved into the threads
+    numbers.push(0);
+    println!("after scaling by 10 on 4 threads: {:?} ... {:?}", &numbers[..3], &numbers[98..]);
+    println!("--- Scoped Threads Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoped_matches_sequential() {
+        let numbers: Vec<i64> = (-500..=1000).collect();
+        for threads in 1..=9 {
+            assert_eq!(scoped_sum(&numbers, threads), sequential_sum(&numbers), "with {} threads", threads);
+        }
+    }
+
+    #[test]
+    fn one_partial_sum_per_chunk_in_order() {
+        let numbers: Vec<i64> = (1..=10).collect();
+        assert_eq!(chunked_sums(&numbers, 2), [15, 40]);
+        assert_eq!(chunked_sums(&numbers, 3), [10, 26, 19]);
+        // more threads than items: one item each, and no empty chunks
+        assert_eq!(chunked_sums(&[7, 8], 5), [7, 8]);
+    }
+
+    #[test]
+    fn nothing_to_sum() {
+        assert_eq!(scoped_sum(&[], 4), 0);
+        assert!(chunked_sums(&[], 4).is_empty());
+        assert_eq!(scoped_sum(&[3, 4], 0), 7);
+    }
+
+    #[test]
+    fn scaling_in_place_matches_a_plain_loop() {
+        let mut numbers: Vec<i64> = (0..37).collect();
+        let expected: Vec<i64> = numbers.iter().map(|x| x * -3).collect();
+        scale_in_place(&mut numbers, -3, 4);
+        assert_eq!(numbers, expected);
+        scale_in_place(&mut [], 2, 4);
+    }
+}
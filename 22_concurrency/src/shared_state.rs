@@ -0,0 +1,151 @@
+/**
+ * Shared-state concurrency: one counter, ten threads.
+ *
+ * A Mutex ("mutual exclusion") guards some data so only one thread at a
+ * time can get at it. `lock()` blocks until the mutex is free, and hands
+ * back a MutexGuard: a smart pointer to the data that unlocks the mutex
+ * when it's dropped. So forgetting to unlock is impossible.
+ *
+ * To give every thread the *same* Mutex we need several owners, which
+ * sounds like a job for Rc. But Rc's count isn't updated safely across
+ * threads, and the compiler knows it: "`Rc<Mutex<i32>>` cannot be sent
+ * between threads safely". Arc is the atomic (thread-safe) version of Rc,
+ * at a small speed cost, so Arc<Mutex<T>> is the classic combination.
+ *
+ * For a plain number there's a lighter option: the atomic integer types,
+ * which update themselves safely without any lock at all.
+ */
+use crate::jitter::Jitter;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// The book's version: `threads` threads, each adding `per_thread` to a
+// shared counter one step at a time.
+pub fn mutex_counter(threads: usize, per_thread: usize) -> i32 {
+    mutex_counter_jittered(threads, per_thread, Jitter::NONE)
+}
+
+// The same, for the tests, with the increment spelled out as the read and
+// the write it really is, and a chance (see ./jitter.rs) for the thread to
+// be switched out in between. The lock is held across both, so whoever
+// runs next has to wait, and no increment can be lost.
+pub(crate) fn mutex_counter_jittered(threads: usize, per_thread: usize, jitter: Jitter) -> i32 {
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for index in 0..threads {
+        let counter = Arc::clone(&counter);
+        let mut pauses = jitter.pauses(index);
+        let handle = thread::spawn(move || {
+            for _ in 0..per_thread {
+                // the guard lives until the end of this block, so the lock
+                // is held for just the one increment
+                let mut count = counter.lock().unwrap();
+                let read = *count;
+                pauses.maybe_yield();
+                *count = read + 1;
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    total
+}
+
+// The same job with an AtomicUsize. fetch_add is a single indivisible
+// read-add-write, so no lock (and no Mutex) is needed. `Ordering::SeqCst`
+// is the strictest and simplest memory ordering; for a lone counter the
+// looser `Relaxed` would do, but that's a rabbit hole for another day.
+pub fn atomic_counter(threads: usize, per_thread: usize) -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let handles: Vec<thread::JoinHandle<()>> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..per_thread {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    counter.load(Ordering::SeqCst)
+}
+
+pub fn demo_shared_state() {
+    println!("--- Shared State Demonstration Begins --- ");
+
+    // a Mutex on a single thread, to see the API on its own
+    let m = Mutex::new(5);
+    {
+        let mut num = m.lock().unwrap();
+        *num = 6;
+    } // guard dropped, lock released
+    println!("m = {:?}", m);
+
+    println!("Mutex result from 10 threads: {}", mutex_counter(10, 1));
+    println!("Atomic result from 10 threads: {}", atomic_counter(10, 1));
+    println!("And with 1000 increments each: {} vs {}",
+             mutex_counter(10, 1000), atomic_counter(10, 1000));
+    println!("--- Shared State Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ten_threads_count_to_ten() {
+        assert_eq!(mutex_counter(10, 1), 10);
+        assert_eq!(atomic_counter(10, 1), 10);
+    }
+
+    #[test]
+    fn mutex_and_atomic_agree() {
+        for &(threads, per_thread) in [(1, 1), (10, 100), (4, 2500), (16, 50)].iter() {
+            let expected = threads * per_thread;
+            assert_eq!(mutex_counter(threads, per_thread) as usize, expected);
+            assert_eq!(atomic_counter(threads, per_thread), expected);
+        }
+    }
+
+    #[test]
+    fn no_threads_no_count() {
+        assert_eq!(mutex_counter(0, 10), 0);
+        assert_eq!(atomic_counter(0, 10), 0);
+    }
+
+    // Many runs, with different numbers of threads and different places
+    // for them to be interrupted: not one increment may go missing
+    #[test]
+    fn no_lost_increments_under_jitter() {
+        for seed in 0..10 {
+            for &threads in [1, 2, 3, 8].iter() {
+                let total = mutex_counter_jittered(threads, 200, Jitter::new(seed, 3));
+                assert_eq!(total as usize, threads * 200, "seed {}, {} threads", seed, threads);
+            }
+        }
+    }
+
+    // The same, for much longer: `cargo test -p concurrency -- --ignored`
+    #[test]
+    #[ignore]
+    fn stress_no_lost_increments() {
+        for seed in 0..500 {
+            for &threads in [2, 5, 16, 32].iter() {
+                let total = mutex_counter_jittered(threads, 1_000, Jitter::new(seed, 2));
+                assert_eq!(total as usize, threads * 1_000, "seed {}, {} threads", seed, threads);
+            }
+        }
+    }
+}
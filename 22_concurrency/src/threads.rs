@@ -0,0 +1,81 @@
+/**
+ * Spawning threads, and waiting for them to finish.
+ *
+ * `thread::spawn` takes a closure and runs it on a brand new OS thread. It
+ * hands back a JoinHandle right away, and `join()` on that handle blocks
+ * until the thread is done, giving us its return value. If main returns
+ * first, every other thread is shut down mid-sentence, so joining matters.
+ */
+use std::thread;
+use std::time::Duration;
+
+// Interleaved output: the two loops take turns, in whatever order the OS
+// scheduler likes. Run it a few times and the pattern changes.
+fn interleave() {
+    let handle = thread::spawn(|| {
+        for i in 1..5 {
+            println!("hi number {} from the spawned thread!", i);
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    for i in 1..3 {
+        println!("hi number {} from the main thread!", i);
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    // comment this out and the spawned thread may never get to 4
+    handle.join().unwrap();
+}
+
+// `move` hands `v` over to the thread. Without it, the closure would only
+// borrow `v`, and the compiler can't be sure `v` outlives the thread:
+// "closure may outlive the current function, but it borrows `v`".
+pub fn sum_on_thread(v: Vec<i32>) -> i32 {
+    let handle = thread::spawn(move || v.iter().sum());
+    handle.join().unwrap()
+}
+
+// Fan out: one thread per chunk, then join them all and add up the parts.
+// Each thread owns its own chunk, so there's nothing to share or lock.
+pub fn parallel_sum(v: &[i32], threads: usize) -> i32 {
+    let size = v.len().div_ceil(threads.max(1)).max(1);
+    let handles: Vec<thread::JoinHandle<i32>> = v
+        .chunks(size)
+        .map(|chunk| {
+            let owned = chunk.to_vec();
+            thread::spawn(move || owned.iter().sum())
+        })
+        .collect();
+    handles.into_iter().map(|h| h.join().unwrap()).sum()
+}
+
+pub fn demo_threads() {
+    println!("--- Threads Demonstration Begins --- ");
+    interleave();
+    println!("sum computed on another thread: {}", sum_on_thread(vec![1, 2, 3]));
+    let numbers: Vec<i32> = (1..=100).collect();
+    println!("1..=100 summed by 4 threads: {}", parallel_sum(&numbers, 4));
+    println!("--- Threads Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_returns_the_threads_value() {
+        assert_eq!(sum_on_thread(vec![10, 20, 30]), 60);
+        assert_eq!(sum_on_thread(vec![]), 0);
+    }
+
+    #[test]
+    fn parallel_sum_matches_a_plain_sum() {
+        let numbers: Vec<i32> = (1..=1000).collect();
+        for threads in 1..=8 {
+            assert_eq!(parallel_sum(&numbers, threads), 500_500);
+        }
+        assert_eq!(parallel_sum(&[], 3), 0);
+        assert_eq!(parallel_sum(&[5], 0), 5);
+    }
+}
@@ -0,0 +1,37 @@
+/**
+ * The demo's whole output, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ *
+ * Except for one thing: the order. Threads take turns however the OS
+ * schedules them, which is the very point of several of these demos, so
+ * each demonstration's lines are sorted before comparing. That still
+ * catches a line that changed, went missing or showed up twice.
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+// sort the lines between each "Begins" banner and its "Finish"
+fn sort_sections(output: &str) -> String {
+    let mut sorted = Vec::new();
+    let mut section: Vec<&str> = Vec::new();
+    for line in output.lines() {
+        if line.contains("Demonstration Finish") {
+            section.sort_unstable();
+            sorted.append(&mut section);
+        }
+        if line.contains("Demonstration Begins") || line.contains("Demonstration Finish") {
+            sorted.push(line);
+        } else {
+            section.push(line);
+        }
+    }
+    sorted.append(&mut section);
+    sorted.join("\n") + "\n"
+}
+
+#[test]
+fn demo() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_concurrency"), DIR), "");
+    golden::check(DIR, "demo", &sort_sections(&output));
+}
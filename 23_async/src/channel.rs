@@ -0,0 +1,144 @@
+/**
+ * An async channel: like mpsc, but `recv` is a future instead of blocking.
+ *
+ * mpsc's `rx.recv()` puts the whole thread to sleep until a message comes.
+ * In async code that would freeze every other task on the executor, so
+ * here receiving returns a future. If the queue is empty, the receiver
+ * leaves its Waker in the channel, and the next `send` (or the last Sender
+ * going away) wakes it up.
+ *
+ * Everything shares one Arc<Mutex<..>>: the queue of messages, the waiting
+ * receiver's Waker, and a count of live Senders so we know when to stop.
+ */
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    senders: usize,
+}
+
+pub struct Sender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared { queue: VecDeque::new(), waker: None, senders: 1 }));
+    (Sender { shared: Arc::clone(&shared) }, Receiver { shared })
+}
+
+impl<T> Sender<T> {
+    // The queue is unbounded, so sending never has to wait, and doesn't
+    // need to be async at all.
+    pub fn send(&self, value: T) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.queue.push_back(value);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+// cloning a Sender adds a producer...
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Sender<T> {
+        self.shared.lock().unwrap().senders += 1;
+        Sender { shared: Arc::clone(&self.shared) }
+    }
+}
+
+// ...and dropping one takes it away. When the last one goes, wake the
+// receiver so it can find out that nothing more is coming.
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.senders -= 1;
+        if shared.senders == 0 {
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    // Some(message), or None once the queue is empty and every Sender is
+    // gone: the same contract as mpsc's iterator.
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv { receiver: self }
+    }
+}
+
+pub struct Recv<'a, T> {
+    receiver: &'a mut Receiver<T>,
+}
+
+impl<T> Future for Recv<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut shared = self.receiver.shared.lock().unwrap();
+        if let Some(value) = shared.queue.pop_front() {
+            Poll::Ready(Some(value))
+        } else if shared.senders == 0 {
+            Poll::Ready(None)
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::block_on;
+    use crate::join::join;
+    use crate::timer::sleep_ms;
+
+    #[test]
+    fn queued_messages_come_out_in_order() {
+        let (tx, mut rx) = channel();
+        tx.send(1);
+        tx.send(2);
+        drop(tx);
+        let got = block_on(async {
+            let mut got = vec![];
+            while let Some(n) = rx.recv().await {
+                got.push(n);
+            }
+            got
+        });
+        assert_eq!(got, vec![1, 2]);
+    }
+
+    #[test]
+    fn receiver_waits_for_a_slow_sender() {
+        let (tx, mut rx) = channel();
+        let producer = async move {
+            sleep_ms(30).await;
+            tx.send("late");
+        };
+        let (_, got) = block_on(join(producer, rx.recv()));
+        assert_eq!(got, Some("late"));
+    }
+
+    #[test]
+    fn ends_when_every_clone_is_dropped() {
+        let (tx, mut rx) = channel::<u8>();
+        let tx2 = tx.clone();
+        drop(tx);
+        tx2.send(9);
+        drop(tx2);
+        assert_eq!(block_on(rx.recv()), Some(9));
+        assert_eq!(block_on(rx.recv()), None);
+    }
+}
@@ -0,0 +1,93 @@
+/**
+ * The world's smallest executor.
+ *
+ * A Future has one method, `poll`, which either returns Ready(value) or
+ * Pending. When it returns Pending, the future promises to call `wake()`
+ * on the Waker it was given once it's worth polling again.
+ *
+ * So block_on is a loop: poll the future; if it's Pending, put this thread
+ * to sleep (`thread::park`) until the Waker unparks it; then poll again.
+ * Parking can wake up spuriously now and then, which is harmless: we just
+ * poll once more and go back to sleep.
+ */
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+// Waking up = unparking the thread that is sitting in block_on
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    // a future may not move once it has been polled (it might hold
+    // pointers into itself), so we pin it in place on the heap first
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => return value,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+async fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// async fns can await other async fns, all the way down
+async fn add_three(a: i32, b: i32, c: i32) -> i32 {
+    let ab = add(a, b).await;
+    add(ab, c).await
+}
+
+pub fn demo_executor() {
+    println!("--- Executor Demonstration Begins --- ");
+    // calling an async fn does nothing yet...
+    let future = add_three(1, 2, 3);
+    println!("made a future, nothing has run so far");
+    // ...until an executor polls it
+    println!("block_on gave back {}", block_on(future));
+    // async blocks make futures too
+    let greeting = block_on(async { format!("hello from an {} block", "async") });
+    println!("{}", greeting);
+    println!("--- Executor Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn runs_a_ready_future() {
+        assert_eq!(block_on(async { 42 }), 42);
+    }
+
+    #[test]
+    fn runs_nested_awaits() {
+        assert_eq!(block_on(add_three(10, 20, 30)), 60);
+    }
+
+    #[test]
+    fn futures_are_lazy() {
+        // a Cell, so the future can set it through a `&` while we still
+        // look at it from out here
+        let ran = Cell::new(false);
+        let future = async {
+            ran.set(true);
+        };
+        // made, but nothing has run yet: that only happens when it's polled
+        assert!(!ran.get());
+        block_on(future);
+        assert!(ran.get());
+    }
+}
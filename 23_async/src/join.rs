@@ -0,0 +1,116 @@
+/**
+ * join: two futures at the same time, on one thread.
+ *
+ * `a.await; b.await;` runs a to completion, *then* starts b. To overlap
+ * them, we need a future that owns both and polls each in turn: whenever
+ * Join is polled, it polls whichever halves aren't finished yet, stashes
+ * any results, and is Ready only once both are in.
+ *
+ * That is all concurrency means here. Nothing runs in parallel: while one
+ * half is stuck waiting (on a timer, say), the other gets to make progress.
+ */
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use crate::executor::block_on;
+use crate::timer::delayed;
+
+// one half of a Join: still running, or finished with its output
+enum Half<F: Future> {
+    Running(Pin<Box<F>>),
+    Done(Option<F::Output>),
+}
+
+impl<F: Future> Half<F> {
+    // poll if still running; true once the output is safely stored
+    fn poll_half(&mut self, cx: &mut Context<'_>) -> bool {
+        if let Half::Running(future) = self {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(output) => *self = Half::Done(Some(output)),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+
+    fn take(&mut self) -> F::Output {
+        match self {
+            Half::Done(output) => output.take().expect("output already taken"),
+            Half::Running(_) => unreachable!("taking output from a running future"),
+        }
+    }
+}
+
+pub struct Join<A: Future, B: Future> {
+    a: Half<A>,
+    b: Half<B>,
+}
+
+// The futures themselves are pinned in their own Boxes, and the outputs
+// are never pinned at all, so moving a Join around is always fine.
+impl<A: Future, B: Future> Unpin for Join<A, B> {}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        // poll both every time, so neither half waits on the other
+        let a_done = this.a.poll_half(cx);
+        let b_done = this.b.poll_half(cx);
+        if a_done && b_done {
+            Poll::Ready((this.a.take(), this.b.take()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join { a: Half::Running(Box::pin(a)), b: Half::Running(Box::pin(b)) }
+}
+
+pub fn demo_join() {
+    println!("--- Join Demonstration Begins --- ");
+    let start = Instant::now();
+    let (a, b) = block_on(async {
+        let a = delayed(100, "slow").await;
+        let b = delayed(100, "steady").await;
+        (a, b)
+    });
+    println!("one after the other: ({}, {}) in {}ms", a, b, start.elapsed().as_millis());
+
+    let start = Instant::now();
+    let (a, b) = block_on(join(delayed(100, "slow"), delayed(100, "steady")));
+    println!("joined: ({}, {}) in {}ms", a, b, start.elapsed().as_millis());
+    println!("--- Join Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn joins_outputs_in_argument_order() {
+        // b finishes first, but the tuple still follows the arguments
+        let out = block_on(join(delayed(40, 'a'), delayed(5, 'b')));
+        assert_eq!(out, ('a', 'b'));
+    }
+
+    #[test]
+    fn joined_timers_overlap() {
+        let start = Instant::now();
+        block_on(join(delayed(150, ()), delayed(150, ())));
+        // sequentially this would take 300ms
+        assert!(start.elapsed() < Duration::from_millis(290));
+    }
+
+    #[test]
+    fn joins_can_nest() {
+        let out = block_on(join(join(async { 1 }, delayed(10, 2)), async { 3 }));
+        assert_eq!(out, ((1, 2), 3));
+    }
+}
@@ -0,0 +1,51 @@
+/**
+ * async/await: concurrency without a thread per task.
+ *
+ * An `async fn` doesn't run when you call it. It returns a *future*: a
+ * value that describes some work, which makes progress only when somebody
+ * polls it. `.await` inside another async fn says "poll this until it's
+ * done, and let other work run whenever it's stuck waiting".
+ *
+ * The standard library gives us the Future trait and the async/await
+ * syntax, but deliberately leaves out the thing that does the polling,
+ * the *executor* (or "runtime"). Real programs pull one in from a crate,
+ * usually tokio. Here we build the smallest possible pieces by hand:
+ *
+ * - executor: block_on, which runs one future to completion
+ * - timer: a future that finishes after a delay
+ * - join: run two futures at the same time, wait for both
+ * - channel and pipeline: async message passing between tasks
+ *
+ * None of this is how you'd write production code, but after writing it,
+ * the runtime crates stop looking like magic.
+ */
+mod executor;
+mod timer;
+mod join;
+mod channel;
+mod pipeline;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("executor", "block_on: poll a future until it is done", executor::demo_executor),
+    Demo::new("timer", "A future that completes after a delay", timer::demo_timer),
+    Demo::new("join", "Two futures at once", join::demo_join),
+    Demo::new("pipeline", "An async channel, and a pipeline of tasks", pipeline::demo_pipeline),
+];
+
+pub fn demo() {
+
+    // block_on: poll a future until it's done
+    executor::demo_executor();
+
+    // a future that completes after a delay
+    timer::demo_timer();
+
+    // two futures at once
+    join::demo_join();
+
+    // an async channel, and a pipeline of tasks connected by channels
+    pipeline::demo_pipeline();
+}
@@ -0,0 +1,79 @@
+/**
+ * A three-stage pipeline of async tasks, wired together with channels.
+ *
+ *      produce --(numbers)--> square --(squares)--> collect
+ *
+ * Each stage is an ordinary async fn that loops over its input channel
+ * and sends to its output channel. Joined together, they all make
+ * progress at once on a single thread: while the producer is waiting on
+ * its timer, the later stages get to work on what's already arrived.
+ */
+use crate::channel::{channel, Receiver, Sender};
+use crate::executor::block_on;
+use crate::join::join;
+use crate::timer::sleep_ms;
+
+// stage one: send each number, with a small delay before each, then stop.
+// Taking `tx` by value means it's dropped at the end, closing the channel.
+async fn produce(numbers: Vec<u64>, delay_ms: u64, tx: Sender<u64>) {
+    for n in numbers {
+        sleep_ms(delay_ms).await;
+        tx.send(n);
+    }
+}
+
+// stage two: square whatever comes in, until the input runs dry
+async fn square(mut rx: Receiver<u64>, tx: Sender<u64>) {
+    while let Some(n) = rx.recv().await {
+        tx.send(n * n);
+    }
+}
+
+// stage three: gather everything up
+async fn collect(mut rx: Receiver<u64>) -> Vec<u64> {
+    let mut results = Vec::new();
+    while let Some(n) = rx.recv().await {
+        results.push(n);
+    }
+    results
+}
+
+pub fn run_pipeline(numbers: Vec<u64>, delay_ms: u64) -> Vec<u64> {
+    let (numbers_tx, numbers_rx) = channel();
+    let (squares_tx, squares_rx) = channel();
+    let stages = join(
+        produce(numbers, delay_ms, numbers_tx),
+        join(square(numbers_rx, squares_tx), collect(squares_rx)),
+    );
+    let ((), ((), results)) = block_on(stages);
+    results
+}
+
+pub fn demo_pipeline() {
+    println!("--- Async Pipeline Demonstration Begins --- ");
+    let results = run_pipeline((1..=5).collect(), 20);
+    println!("squares out of the pipeline: {:?}", results);
+    println!("--- Async Pipeline Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squares_everything_in_order() {
+        assert_eq!(run_pipeline(vec![1, 2, 3, 4], 5), vec![1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn empty_input_closes_cleanly() {
+        assert!(run_pipeline(vec![], 5).is_empty());
+    }
+
+    #[test]
+    fn works_without_any_delay() {
+        let input: Vec<u64> = (0..100).collect();
+        let expected: Vec<u64> = input.iter().map(|n| n * n).collect();
+        assert_eq!(run_pipeline(input, 0), expected);
+    }
+}
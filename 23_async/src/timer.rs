@@ -0,0 +1,107 @@
+/**
+ * A timer future: finishes after a delay, without blocking anybody.
+ *
+ * `thread::sleep` inside an async fn would freeze the whole executor, so
+ * every other task would stop too. Instead, the timer hands the waiting
+ * off to a helper thread. Polling just checks a flag: if it's not set yet,
+ * we leave our Waker behind and return Pending, and the helper thread
+ * calls wake() when the time is up.
+ *
+ * (Real runtimes use one timer thread, or the OS, for all timers at once.
+ * A thread per timer is wasteful, but it's the simplest thing that works.)
+ */
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::executor::block_on;
+
+struct TimerState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+pub struct Timer {
+    state: Arc<Mutex<TimerState>>,
+}
+
+impl Timer {
+    pub fn after(duration: Duration) -> Timer {
+        let state = Arc::new(Mutex::new(TimerState { done: false, waker: None }));
+        let thread_state = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.done = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Timer { state }
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            // always store the *latest* waker: the future may have been
+            // moved to a different task since the last poll
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+pub async fn sleep_ms(ms: u64) {
+    Timer::after(Duration::from_millis(ms)).await
+}
+
+// wait, then hand back a value: a stand-in for a slow network request
+pub async fn delayed<T>(ms: u64, value: T) -> T {
+    sleep_ms(ms).await;
+    value
+}
+
+pub fn demo_timer() {
+    println!("--- Timer Demonstration Begins --- ");
+    let start = Instant::now();
+    let answer = block_on(async {
+        println!("waiting 100ms...");
+        sleep_ms(100).await;
+        println!("...and 50ms more");
+        delayed(50, "done waiting").await
+    });
+    println!("{} after {}ms", answer, start.elapsed().as_millis());
+    println!("--- Timer Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_at_least_as_long_as_asked() {
+        let start = Instant::now();
+        block_on(sleep_ms(50));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn sequential_awaits_add_up() {
+        let start = Instant::now();
+        let value = block_on(async {
+            sleep_ms(30).await;
+            delayed(30, 7).await
+        });
+        assert_eq!(value, 7);
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+}
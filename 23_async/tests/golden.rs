@@ -0,0 +1,29 @@
+/**
+ * The demo's whole output, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ *
+ * Three lines report how long the waiting really took, in whole
+ * milliseconds (`done waiting after 151ms`). `golden::normalize` leaves
+ * round numbers alone, so those get blanked out here instead.
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn blank_elapsed(line: &str) -> String {
+    match line.rsplit_once(' ') {
+        Some((start, last))
+            if last.ends_with("ms") && (start.ends_with(" after") || start.ends_with(" in")) =>
+        {
+            format!("{} <duration>", start)
+        }
+        _ => line.to_string(),
+    }
+}
+
+#[test]
+fn demo() {
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_async_await"), DIR), "");
+    let lines: Vec<String> = output.lines().map(blank_elapsed).collect();
+    golden::check(DIR, "demo", &(lines.join("\n") + "\n"));
+}
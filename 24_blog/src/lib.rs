@@ -0,0 +1,238 @@
+/**
+ * A blog post, built with the state pattern.
+ *
+ * The rules:
+ *
+ * - a new post starts out as an empty draft
+ * - text can be added to a draft
+ * - a draft can be sent off for review
+ * - a post pending review can be approved, which publishes it
+ * - only published posts show their content; everything else shows ""
+ *
+ * Each state is its own struct implementing the State trait, and every
+ * transition *consumes* the old state (`self: Box<Self>`) and returns the
+ * new one. Post stores its state as an `Option<Box<dyn State>>`, so that
+ * it can `take()` the old state out, leaving None behind for a moment,
+ * and then put the new one in its place.
+ *
+ * For a version where the compiler enforces the rules, see `typestate`.
+ */
+pub mod typestate;
+
+pub struct Post {
+    state: Option<Box<dyn State>>,
+    content: String,
+}
+
+impl Post {
+    pub fn new() -> Post {
+        Post {
+            state: Some(Box::new(Draft {})),
+            content: String::new(),
+        }
+    }
+
+    // Adding text is allowed in any state, per the book. (Restricting it to
+    // drafts would be a fine exercise.)
+    pub fn add_text(&mut self, text: &str) {
+        self.content.push_str(text);
+    }
+
+    // ask the state, passing ourselves along so it can read the content
+    pub fn content(&self) -> &str {
+        self.state.as_ref().unwrap().content(self)
+    }
+
+    pub fn request_review(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.request_review())
+        }
+    }
+
+    pub fn approve(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.approve())
+        }
+    }
+
+    // the name of the current state, handy for tests and printouts
+    pub fn status(&self) -> &'static str {
+        self.state.as_ref().unwrap().name()
+    }
+}
+
+impl Default for Post {
+    fn default() -> Post {
+        Post::new()
+    }
+}
+
+trait State {
+    fn request_review(self: Box<Self>) -> Box<dyn State>;
+    fn approve(self: Box<Self>) -> Box<dyn State>;
+    fn name(&self) -> &'static str;
+
+    // a default implementation: every state hides the content, except the
+    // one that overrides it
+    fn content<'a>(&self, _post: &'a Post) -> &'a str {
+        ""
+    }
+}
+
+struct Draft {}
+
+impl State for Draft {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        Box::new(PendingReview {})
+    }
+
+    // approving a draft does nothing: it stays a draft
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "draft"
+    }
+}
+
+struct PendingReview {}
+
+impl State for PendingReview {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        Box::new(Published {})
+    }
+
+    fn name(&self) -> &'static str {
+        "pending review"
+    }
+}
+
+struct Published {}
+
+impl State for Published {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "published"
+    }
+
+    fn content<'a>(&self, post: &'a Post) -> &'a str {
+        &post.content
+    }
+}
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("blog", "A post through draft, review and publish, two ways", demo),
+];
+
+// The book's main.rs, which walks one post through the whole workflow
+pub fn demo() {
+    let mut post = Post::new();
+
+    post.add_text("I ate a salad for lunch today");
+    assert_eq!("", post.content());
+    println!("draft: content is {:?}", post.content());
+
+    post.request_review();
+    assert_eq!("", post.content());
+    println!("pending review: content is {:?}", post.content());
+
+    post.approve();
+    assert_eq!("I ate a salad for lunch today", post.content());
+    println!("published: content is {:?}", post.content());
+
+    // the type-state version: each step is a new type, so each step needs
+    // a new `let`, and there is no content() to call until the very end
+    let mut post = typestate::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    let post = post.request_review();
+    let post = post.approve();
+    assert_eq!("I ate a salad for lunch today", post.content());
+    println!("type-state post published: content is {:?}", post.content());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft_with(text: &str) -> Post {
+        let mut post = Post::new();
+        post.add_text(text);
+        post
+    }
+
+    #[test]
+    fn new_post_is_an_empty_draft() {
+        let post = Post::new();
+        assert_eq!(post.status(), "draft");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn draft_hides_its_text() {
+        let post = draft_with("secret");
+        assert_eq!(post.status(), "draft");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn draft_to_pending_review() {
+        let mut post = draft_with("hello");
+        post.request_review();
+        assert_eq!(post.status(), "pending review");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn pending_review_to_published() {
+        let mut post = draft_with("hello");
+        post.request_review();
+        post.approve();
+        assert_eq!(post.status(), "published");
+        assert_eq!(post.content(), "hello");
+    }
+
+    #[test]
+    fn approving_a_draft_does_nothing() {
+        let mut post = draft_with("hello");
+        post.approve();
+        assert_eq!(post.status(), "draft");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn repeat_requests_are_ignored() {
+        let mut post = draft_with("hello");
+        post.request_review();
+        post.request_review();
+        assert_eq!(post.status(), "pending review");
+        post.approve();
+        post.request_review();
+        post.approve();
+        assert_eq!(post.status(), "published");
+        assert_eq!(post.content(), "hello");
+    }
+
+    #[test]
+    fn text_accumulates() {
+        let mut post = draft_with("one, ");
+        post.add_text("two");
+        post.request_review();
+        post.approve();
+        assert_eq!(post.content(), "one, two");
+    }
+}
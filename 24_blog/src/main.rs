@@ -0,0 +1,23 @@
+/**
+ * Is Rust object-oriented? Sort of!
+ *
+ * It has objects, in the sense of structs with data plus methods. It has
+ * encapsulation, since fields are private unless marked `pub`. It does not
+ * have inheritance, but it gets most of the same mileage from trait
+ * objects: `Box<dyn Trait>` can hold any type that implements the trait,
+ * and method calls get dispatched at runtime.
+ *
+ * The book's showcase is the *state pattern*: a blog post that moves from
+ * draft, to pending review, to published. The Post itself doesn't know the
+ * rules; it holds a state object, and each state decides what comes next.
+ * All the code is in lib.rs, including `demo()`, which drives it exactly as
+ * the book's main does (and where the assert_eq!s double as a description
+ * of the workflow). It lives in the library so that the top-level runner
+ * can call it too; this file just calls it.
+ *
+ * After that comes the book's alternative (in typestate.rs), which gives
+ * up on trait objects and encodes the states as types instead.
+ */
+fn main() {
+    blog::demo();
+}
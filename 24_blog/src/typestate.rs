@@ -0,0 +1,152 @@
+/*!
+ * The same blog workflow, with the states as *types* instead of objects.
+ *
+ * In lib.rs, asking a draft for its content compiles fine and quietly
+ * returns "". Here, a draft doesn't even *have* a content method, so that
+ * mistake won't compile at all. Each state is a separate struct, and each
+ * transition consumes the old struct and returns a new one:
+ *
+ * ```text
+ * Post::new() -> DraftPost --request_review()--> PendingReviewPost
+ *                                            --approve()--> Post
+ * ```
+ *
+ * The price: a transition hands back a *different type*, so callers must
+ * rebind (`let post = post.request_review();`) instead of mutating one
+ * variable in place, and the compiler has to know every step up front.
+ *
+ * The examples marked `compile_fail` below are doctests that *pass* only
+ * if the code is rejected by the compiler, with the error code listed after
+ * the comma (so they can't "pass" by failing for some unrelated reason).
+ * `cargo test` checks them all, so these "this must not compile" claims
+ * can't quietly go stale.
+ *
+ * (Note the `!` right after the opening of this comment, where the other
+ * files have a second `*`. That makes it the documentation for the
+ * *module* itself, instead of for whatever item happens to come next,
+ * which here would have been Post.)
+ */
+
+/**
+ * A published post: the only type with a `content` method.
+ *
+ * The only way to get one is the whole workflow:
+ *
+ * ```
+ * use blog::typestate::Post;
+ *
+ * let mut post = Post::new();
+ * post.add_text("I ate a salad for lunch today");
+ * let post = post.request_review();
+ * let post = post.approve();
+ * assert_eq!("I ate a salad for lunch today", post.content());
+ * ```
+ */
+pub struct Post {
+    content: String,
+}
+
+/**
+ * A post being written. It has no `content` method, so a draft can't be
+ * read by mistake:
+ *
+ * ```compile_fail,E0599
+ * use blog::typestate::Post;
+ *
+ * let mut post = Post::new();
+ * post.add_text("not ready yet");
+ * post.content(); // error: no method named `content` found for `DraftPost`
+ * ```
+ *
+ * And it can't skip review, since only a pending post can be approved:
+ *
+ * ```compile_fail,E0599
+ * use blog::typestate::Post;
+ *
+ * let post = Post::new().approve(); // error: no method named `approve`
+ * ```
+ *
+ * Once sent for review, the draft is gone: `request_review` took it by
+ * value, so it can't be edited behind the reviewer's back:
+ *
+ * ```compile_fail,E0382
+ * use blog::typestate::Post;
+ *
+ * let mut draft = Post::new();
+ * let pending = draft.request_review();
+ * draft.add_text("sneaky edit"); // error: borrow of moved value: `draft`
+ * ```
+ */
+pub struct DraftPost {
+    content: String,
+}
+
+/**
+ * A post waiting for approval. Its text is frozen: there's no `add_text`.
+ *
+ * ```compile_fail,E0599
+ * use blog::typestate::Post;
+ *
+ * let mut pending = Post::new().request_review();
+ * pending.add_text("too late"); // error: no method named `add_text`
+ * ```
+ */
+pub struct PendingReviewPost {
+    content: String,
+}
+
+impl Post {
+    // note the return type: a new Post starts life as a DraftPost
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> DraftPost {
+        DraftPost { content: String::new() }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl DraftPost {
+    pub fn add_text(&mut self, text: &str) {
+        self.content.push_str(text);
+    }
+
+    pub fn request_review(self) -> PendingReviewPost {
+        PendingReviewPost { content: self.content }
+    }
+}
+
+impl PendingReviewPost {
+    pub fn approve(self) -> Post {
+        Post { content: self.content }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_whole_valid_path() {
+        let mut post = Post::new();
+        post.add_text("I ate a salad for lunch today");
+        let post = post.request_review();
+        let post = post.approve();
+        assert_eq!(post.content(), "I ate a salad for lunch today");
+    }
+
+    #[test]
+    fn an_empty_post_can_still_be_published() {
+        let post = Post::new().request_review().approve();
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn text_added_in_pieces_survives_the_trip() {
+        let mut draft = Post::new();
+        draft.add_text("one, ");
+        draft.add_text("two");
+        assert_eq!(draft.request_review().approve().content(), "one, two");
+    }
+}
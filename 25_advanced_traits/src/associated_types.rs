@@ -0,0 +1,102 @@
+/**
+ * Associated types: a placeholder type that each implementation fills in.
+ *
+ * This is a home-made Iterator look-alike called Source. The trait says
+ * "every Source produces *some* type of item", and each impl says which.
+ *
+ * Why not a generic `trait Source<T>` instead? Because then a type could
+ * implement Source<u32> *and* Source<String>, and every call to next_item
+ * would need an annotation saying which one we meant. With an associated
+ * type there can only be one impl per type, so the item type is always
+ * known: one Countdown, one kind of item.
+ */
+pub trait Source {
+    type Item;
+
+    fn next_item(&mut self) -> Option<Self::Item>;
+
+    // a provided method, written once in terms of the associated type
+    fn drain_all(&mut self) -> Vec<Self::Item> {
+        let mut items = Vec::new();
+        while let Some(item) = self.next_item() {
+            items.push(item);
+        }
+        items
+    }
+}
+
+// counts down to 1
+pub struct Countdown(pub u32);
+
+impl Source for Countdown {
+    type Item = u32;
+
+    fn next_item(&mut self) -> Option<u32> {
+        if self.0 == 0 {
+            None
+        } else {
+            self.0 -= 1;
+            Some(self.0 + 1)
+        }
+    }
+}
+
+// hands out the words of a sentence, one at a time
+pub struct Words {
+    words: Vec<String>,
+}
+
+impl Words {
+    pub fn new(sentence: &str) -> Words {
+        // reversed, so pop() gives them back in order
+        Words { words: sentence.split_whitespace().rev().map(String::from).collect() }
+    }
+}
+
+impl Source for Words {
+    type Item = String;
+
+    fn next_item(&mut self) -> Option<String> {
+        self.words.pop()
+    }
+}
+
+// Generic code can talk about `S::Item` without knowing what it will be
+pub fn count_items<S: Source>(source: &mut S) -> usize {
+    source.drain_all().len()
+}
+
+pub fn demo_associated_types() {
+    println!("--- Associated Types Demonstration Begins --- ");
+    println!("countdown: {:?}", Countdown(5).drain_all());
+    let mut words = Words::new("associated types are tidy");
+    println!("first word: {:?}", words.next_item());
+    println!("rest: {:?}", words.drain_all());
+    println!("a countdown from 9 has {} items", count_items(&mut Countdown(9)));
+    println!("--- Associated Types Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_items_are_u32() {
+        let mut c = Countdown(3);
+        assert_eq!(c.next_item(), Some(3));
+        assert_eq!(c.drain_all(), vec![2, 1]);
+        assert_eq!(c.next_item(), None);
+    }
+
+    #[test]
+    fn words_items_are_strings() {
+        let items: Vec<String> = Words::new("a b  c").drain_all();
+        assert_eq!(items, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn generic_over_any_source() {
+        assert_eq!(count_items(&mut Countdown(0)), 0);
+        assert_eq!(count_items(&mut Words::new("one two")), 2);
+    }
+}
@@ -0,0 +1,94 @@
+/**
+ * Fully qualified syntax: when two traits (or a trait and the type itself)
+ * have a method with the same name.
+ *
+ * Nothing stops a Human from implementing both Pilot and Wizard, each of
+ * which has a `fly` method, while also having its own `fly`. By default
+ * `person.fly()` calls the type's own method. To pick a trait's version,
+ * name the trait: `Pilot::fly(&person)`.
+ *
+ * That works for methods, since `&person` tells Rust which type we mean.
+ * An associated function with no `self` gives Rust nothing to go on, so
+ * we need the full form: `<Dog as Animal>::baby_name()`.
+ */
+pub trait Pilot {
+    fn fly(&self) -> String;
+}
+
+pub trait Wizard {
+    fn fly(&self) -> String;
+}
+
+pub struct Human;
+
+impl Pilot for Human {
+    fn fly(&self) -> String {
+        String::from("This is your captain speaking.")
+    }
+}
+
+impl Wizard for Human {
+    fn fly(&self) -> String {
+        String::from("Up!")
+    }
+}
+
+impl Human {
+    pub fn fly(&self) -> String {
+        String::from("*waving arms furiously*")
+    }
+}
+
+pub trait Animal {
+    fn baby_name() -> String;
+}
+
+pub struct Dog;
+
+impl Dog {
+    pub fn baby_name() -> String {
+        String::from("Spot")
+    }
+}
+
+impl Animal for Dog {
+    fn baby_name() -> String {
+        String::from("puppy")
+    }
+}
+
+pub fn demo_disambiguation() {
+    println!("--- Fully Qualified Syntax Demonstration Begins --- ");
+    let person = Human;
+    println!("Pilot says: {}", Pilot::fly(&person));
+    println!("Wizard says: {}", Wizard::fly(&person));
+    println!("Human says: {}", person.fly());
+
+    println!("A baby dog is called a {}", Dog::baby_name());
+    // println!("{}", Animal::baby_name()); // error: cannot call associated
+    //                                      // function on trait without
+    //                                      // specifying the corresponding `impl` type
+    println!("A baby dog is called a {}", <Dog as Animal>::baby_name());
+    println!("--- Fully Qualified Syntax Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_fly_is_reachable() {
+        let person = Human;
+        assert_eq!(person.fly(), "*waving arms furiously*");
+        assert_eq!(Pilot::fly(&person), "This is your captain speaking.");
+        assert_eq!(Wizard::fly(&person), "Up!");
+        // the longhand for the same thing
+        assert_eq!(<Human as Wizard>::fly(&person), "Up!");
+    }
+
+    #[test]
+    fn associated_functions_need_the_full_form() {
+        assert_eq!(Dog::baby_name(), "Spot");
+        assert_eq!(<Dog as Animal>::baby_name(), "puppy");
+    }
+}
@@ -0,0 +1,46 @@
+/**
+ * Advanced traits: the corners of the trait system we skipped in 15_traits.
+ *
+ * None of these come up every day, but every one of them shows up in the
+ * standard library, so it pays to be able to read them:
+ *
+ * - associated types: `type Item;` inside a trait, as in Iterator
+ * - default type parameters: `trait Add<Rhs = Self>`, used for operators
+ * - fully qualified syntax: `<Type as Trait>::method()`, for name clashes
+ * - supertraits: a trait that only works on types with some *other* trait
+ * - the newtype pattern: a wrapper struct, to get around the orphan rule
+ */
+mod associated_types;
+mod operators;
+mod disambiguation;
+mod supertraits;
+mod newtype;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("associated_types", "Placeholder types inside a trait", associated_types::demo_associated_types),
+    Demo::new("operators", "Operator overloading, and Add<Rhs = Self>", operators::demo_operators),
+    Demo::new("disambiguation", "Same method name, several traits", disambiguation::demo_disambiguation),
+    Demo::new("supertraits", "A trait that requires another trait", supertraits::demo_supertraits),
+    Demo::new("newtype", "Wrapping a foreign type to implement a foreign trait", newtype::demo_newtype),
+];
+
+pub fn demo() {
+
+    // placeholder types inside a trait
+    associated_types::demo_associated_types();
+
+    // operator overloading, and Add<Rhs = Self>
+    operators::demo_operators();
+
+    // same method name, several traits
+    disambiguation::demo_disambiguation();
+
+    // a trait that requires another trait
+    supertraits::demo_supertraits();
+
+    // wrapping a foreign type to implement a foreign trait on it
+    newtype::demo_newtype();
+}
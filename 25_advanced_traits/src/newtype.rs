@@ -0,0 +1,75 @@
+/**
+ * The newtype pattern: getting around the orphan rule.
+ *
+ * The orphan rule says we can implement a trait for a type only if the
+ * trait or the type is ours. Display and Vec<String> both belong to std,
+ * so `impl fmt::Display for Vec<String>` is off limits. (Otherwise two
+ * crates could each write that impl, and Rust couldn't choose between them.)
+ *
+ * The way out is a thin tuple struct wrapping the Vec. Wrapper *is* ours,
+ * so it can implement anything. There's no runtime cost: the wrapper is
+ * erased at compile time. The catch is that Wrapper doesn't have any of
+ * Vec's methods; implementing Deref would bring them back, if we wanted.
+ */
+use std::fmt;
+
+pub struct Wrapper(pub Vec<String>);
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.0.join(", "))
+    }
+}
+
+// Newtypes are also handy for giving plain values a meaning the compiler
+// can check. An email address is "just a String", but not every String is
+// an email address, so this one can only be made by passing a check.
+#[derive(Debug, PartialEq)]
+pub struct Email(String);
+
+impl Email {
+    pub fn parse(s: &str) -> Option<Email> {
+        let at = s.find('@')?;
+        if at > 0 && at < s.len() - 1 {
+            Some(Email(s.to_string()))
+        } else {
+            None
+        }
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.0[self.0.find('@').unwrap() + 1..]
+    }
+}
+
+pub fn demo_newtype() {
+    println!("--- Newtype Demonstration Begins --- ");
+    let w = Wrapper(vec![String::from("hello"), String::from("world")]);
+    println!("w = {}", w);
+    match Email::parse("ferris@rust-lang.org") {
+        Some(email) => println!("{:?} has the domain {}", email, email.domain()),
+        None => println!("not an email"),
+    }
+    println!("'nope' is an email? {}", Email::parse("nope").is_some());
+    println!("--- Newtype Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapper_displays_its_vec() {
+        let w = Wrapper(vec![String::from("a"), String::from("b")]);
+        assert_eq!(w.to_string(), "[a, b]");
+        assert_eq!(Wrapper(vec![]).to_string(), "[]");
+    }
+
+    #[test]
+    fn email_must_have_something_on_both_sides_of_the_at() {
+        assert_eq!(Email::parse("a@b").map(|e| e.domain().to_string()), Some(String::from("b")));
+        assert!(Email::parse("@b").is_none());
+        assert!(Email::parse("a@").is_none());
+        assert!(Email::parse("ab").is_none());
+    }
+}
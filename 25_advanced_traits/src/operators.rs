@@ -0,0 +1,83 @@
+/**
+ * Operator overloading, and default type parameters.
+ *
+ * Rust doesn't let us invent new operators, but the existing ones are all
+ * backed by traits in `std::ops`, so implementing `Add` makes `+` work.
+ * Here's the definition of Add:
+ *
+ *      trait Add<Rhs = Self> {
+ *          type Output;
+ *          fn add(self, rhs: Rhs) -> Self::Output;
+ *      }
+ *
+ * `Rhs = Self` is a *default type parameter*: if we write plain
+ * `impl Add for Point`, the right-hand side is another Point. But we can
+ * pick something else, like adding Meters to Millimeters.
+ */
+use std::ops::Add;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+// the default: Point + Point
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+// Newtypes for units, so the compiler stops us mixing them up by accident
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Millimeters(pub u32);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Meters(pub u32);
+
+// the override: Millimeters + Meters, with the conversion built in
+impl Add<Meters> for Millimeters {
+    type Output = Millimeters;
+
+    fn add(self, other: Meters) -> Millimeters {
+        Millimeters(self.0 + (other.0 * 1000))
+    }
+}
+
+// and the plain same-unit version too, since nothing comes for free
+impl Add for Millimeters {
+    type Output = Millimeters;
+
+    fn add(self, other: Millimeters) -> Millimeters {
+        Millimeters(self.0 + other.0)
+    }
+}
+
+pub fn demo_operators() {
+    println!("--- Operator Overloading Demonstration Begins --- ");
+    let p = Point { x: 1, y: 0 } + Point { x: 2, y: 3 };
+    println!("(1, 0) + (2, 3) = {:?}", p);
+    println!("5mm + 2m = {:?}", Millimeters(5) + Meters(2));
+    println!("5mm + 2mm = {:?}", Millimeters(5) + Millimeters(2));
+    // Meters(2) + Millimeters(5); // error: cannot add `Millimeters` to `Meters`
+    println!("--- Operator Overloading Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adding_points() {
+        assert_eq!(Point { x: 1, y: 0 } + Point { x: 2, y: 3 }, Point { x: 3, y: 3 });
+    }
+
+    #[test]
+    fn adding_meters_to_millimeters() {
+        assert_eq!(Millimeters(5) + Meters(2), Millimeters(2005));
+        assert_eq!(Millimeters(5) + Millimeters(2), Millimeters(7));
+    }
+}
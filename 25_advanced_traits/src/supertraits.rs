@@ -0,0 +1,69 @@
+/**
+ * Supertraits: a trait that builds on another trait.
+ *
+ * OutlinePrint draws a box of asterisks around a value's Display output.
+ * Its default method calls `self.to_string()`, which only exists for types
+ * that implement Display. So the trait declares `OutlinePrint: Display`,
+ * and in return for that promise, its methods may use anything Display
+ * offers. Try to implement it for a type without Display and the compiler
+ * says: "`Point` doesn't implement `std::fmt::Display`".
+ */
+use std::fmt;
+
+pub trait OutlinePrint: fmt::Display {
+    fn outline(&self) -> String {
+        let output = self.to_string();
+        let len = output.len();
+        let border = "*".repeat(len + 4);
+        let blank = format!("*{}*", " ".repeat(len + 2));
+        format!("{}\n{}\n* {} *\n{}\n{}", border, blank, output, blank, border)
+    }
+
+    fn outline_print(&self) {
+        println!("{}", self.outline());
+    }
+}
+
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+// first the supertrait...
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// ...and then the trait itself, which needs no code at all
+impl OutlinePrint for Point {}
+
+pub fn demo_supertraits() {
+    println!("--- Supertraits Demonstration Begins --- ");
+    Point { x: 1, y: 3 }.outline_print();
+    println!("--- Supertraits Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_a_box() {
+        let expected = "\
+**********
+*        *
+* (1, 3) *
+*        *
+**********";
+        assert_eq!(Point { x: 1, y: 3 }.outline(), expected);
+    }
+
+    #[test]
+    fn box_grows_with_the_content() {
+        let outline = Point { x: -10, y: 200 }.outline();
+        let widths: Vec<usize> = outline.lines().map(|l| l.len()).collect();
+        assert_eq!(widths, vec![14; 5]);
+    }
+}
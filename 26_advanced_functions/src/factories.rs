@@ -0,0 +1,97 @@
+/**
+ * Returning closures.
+ *
+ * A closure's type has no name we can write, so `-> Fn(i32) -> i32` won't
+ * work: Fn is a trait, not a type. There are two ways out:
+ *
+ * - `-> impl Fn(i32) -> i32`: "some single closure type, I won't say
+ *   which". Zero cost, but every return path must be the *same* closure.
+ * - `-> Box<dyn Fn(i32) -> i32>`: a trait object on the heap. A tiny bit
+ *   slower, but different branches can return different closures.
+ *
+ * Either way, the closure must `move` in whatever it captures, since the
+ * factory's local variables die when the factory returns.
+ */
+pub fn adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// different closures on different branches: only a Box can hold them all
+pub fn operation(name: &str, n: i32) -> Option<Box<dyn Fn(i32) -> i32>> {
+    match name {
+        "add" => Some(Box::new(move |x| x + n)),
+        "mul" => Some(Box::new(move |x| x * n)),
+        "neg" => Some(Box::new(|x| -x)),
+        _ => None,
+    }
+}
+
+// Boxed closures can live together in one Vec, even though each one has
+// a different type underneath. Compose them into a single closure.
+pub fn compose_all(ops: Vec<Box<dyn Fn(i32) -> i32>>) -> Box<dyn Fn(i32) -> i32> {
+    Box::new(move |x| ops.iter().fold(x, |acc, op| op(acc)))
+}
+
+// A counter factory. The returned closure changes its captured state, so
+// it is FnMut, not Fn, and the caller must bind it with `let mut`.
+pub fn counter(start: u32) -> impl FnMut() -> u32 {
+    let mut count = start;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+pub fn demo_factories() {
+    println!("--- Closure Factories Demonstration Begins --- ");
+    let add_five = adder(5);
+    println!("adder(5)(10) = {}", add_five(10));
+
+    let ops: Vec<Box<dyn Fn(i32) -> i32>> = ["add", "mul", "neg"]
+        .iter()
+        .filter_map(|name| operation(name, 3))
+        .collect();
+    let pipeline = compose_all(ops);
+    println!("add 3, multiply by 3, negate, starting from 2: {}", pipeline(2));
+    println!("an unknown operation: {}", operation("sqrt", 0).is_none());
+
+    let mut next = counter(10);
+    println!("counter: {}, {}, {}", next(), next(), next());
+    println!("--- Closure Factories Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_fn_factory() {
+        assert_eq!(adder(2)(3), 5);
+        assert_eq!(adder(-2)(3), 1);
+    }
+
+    #[test]
+    fn boxed_factory_picks_a_closure() {
+        assert_eq!(operation("add", 4).unwrap()(1), 5);
+        assert_eq!(operation("mul", 4).unwrap()(2), 8);
+        assert_eq!(operation("neg", 4).unwrap()(2), -2);
+        assert!(operation("nope", 4).is_none());
+    }
+
+    #[test]
+    fn composition_runs_in_order() {
+        let ops = vec![operation("add", 1).unwrap(), operation("mul", 10).unwrap()];
+        assert_eq!(compose_all(ops)(1), 20);
+        assert_eq!(compose_all(vec![])(7), 7);
+    }
+
+    #[test]
+    fn counters_are_independent() {
+        let mut a = counter(0);
+        let mut b = counter(100);
+        assert_eq!(a(), 1);
+        assert_eq!(a(), 2);
+        assert_eq!(b(), 101);
+        assert_eq!(a(), 3);
+    }
+}
@@ -0,0 +1,35 @@
+/**
+ * Advanced functions and closures.
+ *
+ * Functions are values too. A plain `fn` item can be stored in a variable,
+ * passed to another function, and returned from one, just like a closure.
+ * The difference is in the types:
+ *
+ * - `fn(i32) -> i32` (lowercase f) is a *function pointer*: one concrete
+ *   type, which any fn with that signature fits into. Closures that don't
+ *   capture anything fit into it too.
+ * - `Fn(i32) -> i32` (uppercase F) is a *trait*, which every closure and
+ *   every fn implements. Each closure has its own unique type, so a trait
+ *   is the only way to talk about "any closure like this".
+ *
+ * The rest of this package is about where that difference shows up.
+ */
+mod pointers;
+mod factories;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("pointers", "fn pointers vs Fn closures as arguments", pointers::demo_pointers),
+    Demo::new("factories", "Returning closures from functions", factories::demo_factories),
+];
+
+pub fn demo() {
+
+    // fn pointers vs Fn closures as arguments
+    pointers::demo_pointers();
+
+    // returning closures from functions
+    factories::demo_factories();
+}
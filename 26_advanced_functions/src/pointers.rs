@@ -0,0 +1,120 @@
+/**
+ * Passing functions around: fn pointers vs closures.
+ *
+ * An API that takes `fn(i32) -> i32` accepts named functions and
+ * non-capturing closures, but rejects any closure that captures something,
+ * since a bare function pointer has nowhere to keep the captured values.
+ * An API that takes a generic `F: Fn(i32) -> i32` accepts all of them.
+ * So generics are the friendlier default, and fn pointers are mostly for
+ * talking to C, or for storing a table of same-typed functions.
+ */
+fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+// a function pointer parameter
+pub fn do_twice(f: fn(i32) -> i32, arg: i32) -> i32 {
+    f(arg) + f(arg)
+}
+
+// the generic version, which also takes capturing closures
+pub fn do_twice_generic<F: Fn(i32) -> i32>(f: F, arg: i32) -> i32 {
+    f(arg) + f(arg)
+}
+
+// Since fn pointers are all one type, they can share a Vec without any
+// boxing. Apply each step in turn.
+pub fn run_steps(steps: &[fn(i32) -> i32], start: i32) -> i32 {
+    steps.iter().fold(start, |acc, step| step(acc))
+}
+
+// A named function where a closure is expected: map() wants an FnMut, and
+// `ToString::to_string` (a trait method, named by its path) is one.
+pub fn to_strings(numbers: &[i32]) -> Vec<String> {
+    numbers.iter().map(ToString::to_string).collect()
+}
+
+// ...which is exactly equivalent to the closure version
+pub fn to_strings_closure(numbers: &[i32]) -> Vec<String> {
+    numbers.iter().map(|i| i.to_string()).collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Value(u32),
+    Stop,
+}
+
+// Tuple struct and tuple variant names are *initializer functions*:
+// `Status::Value` really is a fn(u32) -> Status, so it can go straight
+// into map().
+pub fn statuses(range: std::ops::Range<u32>) -> Vec<Status> {
+    range.map(Status::Value).collect()
+}
+
+pub fn demo_pointers() {
+    println!("--- Function Pointers Demonstration Begins --- ");
+    println!("do_twice(add_one, 5) = {}", do_twice(add_one, 5));
+
+    // a non-capturing closure coerces to a fn pointer just fine
+    println!("do_twice(|x| x * 10, 5) = {}", do_twice(|x| x * 10, 5));
+
+    // a capturing one doesn't...
+    let offset = 100;
+    // do_twice(|x| x + offset, 5); // error: expected fn pointer, found closure
+    // ...but the generic version takes it
+    println!("do_twice_generic(|x| x + offset, 5) = {}", do_twice_generic(|x| x + offset, 5));
+
+    let steps: Vec<fn(i32) -> i32> = vec![add_one, double, add_one];
+    println!("run_steps([add_one, double, add_one], 3) = {}", run_steps(&steps, 3));
+
+    println!("to_strings: {:?}", to_strings(&[1, 2, 3]));
+    println!("to_strings_closure: {:?}", to_strings_closure(&[1, 2, 3]));
+    println!("statuses: {:?}, and then {:?}", statuses(0..3), Status::Stop);
+    println!("--- Function Pointers Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_pointers_and_closures() {
+        assert_eq!(do_twice(add_one, 5), 12);
+        assert_eq!(do_twice(double, 5), 20);
+        assert_eq!(do_twice(|x| x - 1, 5), 8);
+    }
+
+    #[test]
+    fn generic_takes_capturing_closures_too() {
+        let offset = 7;
+        assert_eq!(do_twice_generic(|x| x + offset, 1), 16);
+        assert_eq!(do_twice_generic(add_one, 1), do_twice(add_one, 1));
+    }
+
+    #[test]
+    fn a_table_of_fn_pointers() {
+        assert_eq!(run_steps(&[add_one, double, add_one], 3), 9);
+        assert_eq!(run_steps(&[double, double, double], 1), 8);
+        assert_eq!(run_steps(&[], 42), 42);
+    }
+
+    #[test]
+    fn path_and_closure_versions_agree() {
+        let numbers = [-1, 0, 10];
+        assert_eq!(to_strings(&numbers), vec!["-1", "0", "10"]);
+        assert_eq!(to_strings(&numbers), to_strings_closure(&numbers));
+    }
+
+    #[test]
+    fn variant_names_are_functions() {
+        assert_eq!(statuses(3..5), vec![Status::Value(3), Status::Value(4)]);
+        assert!(statuses(0..0).is_empty());
+        let make: fn(u32) -> Status = Status::Value;
+        assert_eq!(make(9), Status::Value(9));
+    }
+}
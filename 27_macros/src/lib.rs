@@ -0,0 +1,262 @@
+/*!
+ * Declarative macros: `macro_rules!`.
+ *
+ * We've used macros since `println!` in hello world, and the `!` is the
+ * giveaway. A macro is code that writes code: it runs at compile time,
+ * takes a chunk of syntax, and expands into other syntax before the rest
+ * of the compiler ever sees it. That's how `println!` and `vec!` can take
+ * any number of arguments, which a function never could.
+ *
+ * A macro_rules! macro is a `match` on *syntax*. Each arm is a pattern of
+ * tokens on the left and the code to produce on the right:
+ *
+ * - `$x:expr` matches one expression and names it `$x` (other fragment
+ *   kinds include `ident`, `ty`, `tt` and `literal`)
+ * - `$( ... ),*` matches zero or more repeats, separated by commas, and
+ *   the same `$( ... )*` on the right side repeats the output once for each
+ * - `$(,)?` allows an optional trailing comma
+ *
+ * The macros live in this library so they can be `#[macro_export]`ed, and
+ * main.rs (a separate crate) can use them just like any other user would.
+ * (This header opens with a `!`, making it the docs for the whole crate,
+ * since the first thing after it is a macro rather than a normal item.)
+ */
+
+// Our own `vec!`, with all three of its forms:
+//
+//      my_vec![]          an empty Vec
+//      my_vec![1, 2, 3]   a Vec holding those values
+//      my_vec![0; 5]      five copies of 0
+//
+// The arms are tried in order, like match arms. The doubled braces on the
+// right are deliberate: the outer pair belongs to the macro syntax, and the
+// inner pair makes the expansion a block *expression*, so that the `let`
+// inside doesn't leak out and the whole thing has a value.
+#[macro_export]
+macro_rules! my_vec {
+    () => {
+        Vec::new()
+    };
+    ($elem:expr; $n:expr) => {{
+        let mut v = Vec::new();
+        v.resize($n, $elem);
+        v
+    }};
+    ($($x:expr),+ $(,)?) => {{
+        // clippy would rather we used vec![] for this, which rather misses
+        // the point of writing our own, so it is told to hush
+        #[allow(clippy::vec_init_then_push)]
+        let v = {
+            let mut v = Vec::new();
+            $(
+                v.push($x);
+            )+
+            v
+        };
+        v
+    }};
+}
+
+// A HashMap literal, which std doesn't have (as a macro, anyway):
+//
+//      hashmap!{ "one" => 1, "two" => 2 }
+//
+// The full path `::std::collections::HashMap` matters: the macro expands
+// in the *caller's* code, which might not have a `use` for HashMap.
+#[macro_export]
+macro_rules! hashmap {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $(
+            map.insert($key, $value);
+        )+
+        map
+    }};
+}
+
+// A home-made `dbg!`: print where we are, the expression's source text,
+// and its value, then hand the value back so it can be used in the middle
+// of a bigger expression. `file!()`, `line!()` and `stringify!()` are
+// macros too, and because they're expanded at the spot where debug_here!
+// is *used*, they report the caller's file and line, not this one.
+#[macro_export]
+macro_rules! debug_here {
+    ($e:expr) => {{
+        let value = $e;
+        eprintln!("{}", $crate::debug_line!(value, $e));
+        value
+    }};
+}
+
+// The formatting half of debug_here!, split out so tests can look at the
+// text. The one-argument form formats an expression; the two-argument form
+// formats an already-computed value under some other expression's name.
+#[macro_export]
+macro_rules! debug_line {
+    ($e:expr) => {
+        format!("[{}:{}] {} = {:?}", file!(), line!(), stringify!($e), &$e)
+    };
+    ($value:expr, $e:expr) => {
+        format!("[{}:{}] {} = {:?}", file!(), line!(), stringify!($e), &$value)
+    };
+}
+
+// A Display impl from a format string and a list of fields, for the many
+// structs whose `{}` is nothing more than their fields dropped into a
+// sentence. This:
+//
+//      display_as!(Rectangle, "{}x{} rectangle", width, height);
+//
+// writes out the same impl you'd write by hand:
+//
+//      impl std::fmt::Display for Rectangle {
+//          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//              write!(f, "{}x{} rectangle", self.width, self.height)
+//          }
+//      }
+//
+// The fields are `tt`s rather than `ident`s so that a tuple struct's `0`,
+// `1` and `2` work too (09_structs uses it for both kinds). The type is a
+// `ty`, but a struct with generics would need its `<T: Display>` bounds
+// spelled out, and this macro doesn't try: that's the point where a real
+// derive macro (a separate proc-macro crate) earns its keep.
+#[macro_export]
+macro_rules! display_as {
+    ($t:ty, $format:literal $(, $field:tt)* $(,)?) => {
+        impl ::std::fmt::Display for $t {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::write!(f, $format $(, self.$field)*)
+            }
+        }
+    };
+}
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("macros", "my_vec!, hashmap!, debug_here! and display_as!", demo),
+];
+
+// something for display_as! to display (09_structs has real ones)
+struct Version(u32, u32, u32);
+
+// Inside the crate that defines them, no `use` is needed at all: a
+// macro_rules! macro can be called anywhere *below* its definition.
+display_as!(Version, "v{}.{}.{}", 0, 1, 2);
+
+pub fn demo() {
+    println!("--- Macros Demonstration Begins --- ");
+
+    let empty: Vec<u8> = my_vec![];
+    let listed = my_vec![1, 2, 3];
+    let repeated = my_vec!["hi"; 3];
+    println!("my_vec! forms: {:?}, {:?}, {:?}", empty, listed, repeated);
+
+    let capitals = hashmap! {
+        "France" => "Paris",
+        "Japan" => "Tokyo",
+    };
+    println!("hashmap! literal has {} entries; Japan -> {}", capitals.len(), capitals["Japan"]);
+
+    // debug_here! prints to stderr, and passes the value through
+    let total = debug_here!(listed.iter().sum::<i32>()) * 10;
+    println!("total * 10 = {}", total);
+    println!("as a string: {}", debug_line!(total / 2));
+
+    println!("display_as! wrote Version's Display: {}", Version(1, 2, 3));
+
+    println!("--- Macros Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[test]
+    fn my_vec_empty() {
+        let v: Vec<i32> = my_vec![];
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn my_vec_list() {
+        assert_eq!(my_vec![1, 2, 3], vec![1, 2, 3]);
+        assert_eq!(my_vec!["a", "b",], vec!["a", "b"]);
+        // any expressions work, not just literals
+        assert_eq!(my_vec![1 + 1, 2 * 3], vec![2, 6]);
+    }
+
+    #[test]
+    fn my_vec_repeat() {
+        assert_eq!(my_vec![0; 4], vec![0, 0, 0, 0]);
+        assert_eq!(my_vec![String::from("x"); 2], vec![String::from("x"); 2]);
+        let none: Vec<u8> = my_vec![7; 0];
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn hashmap_literal() {
+        let map = hashmap! { "one" => 1, "two" => 2, };
+        let mut expected = HashMap::new();
+        expected.insert("one", 1);
+        expected.insert("two", 2);
+        assert_eq!(map, expected);
+
+        let empty: HashMap<String, u8> = hashmap! {};
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn hashmap_later_keys_win() {
+        let map = hashmap! { 'a' => 1, 'a' => 2 };
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&'a'], 2);
+    }
+
+    #[test]
+    fn debug_line_captures_file_line_and_source() {
+        let x = 5;
+        let (text, line) = (debug_line!(x * 2), line!());
+        // file!() is relative to wherever cargo was started (this package, or
+        // the workspace root), so compare against file!() rather than a literal
+        assert_eq!(text, format!("[{}:{}] x * 2 = 10", file!(), line));
+    }
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    display_as!(Point, "({}, {})", x, y);
+
+    struct Unit;
+    display_as!(Unit, "nothing to see",);
+
+    #[test]
+    fn display_as_named_and_numbered_fields() {
+        assert_eq!(Point { x: 3, y: -4 }.to_string(), "(3, -4)");
+        assert_eq!(super::Version(0, 10, 2).to_string(), "v0.10.2");
+        assert_eq!(Unit.to_string(), "nothing to see");
+        // and the usual width and alignment work on the fields inside
+        struct Padded(u8);
+        display_as!(Padded, "[{:>4}]", 0);
+        assert_eq!(format!("{}", Padded(7)), "[   7]");
+    }
+
+    #[test]
+    fn debug_here_hands_the_value_back() {
+        let doubled = debug_here!(21 * 2);
+        assert_eq!(doubled, 42);
+        // and only evaluates its expression once
+        let mut calls = 0;
+        let mut bump = || {
+            calls += 1;
+            calls
+        };
+        assert_eq!(debug_here!(bump()), 1);
+        assert_eq!(calls, 1);
+    }
+}
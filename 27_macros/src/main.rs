@@ -0,0 +1,17 @@
+/**
+ * Using the macros from lib.rs.
+ *
+ * The demo that exercises them is `macros::demo()`, which lives in lib.rs
+ * so the top-level runner can call it too. But any other crate can use the
+ * macros directly: thanks to `#[macro_export]`, they're available at the
+ * root of the `macros` crate, and can be imported with a plain `use` like
+ * anything else:
+ *
+ *      use macros::{debug_here, debug_line, hashmap, my_vec};
+ *
+ * (The old way was `#[macro_use] extern crate macros;`, which you'll
+ * still see in older code.)
+ */
+fn main() {
+    macros::demo();
+}
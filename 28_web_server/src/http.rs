@@ -0,0 +1,377 @@
+/**
+ * HTTP/1.1 requests and responses, as types instead of strings.
+ *
+ * The book's server matches the whole request line as one string,
+ * "GET / HTTP/1.1", which works until a browser sends "GET /?x=1 HTTP/1.1"
+ * or a client says HTTP/1.0. Here the request is read into its parts:
+ *
+ * ```text
+ * POST /guess HTTP/1.1\r\n         request line: method, path, version
+ * Host: localhost\r\n              headers: "Name: value", one per line
+ * Content-Length: 2\r\n
+ * \r\n                             a blank line ends the headers
+ * 42                               and Content-Length bytes of body
+ * ```
+ *
+ * Anything that doesn't look like that is a ParseError, which the server
+ * answers with a 400 instead of guessing.
+ *
+ * The response goes the other way, from parts to bytes, built up a piece
+ * at a time:
+ *
+ * ```text
+ * HttpResponse::new(404).header("Content-Type", "text/html").body(page)
+ * ```
+ *
+ * and Content-Length is worked out from the body, so it can't be wrong.
+ */
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read};
+use std::time::Duration;
+
+// Bodies bigger than this are refused (with a 413), rather than letting a
+// client make us allocate however much it likes
+pub const MAX_BODY: usize = 1024 * 1024;
+
+// and the same goes for the request line and headers together (a 431)
+pub const MAX_HEAD: usize = 8 * 1024;
+
+// How long a client gets to send its request. Each connection has a worker
+// to itself, so one that connects and says nothing would otherwise keep
+// that worker forever.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub version: String,
+    // in the order they came, names as the client spelled them
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    // the client hung up before sending anything at all
+    Empty,
+    // the stream ended before the blank line, or before the whole body
+    Incomplete,
+    BadRequestLine(String),
+    BadVersion(String),
+    BadHeader(String),
+    BadContentLength(String),
+    TooLarge(usize),
+    // the request line and headers ran past MAX_HEAD
+    HeadTooLarge,
+    NotUtf8,
+    Io(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty request"),
+            ParseError::Incomplete => write!(f, "the request ended too soon"),
+            ParseError::BadRequestLine(line) => write!(f, "bad request line: {:?}", line),
+            ParseError::BadVersion(version) => write!(f, "unsupported HTTP version: {:?}", version),
+            ParseError::BadHeader(line) => write!(f, "bad header: {:?}", line),
+            ParseError::BadContentLength(value) => write!(f, "bad Content-Length: {:?}", value),
+            ParseError::TooLarge(length) => write!(f, "a {} byte body is more than {} allowed", length, MAX_BODY),
+            ParseError::HeadTooLarge => write!(f, "the request line and headers are more than {} bytes", MAX_HEAD),
+            ParseError::NotUtf8 => write!(f, "the request line and headers must be text"),
+            ParseError::Io(e) => write!(f, "reading the request failed: {}", e),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl ParseError {
+    // What to tell the client: a 413 for a body that's too big, a 431 for
+    // headers that are, a 400 for everything else, and nothing at all if it
+    // never said anything
+    pub fn response(&self) -> Option<HttpResponse> {
+        match self {
+            ParseError::Empty => None,
+            ParseError::TooLarge(_) => Some(HttpResponse::new(413)),
+            ParseError::HeadTooLarge => Some(HttpResponse::new(431)),
+            _ => Some(HttpResponse::new(400).header("Content-Type", "text/plain; charset=utf-8").body(self.to_string())),
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> ParseError {
+        ParseError::Io(e.to_string())
+    }
+}
+
+// One line of the head, without its "\r\n" (or bare "\n"); None at the end
+// of the stream. `budget` is how much more of the head may be read, and a
+// line that hasn't ended by the time it runs out is an error: otherwise one
+// endless header line could take all the memory there is.
+fn read_line(reader: &mut impl BufRead, budget: &mut usize) -> Result<Option<String>, ParseError> {
+    if *budget == 0 {
+        return Err(ParseError::HeadTooLarge);
+    }
+    let mut bytes = Vec::new();
+    let read = reader.take(*budget as u64).read_until(b'\n', &mut bytes)?;
+    if read == 0 {
+        return Ok(None);
+    }
+    *budget -= read;
+    if !bytes.ends_with(b"\n") && *budget == 0 {
+        return Err(ParseError::HeadTooLarge);
+    }
+    if bytes.ends_with(b"\n") {
+        bytes.pop();
+        if bytes.ends_with(b"\r") {
+            bytes.pop();
+        }
+    }
+    String::from_utf8(bytes).map(Some).map_err(|_| ParseError::NotUtf8)
+}
+
+fn parse_request_line(line: &str) -> Result<(String, String, String), ParseError> {
+    let bad = || ParseError::BadRequestLine(line.to_string());
+    let parts: Vec<&str> = line.split(' ').collect();
+    let (method, path, version) = match parts[..] {
+        [method, path, version] => (method, path, version),
+        _ => return Err(bad()),
+    };
+    if method.is_empty() || !method.chars().all(|c| c.is_ascii_uppercase()) || !path.starts_with('/') {
+        return Err(bad());
+    }
+    if version != "HTTP/1.1" && version != "HTTP/1.0" {
+        return Err(ParseError::BadVersion(version.to_string()));
+    }
+    Ok((method.to_string(), path.to_string(), version.to_string()))
+}
+
+fn parse_header(line: &str) -> Result<(String, String), ParseError> {
+    match line.split_once(':') {
+        // no spaces allowed in the name: "Bad Name: x" is a typo, not a header
+        Some((name, value)) if !name.is_empty() && !name.contains(char::is_whitespace) => {
+            Ok((name.to_string(), value.trim().to_string()))
+        }
+        _ => Err(ParseError::BadHeader(line.to_string())),
+    }
+}
+
+impl HttpRequest {
+    // Read one request off a stream (or anything else that's BufRead):
+    // the request line, headers up to the blank line, then the body
+    pub fn read_from(reader: &mut impl BufRead) -> Result<HttpRequest, ParseError> {
+        let mut budget = MAX_HEAD;
+        let request_line = read_line(reader, &mut budget)?.ok_or(ParseError::Empty)?;
+        let (method, path, version) = parse_request_line(&request_line)?;
+
+        let mut headers = Vec::new();
+        loop {
+            match read_line(reader, &mut budget)? {
+                None => return Err(ParseError::Incomplete),
+                Some(line) if line.is_empty() => break,
+                Some(line) => headers.push(parse_header(&line)?),
+            }
+        }
+
+        let mut request = HttpRequest { method, path, version, headers, body: Vec::new() };
+        let length = match request.header("Content-Length") {
+            None => 0,
+            Some(value) => value.parse().map_err(|_| ParseError::BadContentLength(value.to_string()))?,
+        };
+        if length > MAX_BODY {
+            return Err(ParseError::TooLarge(length));
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => ParseError::Incomplete,
+            _ => ParseError::from(e),
+        })?;
+        request.body = body;
+        Ok(request)
+    }
+
+    pub fn parse(mut bytes: &[u8]) -> Result<HttpRequest, ParseError> {
+        HttpRequest::read_from(&mut bytes)
+    }
+
+    // a header's value, by name: header names ignore case in HTTP
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    // the path without its "?query"
+    pub fn route(&self) -> &str {
+        self.path.split('?').next().unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+// The words that go with each status code the server uses, in the book's
+// shouty style
+pub fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "BAD REQUEST",
+        404 => "NOT FOUND",
+        405 => "METHOD NOT ALLOWED",
+        413 => "PAYLOAD TOO LARGE",
+        431 => "REQUEST HEADER FIELDS TOO LARGE",
+        500 => "INTERNAL SERVER ERROR",
+        _ => "",
+    }
+}
+
+impl HttpResponse {
+    pub fn new(status: u16) -> HttpResponse {
+        HttpResponse { status, headers: Vec::new(), body: Vec::new() }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> HttpResponse {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    // Bytes rather than a String, because not every body is text
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> HttpResponse {
+        self.body = body.into();
+        self
+    }
+
+    pub fn status_line(&self) -> String {
+        format!("HTTP/1.1 {} {}", self.status, reason(self.status)).trim_end().to_string()
+    }
+
+    // the whole thing, ready to write to the socket
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut head = self.status_line() + "\r\n";
+        for (name, value) in &self.headers {
+            head += &format!("{}: {}\r\n", name, value);
+        }
+        head += &format!("Content-Length: {}\r\n\r\n", self.body.len());
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_simple_get() {
+        let request = HttpRequest::parse(b"GET /a/b?x=1 HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/a/b?x=1");
+        assert_eq!(request.route(), "/a/b");
+        assert_eq!(request.version, "HTTP/1.1");
+        assert_eq!(request.header("host"), Some("localhost"));
+        assert_eq!(request.header("Accept"), None);
+        assert!(request.body.is_empty());
+    }
+
+    #[test]
+    fn reads_exactly_content_length_bytes_of_body() {
+        let bytes = b"POST /guess HTTP/1.0\r\ncontent-length: 2\r\nX-Note:  padded  \r\n\r\n42 and then some";
+        let request = HttpRequest::parse(bytes).unwrap();
+        assert_eq!(request.body, b"42");
+        assert_eq!(request.header("x-note"), Some("padded"));
+        // a bare \n is forgiven, as most servers do
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\n\n").unwrap().path, "/");
+    }
+
+    #[test]
+    fn rejects_malformed_request_lines() {
+        assert_eq!(HttpRequest::parse(b""), Err(ParseError::Empty));
+        for line in ["nonsense", "GET /", "GET / HTTP/1.1 extra", "get / HTTP/1.1", "GET nope HTTP/1.1", " / HTTP/1.1"].iter() {
+            let bytes = format!("{}\r\n\r\n", line);
+            assert_eq!(HttpRequest::parse(bytes.as_bytes()), Err(ParseError::BadRequestLine(line.to_string())));
+        }
+        assert_eq!(
+            HttpRequest::parse(b"GET / HTTP/2\r\n\r\n"),
+            Err(ParseError::BadVersion(String::from("HTTP/2")))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_headers() {
+        assert_eq!(
+            HttpRequest::parse(b"GET / HTTP/1.1\r\nno colon here\r\n\r\n"),
+            Err(ParseError::BadHeader(String::from("no colon here")))
+        );
+        assert!(matches!(HttpRequest::parse(b"GET / HTTP/1.1\r\n: no name\r\n\r\n"), Err(ParseError::BadHeader(_))));
+        assert!(matches!(HttpRequest::parse(b"GET / HTTP/1.1\r\nBad Name: x\r\n\r\n"), Err(ParseError::BadHeader(_))));
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\r\n\xff\xfe\r\n\r\n"), Err(ParseError::NotUtf8));
+    }
+
+    #[test]
+    fn rejects_bad_or_missing_bodies() {
+        assert_eq!(
+            HttpRequest::parse(b"POST / HTTP/1.1\r\nContent-Length: lots\r\n\r\n"),
+            Err(ParseError::BadContentLength(String::from("lots")))
+        );
+        assert_eq!(
+            HttpRequest::parse(b"POST / HTTP/1.1\r\nContent-Length: -1\r\n\r\n"),
+            Err(ParseError::BadContentLength(String::from("-1")))
+        );
+        assert_eq!(HttpRequest::parse(b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort"), Err(ParseError::Incomplete));
+        let huge = format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY + 1);
+        assert_eq!(HttpRequest::parse(huge.as_bytes()), Err(ParseError::TooLarge(MAX_BODY + 1)));
+    }
+
+    #[test]
+    fn the_head_has_a_limit_too() {
+        // one endless header line
+        let endless = format!("GET / HTTP/1.1\r\nX-Long: {}", "x".repeat(MAX_HEAD * 4));
+        assert_eq!(HttpRequest::parse(endless.as_bytes()), Err(ParseError::HeadTooLarge));
+        // or a great many short ones
+        let many = format!("GET / HTTP/1.1\r\n{}\r\n", "X-Short: x\r\n".repeat(MAX_HEAD));
+        assert_eq!(HttpRequest::parse(many.as_bytes()), Err(ParseError::HeadTooLarge));
+        // and just under the limit is fine
+        let header = format!("X-Fits: {}\r\n", "x".repeat(MAX_HEAD - 100));
+        let request = HttpRequest::parse(format!("GET / HTTP/1.1\r\n{}\r\n", header).as_bytes()).unwrap();
+        assert_eq!(request.headers.len(), 1);
+    }
+
+    #[test]
+    fn the_headers_have_to_end() {
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\r\nHost: localhost\r\n"), Err(ParseError::Incomplete));
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1"), Err(ParseError::Incomplete));
+    }
+
+    #[test]
+    fn builds_responses() {
+        let response = HttpResponse::new(404).header("Content-Type", "text/plain").body("gone");
+        assert_eq!(response.status_line(), "HTTP/1.1 404 NOT FOUND");
+        assert_eq!(
+            response.to_bytes(),
+            b"HTTP/1.1 404 NOT FOUND\r\nContent-Type: text/plain\r\nContent-Length: 4\r\n\r\ngone"
+        );
+        // no reason phrase we know of: just the number
+        assert_eq!(HttpResponse::new(418).status_line(), "HTTP/1.1 418");
+        assert_eq!(HttpResponse::new(200).to_bytes(), b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n");
+    }
+
+    #[test]
+    fn parse_errors_become_responses() {
+        assert_eq!(ParseError::Empty.response(), None);
+        assert_eq!(ParseError::TooLarge(MAX_BODY + 1).response().unwrap().status, 413);
+        assert_eq!(ParseError::HeadTooLarge.response().unwrap().status_line(), "HTTP/1.1 431 REQUEST HEADER FIELDS TOO LARGE");
+        let response = ParseError::BadHeader(String::from("oops")).response().unwrap();
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body, b"bad header: \"oops\"");
+    }
+}
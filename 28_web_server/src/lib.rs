@@ -0,0 +1,353 @@
+/**
+ * The guts of the web server: answering one request, and the ThreadPool.
+ *
+ * Spawning a brand new thread for every request would work, right up until
+ * someone sends ten thousand requests and the machine falls over. A pool
+ * starts a fixed number of worker threads once, up front. Jobs go into a
+ * channel, and whichever worker is free takes the next one out.
+ *
+ * ```text
+ *                             +--> Worker 0
+ *      execute(job) --> channel --> Worker 1
+ *                             +--> Worker 2 ...
+ * ```
+ *
+ * The channel's receiving end is shared by every worker, so it sits in an
+ * Arc<Mutex<..>>: the Mutex makes sure only one worker takes each job.
+ *
+ * Shutting down is the other half of the story. When the pool is dropped,
+ * it sends one Terminate message per worker and then joins every thread,
+ * so a job that is halfway through a response gets to finish it instead
+ * of being cut off when main() returns.
+ */
+use std::fs;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// requests and responses, parsed and built rather than matched as strings
+pub mod http;
+// route(), grown up: a table of handler closures, and files from public/
+pub mod router;
+
+use http::{HttpRequest, HttpResponse, READ_TIMEOUT};
+
+// A job is any closure that we can run once, on another thread. Box<dyn ..>
+// because every closure has a different type, and `Send + 'static` because
+// it will cross over to a worker thread and may outlive the caller.
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+// What travels down the channel: either work to do, or an order to stop.
+enum Message {
+    NewJob(Job),
+    Terminate,
+}
+
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: mpsc::Sender<Message>,
+}
+
+impl ThreadPool {
+    // Create a new ThreadPool with `size` worker threads. A pool with no
+    // threads could never run anything, so zero is a bug: panic.
+    pub fn new(size: usize) -> ThreadPool {
+        assert!(size > 0);
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            workers.push(Worker::new(id, Arc::clone(&receiver)));
+        }
+
+        ThreadPool { workers, sender }
+    }
+
+    // The same signature as thread::spawn, minus the return value.
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let job = Box::new(f);
+        self.sender.send(Message::NewJob(job)).unwrap();
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+impl Drop for ThreadPool {
+    // Two separate loops, on purpose. If we sent one Terminate and then
+    // joined worker 0 straight away, worker 1 might be the one that picks
+    // up that Terminate, and we'd wait on worker 0 forever. Sending them
+    // all first guarantees every worker eventually gets one.
+    fn drop(&mut self) {
+        println!("Sending terminate message to all workers.");
+        for _ in &self.workers {
+            self.sender.send(Message::Terminate).unwrap();
+        }
+
+        println!("Shutting down all workers.");
+        for worker in &mut self.workers {
+            println!("Shutting down worker {}", worker.id);
+            // take() leaves None behind: join() needs to own the handle,
+            // and we only have a &mut to the worker
+            if let Some(thread) = worker.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+}
+
+struct Worker {
+    id: usize,
+    // None once the thread has been joined
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    // Each worker loops until told to stop: wait for a message, act on it,
+    // repeat. The lock is only held while *receiving*: `let message = ...;`
+    // ends the statement, which drops the MutexGuard before the job runs.
+    // (Writing this as `while let Ok(message) = receiver.lock().unwrap().recv()`
+    // would keep the lock for the whole loop body, and one slow job would
+    // block them all.)
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Message>>>) -> Worker {
+        let thread = thread::spawn(move || loop {
+            let message = receiver.lock().unwrap().recv().unwrap();
+            match message {
+                Message::NewJob(job) => {
+                    println!("Worker {} got a job; executing.", id);
+                    job();
+                }
+                Message::Terminate => {
+                    println!("Worker {} was told to terminate.", id);
+                    break;
+                }
+            }
+        });
+
+        Worker { id, thread: Some(thread) }
+    }
+}
+
+// Pull `--max-requests N` out of the command line (args[0] is the program
+// name). No flag means None: serve forever.
+pub fn parse_max_requests(args: &[String]) -> Result<Option<usize>, String> {
+    match args.iter().position(|arg| arg == "--max-requests") {
+        None => Ok(None),
+        Some(i) => {
+            let value = args.get(i + 1).ok_or("--max-requests needs a number")?;
+            value
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("--max-requests: '{}' is not a number", value))
+        }
+    }
+}
+
+// Pull `--port N` out of the command line, the same way. No flag means the
+// book's 7878, and 0 asks the OS for any free port (main prints which).
+pub fn parse_port(args: &[String]) -> Result<u16, String> {
+    match args.iter().position(|arg| arg == "--port") {
+        None => Ok(7878),
+        Some(i) => {
+            let value = args.get(i + 1).ok_or("--port needs a number")?;
+            value
+                .parse()
+                .map_err(|_| format!("--port: '{}' is not a port number", value))
+        }
+    }
+}
+
+// Accept connections and hand each one to the pool. With a limit, stop
+// after that many: take() ends the loop, the pool goes out of scope at the
+// end of the function, and its Drop waits for the last responses to go out.
+// That's the only way out of here, so it's what exercises the shutdown path.
+pub fn serve(listener: TcpListener, pool_size: usize, max_requests: Option<usize>) {
+    let pool = ThreadPool::new(pool_size);
+    let limit = max_requests.unwrap_or(usize::MAX);
+
+    for stream in listener.incoming().take(limit) {
+        match stream {
+            Ok(stream) => pool.execute(|| handle_connection(stream)),
+            Err(e) => eprintln!("Connection failed: {}", e),
+        }
+    }
+
+    println!("Served {} request(s); shutting down.", limit);
+}
+
+// The pages live next to Cargo.toml. Building the path from the manifest
+// directory (baked in at compile time) means they're found no matter which
+// directory the server, or a test, happens to be started from.
+fn page(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(name);
+    fs::read_to_string(path).unwrap_or_else(|e| format!("<h1>missing {}: {}</h1>", name, e))
+}
+
+// Map the request to a status code and page. Matching on its parts (see
+// http.rs) rather than the raw request line means "GET /?x=1 HTTP/1.0"
+// still finds the hello page.
+pub fn route(request: &HttpRequest) -> (u16, &'static str) {
+    match (request.method.as_str(), request.route()) {
+        ("GET", "/") => (200, "hello.html"),
+        ("GET", "/sleep") => {
+            // simulate a slow request, to show why we want a pool
+            thread::sleep(Duration::from_secs(5));
+            (200, "hello.html")
+        }
+        _ => (404, "404.html"),
+    }
+}
+
+// Read the request, and write back a response. One that won't parse gets
+// a 400 (or a 413 or 431, if its body or head is too big), and no page.
+pub fn handle_connection(mut stream: TcpStream) {
+    // (a client that never sends anything then gets an error, not a worker)
+    if let Err(e) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+        eprintln!("Failed to set a timeout: {}", e);
+        return;
+    }
+    let response = match HttpRequest::read_from(&mut BufReader::new(&mut stream)) {
+        Ok(request) => {
+            let (status, filename) = route(&request);
+            HttpResponse::new(status).header("Content-Type", "text/html; charset=utf-8").body(page(filename))
+        }
+        Err(e) => match e.response() {
+            Some(response) => response,
+            // the client hung up before saying anything: nobody to answer
+            None => return,
+        },
+    };
+
+    // a client that has already gone away isn't worth crashing a worker for
+    if let Err(e) = stream.write_all(&response.to_bytes()) {
+        eprintln!("Failed to respond: {}", e);
+    }
+}
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("web_server", "Serve two requests on a local port, then shut down", demo_web_server),
+    Demo::new("router", "Handler closures, static files, 404s and 500s", router::demo_router),
+];
+
+// What the top-level runner shows for this chapter. A server that runs
+// until Ctrl-C would never hand control back, so instead this one listens
+// on any free port, plays its own client for two requests, and then shuts
+// itself down through the --max-requests path.
+pub fn demo() {
+    demo_web_server();
+    router::demo_router();
+}
+
+pub fn demo_web_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    println!("Listening on http://{}", addr);
+    let server = thread::spawn(move || serve(listener, 2, Some(2)));
+
+    for path in ["/", "/no/such/page"].iter() {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        println!("GET {} -> {}", path, response.lines().next().unwrap_or(""));
+    }
+
+    server.join().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_has_the_requested_size() {
+        assert_eq!(ThreadPool::new(3).size(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_pool_panics() {
+        ThreadPool::new(0);
+    }
+
+    #[test]
+    fn pool_runs_every_job() {
+        let pool = ThreadPool::new(4);
+        let (tx, rx) = mpsc::channel();
+        for i in 0..20 {
+            let tx = tx.clone();
+            pool.execute(move || tx.send(i).unwrap());
+        }
+        drop(tx);
+        let mut done: Vec<i32> = rx.iter().take(20).collect();
+        done.sort();
+        assert_eq!(done, (0..20).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn dropping_the_pool_waits_for_running_jobs() {
+        let finished = Arc::new(Mutex::new(0));
+        let pool = ThreadPool::new(2);
+        for _ in 0..4 {
+            let finished = Arc::clone(&finished);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(50));
+                *finished.lock().unwrap() += 1;
+            });
+        }
+        drop(pool);
+        // no waiting or polling needed: drop() already joined every worker
+        assert_eq!(*finished.lock().unwrap(), 4);
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn max_requests_flag() {
+        assert_eq!(parse_max_requests(&args(&["server"])), Ok(None));
+        assert_eq!(parse_max_requests(&args(&["server", "--max-requests", "2"])), Ok(Some(2)));
+        assert!(parse_max_requests(&args(&["server", "--max-requests"])).is_err());
+        assert!(parse_max_requests(&args(&["server", "--max-requests", "lots"])).is_err());
+    }
+
+    #[test]
+    fn port_flag() {
+        assert_eq!(parse_port(&args(&["server"])), Ok(7878));
+        assert_eq!(parse_port(&args(&["server", "--port", "0"])), Ok(0));
+        assert!(parse_port(&args(&["server", "--port"])).is_err());
+        assert!(parse_port(&args(&["server", "--port", "70000"])).is_err());
+    }
+
+    fn request(head: &str) -> HttpRequest {
+        HttpRequest::parse(format!("{}\r\n\r\n", head).as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn routes() {
+        assert_eq!(route(&request("GET / HTTP/1.1")), (200, "hello.html"));
+        assert_eq!(route(&request("GET /?from=a-link HTTP/1.0")), (200, "hello.html"));
+        assert_eq!(route(&request("GET /nope HTTP/1.1")), (404, "404.html"));
+        assert_eq!(route(&request("POST / HTTP/1.1")).0, 404);
+    }
+
+    #[test]
+    fn pages_are_found() {
+        assert!(page("hello.html").contains("Hi from Rust"));
+        assert!(page("404.html").contains("Oops!"));
+    }
+}
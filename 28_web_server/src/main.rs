@@ -0,0 +1,56 @@
+/**
+ * The book's final project: a multi-threaded web server, from scratch.
+ *
+ *      cargo run
+ *
+ * and then visit http://127.0.0.1:7878 in a browser. Also try /sleep in one
+ * tab, which takes five seconds to answer, and / in another: with a pool
+ * of threads, the slow request no longer holds up the fast one.
+ *
+ * No web framework, no HTTP crate: just a TcpListener from std, some
+ * string handling for the (tiny) bit of HTTP we speak (in http.rs), and a
+ * ThreadPool we build ourselves in lib.rs. It pulls together most of the
+ * book: structs, enums, error handling, traits, closures, smart pointers,
+ * and threads.
+ *
+ * Left alone, it serves forever and the only way out is Ctrl-C. To watch
+ * a graceful shutdown instead, cap the number of requests:
+ *
+ *      cargo run -- --max-requests 2
+ *
+ * After the second request the pool is dropped, which tells each worker to
+ * stop and waits for all of them to finish before the program exits.
+ *
+ * If 7878 is taken, `--port` picks another; `--port 0` lets the OS choose.
+ *
+ * `--router` serves the same way, but through the Router in router.rs:
+ * pages from handler closures, and every file in public/.
+ */
+use std::env;
+use std::net::TcpListener;
+use std::process;
+
+use web_server::{parse_max_requests, parse_port, router, serve};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let (max_requests, port) = match (parse_max_requests(&args), parse_port(&args)) {
+        (Ok(max_requests), Ok(port)) => (max_requests, port),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("Problem parsing arguments: {}", err);
+            process::exit(1);
+        }
+    };
+
+    // bind() can fail (the port is taken, or needs admin rights), and a
+    // server that can't listen has nothing else to do, so unwrap is fair
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+    // ask the listener, rather than echoing `port`, which might be 0
+    println!("Listening on http://{}", listener.local_addr().unwrap());
+
+    if args.iter().any(|arg| arg == "--router") {
+        router::serve(listener, 4, max_requests, router::app());
+    } else {
+        serve(listener, 4, max_requests);
+    }
+}
@@ -0,0 +1,358 @@
+/**
+ * A Router: the `match` in lib.rs's route(), turned into data.
+ *
+ * route() is fine for two pages, but every new page means editing the
+ * match, and the answer is always a file. A Router instead keeps a table
+ * from (method, path) to a handler closure, which can build any response
+ * it likes, and falls back on the files in a directory for everything the
+ * table doesn't know:
+ *
+ * ```text
+ * GET /hello        --> table --> the closure registered for it
+ * GET /style.css    --> table: no --> public/style.css, as text/css
+ * GET /             --> table: no --> public/index.html
+ * GET /nope         --> table: no --> public/: no --> public/404.html
+ * POST /hello       --> table: /hello is there, but not for POST --> 405
+ * POST /style.css   --> table: no --> public/style.css, but only for GET --> 405
+ * a handler's Err   --> 500 (and so does a handler that panics)
+ * ```
+ *
+ * A 405 says which methods *would* have worked, in an Allow header: HTTP
+ * insists on that much.
+ *
+ * The handlers run on the ThreadPool's workers, several at once, so the
+ * Router is shared through an Arc and every handler must be Send + Sync.
+ */
+use crate::http::{HttpRequest, HttpResponse, READ_TIMEOUT};
+use crate::ThreadPool;
+use std::collections::HashMap;
+use std::fs;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+// Every error page is the same short HTML, with the message in it. A
+// handler's message can have bits of the request in it, so it's escaped:
+// otherwise a request could put its own HTML (or script) in the page.
+fn error(status: u16, message: &str) -> HttpResponse {
+    HttpResponse::new(status)
+        .header("Content-Type", "text/html; charset=utf-8")
+        .body(format!("<h1>{}</h1>\n", escape_html(message)))
+}
+
+// `text` with the characters that mean something in HTML written as entities
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Anything that turns a request into a response, or fails trying. An Err
+// becomes a 500, with the message in the page.
+pub type Handler = Box<dyn Fn(&HttpRequest) -> Result<HttpResponse, String> + Send + Sync>;
+
+#[derive(Default)]
+pub struct Router {
+    routes: HashMap<(String, String), Handler>,
+    public: Option<PathBuf>,
+}
+
+impl Router {
+    pub fn new() -> Router {
+        Router::default()
+    }
+
+    // Register `handler` for `method` and `path`. A second registration
+    // for the same pair replaces the first.
+    pub fn route<F>(mut self, method: &str, path: &str, handler: F) -> Router
+    where
+        F: Fn(&HttpRequest) -> Result<HttpResponse, String> + Send + Sync + 'static,
+    {
+        self.routes.insert((method.to_string(), path.to_string()), Box::new(handler));
+        self
+    }
+
+    pub fn get<F>(self, path: &str, handler: F) -> Router
+    where
+        F: Fn(&HttpRequest) -> Result<HttpResponse, String> + Send + Sync + 'static,
+    {
+        self.route("GET", path, handler)
+    }
+
+    // serve the files under `dir` for any GET the table doesn't answer
+    pub fn public(mut self, dir: impl Into<PathBuf>) -> Router {
+        self.public = Some(dir.into());
+        self
+    }
+
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let key = (request.method.clone(), request.route().to_string());
+        if let Some(handler) = self.routes.get(&key) {
+            // A panic would otherwise take the worker thread down with it,
+            // and the pool would be one worker short from then on.
+            // (AssertUnwindSafe: a handler only gets `&` access to anything,
+            // so there's no half-changed state for anyone to see afterwards.)
+            return match panic::catch_unwind(AssertUnwindSafe(|| handler(request))) {
+                Ok(Ok(response)) => response,
+                Ok(Err(message)) => error(500, &message),
+                Err(_) => error(500, "the handler panicked"),
+            };
+        }
+        if request.method == "GET" {
+            if let Some(response) = self.static_file(request.route()) {
+                return response;
+            }
+        }
+        let allowed = self.allowed(request.route());
+        if !allowed.is_empty() {
+            return error(405, "Method not allowed").header("Allow", &allowed.join(", "));
+        }
+        self.not_found()
+    }
+
+    // every method something answers for `path`, in order: the table's, and
+    // GET for a file
+    fn allowed(&self, path: &str) -> Vec<&str> {
+        let mut methods: Vec<&str> =
+            self.routes.keys().filter(|(_, route)| route == path).map(|(method, _)| method.as_str()).collect();
+        let is_file = self.public.as_ref().and_then(|dir| resolve(dir, path)).is_some();
+        if is_file && !methods.contains(&"GET") {
+            methods.push("GET");
+        }
+        methods.sort();
+        methods
+    }
+
+    fn static_file(&self, path: &str) -> Option<HttpResponse> {
+        let file = resolve(self.public.as_ref()?, path)?;
+        let body = fs::read(&file).ok()?;
+        Some(HttpResponse::new(200).header("Content-Type", content_type(&file)).body(body))
+    }
+
+    // the public directory's own 404.html, if it has one
+    fn not_found(&self) -> HttpResponse {
+        let page = self.public.as_ref().and_then(|dir| fs::read(dir.join("404.html")).ok());
+        match page {
+            Some(body) => HttpResponse::new(404).header("Content-Type", "text/html; charset=utf-8").body(body),
+            None => error(404, "Not found"),
+        }
+    }
+}
+
+// Where `path` points inside `dir`, if it's a file that's really in there.
+// Anything with a ".." in it is turned away before it gets near the disk:
+// "/../Cargo.toml" would otherwise be a way out of the public directory.
+// A directory means its index.html, the way every web server does it.
+pub fn resolve(dir: &Path, path: &str) -> Option<PathBuf> {
+    let relative = Path::new(path.trim_start_matches('/'));
+    if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+        return None;
+    }
+    let mut file = dir.join(relative);
+    if file.is_dir() {
+        file.push("index.html");
+    }
+    if file.is_file() {
+        Some(file)
+    } else {
+        None
+    }
+}
+
+// The Content-Type for a file, from its extension. Browsers need this to
+// know whether they're looking at a page, a stylesheet or a picture.
+pub fn content_type(file: &Path) -> &'static str {
+    let extension = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    match extension.to_ascii_lowercase().as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "txt" => "text/plain; charset=utf-8",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "wasm" => "application/wasm",
+        // "some bytes": the browser will offer to download it
+        _ => "application/octet-stream",
+    }
+}
+
+// The same as lib.rs's handle_connection, with the Router deciding
+pub fn handle_connection(router: &Router, mut stream: TcpStream) {
+    // (a client that never sends anything then gets an error, not a worker)
+    if let Err(e) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+        eprintln!("Failed to set a timeout: {}", e);
+        return;
+    }
+    let response = match HttpRequest::read_from(&mut BufReader::new(&mut stream)) {
+        Ok(request) => router.handle(&request),
+        Err(e) => match e.response() {
+            Some(response) => response,
+            None => return,
+        },
+    };
+    if let Err(e) = stream.write_all(&response.to_bytes()) {
+        eprintln!("Failed to respond: {}", e);
+    }
+}
+
+// lib.rs's serve(), with a Router. Every worker needs the Router, so it
+// goes in an Arc, and each job gets a clone of the Arc (not the Router).
+pub fn serve(listener: TcpListener, pool_size: usize, max_requests: Option<usize>, router: Router) {
+    let pool = ThreadPool::new(pool_size);
+    let router = Arc::new(router);
+    let limit = max_requests.unwrap_or(usize::MAX);
+
+    for stream in listener.incoming().take(limit) {
+        match stream {
+            Ok(stream) => {
+                let router = Arc::clone(&router);
+                pool.execute(move || handle_connection(&router, stream));
+            }
+            Err(e) => eprintln!("Connection failed: {}", e),
+        }
+    }
+
+    println!("Served {} request(s); shutting down.", limit);
+}
+
+// route()'s hello page, the files in public/, and a route that always
+// fails, to show off the 500
+pub fn app() -> Router {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let hello = dir.join("hello.html");
+    Router::new()
+        .get("/hello", move |_| {
+            let page = fs::read(&hello).map_err(|e| e.to_string())?;
+            Ok(HttpResponse::new(200).header("Content-Type", "text/html; charset=utf-8").body(page))
+        })
+        .get("/broken", |_| Err(String::from("this route is broken on purpose")))
+        .public(dir.join("public"))
+}
+
+// Like demo_web_server, this one plays its own client, then shuts down
+pub fn demo_router() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    println!("Listening on http://{}", addr);
+    let paths = ["/", "/hello", "/style.css", "/nope", "/broken", "/../Cargo.toml"];
+    let server = std::thread::spawn(move || serve(listener, 2, Some(paths.len()), app()));
+
+    for path in paths.iter() {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let content_type = response.lines().find_map(|line| line.strip_prefix("Content-Type: ")).unwrap_or("");
+        println!("GET {} -> {} ({})", path, response.lines().next().unwrap_or(""), content_type);
+    }
+
+    server.join().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, path: &str) -> HttpRequest {
+        HttpRequest::parse(format!("{} {} HTTP/1.1\r\n\r\n", method, path).as_bytes()).unwrap()
+    }
+
+    fn get(path: &str) -> HttpRequest {
+        request("GET", path)
+    }
+
+    fn text(body: &str) -> HttpResponse {
+        HttpResponse::new(200).header("Content-Type", "text/plain").body(body)
+    }
+
+    fn public() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("public")
+    }
+
+    fn allow(response: &HttpResponse) -> Option<&str> {
+        response.headers.iter().find(|(name, _)| name == "Allow").map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn content_types_by_extension() {
+        assert_eq!(content_type(Path::new("a/index.html")), "text/html; charset=utf-8");
+        assert_eq!(content_type(Path::new("style.CSS")), "text/css; charset=utf-8");
+        assert_eq!(content_type(Path::new("logo.png")), "image/png");
+        assert_eq!(content_type(Path::new("Makefile")), "application/octet-stream");
+    }
+
+    #[test]
+    fn resolves_files_inside_the_directory_only() {
+        assert_eq!(resolve(&public(), "/style.css"), Some(public().join("style.css")));
+        assert_eq!(resolve(&public(), "/"), Some(public().join("index.html")));
+        assert_eq!(resolve(&public(), "/no-such-file.txt"), None);
+        assert_eq!(resolve(&public(), "/../Cargo.toml"), None);
+    }
+
+    #[test]
+    fn the_table_comes_before_the_files() {
+        let router = Router::new().get("/style.css", |_| Ok(text("from the table"))).public(public());
+        assert_eq!(router.handle(&get("/style.css")).body, b"from the table");
+        assert_eq!(router.handle(&get("/index.html")).status, 200);
+        // the query string doesn't get in the way of either
+        assert_eq!(router.handle(&get("/style.css?v=2")).body, b"from the table");
+        assert_eq!(router.handle(&get("/index.html?v=2")).status, 200);
+    }
+
+    #[test]
+    fn errors_and_panics_are_500s() {
+        let router = Router::new()
+            .get("/err", |_| Err(String::from("nope")))
+            .get("/panic", |_| panic!("boom"));
+        let response = router.handle(&get("/err"));
+        assert_eq!(response.status, 500);
+        assert_eq!(response.body, b"<h1>nope</h1>\n");
+        assert_eq!(router.handle(&get("/panic")).status, 500);
+    }
+
+    #[test]
+    fn error_messages_are_escaped() {
+        let router = Router::new().get("/echo", |request| Err(format!("no {}", request.path)));
+        let response = router.handle(&get("/echo?<script>"));
+        assert_eq!(response.body, b"<h1>no /echo?&lt;script&gt;</h1>\n");
+        assert_eq!(escape_html(r#"a & "b""#), "a &amp; &quot;b&quot;");
+    }
+
+    #[test]
+    fn wrong_method_and_missing_paths() {
+        let router = Router::new().get("/", |_| Ok(text("hi"))).route("PUT", "/", |_| Ok(text("put")));
+        let response = router.handle(&request("POST", "/"));
+        assert_eq!(response.status, 405);
+        assert_eq!(allow(&response), Some("GET, PUT"));
+        // no public directory: a plain 404
+        assert_eq!(router.handle(&get("/missing")).status, 404);
+        // with one: its own 404 page
+        let response = router.public(public()).handle(&get("/missing"));
+        assert_eq!(response.status, 404);
+        assert!(String::from_utf8(response.body).unwrap().contains("Nothing here"));
+    }
+
+    #[test]
+    fn files_are_only_for_get() {
+        let router = Router::new().public(public());
+        let response = router.handle(&request("POST", "/style.css"));
+        assert_eq!(response.status, 405);
+        assert_eq!(allow(&response), Some("GET"));
+        assert_eq!(router.handle(&request("POST", "/missing")).status, 404);
+    }
+}
@@ -0,0 +1,29 @@
+/**
+ * The request parser, fed thousands of made-up requests (see
+ * common/src/fuzz.rs). Whatever a client sends, the server should answer
+ * with a 400, 413 or 431 (a ParseError), never fall over: a panic here would
+ * be a way to knock out a worker thread from outside.
+ */
+use common::fuzz;
+use web_server::http::{HttpRequest, ParseError};
+
+#[test]
+fn requests() {
+    let corpus: &[&[u8]] = &[
+        b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        b"GET /hello?name=frog HTTP/1.0\r\n\r\n",
+        b"POST /echo HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello",
+        b"nonsense\r\n\r\n",
+    ];
+    fuzz::check("HttpRequest::parse", corpus, |bytes| match HttpRequest::parse(bytes) {
+        Ok(request) => {
+            assert!(!request.method.is_empty());
+            // the query string never leaks into the route
+            assert!(!request.route().contains('?'));
+        }
+        // nothing to answer
+        Err(ParseError::Empty) => {}
+        // but every other error has a response to send back
+        Err(e) => assert!(e.response().is_some(), "{:?}", e),
+    });
+}
@@ -0,0 +1,29 @@
+/**
+ * The server starting up and shutting straight down again, checked against
+ * tests/golden/ (the how and why are in common/src/golden.rs).
+ *
+ * `--port 0` takes any free port, so this never collides with a server
+ * already on 7878, and `--max-requests 0` means it stops without waiting
+ * for a browser. Two things still vary from run to run, so they're evened
+ * out before comparing: the port number, and the order of the shutdown
+ * messages, which the workers print from their own threads.
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn starts_and_stops() {
+    let mut command = golden::command(env!("CARGO_BIN_EXE_web_server"), DIR);
+    let output = golden::output(command.args(["--port", "0", "--max-requests", "0"]), "");
+
+    let mut lines: Vec<String> = output
+        .lines()
+        .map(|line| match line.strip_prefix("Listening on http://127.0.0.1:") {
+            Some(_) => String::from("Listening on http://127.0.0.1:<port>"),
+            None => line.to_string(),
+        })
+        .collect();
+    lines.sort();
+    golden::check(DIR, "starts_and_stops", &(lines.join("\n") + "\n"));
+}
@@ -0,0 +1,110 @@
+/**
+ * End-to-end, like server.rs, but through the Router: a live server on an
+ * ephemeral port, with router::app()'s routes and the files in public/.
+ */
+use std::io::prelude::*;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+
+use web_server::http::HttpResponse;
+use web_server::router::{self, Router};
+
+// start router::serve in the background, and return its address
+fn start(router: Router) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || router::serve(listener, 2, None, router));
+    addr
+}
+
+// send one request, and split the reply into its status line, its
+// Content-Type, and its body
+fn request(addr: SocketAddr, method: &str, path: &str) -> (String, String, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\n\r\n", method, path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_at(response.find("\r\n\r\n").unwrap() + 4);
+    let status = head.lines().next().unwrap().to_string();
+    let content_type = head.lines().find_map(|line| line.strip_prefix("Content-Type: ")).unwrap().to_string();
+    (status, content_type, body.to_string())
+}
+
+fn get(addr: SocketAddr, path: &str) -> (String, String, String) {
+    request(addr, "GET", path)
+}
+
+#[test]
+fn handlers_answer_their_routes() {
+    let addr = start(router::app());
+    let (status, content_type, body) = get(addr, "/hello");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert_eq!(content_type, "text/html; charset=utf-8");
+    assert!(body.contains("Hi from Rust"));
+}
+
+#[test]
+fn static_files_come_with_their_content_type() {
+    let addr = start(router::app());
+    let (status, content_type, body) = get(addr, "/style.css");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert_eq!(content_type, "text/css; charset=utf-8");
+    assert!(body.contains("font-family"));
+
+    // and "/" is the directory, so its index.html
+    let (status, content_type, body) = get(addr, "/");
+    assert_eq!((status.as_str(), content_type.as_str()), ("HTTP/1.1 200 OK", "text/html; charset=utf-8"));
+    assert!(body.contains("served by the Router"));
+}
+
+#[test]
+fn unknown_paths_get_the_404_page() {
+    let addr = start(router::app());
+    let (status, _, body) = get(addr, "/no/such/page.html");
+    assert_eq!(status, "HTTP/1.1 404 NOT FOUND");
+    assert!(body.contains("Nothing here"));
+}
+
+#[test]
+fn nothing_outside_public_is_served() {
+    let addr = start(router::app());
+    for path in ["/../Cargo.toml", "/../src/lib.rs", "/%2e%2e/Cargo.toml"].iter() {
+        assert_eq!(get(addr, path).0, "HTTP/1.1 404 NOT FOUND", "for {}", path);
+    }
+}
+
+#[test]
+fn failing_handlers_get_a_500() {
+    let addr = start(router::app());
+    let (status, _, body) = get(addr, "/broken");
+    assert_eq!(status, "HTTP/1.1 500 INTERNAL SERVER ERROR");
+    assert!(body.contains("broken on purpose"));
+}
+
+#[test]
+fn a_panicking_handler_does_not_take_the_server_down() {
+    let router = Router::new()
+        .get("/panic", |_| panic!("a bug in a handler"))
+        .get("/fine", |_| Ok(HttpResponse::new(200).header("Content-Type", "text/plain").body("still here")));
+    let addr = start(router);
+    // more panics than the pool has workers: if each one killed a worker,
+    // nobody would be left to answer /fine
+    for _ in 0..4 {
+        assert_eq!(get(addr, "/panic").0, "HTTP/1.1 500 INTERNAL SERVER ERROR");
+    }
+    assert_eq!(get(addr, "/fine"), (String::from("HTTP/1.1 200 OK"), String::from("text/plain"), String::from("still here")));
+}
+
+#[test]
+fn wrong_methods_and_bad_requests() {
+    let addr = start(router::app());
+    assert_eq!(request(addr, "POST", "/hello").0, "HTTP/1.1 405 METHOD NOT ALLOWED");
+    // files are only for GET, so a POST of one is a 405 too
+    assert_eq!(request(addr, "POST", "/style.css").0, "HTTP/1.1 405 METHOD NOT ALLOWED");
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "nonsense\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 400 BAD REQUEST\r\n"), "got: {}", response);
+}
@@ -0,0 +1,114 @@
+/**
+ * End-to-end: start the server for real and talk HTTP to it.
+ *
+ * Binding to port 0 asks the OS for any free port, so this test never
+ * clashes with a server already running on 7878 (or with itself, when the
+ * tests run in parallel). `local_addr()` tells us which port we got.
+ */
+use std::io::prelude::*;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+
+use web_server::{handle_connection, serve, ThreadPool};
+
+// start a server in the background, and return its address
+fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        let pool = ThreadPool::new(2);
+        for stream in listener.incoming() {
+            let stream = stream.unwrap();
+            pool.execute(|| handle_connection(stream));
+        }
+    });
+    addr
+}
+
+// send one request, and read the reply until the server hangs up
+fn fetch(addr: SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn serves_the_hello_page() {
+    let addr = start_server();
+    let response = fetch(addr, "/");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "got: {}", response);
+    assert!(response.contains("Hi from Rust"));
+}
+
+#[test]
+fn unknown_paths_get_a_404() {
+    let addr = start_server();
+    let response = fetch(addr, "/no/such/page");
+    assert!(response.starts_with("HTTP/1.1 404 NOT FOUND\r\n"), "got: {}", response);
+    assert!(response.contains("Oops!"));
+}
+
+#[test]
+fn content_length_matches_the_body() {
+    let addr = start_server();
+    let response = fetch(addr, "/");
+    let (head, body) = response.split_at(response.find("\r\n\r\n").unwrap() + 4);
+    let length: usize = head
+        .lines()
+        .find_map(|line| line.strip_prefix("Content-Length: "))
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap();
+    assert_eq!(length, body.len());
+}
+
+#[test]
+fn handles_several_requests_at_once() {
+    let addr = start_server();
+    let clients: Vec<thread::JoinHandle<String>> =
+        (0..8).map(|_| thread::spawn(move || fetch(addr, "/"))).collect();
+    for client in clients {
+        assert!(client.join().unwrap().contains("200 OK"));
+    }
+}
+
+#[test]
+fn max_requests_shuts_the_server_down() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || serve(listener, 2, Some(3)));
+
+    for _ in 0..3 {
+        assert!(fetch(addr, "/").contains("200 OK"));
+    }
+    // serve() only returns once the pool has joined all its workers, so if
+    // shutdown were broken this join would hang the test
+    server.join().unwrap();
+    assert!(TcpStream::connect(addr).is_err());
+}
+
+#[test]
+fn query_strings_and_http_1_0_still_find_the_page() {
+    let addr = start_server();
+    assert!(fetch(addr, "/?from=a-link").starts_with("HTTP/1.1 200 OK\r\n"));
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET / HTTP/1.0\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.contains("Hi from Rust"), "got: {}", response);
+}
+
+#[test]
+fn malformed_requests_get_a_400() {
+    let addr = start_server();
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET / HTTP/1.1\r\nthis is not a header\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 400 BAD REQUEST\r\n"), "got: {}", response);
+    assert!(response.ends_with("bad header: \"this is not a header\""));
+}
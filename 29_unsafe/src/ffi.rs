@@ -0,0 +1,68 @@
+/**
+ * FFI: calling a function written in C.
+ *
+ * An `extern "C"` block declares functions that live in some other
+ * language, using the C calling convention (the "application binary
+ * interface", or ABI). Rust can't check what foreign code does, so every
+ * call is unsafe.
+ *
+ * `abs` comes from the C standard library, which Rust programs link
+ * against already on every mainstream platform, so there's nothing to set
+ * up. For any other C library we'd need a `#[link(name = "...")]` too.
+ */
+use std::os::raw::c_int;
+
+// C's `int` isn't *guaranteed* to be 32 bits, so FFI declarations use the
+// c_int alias, which is whatever `int` is on the platform we build for.
+extern "C" {
+    fn abs(input: c_int) -> c_int;
+}
+
+// The safe wrapper. C's abs(INT_MIN) is undefined behavior (the answer
+// doesn't fit in an int), so we refuse that one input rather than pass it
+// along. Every other i32 is fine.
+pub fn c_abs(input: i32) -> Option<i32> {
+    if input == i32::MIN {
+        return None;
+    }
+    // SAFETY: abs has no preconditions beyond a representable result,
+    // which excluding i32::MIN guarantees. It touches no memory.
+    Some(unsafe { abs(input) })
+}
+
+// The other direction: a Rust function that C code could call. No mangling,
+// so the linker sees the plain name, and the C calling convention.
+#[no_mangle]
+pub extern "C" fn call_from_c(x: i32) -> i32 {
+    x.wrapping_mul(2)
+}
+
+pub fn demo_ffi() {
+    println!("--- FFI Demonstration Begins --- ");
+    println!("Absolute value of -3 according to C: {:?}", c_abs(-3));
+    println!("...and of i32::MIN: {:?}", c_abs(i32::MIN));
+    println!("A C-callable Rust fn, called from Rust: {}", call_from_c(21));
+    println!("--- FFI Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_agrees_with_rust() {
+        for n in [-100, -1, 0, 1, 100, i32::MAX, i32::MIN + 1].iter() {
+            assert_eq!(c_abs(*n), Some(n.abs()));
+        }
+    }
+
+    #[test]
+    fn min_is_rejected() {
+        assert_eq!(c_abs(i32::MIN), None);
+    }
+
+    #[test]
+    fn exported_fn_works_from_rust_too() {
+        assert_eq!(call_from_c(4), 8);
+    }
+}
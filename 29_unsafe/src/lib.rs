@@ -0,0 +1,50 @@
+/**
+ * Unsafe Rust: turning off (some of) the safety checks, on purpose.
+ *
+ * The compiler is conservative. It rejects every program it can't *prove*
+ * is memory safe, and that includes some programs that are perfectly fine.
+ * Plus, the hardware underneath is fundamentally unsafe, and some code
+ * (operating systems, allocators, talking to C) has to deal with it.
+ *
+ * An `unsafe` block unlocks exactly five extra powers:
+ *
+ * - dereferencing a raw pointer
+ * - calling an unsafe function or method (including foreign C functions)
+ * - accessing or modifying a mutable static variable
+ * - implementing an unsafe trait
+ * - accessing the fields of a union
+ *
+ * That's all. The borrow checker and every other check stay switched on.
+ * The idea is to keep unsafe code small, wrap it in a *safe* function, and
+ * write down (in a `// SAFETY:` comment) why the rules really are upheld.
+ * Then, if memory ever does go wrong, there are only a few places to look.
+ */
+mod raw_pointers;
+mod split;
+mod ffi;
+mod statics;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("raw_pointers", "*const T and *mut T, and unsafe fns", raw_pointers::demo_raw_pointers),
+    Demo::new("split", "A safe API around unsafe internals: split_at_mut", split::demo_split),
+    Demo::new("ffi", "Calling C code", ffi::demo_ffi),
+    Demo::new("statics", "Global mutable state", statics::demo_statics),
+];
+
+pub fn demo() {
+
+    // *const T and *mut T, and unsafe fns
+    raw_pointers::demo_raw_pointers();
+
+    // a safe API around unsafe internals: split_at_mut
+    split::demo_split();
+
+    // calling C code
+    ffi::demo_ffi();
+
+    // global mutable state
+    statics::demo_statics();
+}
@@ -0,0 +1,101 @@
+/**
+ * Raw pointers: `*const T` and `*mut T`.
+ *
+ * Unlike references, raw pointers may be null, may dangle, may alias (a
+ * `*mut` and a `*const` to the same place at once), and carry no lifetime.
+ * *Creating* one is safe, since a pointer that's never followed can't hurt
+ * anybody. *Dereferencing* one is where `unsafe` comes in.
+ */
+// An unsafe fn: the caller promises something the compiler can't check.
+// Here, the promise is that `ptr` points to a valid, readable i32.
+//
+// # Safety
+//
+// `ptr` must be non-null, aligned, and point to an initialized i32 that
+// is not being written to by anyone else for the duration of the call.
+pub unsafe fn read_raw(ptr: *const i32) -> i32 {
+    *ptr
+}
+
+// The safe wrapper: a reference is always valid, so turning it into a
+// pointer and reading back through it can't go wrong.
+pub fn read_via_pointer(value: &i32) -> i32 {
+    let ptr = value as *const i32;
+    // SAFETY: `ptr` came from a live reference, so it's non-null, aligned
+    // and initialized, and the shared borrow stops anyone from writing.
+    unsafe { read_raw(ptr) }
+}
+
+// Both kinds of pointer to one variable at once, which references forbid.
+// Writing through one and reading through the other is fine, as long as
+// there's no live *reference* in the way while we do it.
+pub fn bump_through_pointers(mut num: i32) -> (i32, i32) {
+    let r1 = &num as *const i32;
+    let r2 = &mut num as *mut i32;
+    // SAFETY: both pointers point at `num`, which is alive for the whole
+    // block, and no references to `num` exist while they're in use.
+    unsafe {
+        *r2 += 1;
+        (*r1, *r2)
+    }
+}
+
+// Null is a perfectly good raw pointer value (references can never be
+// null, which is why Option<&T> exists). Here we go from Option<&T> to a
+// possibly-null pointer and back again. `as_ref` is the usual way out of
+// raw-pointer-land: it gives an Option<&T>, with None for null.
+pub fn describe(value: Option<&i32>) -> String {
+    let ptr: *const i32 = match value {
+        Some(r) => r,
+        None => std::ptr::null(),
+    };
+    // SAFETY: as_ref needs the pointer to be either null or valid, and it
+    // was built just above from either nothing or a live reference.
+    match unsafe { ptr.as_ref() } {
+        Some(value) => format!("points to {}", value),
+        None => String::from("null"),
+    }
+}
+
+pub fn demo_raw_pointers() {
+    println!("--- Raw Pointers Demonstration Begins --- ");
+    let x = 5;
+    println!("read through a raw pointer: {}", read_via_pointer(&x));
+    println!("bumped 41 through a *mut, read back via both: {:?}", bump_through_pointers(41));
+    println!("&x {}; a null pointer is {}", describe(Some(&x)), describe(None));
+
+    // creating a pointer to an arbitrary address compiles fine (it's
+    // following it that would be trouble, so we don't)
+    let address = 0x012345usize;
+    let _r = address as *const i32;
+    println!("--- Raw Pointers Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_back_the_value() {
+        assert_eq!(read_via_pointer(&-7), -7);
+    }
+
+    #[test]
+    fn const_and_mut_pointers_see_the_same_write() {
+        assert_eq!(bump_through_pointers(1), (2, 2));
+    }
+
+    #[test]
+    fn null_is_handled() {
+        assert_eq!(describe(None), "null");
+        assert_eq!(describe(Some(&3)), "points to 3");
+    }
+
+    #[test]
+    fn calling_the_unsafe_fn_directly() {
+        let values = [10, 20, 30];
+        // SAFETY: add(2) stays inside the three-element array
+        let third = unsafe { read_raw(values.as_ptr().add(2)) };
+        assert_eq!(third, 30);
+    }
+}
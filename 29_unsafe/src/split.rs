@@ -0,0 +1,111 @@
+/**
+ * A safe function with unsafe insides: split_at_mut.
+ *
+ * Splitting a mutable slice into two non-overlapping halves is obviously
+ * fine, but the borrow checker can't see that the halves don't overlap.
+ * All it sees is two `&mut` borrows of the same slice:
+ *
+ *      let len = values.len();
+ *      (&mut values[..mid], &mut values[mid..])
+ *      // error: cannot borrow `*values` as mutable more than once at a time
+ *
+ * So we build the two halves from raw parts ourselves. The function as a
+ * whole is still *safe* to call: the assert guarantees `mid` is in range,
+ * which is the one thing the unsafe code relies on.
+ */
+use std::slice;
+
+pub fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    let len = values.len();
+    let ptr = values.as_mut_ptr();
+
+    assert!(mid <= len);
+
+    // SAFETY: `ptr` is valid for `len` elements because it came from the
+    // slice. [0, mid) and [mid, len) don't overlap, and both fit within
+    // `len` thanks to the assert above, so the two slices never alias.
+    unsafe {
+        (
+            slice::from_raw_parts_mut(ptr, mid),
+            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+// Something that really needs both halves mutable at once: swap the two
+// halves' contents pairwise (the first item of each, then the second...).
+pub fn swap_halves(values: &mut [i32]) {
+    let mid = values.len() / 2;
+    let (left, right) = split_at_mut(values, mid);
+    for (a, b) in left.iter_mut().zip(right.iter_mut()) {
+        std::mem::swap(a, b);
+    }
+}
+
+pub fn demo_split() {
+    println!("--- split_at_mut Demonstration Begins --- ");
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+    {
+        let (a, b) = split_at_mut(&mut v, 3);
+        a[0] = 100;
+        b[0] = 400;
+        println!("halves after editing: {:?} and {:?}", a, b);
+    }
+    swap_halves(&mut v);
+    println!("after swapping halves: {:?}", v);
+    println!("--- split_at_mut Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_where_asked() {
+        let mut v = [1, 2, 3, 4, 5];
+        let (a, b) = split_at_mut(&mut v, 2);
+        assert_eq!(a, &mut [1, 2]);
+        assert_eq!(b, &mut [3, 4, 5]);
+    }
+
+    #[test]
+    fn both_halves_are_writable() {
+        let mut v = [0; 4];
+        let (a, b) = split_at_mut(&mut v, 1);
+        a[0] = 1;
+        b[2] = 9;
+        assert_eq!(v, [1, 0, 0, 9]);
+    }
+
+    #[test]
+    fn edges_are_allowed() {
+        let mut v = [1, 2];
+        assert_eq!(split_at_mut(&mut v, 0).0.len(), 0);
+        assert_eq!(split_at_mut(&mut v, 2).1.len(), 0);
+        let mut empty: [i32; 0] = [];
+        let (a, b) = split_at_mut(&mut empty, 0);
+        assert!(a.is_empty() && b.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_panics_instead_of_misbehaving() {
+        split_at_mut(&mut [1, 2, 3], 4);
+    }
+
+    #[test]
+    fn matches_the_standard_library() {
+        let mut ours = [5, 6, 7, 8, 9];
+        let mut theirs = ours;
+        for mid in 0..=5 {
+            assert_eq!(split_at_mut(&mut ours, mid), theirs.split_at_mut(mid));
+        }
+    }
+
+    #[test]
+    fn swapping_halves() {
+        let mut v = [1, 2, 3, 4, 5];
+        swap_halves(&mut v);
+        assert_eq!(v, [3, 4, 1, 2, 5]);
+    }
+}
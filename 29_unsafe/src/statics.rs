@@ -0,0 +1,85 @@
+/**
+ * Mutable statics: global variables, the unsafe way.
+ *
+ * A `static` lives at one fixed address for the whole program. Reading an
+ * immutable static is safe. A `static mut` is another story: any thread
+ * could be changing it at any moment, which is a data race waiting to
+ * happen, so *every* access is unsafe.
+ *
+ * We keep the `static mut` private, and only touch it through a couple of
+ * small functions. Those take a lock first, so even when several threads
+ * (like the test runner's!) call in at once, only one is ever inside.
+ *
+ * In real code, reach for an atomic (see 22_concurrency) or a Mutex in a
+ * plain static instead: they give the same result with no unsafe at all.
+ * This is here to show what those safe types are protecting us from.
+ */
+use std::sync::Mutex;
+
+pub static HELLO_WORLD: &str = "Hello, world!";
+
+static mut COUNTER: u32 = 0;
+
+// Guards COUNTER. The Mutex holds no data of its own (just `()`); owning
+// the guard is simply our permission slip to touch COUNTER.
+static COUNTER_LOCK: Mutex<()> = Mutex::new(());
+
+// Add `inc` to the global counter, returning the new total.
+pub fn add_to_count(inc: u32) -> u32 {
+    let _guard = COUNTER_LOCK.lock().unwrap();
+    // SAFETY: COUNTER is only ever accessed while holding COUNTER_LOCK,
+    // so no other thread can be reading or writing it right now.
+    unsafe {
+        COUNTER += inc;
+        COUNTER
+    }
+}
+
+pub fn count() -> u32 {
+    let _guard = COUNTER_LOCK.lock().unwrap();
+    // SAFETY: as above, the lock is held for the read.
+    unsafe { COUNTER }
+}
+
+pub fn demo_statics() {
+    println!("--- Mutable Static Demonstration Begins --- ");
+    println!("An immutable static: {}", HELLO_WORLD);
+    add_to_count(3);
+    add_to_count(4);
+    println!("COUNTER: {}", count());
+    println!("--- Mutable Static Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // Tests share the one global counter (and run in parallel), so they
+    // only assert on how much *their own* calls changed it.
+    #[test]
+    fn adding_increases_the_count() {
+        let before = count();
+        let after = add_to_count(5);
+        assert!(after >= before + 5);
+    }
+
+    #[test]
+    fn many_threads_lose_no_updates() {
+        let before = count();
+        let handles: Vec<thread::JoinHandle<()>> = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..1000 {
+                        add_to_count(1);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        // every increment landed (the other test may have added more)
+        assert!(count() >= before + 8000);
+    }
+}
@@ -0,0 +1,133 @@
+/**
+ * let-else: "this should match; if it doesn't, get out of here".
+ *
+ *      let Some(x) = maybe_number else {
+ *          return None;
+ *      };
+ *
+ * It's a `let` that accepts a refutable pattern, as long as it comes with
+ * an else block that *diverges*: return, break, continue, or panic. The
+ * else can't just fall through, because then `x` would exist without a
+ * value. In exchange, `x` is bound for the whole rest of the function,
+ * rather than only inside an `if let` block.
+ *
+ * That's what makes it good for validation: check each thing up front,
+ * bail out on the first problem, and the happy path stays flat instead of
+ * drifting further right with every nested `if let`.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Move { dx: i32, dy: i32 },
+    Say(String),
+}
+
+// "3,4" -> Ok((3, 4)). Each step might fail, and each failure returns
+// its own complaint. (If all we wanted was to hand back None, `?` would do
+// the same job in fewer characters; let-else earns its keep when the else
+// block has something to say.)
+pub fn parse_pair(text: &str) -> Result<(i32, i32), &'static str> {
+    let Some((left, right)) = text.split_once(',') else {
+        return Err("no comma");
+    };
+    let Ok(x) = left.trim().parse() else {
+        return Err("bad x");
+    };
+    let Ok(y) = right.trim().parse() else {
+        return Err("bad y");
+    };
+    Ok((x, y))
+}
+
+// The same idea for a whole command. Compare the nested version we'd
+// have to write with `if let`: three levels deep before doing any work.
+pub fn parse_command(line: &str) -> Result<Command, String> {
+    let mut words = line.split_whitespace();
+    let Some(verb) = words.next() else {
+        return Err(String::from("empty command"));
+    };
+    match verb {
+        "move" => {
+            let (Some(dx), Some(dy)) = (words.next(), words.next()) else {
+                return Err(String::from("move needs two numbers"));
+            };
+            let (Ok(dx), Ok(dy)) = (dx.parse(), dy.parse()) else {
+                return Err(format!("can't move by '{}' '{}'", dx, dy));
+            };
+            Ok(Command::Move { dx, dy })
+        }
+        "say" => {
+            let rest: Vec<&str> = words.collect();
+            Ok(Command::Say(rest.join(" ")))
+        }
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+// Inside a loop, the else block can `continue` instead: skip the bad line
+// and carry on with the next one.
+pub fn sum_values(lines: &[&str]) -> i32 {
+    let mut total = 0;
+    for line in lines {
+        let Some((_key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let Ok(value) = value.trim().parse::<i32>() else {
+            continue;
+        };
+        total += value;
+    }
+    total
+}
+
+pub fn demo_let_else() {
+    println!("--- Let-Else Demonstration Begins --- ");
+    for text in ["3,4", " -1 , 7 ", "3;4", "three,4", "3,four"].iter() {
+        println!("parse_pair({:?}) = {:?}", text, parse_pair(text));
+    }
+    for line in ["move 1 -2", "say hello there", "move 1", "move a b", "jump", ""].iter() {
+        println!("parse_command({:?}) = {:?}", line, parse_command(line));
+    }
+    let lines = ["apples = 3", "junk", "pears = 4", "plums = lots"];
+    println!("sum_values({:?}) = {}", lines, sum_values(&lines));
+    println!("--- Let-Else Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_that_parse() {
+        assert_eq!(parse_pair("3,4"), Ok((3, 4)));
+        assert_eq!(parse_pair(" -1 , 7 "), Ok((-1, 7)));
+    }
+
+    #[test]
+    fn pairs_that_bail_out() {
+        assert_eq!(parse_pair("3;4"), Err("no comma"));
+        assert_eq!(parse_pair("three,4"), Err("bad x"));
+        assert_eq!(parse_pair("3,four"), Err("bad y"));
+        assert_eq!(parse_pair(""), Err("no comma"));
+    }
+
+    #[test]
+    fn good_commands() {
+        assert_eq!(parse_command("move 1 -2"), Ok(Command::Move { dx: 1, dy: -2 }));
+        assert_eq!(parse_command("say hello  there"), Ok(Command::Say(String::from("hello there"))));
+        assert_eq!(parse_command("say"), Ok(Command::Say(String::new())));
+    }
+
+    #[test]
+    fn each_else_reports_its_own_problem() {
+        assert_eq!(parse_command(""), Err(String::from("empty command")));
+        assert_eq!(parse_command("move 1"), Err(String::from("move needs two numbers")));
+        assert_eq!(parse_command("move a b"), Err(String::from("can't move by 'a' 'b'")));
+        assert_eq!(parse_command("jump"), Err(String::from("unknown command 'jump'")));
+    }
+
+    #[test]
+    fn continue_skips_bad_lines() {
+        assert_eq!(sum_values(&["apples = 3", "junk", "pears = 4", "plums = lots"]), 7);
+        assert_eq!(sum_values(&[]), 0);
+    }
+}
@@ -0,0 +1,45 @@
+/**
+ * Patterns, and all the places they can appear.
+ *
+ * Back in 10_enums we saw patterns as the left side of a match arm. But a
+ * pattern is lurking in lots of spots that don't look like matching at
+ * all. Every `let` is a pattern (`let x = 5;` just happens to be the
+ * simplest one possible), and so is every function parameter, every
+ * closure parameter, and the bit between `for` and `in`.
+ *
+ * Patterns come in two flavors:
+ *
+ * - irrefutable: can't fail to match, whatever the value. `x`, `(a, b)`,
+ *   `Point { x, y }`. These are the only kind allowed in `let`, `for` and
+ *   parameters, since there's no sensible "else" for them to fall into.
+ * - refutable: might not match. `Some(x)`, `Ok(n)`, `3..=7`. These need a
+ *   construct with somewhere to go on failure: `match`, `if let`,
+ *   `while let`, or `let ... else`.
+ *
+ * Every example is a small function that returns a value, so the tests can
+ * check exactly what each pattern did.
+ */
+mod places;
+mod refutability;
+mod let_else;
+
+// one entry per demo, for the runner's menu (see common/src/demos.rs)
+use common::Demo;
+
+pub const DEMOS: &[Demo] = &[
+    Demo::new("places", "let, for, while let, and parameters", places::demo_places),
+    Demo::new("refutability", "Which patterns can fail, and where they are allowed", refutability::demo_refutability),
+    Demo::new("let_else", "let-else: match or bail out early", let_else::demo_let_else),
+];
+
+pub fn demo() {
+
+    // let, for, while let, and function / closure parameters
+    places::demo_places();
+
+    // which patterns can fail, and where they're allowed
+    refutability::demo_refutability();
+
+    // let-else: match or bail out early
+    let_else::demo_let_else();
+}
@@ -0,0 +1,156 @@
+/**
+ * Patterns hiding in plain sight.
+ *
+ * None of these say `match`, but each one is matching a pattern against a
+ * value and binding names to the pieces. The irrefutable ones (let, for,
+ * parameters) always succeed; `while let` is the odd one out, because its
+ * pattern failing to match is exactly how the loop knows to stop.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+// `let` takes a pattern, so a tuple can be pulled apart in one go. The
+// pattern has to have the same shape as the value: `let (a, b) = triple`
+// is a compile error (expected a tuple with 3 elements, found one with 2).
+pub fn sum_triple(triple: (i32, i32, i32)) -> i32 {
+    let (a, b, c) = triple;
+    a + b + c
+}
+
+// `..` skips whatever is in the middle, however many elements that is
+pub fn first_and_last(values: (i32, i32, i32, i32, i32)) -> (i32, i32) {
+    let (first, .., last) = values;
+    (first, last)
+}
+
+// The bit between `for` and `in` is a pattern too. enumerate() hands out
+// (index, item) tuples, and we destructure each one as it arrives.
+pub fn numbered(items: &[&str]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        lines.push(format!("{}. {}", i + 1, item));
+    }
+    lines
+}
+
+// `while let` keeps looping for as long as the pattern matches. pop()
+// returns Some(top) until the Vec is empty, and then None ends the loop.
+pub fn drain_stack(mut stack: Vec<i32>) -> Vec<i32> {
+    let mut popped = Vec::new();
+    while let Some(top) = stack.pop() {
+        popped.push(top);
+    }
+    popped
+}
+
+// A more realistic `while let`: read digits off the front of a string, and
+// stop at the first thing that isn't one. peek() looks without consuming,
+// so the first non-digit is still there for whoever reads next.
+pub fn leading_number(text: &str) -> (u32, String) {
+    let mut chars = text.chars().peekable();
+    let mut number = 0;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        number = number * 10 + digit;
+        chars.next();
+    }
+    (number, chars.collect())
+}
+
+// Function parameters are patterns as well. Here the `&(x, y)` both
+// dereferences the argument and splits the tuple, before the body even
+// starts.
+pub fn distance_squared(&(x, y): &(i32, i32)) -> i32 {
+    x * x + y * y
+}
+
+// ...and a struct can be taken apart right in the parameter list
+pub fn describe_point(Point { x, y }: Point) -> String {
+    match (x, y) {
+        (0, 0) => String::from("the origin"),
+        (_, 0) => format!("on the x axis at {}", x),
+        (0, _) => format!("on the y axis at {}", y),
+        _ => format!("at ({}, {})", x, y),
+    }
+}
+
+// Closure parameters work the same way. iter() yields &(i32, i32), and the
+// `&(a, b)` pattern strips off the reference and splits the pair at once.
+pub fn dot_product(pairs: &[(i32, i32)]) -> i32 {
+    pairs.iter().map(|&(a, b)| a * b).sum()
+}
+
+// `_` matches anything and binds nothing, so it's the way to say "I don't
+// care about this part" without an unused-variable warning
+pub fn second_halves(pairs: &[(i32, i32)]) -> Vec<i32> {
+    pairs.iter().map(|&(_, b)| b).collect()
+}
+
+pub fn demo_places() {
+    println!("--- Pattern Places Demonstration Begins --- ");
+    println!("let (a, b, c) = (1, 2, 3); a + b + c = {}", sum_triple((1, 2, 3)));
+    println!("let (first, .., last) on (2, 4, 8, 16, 32) = {:?}",
+             first_and_last((2, 4, 8, 16, 32)));
+    for line in numbered(&["eggs", "milk", "bread"]) {
+        println!("for (i, item) in ...: {}", line);
+    }
+    println!("while let Some(top) = stack.pop(): {:?}", drain_stack(vec![1, 2, 3]));
+    println!("Leading number of \"42 apples\": {:?}", leading_number("42 apples"));
+    println!("fn distance_squared(&(x, y)) for (3, 4): {}", distance_squared(&(3, 4)));
+    for point in [Point { x: 0, y: 0 }, Point { x: 5, y: 0 }, Point { x: 2, y: -1 }].iter() {
+        println!("fn describe_point(Point {{ x, y }}) for {:?}: {}", point, describe_point(*point));
+    }
+    let pairs = [(1, 4), (2, 2), (3, 3)];
+    println!("|&(a, b)| a * b over {:?}: dot product {}", pairs, dot_product(&pairs));
+    println!("|&(_, b)| b over {:?}: {:?}", pairs, second_halves(&pairs));
+    println!("--- Pattern Places Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn let_destructures_tuples() {
+        assert_eq!(sum_triple((1, 2, 3)), 6);
+        assert_eq!(sum_triple((-1, 1, 0)), 0);
+        assert_eq!(first_and_last((2, 4, 8, 16, 32)), (2, 32));
+    }
+
+    #[test]
+    fn for_destructures_each_item() {
+        assert_eq!(numbered(&["a", "b"]), vec!["1. a", "2. b"]);
+        assert!(numbered(&[]).is_empty());
+    }
+
+    #[test]
+    fn while_let_stops_when_the_pattern_fails() {
+        assert_eq!(drain_stack(vec![1, 2, 3]), vec![3, 2, 1]);
+        assert_eq!(drain_stack(Vec::new()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn while_let_leaves_the_rest_alone() {
+        assert_eq!(leading_number("42 apples"), (42, String::from(" apples")));
+        assert_eq!(leading_number("007"), (7, String::new()));
+        assert_eq!(leading_number("no digits"), (0, String::from("no digits")));
+    }
+
+    #[test]
+    fn parameters_destructure() {
+        assert_eq!(distance_squared(&(3, 4)), 25);
+        assert_eq!(describe_point(Point { x: 0, y: 0 }), "the origin");
+        assert_eq!(describe_point(Point { x: 5, y: 0 }), "on the x axis at 5");
+        assert_eq!(describe_point(Point { x: 0, y: -2 }), "on the y axis at -2");
+        assert_eq!(describe_point(Point { x: 2, y: -1 }), "at (2, -1)");
+    }
+
+    #[test]
+    fn closure_parameters_destructure() {
+        assert_eq!(dot_product(&[(1, 4), (2, 2), (3, 3)]), 17);
+        assert_eq!(dot_product(&[]), 0);
+        assert_eq!(second_halves(&[(1, 4), (2, 2), (3, 3)]), vec![4, 2, 3]);
+    }
+}
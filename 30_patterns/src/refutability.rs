@@ -0,0 +1,151 @@
+/**
+ * Refutable vs irrefutable: can this pattern fail to match?
+ *
+ * `let Some(x) = maybe_number;` looks reasonable, but it won't compile:
+ *
+ *      error[E0005]: refutable pattern in local binding
+ *      pattern `None` not covered
+ *
+ * A plain `let` has nowhere to go if the value turns out to be None, so
+ * the compiler only accepts patterns that can't fail there. The fix is to
+ * pick a construct that has a plan B: `if let` (with an optional else),
+ * `match` (with an arm for every case), or `let ... else` (see let_else.rs).
+ *
+ * It works the other way too. `if let x = 5 { ... }` compiles, but with a
+ * warning (irrefutable `if let` pattern): an `if` that can never be false
+ * is pointless, so just use `let`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+// Irrefutable: any (i32, i32) fits the pattern (a, b), so `let` is fine,
+// and so is using the same pattern as a parameter.
+pub fn swap((a, b): (i32, i32)) -> (i32, i32) {
+    let (second, first) = (b, a);
+    (second, first)
+}
+
+// Refutable: the text might not parse, so `Ok(n)` might not match. `if let`
+// runs its block only when it does, and the else covers everything else.
+pub fn double_if_number(text: &str) -> Option<i32> {
+    if let Ok(n) = text.trim().parse::<i32>() {
+        Some(n * 2)
+    } else {
+        None
+    }
+}
+
+// `if let` chains can mix in plain boolean tests, and each branch can
+// match a different pattern against a different value. This is the book's
+// "favorite color" example: the compiler doesn't check that the chain
+// covers every case, the way `match` would.
+pub fn background_color(favorite: Option<&str>, is_tuesday: bool, age: Result<u8, String>) -> String {
+    if let Some(color) = favorite {
+        format!("your favorite, {}", color)
+    } else if is_tuesday {
+        String::from("green, since it's Tuesday")
+    } else if let Ok(age) = age {
+        if age > 30 {
+            String::from("purple")
+        } else {
+            String::from("orange")
+        }
+    } else {
+        String::from("blue")
+    }
+}
+
+// The same question asked with `match`, which *is* checked: delete the
+// Square arm and this stops compiling (pattern `Shape::Square(_)` not
+// covered). Each arm's pattern is refutable, but together they're not.
+pub fn area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Circle(r) => std::f64::consts::PI * r * r,
+        Shape::Square(side) => side * side,
+    }
+}
+
+// When we genuinely only care about one variant, `if let` says so with
+// less ceremony than a match with a `_ => {}` arm.
+pub fn total_circle_radius(shapes: &[Shape]) -> f64 {
+    let mut total = 0.0;
+    for shape in shapes {
+        if let Shape::Circle(r) = shape {
+            total += r;
+        }
+    }
+    total
+}
+
+// matches! is a shorthand for "does this refutable pattern match?", handy
+// where all we need is a bool
+pub fn is_small_square(shape: Shape) -> bool {
+    matches!(shape, Shape::Square(side) if side < 1.0)
+}
+
+pub fn demo_refutability() {
+    println!("--- Refutability Demonstration Begins --- ");
+    println!("Irrefutable swap of (1, 2): {:?}", swap((1, 2)));
+    for text in ["21", "twenty-one"].iter() {
+        println!("if let Ok(n) on {:?}: {:?}", text, double_if_number(text));
+    }
+    println!("Background: {}", background_color(None, false, Ok(34)));
+    println!("Background: {}", background_color(Some("red"), true, Ok(34)));
+    println!("Background: {}", background_color(None, false, Err(String::from("??"))));
+    let shapes = [Shape::Circle(1.0), Shape::Square(0.5), Shape::Circle(2.0)];
+    for shape in shapes.iter() {
+        println!("{:?}: area {:.3}, small square? {}", shape, area(*shape), is_small_square(*shape));
+    }
+    println!("Total radius of just the circles: {}", total_circle_radius(&shapes));
+    println!("--- Refutability Demonstration Finish --- ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn irrefutable_patterns_always_match() {
+        assert_eq!(swap((1, 2)), (2, 1));
+        assert_eq!(swap((0, 0)), (0, 0));
+    }
+
+    #[test]
+    fn if_let_falls_through_to_else() {
+        assert_eq!(double_if_number(" 21 "), Some(42));
+        assert_eq!(double_if_number("twenty-one"), None);
+        assert_eq!(double_if_number(""), None);
+    }
+
+    #[test]
+    fn if_let_chain_takes_the_first_match() {
+        assert_eq!(background_color(Some("red"), true, Ok(50)), "your favorite, red");
+        assert_eq!(background_color(None, true, Ok(50)), "green, since it's Tuesday");
+        assert_eq!(background_color(None, false, Ok(50)), "purple");
+        assert_eq!(background_color(None, false, Ok(20)), "orange");
+        assert_eq!(background_color(None, false, Err(String::from("x"))), "blue");
+    }
+
+    #[test]
+    fn match_covers_every_variant() {
+        assert_eq!(area(Shape::Square(3.0)), 9.0);
+        assert!((area(Shape::Circle(1.0)) - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn if_let_skips_other_variants() {
+        let shapes = [Shape::Circle(1.0), Shape::Square(5.0), Shape::Circle(2.5)];
+        assert_eq!(total_circle_radius(&shapes), 3.5);
+        assert_eq!(total_circle_radius(&[Shape::Square(1.0)]), 0.0);
+    }
+
+    #[test]
+    fn matches_with_a_guard() {
+        assert!(is_small_square(Shape::Square(0.5)));
+        assert!(!is_small_square(Shape::Square(2.0)));
+        assert!(!is_small_square(Shape::Circle(0.5)));
+    }
+}
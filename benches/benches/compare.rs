@@ -0,0 +1,85 @@
+/**
+ * The benchmarks themselves. Each criterion *group* is one question ("which
+ * way of summing is faster?"), and each function in the group is one answer.
+ *
+ * `black_box` hides a value from the optimizer. Without it, the compiler is
+ * free to notice that we compute the same sum ten thousand times and simply
+ * not bother, which makes for very impressive and very meaningless numbers.
+ */
+use benches::{concat, dispatch, fibonacci, lookups, parallel, squares, sums};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn sum(c: &mut Criterion) {
+    let values: Vec<i64> = (0..10_000).collect();
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("while loop", |b| b.iter(|| sums::with_loop(black_box(&values))));
+    group.bench_function("iterator", |b| b.iter(|| sums::with_iterator(black_box(&values))));
+    group.finish();
+}
+
+fn concatenate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concat");
+    group.bench_function("+", |b| {
+        b.iter(|| concat::with_plus(black_box("tic"), black_box("tac"), black_box("toe")))
+    });
+    group.bench_function("format!", |b| {
+        b.iter(|| concat::with_format(black_box("tic"), black_box("tac"), black_box("toe")))
+    });
+    group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+    let keys = lookups::keys(1_000);
+    let hash = lookups::hash_map(&keys);
+    let btree = lookups::btree_map(&keys);
+    let mut group = c.benchmark_group("lookup");
+    group.bench_function("HashMap", |b| b.iter(|| lookups::sum_hash(black_box(&hash), &keys)));
+    group.bench_function("BTreeMap", |b| b.iter(|| lookups::sum_btree(black_box(&btree), &keys)));
+    group.finish();
+}
+
+fn dispatching(c: &mut Criterion) {
+    let (as_enums, as_traits, as_circles) = dispatch::circles(1_000);
+    let mut group = c.benchmark_group("dispatch");
+    group.bench_function("enum match", |b| b.iter(|| dispatch::total_area_enum(black_box(&as_enums))));
+    group.bench_function("dyn Shape", |b| b.iter(|| dispatch::total_area_dyn(black_box(&as_traits))));
+    group.bench_function("generic <S: Shape>", |b| {
+        b.iter(|| dispatch::total_area_generic(black_box(&as_circles)))
+    });
+    group.finish();
+}
+
+// F(25) is as far as `recursive` goes in a reasonable time: about 250,000
+// calls, where the others need 25 steps
+fn fibonaccis(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci");
+    group.bench_function("recursive", |b| b.iter(|| fibonacci::recursive(black_box(25))));
+    group.bench_function("memoized", |b| b.iter(|| fibonacci::memoized(black_box(25))));
+    group.bench_function("iterative", |b| b.iter(|| fibonacci::iterative(black_box(25))));
+    group.bench_function("Fib iterator", |b| b.iter(|| fibonacci::with_iterator(black_box(25))));
+    group.bench_function("checked u128", |b| b.iter(|| fibonacci::checked(black_box(25))));
+    group.finish();
+}
+
+fn sum_of_squares(c: &mut Criterion) {
+    let values: Vec<i64> = (0..100_000).collect();
+    let mut group = c.benchmark_group("sum of even squares");
+    group.bench_function("index loop", |b| b.iter(|| squares::indexed(black_box(&values))));
+    group.bench_function("for-in loop", |b| b.iter(|| squares::for_in(black_box(&values))));
+    group.bench_function("iterator chain", |b| b.iter(|| squares::chained(black_box(&values))));
+    group.finish();
+}
+
+// a million items, which is where the threads start to earn their keep
+fn parallelism(c: &mut Criterion) {
+    let values = parallel::big_list();
+    let mut group = c.benchmark_group("parallel");
+    group.bench_function("largest", |b| b.iter(|| parallel::largest(black_box(&values))));
+    group.bench_function("par_largest", |b| b.iter(|| parallel::par_largest(black_box(&values))));
+    group.bench_function("sum", |b| b.iter(|| parallel::sum(black_box(&values))));
+    group.bench_function("par_sum", |b| b.iter(|| parallel::par_sum(black_box(&values))));
+    group.finish();
+}
+
+criterion_group!(benches, sum, concatenate, lookup, dispatching, fibonaccis, sum_of_squares, parallelism);
+criterion_main!(benches);
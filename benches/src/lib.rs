@@ -0,0 +1,182 @@
+/*!
+ * Contenders: pairs of functions that get the same answer in the ways that
+ * different chapters teach, so that benches/compare.rs can time them
+ * against each other.
+ *
+ * Each pair is tested below to agree with itself, because a benchmark that
+ * compares two functions computing *different* things is worse than none.
+ * The interesting reading is in the numbers, though:
+ *
+ * ```text
+ * cargo bench -p benches     # slow! criterion runs each one many times
+ * cargo run -p benches       # then print the results as a markdown table
+ * ```
+ */
+pub mod report;
+
+// 06_branches adds things up with a loop and an index;
+// 20_iterators says to let an iterator do it
+pub mod sums {
+    pub fn with_loop(values: &[i64]) -> i64 {
+        let mut total = 0;
+        let mut index = 0;
+        while index < values.len() {
+            total += values[index];
+            index += 1;
+        }
+        total
+    }
+
+    pub fn with_iterator(values: &[i64]) -> i64 {
+        values.iter().sum()
+    }
+}
+
+// 12_collections glues "tic", "tac" and "toe" together both ways
+pub mod concat {
+    // `+` takes ownership of the left side, and borrows the right side
+    pub fn with_plus(s1: &str, s2: &str, s3: &str) -> String {
+        String::from(s1) + "-" + s2 + "-" + s3
+    }
+
+    pub fn with_format(s1: &str, s2: &str, s3: &str) -> String {
+        format!("{}-{}-{}", s1, s2, s3)
+    }
+}
+
+// 12_collections uses HashMap for everything; the standard library also
+// has BTreeMap, which keeps its keys sorted
+pub mod lookups {
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn keys(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("key-{}", i)).collect()
+    }
+
+    pub fn hash_map(keys: &[String]) -> HashMap<String, usize> {
+        keys.iter().cloned().zip(0..).collect()
+    }
+
+    pub fn btree_map(keys: &[String]) -> BTreeMap<String, usize> {
+        keys.iter().cloned().zip(0..).collect()
+    }
+
+    // look every key up, and add up what we find
+    pub fn sum_hash(map: &HashMap<String, usize>, keys: &[String]) -> usize {
+        keys.iter().filter_map(|key| map.get(key)).sum()
+    }
+
+    pub fn sum_btree(map: &BTreeMap<String, usize>, keys: &[String]) -> usize {
+        keys.iter().filter_map(|key| map.get(key)).sum()
+    }
+}
+
+// Three ways to add up areas. The enum and the trait-object versions come
+// straight from 10_enums; the generic one is the trait bound from
+// 15_traits, which only works because all its shapes are the same type.
+pub mod dispatch {
+    pub use enums::shapes::{total_area_dyn, total_area_enum, Circle, Shape, ShapeEnum};
+
+    pub fn total_area_generic<S: Shape>(shapes: &[S]) -> f64 {
+        shapes.iter().map(|shape| shape.area()).sum()
+    }
+
+    // the same circles, once for each approach
+    pub fn circles(count: usize) -> (Vec<ShapeEnum>, Vec<Box<dyn Shape>>, Vec<Circle>) {
+        let radii: Vec<f64> = (0..count).map(|i| i as f64 / 10.0).collect();
+        (
+            radii.iter().map(|&radius| ShapeEnum::Circle { radius }).collect(),
+            radii.iter().map(|&radius| Box::new(Circle { radius }) as Box<dyn Shape>).collect(),
+            radii.iter().map(|&radius| Circle { radius }).collect(),
+        )
+    }
+}
+
+// 05_functions works out Fibonacci numbers four ways, from the definition
+// (exponential) to a loop (linear)
+pub mod fibonacci {
+    pub use functions::fibonacci::{checked, iterative, memoized, recursive, Fib};
+
+    // the Iterator, asked for the nth one
+    pub fn with_iterator(n: u32) -> u64 {
+        Fib::new().nth(n as usize).expect("F(n) doesn't fit in a u64")
+    }
+}
+
+// 12_collections adds up the squares of the even values three ways, to
+// see whether an iterator chain really costs nothing over a loop
+pub mod squares {
+    pub use collections::aggregates::{chained, for_in, indexed};
+}
+
+// 15_traits finds the largest item with one loop on one thread;
+// 22_concurrency splits the slice up and gives each core a piece
+pub mod parallel {
+    pub use concurrency::parallel::{big_list, par_largest, par_sum};
+    pub use traits_lib::largest;
+
+    pub fn sum(values: &[u64]) -> u64 {
+        values.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_agree() {
+        let values: Vec<i64> = (1..=100).collect();
+        assert_eq!(sums::with_loop(&values), 5050);
+        assert_eq!(sums::with_iterator(&values), 5050);
+        assert_eq!(sums::with_loop(&[]), 0);
+    }
+
+    #[test]
+    fn concatenations_agree() {
+        assert_eq!(concat::with_plus("tic", "tac", "toe"), "tic-tac-toe");
+        assert_eq!(concat::with_format("tic", "tac", "toe"), "tic-tac-toe");
+    }
+
+    #[test]
+    fn maps_agree() {
+        let keys = lookups::keys(10);
+        let expected = (0..10).sum::<usize>();
+        assert_eq!(lookups::sum_hash(&lookups::hash_map(&keys), &keys), expected);
+        assert_eq!(lookups::sum_btree(&lookups::btree_map(&keys), &keys), expected);
+    }
+
+    #[test]
+    fn dispatch_agrees() {
+        let (as_enums, as_traits, as_circles) = dispatch::circles(20);
+        let total = dispatch::total_area_enum(&as_enums);
+        assert!((dispatch::total_area_dyn(&as_traits) - total).abs() < 1e-9);
+        assert!((dispatch::total_area_generic(&as_circles) - total).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fibonaccis_agree() {
+        for n in [0, 1, 2, 20, 25] {
+            let expected = fibonacci::iterative(n);
+            assert_eq!(fibonacci::recursive(n), expected);
+            assert_eq!(fibonacci::memoized(n), expected);
+            assert_eq!(fibonacci::with_iterator(n), expected);
+            assert_eq!(fibonacci::checked(n), Some(expected as u128));
+        }
+    }
+
+    #[test]
+    fn squares_agree() {
+        let values: Vec<i64> = (0..1_000).collect();
+        let expected = squares::chained(&values);
+        assert_eq!(squares::indexed(&values), expected);
+        assert_eq!(squares::for_in(&values), expected);
+    }
+
+    #[test]
+    fn parallel_agrees() {
+        let values = parallel::big_list();
+        assert_eq!(parallel::par_largest(&values), parallel::largest(&values));
+        assert_eq!(parallel::par_sum(&values), parallel::sum(&values));
+    }
+}
@@ -0,0 +1,42 @@
+/**
+ * Print the latest benchmark results as markdown, or write them to a file:
+ *
+ *      cargo bench -p benches                  # first, take the measurements
+ *      cargo run -p benches                    # then print the tables
+ *      cargo run -p benches -- BENCHMARKS.md   # or save them
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+fn main() {
+    // criterion writes under the workspace's target/ directory, unless
+    // CARGO_TARGET_DIR has moved it
+    let target = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("target"));
+    let criterion_dir = target.join("criterion");
+
+    let measurements = match benches::report::collect(&criterion_dir) {
+        Ok(measurements) if !measurements.is_empty() => measurements,
+        _ => {
+            eprintln!(
+                "No results in {}. Run `cargo bench -p benches` first.",
+                criterion_dir.display()
+            );
+            process::exit(1);
+        }
+    };
+
+    let report = benches::report::markdown(&measurements);
+    match env::args().nth(1) {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, report) {
+                eprintln!("Couldn't write {}: {}", path, e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", report),
+    }
+}
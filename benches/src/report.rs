@@ -0,0 +1,148 @@
+/*!
+ * Turn criterion's results into a markdown table.
+ *
+ * After `cargo bench`, criterion leaves a directory per benchmark under
+ * `target/criterion/<group>/<function>/`, and the latest run's numbers sit
+ * in `new/benchmark.json` (which benchmark this was) and
+ * `new/estimates.json` (how long it took, in nanoseconds). Criterion also
+ * writes HTML reports, but a markdown table is something you can paste into
+ * a README or an issue.
+ */
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub group: String,
+    pub function: String,
+    // the mean time per iteration, in nanoseconds
+    pub mean_ns: f64,
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+// one benchmark's latest numbers, from its `new/` directory
+pub fn read_measurement(new_dir: &Path) -> Option<Measurement> {
+    let benchmark = read_json(&new_dir.join("benchmark.json"))?;
+    let estimates = read_json(&new_dir.join("estimates.json"))?;
+    Some(Measurement {
+        group: benchmark["group_id"].as_str()?.to_string(),
+        function: benchmark["function_id"].as_str()?.to_string(),
+        mean_ns: estimates["mean"]["point_estimate"].as_f64()?,
+    })
+}
+
+// Every benchmark under criterion's output directory. Anything that isn't
+// a benchmark (criterion's own `report/` directory, say) is skipped.
+pub fn collect(criterion_dir: &Path) -> io::Result<Vec<Measurement>> {
+    let mut measurements = Vec::new();
+    for group in fs::read_dir(criterion_dir)? {
+        let group = group?.path();
+        if !group.is_dir() {
+            continue;
+        }
+        for function in fs::read_dir(&group)? {
+            if let Some(measurement) = read_measurement(&function?.path().join("new")) {
+                measurements.push(measurement);
+            }
+        }
+    }
+    Ok(measurements)
+}
+
+// nanoseconds, in whichever unit keeps the number readable
+pub fn format_time(ns: f64) -> String {
+    if ns < 1_000.0 {
+        format!("{:.1} ns", ns)
+    } else if ns < 1_000_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else if ns < 1_000_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else {
+        format!("{:.2} s", ns / 1_000_000_000.0)
+    }
+}
+
+// One table per group, fastest first, with every time also shown as a
+// multiple of the fastest one
+pub fn markdown(measurements: &[Measurement]) -> String {
+    let mut groups: Vec<&str> = measurements.iter().map(|m| m.group.as_str()).collect();
+    groups.sort_unstable();
+    groups.dedup();
+
+    let mut out = String::from("# Benchmarks\n");
+    for group in groups {
+        let mut rows: Vec<&Measurement> = measurements.iter().filter(|m| m.group == group).collect();
+        rows.sort_by(|a, b| a.mean_ns.total_cmp(&b.mean_ns));
+        let fastest = rows[0].mean_ns;
+
+        out.push_str(&format!("\n## {}\n\n", group));
+        out.push_str("| approach | mean time | relative |\n");
+        out.push_str("|---|---:|---:|\n");
+        for row in rows {
+            out.push_str(&format!(
+                "| {} | {} | {:.2}x |\n",
+                row.function,
+                format_time(row.mean_ns),
+                row.mean_ns / fastest
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn measurement(group: &str, function: &str, mean_ns: f64) -> Measurement {
+        Measurement { group: group.to_string(), function: function.to_string(), mean_ns }
+    }
+
+    #[test]
+    fn times_pick_a_sensible_unit() {
+        assert_eq!(format_time(12.34), "12.3 ns");
+        assert_eq!(format_time(4_560.0), "4.56 µs");
+        assert_eq!(format_time(1_230_000.0), "1.23 ms");
+        assert_eq!(format_time(2_500_000_000.0), "2.50 s");
+    }
+
+    #[test]
+    fn tables_put_the_fastest_first() {
+        let report = markdown(&[
+            measurement("sum", "while loop", 3_000.0),
+            measurement("concat", "+", 40.0),
+            measurement("sum", "iterator", 1_000.0),
+        ]);
+        assert_eq!(
+            report,
+            "# Benchmarks\n\
+             \n## concat\n\n\
+             | approach | mean time | relative |\n|---|---:|---:|\n\
+             | + | 40.0 ns | 1.00x |\n\
+             \n## sum\n\n\
+             | approach | mean time | relative |\n|---|---:|---:|\n\
+             | iterator | 1.00 µs | 1.00x |\n\
+             | while loop | 3.00 µs | 3.00x |\n"
+        );
+    }
+
+    #[test]
+    fn reads_criterions_files() {
+        let root = env::temp_dir().join(format!("benches-report-{}", std::process::id()));
+        let new_dir = root.join("sum").join("iterator").join("new");
+        fs::create_dir_all(&new_dir).unwrap();
+        fs::create_dir_all(root.join("report")).unwrap();
+        fs::write(new_dir.join("benchmark.json"), r#"{"group_id":"sum","function_id":"iterator"}"#).unwrap();
+        fs::write(new_dir.join("estimates.json"), r#"{"mean":{"point_estimate":812.5}}"#).unwrap();
+
+        let found = collect(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(found.unwrap(), vec![measurement("sum", "iterator", 812.5)]);
+    }
+}
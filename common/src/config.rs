@@ -0,0 +1,399 @@
+/*!
+ * Settings from an `examples.toml` file, for the things you'd otherwise
+ * type on every run.
+ *
+ * ```text
+ * # examples.toml, next to the top-level Cargo.toml (or in a chapter's directory)
+ * [log]
+ * level = "debug"          # the same filters DEMO_LOG takes (see ./log.rs)
+ * format = "json"          # like --format json
+ *
+ * [guessing_game]
+ * max = 1000               # the secret number is from 1 to max
+ *
+ * [runner]
+ * default = "--menu"       # what `runner` does with no arguments
+ * ```
+ *
+ * Every setting has three layers, and the later ones win: a built-in
+ * default, then the file, then the command line (or, for the log level, the
+ * DEMO_LOG environment variable). So a file can change what a program does
+ * by default, and a flag can still change it just this once.
+ *
+ * The file is TOML, or rather a small subset of it, parsed by hand below:
+ * `[section]` headers, `key = value` lines and `#` comments, where a value
+ * is a string ("double" or 'single' quoted), an integer, or true or false.
+ * That's all these settings need, and it keeps the parser short enough to
+ * read. Anything else gets an error naming the line, rather than being
+ * quietly misread.
+ *
+ * The file is looked for in the current directory, then in each directory
+ * above it, so `cargo run` from a chapter's directory finds the top-level
+ * one too. `DEMO_CONFIG=<path>` names a file instead, and `DEMO_CONFIG=`
+ * (empty) means no file at all, which is what the golden-output tests use.
+ */
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const FILE_NAME: &str = "examples.toml";
+
+// the environment variable that names a config file (or none)
+pub const ENV_VAR: &str = "DEMO_CONFIG";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Boolean(_) => "true or false",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+// Every `key = value` in the file, with the section it was in ("" for the
+// keys before the first header). A Vec, because there are only ever a
+// handful, and it keeps them in the order they were written.
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    values: Vec<(String, String, Value)>,
+    // where it came from, for error messages
+    source: String,
+}
+
+fn is_bare(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// everything before a `#` that isn't inside quotes
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+// A "basic" string, from its opening quote to its closing one: the first
+// `"` that isn't escaped. Anything after that (`"a" "b"`, say) is an error,
+// not more of the string.
+fn parse_string(text: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut chars = text[1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return closed(text, 1 + i + 1).map(|_| out),
+            '\\' => {}
+            _ => {
+                out.push(c);
+                continue;
+            }
+        }
+        match chars.next().map(|(_, c)| c) {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some(other) => return Err(format!("unsupported escape '\\{}'", other)),
+            None => break,
+        }
+    }
+    Err(format!("unclosed string {}", text))
+}
+
+// the string in `text` ended at byte `end`, which has to be the end of the line
+fn closed(text: &str, end: usize) -> Result<(), String> {
+    if text[end..].is_empty() {
+        Ok(())
+    } else {
+        Err(format!("the string {} goes on after its closing quote", text))
+    }
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if text.starts_with('"') {
+        return parse_string(text).map(Value::String);
+    }
+    if let Some(rest) = text.strip_prefix('\'') {
+        // 'literal' strings take backslashes as they are, and can't hold a '
+        let end = rest.find('\'').ok_or_else(|| format!("unclosed string {}", text))?;
+        closed(text, 1 + end + 1)?;
+        return Ok(Value::String(rest[..end].to_string()));
+    }
+    match text {
+        "true" => return Ok(Value::Boolean(true)),
+        "false" => return Ok(Value::Boolean(false)),
+        _ => {}
+    }
+    // TOML lets big numbers be written 1_000_000
+    let digits = text.replace('_', "");
+    digits.parse().map(Value::Integer).map_err(|_| {
+        format!("can't read the value {} (this is a TOML subset: strings, integers and booleans only)", text)
+    })
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+        let mut section = String::new();
+        for (n, line) in text.lines().enumerate() {
+            let error = |message: String| ParseError { line: n + 1, message };
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let name = header.strip_suffix(']').ok_or_else(|| error(format!("unclosed header {}", line)))?;
+                let name = name.trim();
+                if !is_bare(name) {
+                    return Err(error(format!(
+                        "can't use [{}] as a section (nested and quoted names aren't supported)",
+                        name
+                    )));
+                }
+                section = name.to_string();
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| error(format!("expected key = value, not {}", line)))?;
+            let key = key.trim();
+            if !is_bare(key) {
+                return Err(error(format!("can't use {} as a key (dotted and quoted keys aren't supported)", key)));
+            }
+            if config.get(&section, key).is_some() {
+                return Err(error(format!("{} is set twice", config.name(&section, key))));
+            }
+            let value = parse_value(value.trim()).map_err(error)?;
+            config.values.push((section.clone(), key.to_string(), value));
+        }
+        Ok(config)
+    }
+
+    // DEMO_CONFIG's file if it's set, otherwise the nearest examples.toml.
+    // No file is fine (and means the defaults); a file that can't be read
+    // or parsed is an error.
+    pub fn load() -> Result<Config, String> {
+        let path = match env::var_os(ENV_VAR) {
+            Some(path) if path.is_empty() => return Ok(Config::default()),
+            Some(path) => PathBuf::from(path),
+            None => match env::current_dir().ok().and_then(|dir| find(&dir)) {
+                Some(path) => path,
+                None => return Ok(Config::default()),
+            },
+        };
+        let text = fs::read_to_string(&path).map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+        let mut config = Config::parse(&text).map_err(|e| format!("{}, {}", path.display(), e))?;
+        config.source = path.display().to_string();
+        Ok(config)
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&Value> {
+        self.values.iter().find(|(s, k, _)| s == section && k == key).map(|(_, _, value)| value)
+    }
+
+    // "log.level", or just "format" for a key outside any section
+    fn name(&self, section: &str, key: &str) -> String {
+        if section.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", section, key)
+        }
+    }
+
+    // the file it was loaded from, for messages about it
+    pub fn source(&self) -> &str {
+        if self.source.is_empty() {
+            FILE_NAME
+        } else {
+            &self.source
+        }
+    }
+
+    fn wrong_kind(&self, section: &str, key: &str, wanted: &str, value: &Value) -> String {
+        format!("{}: {} should be {}, not {}", self.source(), self.name(section, key), wanted, value.kind())
+    }
+
+    // The typed getters: None when the setting isn't there, and an error
+    // when it is, but is the wrong kind of value
+    pub fn string(&self, section: &str, key: &str) -> Result<Option<&str>, String> {
+        match self.get(section, key) {
+            None => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s)),
+            Some(other) => Err(self.wrong_kind(section, key, "a string", other)),
+        }
+    }
+
+    pub fn integer(&self, section: &str, key: &str) -> Result<Option<i64>, String> {
+        match self.get(section, key) {
+            None => Ok(None),
+            Some(Value::Integer(n)) => Ok(Some(*n)),
+            Some(other) => Err(self.wrong_kind(section, key, "an integer", other)),
+        }
+    }
+
+    pub fn boolean(&self, section: &str, key: &str) -> Result<Option<bool>, String> {
+        match self.get(section, key) {
+            None => Ok(None),
+            Some(Value::Boolean(b)) => Ok(Some(*b)),
+            Some(other) => Err(self.wrong_kind(section, key, "true or false", other)),
+        }
+    }
+}
+
+// examples.toml in `dir`, or the nearest directory above it that has one
+pub fn find(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors().map(|dir| dir.join(FILE_NAME)).find(|path| path.is_file())
+}
+
+// Take `flag <value>` out of a command line (so whatever reads the rest of
+// it never sees the flag), and return the value. This is the top layer.
+pub fn take_flag(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let at = match args.iter().position(|arg| arg == flag) {
+        Some(at) => at,
+        None => return Ok(None),
+    };
+    if at + 1 >= args.len() {
+        return Err(format!("{} needs a value", flag));
+    }
+    let value = args.remove(at + 1);
+    args.remove(at);
+    Ok(Some(value))
+}
+
+// The layers, lowest first: the first of `command_line`, then `file`, that
+// has something to say, and the default if neither does
+pub fn layered<T>(default: T, file: Option<T>, command_line: Option<T>) -> T {
+    command_line.or(file).unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+# settings for everything
+format = "json"
+
+[log]
+level = 'warn,collections=debug'   # the usual filter
+
+[guessing_game]
+max = 1_000
+cheat = false
+greeting = "say \"hi\"\t# not a comment"
+"#;
+
+    #[test]
+    fn reads_sections_keys_and_values() {
+        let config = Config::parse(EXAMPLE).unwrap();
+        assert_eq!(config.string("", "format"), Ok(Some("json")));
+        assert_eq!(config.string("log", "level"), Ok(Some("warn,collections=debug")));
+        assert_eq!(config.integer("guessing_game", "max"), Ok(Some(1000)));
+        assert_eq!(config.boolean("guessing_game", "cheat"), Ok(Some(false)));
+        assert_eq!(config.string("guessing_game", "greeting"), Ok(Some("say \"hi\"\t# not a comment")));
+        // a key only counts in its own section
+        assert_eq!(config.get("log", "max"), None);
+    }
+
+    #[test]
+    fn the_wrong_kind_of_value_is_an_error() {
+        let config = Config::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            config.integer("log", "level"),
+            Err(String::from("examples.toml: log.level should be an integer, not a string"))
+        );
+        assert!(config.string("guessing_game", "max").is_err());
+        assert_eq!(config.integer("guessing_game", "min"), Ok(None));
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert_eq!(error("[log]\nlevel debug").line, 2);
+        assert_eq!(error("[log\n").message, "unclosed header [log");
+        assert_eq!(error("[a.b]").line, 1);
+        assert_eq!(error("a.b = 1").line, 1);
+        assert_eq!(error("x = [1, 2]").line, 1);
+        assert_eq!(error("x = \"open").message, "unclosed string \"open");
+        assert_eq!(error("x = \"ends in \\\"").message, "unclosed string \"ends in \\\"");
+        assert_eq!(error("x = 'open").message, "unclosed string 'open");
+        assert_eq!(error("[log]\nlevel = 1\nlevel = 2").message, "log.level is set twice");
+    }
+
+    #[test]
+    fn a_string_ends_at_its_closing_quote() {
+        let error = |text: &str| Config::parse(text).unwrap_err().message;
+        assert_eq!(error(r#"x = "a" "b""#), r#"the string "a" "b" goes on after its closing quote"#);
+        assert_eq!(error("x = 'a' 'b'"), "the string 'a' 'b' goes on after its closing quote");
+        assert_eq!(error(r#"x = "a"b"#), r#"the string "a"b goes on after its closing quote"#);
+        // an odd run of backslashes escapes the quote, and an even one doesn't
+        assert_eq!(error(r#"x = "x\\\""#), r#"unclosed string "x\\\""#);
+        let config = Config::parse(r#"x = "x\\""#).unwrap();
+        assert_eq!(config.string("", "x"), Ok(Some(r"x\")));
+        let config = Config::parse(r#"x = "say \"hi\"""#).unwrap();
+        assert_eq!(config.string("", "x"), Ok(Some(r#"say "hi""#)));
+    }
+
+    #[test]
+    fn flags_come_out_of_the_command_line() {
+        let mut args: Vec<String> = ["game", "--max", "50", "extra"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&mut args, "--max"), Ok(Some(String::from("50"))));
+        assert_eq!(args, ["game", "extra"]);
+        assert_eq!(take_flag(&mut args, "--max"), Ok(None));
+        args.push(String::from("--max"));
+        assert!(take_flag(&mut args, "--max").is_err());
+    }
+
+    #[test]
+    fn later_layers_win() {
+        assert_eq!(layered(100, None, None), 100);
+        assert_eq!(layered(100, Some(1000), None), 1000);
+        assert_eq!(layered(100, Some(1000), Some(10)), 10);
+        assert_eq!(layered(100, None, Some(10)), 10);
+    }
+
+    #[test]
+    fn the_nearest_file_is_found() {
+        let top = env::temp_dir().join(format!("config-find-{}", std::process::id()));
+        let chapter = top.join("10_enums");
+        fs::create_dir_all(&chapter).unwrap();
+        fs::write(top.join(FILE_NAME), "").unwrap();
+        assert_eq!(find(&chapter), Some(top.join(FILE_NAME)));
+        fs::write(chapter.join(FILE_NAME), "").unwrap();
+        assert_eq!(find(&chapter), Some(chapter.join(FILE_NAME)));
+        fs::remove_dir_all(&top).unwrap();
+    }
+}
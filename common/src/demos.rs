@@ -0,0 +1,66 @@
+/*!
+ * What a chapter tells the outside world about its demos.
+ *
+ * Every chapter exports a `pub const DEMOS: &[Demo]`, one entry per demo
+ * that makes sense to run on its own, so that the runner's menu can list
+ * them, describe them and run any one of them. A chapter whose demo is one
+ * long story just has the one entry.
+ *
+ * `run: fn()` is the same kind of plain function pointer the runner already
+ * keeps for whole chapters, and `new` is a `const fn`, so a chapter can build
+ * its whole table at compile time:
+ *
+ * ```text
+ * pub const DEMOS: &[Demo] = &[
+ *     Demo::new("vectors", "Vec<T>: building, reading and iterating", vectors::demo_vectors),
+ *     Demo::new("strings", "String, &str and unicode", strings::demo_strings),
+ * ];
+ * ```
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct Demo {
+    // a short, lowercase handle, e.g. "vectors"
+    pub name: &'static str,
+    // one line on what the demo shows
+    pub summary: &'static str,
+    pub run: fn(),
+    // true for demos that read from stdin, which can't run with their
+    // output captured: there would be nobody to answer them
+    pub interactive: bool,
+}
+
+impl Demo {
+    pub const fn new(name: &'static str, summary: &'static str, run: fn()) -> Demo {
+        Demo { name, summary, run, interactive: false }
+    }
+
+    pub const fn interactive(name: &'static str, summary: &'static str, run: fn()) -> Demo {
+        Demo { name, summary, run, interactive: true }
+    }
+}
+
+// look a demo up by name, ignoring case
+pub fn find<'a>(demos: &'a [Demo], name: &str) -> Option<&'a Demo> {
+    let name = name.trim();
+    demos.iter().find(|demo| demo.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nothing() {}
+
+    const DEMOS: &[Demo] = &[
+        Demo::new("vectors", "Vec<T>", nothing),
+        Demo::interactive("guess", "reads stdin", nothing),
+    ];
+
+    #[test]
+    fn finds_demos_by_name() {
+        assert_eq!(find(DEMOS, "Vectors ").map(|demo| demo.summary), Some("Vec<T>"));
+        assert!(find(DEMOS, "guess").unwrap().interactive);
+        assert!(!find(DEMOS, "vectors").unwrap().interactive);
+        assert!(find(DEMOS, "strings").is_none());
+    }
+}
@@ -0,0 +1,29 @@
+/*!
+ * Console decoration, so the output of one demo is easy to tell apart from
+ * the next.
+ */
+
+// the divider 12_collections has always used
+pub const DIVIDER: &str = "///////////";
+
+// (it goes through the log facade, so it can be silenced with the rest)
+pub fn print_divider() {
+    crate::info!("{}", DIVIDER);
+}
+
+// a divider of any width, made of any character
+pub fn divider(width: usize, fill: char) -> String {
+    std::iter::repeat_n(fill, width).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_dividers() {
+        assert_eq!(divider(3, '='), "===");
+        assert_eq!(divider(0, '='), "");
+        assert_eq!(divider(DIVIDER.len(), '/'), DIVIDER);
+    }
+}
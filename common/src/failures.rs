@@ -0,0 +1,122 @@
+/*!
+ * Failure demos: the lines you were told to uncomment, runnable from the
+ * command line instead.
+ *
+ * Some chapters teach by breaking things: "uncomment the next line to see
+ * a panic". That works, but it means editing the source and remembering
+ * to put the comment back. Instead, a chapter can list its failures in a
+ * `failures.rs` (as a table of `Demo`s, the same type as its DEMOS) and
+ * have main.rs hand the command line to `handle_flag`:
+ *
+ * ```text
+ * cargo run -p errors -- --demo eek      # one failure
+ * cargo run -p errors -- --demo          # what is there to choose from?
+ * ```
+ *
+ * A failure that panics gets its panic caught by `std::panic::catch_unwind`.
+ * The panic message still prints (that's what you came to see), but then
+ * `run` reports it and the program finishes normally, instead of dying.
+ * Failures that are *recoverable* (a Result with an Err in it) don't panic
+ * at all: they just print the error they got.
+ */
+use crate::demos::{find, Demo};
+use std::any::Any;
+use std::panic;
+
+// the command line flag that picks a failure to run
+pub const FLAG: &str = "--demo";
+
+// What a panic carried: `panic!("...")` with a literal gives a &str, and
+// with format arguments a String. Anything else (`panic_any`) is rare.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("(a panic without a message)")
+    }
+}
+
+pub fn list(failures: &[Demo]) -> String {
+    let width = failures.iter().map(|failure| failure.name.len()).max().unwrap_or(0);
+    failures
+        .iter()
+        .map(|failure| format!("  {:<width$}  {}", failure.name, failure.summary, width = width))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Run the failure called `name`. Err means it couldn't be found, or that it
+// panicked, with what the panic said.
+pub fn run(failures: &[Demo], name: &str) -> Result<(), String> {
+    let failure = find(failures, name).ok_or_else(|| format!("no failure demo called '{}'", name))?;
+    panic::catch_unwind(failure.run).map_err(|payload| panic_message(&*payload))
+}
+
+// For main.rs: with no `--demo` flag, None, so the ordinary demos can run.
+// Otherwise run the named failure (or list them all, if there's no name),
+// and return the exit status to finish with.
+pub fn handle_flag(failures: &[Demo], args: &[String]) -> Option<i32> {
+    let at = args.iter().position(|arg| arg == FLAG)?;
+    let name = match args.get(at + 1) {
+        Some(name) => name,
+        None => {
+            println!("{} <name> runs one of these:\n{}", FLAG, list(failures));
+            return Some(0);
+        }
+    };
+    if find(failures, name).is_none() {
+        eprintln!("no failure demo called '{}'; try one of these:\n{}", name, list(failures));
+        return Some(1);
+    }
+    if let Err(message) = run(failures, name) {
+        // we *wanted* the panic, so the program still finishes happily
+        println!("(caught the panic: \"{}\", so the program carries on)", message);
+    }
+    Some(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fine() {}
+
+    fn boom() {
+        panic!("boom {}", 42);
+    }
+
+    const FAILURES: &[Demo] = &[Demo::new("fine", "doesn't fail", fine), Demo::new("boom", "panics", boom)];
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn panics_are_caught_and_reported() {
+        assert_eq!(run(FAILURES, "fine"), Ok(()));
+        assert_eq!(run(FAILURES, "boom"), Err(String::from("boom 42")));
+        assert_eq!(run(FAILURES, "fizz"), Err(String::from("no failure demo called 'fizz'")));
+    }
+
+    #[test]
+    fn messages_come_out_of_either_kind_of_payload() {
+        assert_eq!(panic_message(&"literal"), "literal");
+        assert_eq!(panic_message(&String::from("formatted")), "formatted");
+        assert_eq!(panic_message(&7), "(a panic without a message)");
+    }
+
+    #[test]
+    fn the_flag_picks_what_to_do() {
+        assert_eq!(handle_flag(FAILURES, &args(&["errors"])), None);
+        assert_eq!(handle_flag(FAILURES, &args(&["errors", "--demo", "boom"])), Some(0));
+        assert_eq!(handle_flag(FAILURES, &args(&["errors", "--demo"])), Some(0));
+        assert_eq!(handle_flag(FAILURES, &args(&["errors", "--demo", "fizz"])), Some(1));
+    }
+
+    #[test]
+    fn lists_line_up() {
+        assert_eq!(list(FAILURES), "  fine  doesn't fail\n  boom  panics");
+    }
+}
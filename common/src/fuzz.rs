@@ -0,0 +1,169 @@
+/*!
+ * Fuzz-style tests: throw thousands of made-up inputs at a parser, and
+ * check that it never panics. Whatever the input, the answer should be an
+ * `Ok` or one of the parser's own typed errors.
+ *
+ * A chapter's `tests/fuzz.rs` goes along these lines:
+ *
+ * ```text
+ * fuzz::check("IpAddr::parse", &[b"127.0.0.1", b"::1"], |bytes| {
+ *     let _ = IpAddr::parse(&String::from_utf8_lossy(bytes));
+ * });
+ * ```
+ *
+ * The inputs aren't just noise, which a parser would mostly reject on its
+ * first byte. Some are random bytes, but most are *mutations* of the
+ * examples passed in (the "corpus"): a real address with a byte flipped,
+ * a chunk doubled, a dot or a colon thrown in, cut off halfway. Those get
+ * deep into the parser, where the interesting mistakes are.
+ *
+ * It's all seeded, so a run is the same every time, and a failure says
+ * which input did it and how to get it back. It's also bounded, so that
+ * `cargo test` stays quick: 2,000 inputs per check unless FUZZ_ITERATIONS
+ * says otherwise. For a long run, try another seed or two:
+ *
+ * ```text
+ * FUZZ_ITERATIONS=1000000 FUZZ_SEED=7 cargo test --test fuzz
+ * ```
+ *
+ * (This is the poor man's version of cargo-fuzz, which watches which
+ * branches each input reaches and breeds the inputs that find new ones.
+ * The real thing is in ../../fuzz/, for anyone with a nightly compiler.)
+ */
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+
+pub const ITERATIONS_VAR: &str = "FUZZ_ITERATIONS";
+pub const SEED_VAR: &str = "FUZZ_SEED";
+
+const DEFAULT_ITERATIONS: usize = 2_000;
+
+// the bytes most likely to mean something to a parser, to sprinkle in
+const INTERESTING: &[u8] = b"0123456789 .:-+[]x{}\r\n\t\0\xff\xc3\xa9";
+
+// a number from the environment, or `default` if it's unset or nonsense
+fn from_env(var: &str, default: u64) -> u64 {
+    env::var(var).ok().and_then(|value| value.trim().parse().ok()).unwrap_or(default)
+}
+
+pub fn iterations() -> usize {
+    from_env(ITERATIONS_VAR, DEFAULT_ITERATIONS as u64) as usize
+}
+
+pub fn seed() -> u64 {
+    from_env(SEED_VAR, 0)
+}
+
+// An endless supply of inputs, made from `corpus`
+pub struct Inputs {
+    rng: StdRng,
+    corpus: Vec<Vec<u8>>,
+}
+
+impl Inputs {
+    pub fn new(seed: u64, corpus: &[&[u8]]) -> Inputs {
+        Inputs { rng: StdRng::seed_from_u64(seed), corpus: corpus.iter().map(|c| c.to_vec()).collect() }
+    }
+
+    pub fn next_bytes(&mut self) -> Vec<u8> {
+        if self.corpus.is_empty() || self.rng.gen_range(0, 4) == 0 {
+            let len = self.rng.gen_range(0, 48);
+            (0..len).map(|_| self.byte()).collect()
+        } else {
+            let mut bytes = self.corpus[self.rng.gen_range(0, self.corpus.len())].clone();
+            // one mutation, or a few on top of each other
+            for _ in 0..self.rng.gen_range(1, 4) {
+                self.mutate(&mut bytes);
+            }
+            bytes
+        }
+    }
+
+    // half the time an interesting byte, half the time any byte at all
+    fn byte(&mut self) -> u8 {
+        if self.rng.gen() {
+            INTERESTING[self.rng.gen_range(0, INTERESTING.len())]
+        } else {
+            self.rng.gen()
+        }
+    }
+
+    fn mutate(&mut self, bytes: &mut Vec<u8>) {
+        let at = self.rng.gen_range(0, bytes.len() + 1);
+        match self.rng.gen_range(0, 5) {
+            0 => bytes.insert(at, self.byte()),
+            1 if at < bytes.len() => {
+                bytes.remove(at);
+            }
+            2 if at < bytes.len() => bytes[at] = self.byte(),
+            // double a chunk: "1.2.3.4" -> "1.2.3.2.3.4"
+            3 => {
+                let end = self.rng.gen_range(at, bytes.len() + 1);
+                let chunk = bytes[at..end].to_vec();
+                bytes.splice(at..at, chunk);
+            }
+            // cut it off
+            _ => bytes.truncate(at),
+        }
+    }
+}
+
+// Run `test` on `iterations()` inputs made from `corpus`, and panic with
+// the input that made it panic, if one does
+pub fn check<F: FnMut(&[u8])>(name: &str, corpus: &[&[u8]], mut test: F) {
+    let seed = seed();
+    let mut inputs = Inputs::new(seed, corpus);
+    for iteration in 0..iterations() {
+        let input = inputs.next_bytes();
+        if panic::catch_unwind(AssertUnwindSafe(|| test(&input))).is_err() {
+            panic!(
+                "{} panicked on input {:?} ({}={}, input number {})",
+                name,
+                String::from_utf8_lossy(&input),
+                SEED_VAR,
+                seed,
+                iteration
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_makes_the_same_inputs() {
+        let corpus: &[&[u8]] = &[b"127.0.0.1", b"GET / HTTP/1.1"];
+        let first: Vec<Vec<u8>> = {
+            let mut inputs = Inputs::new(5, corpus);
+            (0..50).map(|_| inputs.next_bytes()).collect()
+        };
+        let mut again = Inputs::new(5, corpus);
+        assert!(first.iter().all(|input| *input == again.next_bytes()));
+        let mut other = Inputs::new(6, corpus);
+        assert!(first.iter().any(|input| *input != other.next_bytes()));
+    }
+
+    #[test]
+    fn inputs_stay_close_to_the_corpus() {
+        // most inputs are mutations, so most still have a dot in them
+        let mut inputs = Inputs::new(1, &[b"1.2.3.4"]);
+        let dotted = (0..1000).filter(|_| inputs.next_bytes().contains(&b'.')).count();
+        assert!(dotted > 500, "only {} of 1000 had a dot", dotted);
+    }
+
+    #[test]
+    fn an_empty_corpus_is_all_noise() {
+        let mut inputs = Inputs::new(1, &[]);
+        assert!((0..100).map(|_| inputs.next_bytes()).all(|input| input.len() < 48));
+    }
+
+    #[test]
+    #[should_panic(expected = "always panicked on input")]
+    fn a_panic_names_its_input() {
+        check("always", &[b"x"], |_| panic!("boom"));
+    }
+}
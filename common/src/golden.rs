@@ -0,0 +1,186 @@
+/*!
+ * Golden-output tests: run a chapter's binary, and compare everything it
+ * prints against a file checked in next to the test.
+ *
+ * Unit tests check that functions return the right values; these check
+ * that the *program* still says what it said before, which catches a demo
+ * that quietly stopped printing something, or started panicking. Each
+ * chapter has a `tests/golden.rs` along these lines:
+ *
+ * ```text
+ * let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_hello_cargo"), dir), "");
+ * golden::check(dir, "demo", &output);     // against tests/golden/demo.txt
+ * ```
+ *
+ * A golden file only works if a run prints the same thing every time, so
+ * `command` fixes what it can: the random numbers (DEMO_SEED, see
+ * ./random.rs), the language (DEMO_LANG, see ./i18n.rs), the log filter,
+ * and no examples.toml (DEMO_CONFIG, see ./config.rs).
+ * What can't be fixed, like how long something took, `normalize` blanks out.
+ *
+ * When a change is *meant* to change a demo's output, regenerate its file
+ * and review the diff like any other:
+ *
+ * ```text
+ * UPDATE_GOLDEN=1 cargo test --test golden
+ * git diff
+ * ```
+ */
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+// set this to rewrite the golden files instead of checking against them
+pub const UPDATE_VAR: &str = "UPDATE_GOLDEN";
+
+// the seed every golden run uses, so its "random" numbers never change
+pub const SEED: &str = "42";
+
+// `bin`, to be run in `dir`, with the environment pinned down
+pub fn command(bin: &str, dir: &str) -> Command {
+    let mut command = Command::new(bin);
+    command
+        .current_dir(dir)
+        .env(crate::random::ENV_VAR, SEED)
+        .env(crate::i18n::ENV_VAR, "en")
+        .env(crate::config::ENV_VAR, "")
+        .env_remove(crate::log::ENV_VAR)
+        .env_remove("RUST_BACKTRACE");
+    command
+}
+
+// Run `command` with `stdin` piped in, and collect what it printed. Stderr
+// and a failing exit status are part of the output too, after stdout.
+pub fn output(command: &mut Command, stdin: &str) -> String {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("couldn't start the binary");
+    // a program that quits without reading all of its input is fine by us
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    let output = child.wait_with_output().expect("couldn't wait for the binary");
+
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !output.stderr.is_empty() {
+        text.push_str("--- stderr ---\n");
+        text.push_str(&String::from_utf8_lossy(&output.stderr));
+    }
+    if !output.status.success() {
+        text.push_str(&format!("--- {} ---\n", output.status));
+    }
+    text
+}
+
+// Does `text`, from position `at`, hold a measured duration in Debug format,
+// such as `2.392904ms`? If so, return where it ends. Only numbers with a
+// fractional part count: a measurement is never a round number, and the
+// durations demos print on purpose (`Red for 30s`) always are.
+fn duration_end(text: &str, at: usize) -> Option<usize> {
+    let number = text[at..].find(|c: char| !c.is_ascii_digit() && c != '.').map_or(text.len(), |n| at + n);
+    if !text[at..number].contains('.') {
+        return None;
+    }
+    let rest = &text[number..];
+    let unit = ["ns", "µs", "ms", "s"].iter().find(|unit| rest.starts_with(*unit))?;
+    let end = number + unit.len();
+    // "5 seconds" isn't a duration, and neither is the 5 in "5s3"
+    match text[end..].chars().next() {
+        Some(c) if c.is_alphanumeric() => None,
+        _ => Some(end),
+    }
+}
+
+// Replace every measured duration (and nothing else) with `<duration>`
+pub fn normalize(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let starts_word = i == 0 || !text[..i].ends_with(|p: char| p.is_alphanumeric() || p == '.');
+        if !(c.is_ascii_digit() && starts_word && i >= rest) {
+            continue;
+        }
+        if let Some(end) = duration_end(text, i) {
+            out.push_str(&text[rest..i]);
+            out.push_str("<duration>");
+            rest = end;
+            while chars.peek().is_some_and(|&(j, _)| j < end) {
+                chars.next();
+            }
+        }
+    }
+    out.push_str(&text[rest..]);
+    out
+}
+
+// where the golden file called `name` lives, for the package in `dir`
+pub fn path(dir: &str, name: &str) -> PathBuf {
+    PathBuf::from(dir).join("tests").join("golden").join(format!("{}.txt", name))
+}
+
+// Compare `output` (normalized) against the golden file, or with
+// UPDATE_GOLDEN set, write it there instead
+pub fn check(dir: &str, name: &str, output: &str) {
+    let path = path(dir, name);
+    let actual = normalize(output);
+    if env::var_os(UPDATE_VAR).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("no golden file at {}: run with {}=1 to create it", path.display(), UPDATE_VAR)
+    });
+    if actual == expected {
+        return;
+    }
+    // point at the first line that differs, rather than dumping two walls
+    // of text and leaving the reader to play spot-the-difference
+    let (n, (want, got)) = expected
+        .lines()
+        .map(Some)
+        .chain(std::iter::repeat(None))
+        .zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+        .enumerate()
+        .find(|(_, (want, got))| want != got)
+        .unwrap_or((0, (None, None)));
+    panic!(
+        "output differs from {} at line {}\n  expected: {}\n  actual:   {}\n\
+         (if the change is intended, rerun with {}=1 and review the diff)",
+        path.display(),
+        n + 1,
+        want.unwrap_or("<end of file>"),
+        got.unwrap_or("<end of output>"),
+        UPDATE_VAR
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_blanked_out() {
+        assert_eq!(normalize("took 2.392904ms, then 812.5ns"), "took <duration>, then <duration>");
+        assert_eq!(normalize("1.5s and 56.1µs."), "<duration> and <duration>.");
+    }
+
+    #[test]
+    fn other_numbers_are_left_alone() {
+        let text = "5 seconds, 12 items, 3.5s4, v1.2ms, 10.5 ms, Red for 30s";
+        assert_eq!(normalize(text), text);
+        assert_eq!(normalize("Scores: {\"Blue\": 101}\n"), "Scores: {\"Blue\": 101}\n");
+    }
+
+    #[test]
+    fn output_includes_stderr_and_failure() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "cat; echo oops >&2; exit 3"]);
+        assert_eq!(output(&mut command, "hi\n"), "hi\n--- stderr ---\noops\n--- exit status: 3 ---\n");
+    }
+}
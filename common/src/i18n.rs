@@ -0,0 +1,166 @@
+/*!
+ * Message catalogs, so the interactive programs can speak more than English.
+ *
+ * A program keeps one `Catalog` per language: a list of `(key, text)` pairs.
+ * Code asks for a message by key, never by its English wording, and `Messages`
+ * looks the key up in whichever language was picked:
+ *
+ * ```text
+ * DEMO_LANG=es cargo run -p guessing_game
+ * ```
+ *
+ * With no `DEMO_LANG`, the usual `LC_ALL`, `LC_MESSAGES` and `LANG` variables
+ * get a say (so `LANG=es_ES.UTF-8` means Spanish), and failing those it's
+ * English. A missing key falls back to the first catalog in the list (English,
+ * by convention), and a key that's missing from *that* comes out as the key
+ * itself: ugly enough to get noticed, but not worth a panic.
+ *
+ * Texts can have named placeholders, filled in by `format`:
+ *
+ * ```text
+ * ("you_guessed", "You guessed {guess}")   // English
+ * ("you_guessed", "Has dicho {guess}")     // Spanish
+ * messages.format("you_guessed", &[("guess", &50)])
+ * ```
+ *
+ * Names rather than `{}`s, because a translation may need to put them in a
+ * different order than English does.
+ */
+use std::env;
+use std::fmt::Display;
+
+// the environment variable that `language_from_env` reads first
+pub const ENV_VAR: &str = "DEMO_LANG";
+
+pub struct Catalog {
+    pub lang: &'static str,
+    pub messages: &'static [(&'static str, &'static str)],
+}
+
+impl Catalog {
+    pub fn get(&self, key: &str) -> Option<&'static str> {
+        self.messages.iter().find(|(k, _)| *k == key).map(|(_, text)| *text)
+    }
+}
+
+// "es_ES.UTF-8" and "es-es" both become "es-es": lowercase, a dash between
+// language and region, and no encoding
+fn normalize(lang: &str) -> String {
+    let lang = lang.split('.').next().unwrap_or("");
+    lang.trim().replace('_', "-").to_lowercase()
+}
+
+// DEMO_LANG, then the standard locale variables. "C" and "POSIX" are what
+// an unconfigured system says, and mean "no preference", so they're skipped.
+pub fn language_from_env() -> String {
+    for var in [ENV_VAR, "LC_ALL", "LC_MESSAGES", "LANG"] {
+        if let Ok(value) = env::var(var) {
+            let lang = normalize(&value);
+            if !lang.is_empty() && lang != "c" && lang != "posix" {
+                return lang;
+            }
+        }
+    }
+    String::from("en")
+}
+
+pub struct Messages {
+    catalogs: &'static [Catalog],
+    // the catalog for the chosen language, if there is one
+    chosen: Option<&'static Catalog>,
+}
+
+impl Messages {
+    // "es-mx" uses an "es-mx" catalog if there is one, and "es" if not
+    pub fn new(catalogs: &'static [Catalog], lang: &str) -> Messages {
+        let lang = normalize(lang);
+        let base = lang.split('-').next().unwrap_or("");
+        let chosen = catalogs
+            .iter()
+            .find(|c| c.lang == lang)
+            .or_else(|| catalogs.iter().find(|c| c.lang == base));
+        Messages { catalogs, chosen }
+    }
+
+    pub fn from_env(catalogs: &'static [Catalog]) -> Messages {
+        Messages::new(catalogs, &language_from_env())
+    }
+
+    // the language actually in use, which may not be the one asked for
+    pub fn lang(&self) -> &'static str {
+        self.chosen.or_else(|| self.catalogs.first()).map_or("", |c| c.lang)
+    }
+
+    pub fn get<'a>(&self, key: &'a str) -> &'a str {
+        self.chosen
+            .and_then(|c| c.get(key))
+            .or_else(|| self.catalogs.first().and_then(|c| c.get(key)))
+            .unwrap_or(key)
+    }
+
+    // `get`, with each `{name}` replaced by the matching argument
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.get(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        text
+    }
+}
+
+// The keys the fallback (first) catalog has and `lang`'s catalog doesn't.
+// Every program with catalogs has a test asserting this is empty, so a new
+// English message can't quietly go untranslated.
+pub fn missing_keys(catalogs: &[Catalog], lang: &str) -> Vec<&'static str> {
+    let (fallback, other) = match (catalogs.first(), catalogs.iter().find(|c| c.lang == lang)) {
+        (Some(fallback), Some(other)) => (fallback, other),
+        _ => return Vec::new(),
+    };
+    fallback
+        .messages
+        .iter()
+        .map(|(key, _)| *key)
+        .filter(|key| other.get(key).is_none())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static CATALOGS: &[Catalog] = &[
+        Catalog { lang: "en", messages: &[("hello", "Hello, {name}!"), ("bye", "Goodbye"), ("new", "New")] },
+        Catalog { lang: "es", messages: &[("hello", "¡Hola, {name}!"), ("bye", "Adiós")] },
+        Catalog { lang: "es-mx", messages: &[("bye", "Nos vemos")] },
+    ];
+
+    #[test]
+    fn picks_the_closest_language() {
+        assert_eq!(Messages::new(CATALOGS, "es").get("bye"), "Adiós");
+        assert_eq!(Messages::new(CATALOGS, "es_MX.UTF-8").get("bye"), "Nos vemos");
+        assert_eq!(Messages::new(CATALOGS, "es-AR").lang(), "es");
+        assert_eq!(Messages::new(CATALOGS, "fr").lang(), "en");
+    }
+
+    #[test]
+    fn missing_messages_fall_back_to_english_then_the_key() {
+        let spanish = Messages::new(CATALOGS, "es");
+        assert_eq!(spanish.get("new"), "New");
+        assert_eq!(spanish.get("no_such_key"), "no_such_key");
+        // a region catalog only overrides what it has
+        assert_eq!(Messages::new(CATALOGS, "es-mx").get("new"), "New");
+    }
+
+    #[test]
+    fn placeholders_are_filled_by_name() {
+        assert_eq!(Messages::new(CATALOGS, "es").format("hello", &[("name", &"Ferris")]), "¡Hola, Ferris!");
+        assert_eq!(Messages::new(CATALOGS, "en").format("hello", &[]), "Hello, {name}!");
+    }
+
+    #[test]
+    fn missing_keys_are_listed() {
+        assert_eq!(missing_keys(CATALOGS, "es"), ["new"]);
+        assert_eq!(missing_keys(CATALOGS, "en"), Vec::<&str>::new());
+        assert_eq!(missing_keys(CATALOGS, "de"), Vec::<&str>::new());
+    }
+}
@@ -0,0 +1,46 @@
+/*!
+ * Random, uuid-ish identifiers: good enough for telling demo objects apart,
+ * *not* good enough for anything that needs to be unguessable.
+ */
+use crate::random::{Rng, ThreadRng};
+
+// `length` random letters and digits (the same ones every run, if DEMO_SEED
+// is set: see ./random.rs)
+pub fn random_id(length: usize) -> String {
+    random_id_from(&mut ThreadRng, length)
+}
+
+// the same, drawing from `rng`
+pub fn random_id_from(rng: &mut impl Rng, length: usize) -> String {
+    (0..length).map(|_| rng.alphanumeric()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SeededRng;
+
+    #[test]
+    fn ids_have_the_requested_length() {
+        assert_eq!(random_id(0), "");
+        assert_eq!(random_id(8).len(), 8);
+        assert_eq!(random_id(100).chars().count(), 100);
+    }
+
+    #[test]
+    fn ids_are_alphanumeric() {
+        assert!(random_id(64).chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn ids_are_not_all_the_same() {
+        // 62^16 possibilities: a collision here means the rng is broken
+        assert_ne!(random_id(16), random_id(16));
+    }
+
+    #[test]
+    fn a_seeded_rng_gives_the_same_id() {
+        let id = random_id_from(&mut SeededRng::new(11), 12);
+        assert_eq!(random_id_from(&mut SeededRng::new(11), 12), id);
+    }
+}
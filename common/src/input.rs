@@ -0,0 +1,62 @@
+/*!
+ * Ask a question, read one line back.
+ *
+ * The work happens in `prompt_from`, which takes *any* reader and writer.
+ * `prompt` just plugs in stdin and stdout. That split is what makes it
+ * testable: a test hands it a byte string and a Vec, instead of a human
+ * at a keyboard.
+ */
+use std::io::{self, BufRead, Write};
+
+// Print `message`, then read one line. The trailing newline is trimmed off.
+// Ok(None) means the input has run dry (Ctrl-D, or the end of a pipe), which
+// callers usually want to treat differently from an empty line.
+pub fn prompt_from<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    message: &str,
+) -> io::Result<Option<String>> {
+    write!(output, "{}", message)?;
+    // without a flush, a prompt with no newline could sit in the buffer
+    // until *after* the user has typed their answer
+    output.flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+pub fn prompt(message: &str) -> io::Result<Option<String>> {
+    let stdin = io::stdin();
+    prompt_from(&mut stdin.lock(), &mut io::stdout(), message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ask(typed: &str) -> (Option<String>, String) {
+        let mut output = Vec::new();
+        let answer = prompt_from(&mut typed.as_bytes(), &mut output, "Name? ").unwrap();
+        (answer, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn reads_one_line_and_trims_the_newline() {
+        assert_eq!(ask("Ferris\nsecond line\n"), (Some(String::from("Ferris")), String::from("Name? ")));
+        assert_eq!(ask("windows\r\n").0, Some(String::from("windows")));
+    }
+
+    #[test]
+    fn keeps_other_whitespace() {
+        assert_eq!(ask("  spaced out  \n").0, Some(String::from("  spaced out  ")));
+    }
+
+    #[test]
+    fn empty_line_is_not_end_of_input() {
+        assert_eq!(ask("\n").0, Some(String::new()));
+        assert_eq!(ask("").0, None);
+    }
+}
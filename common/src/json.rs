@@ -0,0 +1,216 @@
+/*!
+ * Just enough JSON to write it out: a value type, and text from it.
+ *
+ * The real thing is `serde_json` (the benches package uses it to *read*
+ * criterion's files), but writing JSON is small enough to do by hand, and
+ * doing it by hand shows off an enum with data in every variant, a
+ * recursive `Display`, and the fiddly bit everyone gets wrong the first
+ * time: escaping strings.
+ *
+ * ```text
+ * let record = Json::object([("level", Json::from("info")), ("line", Json::from(3))]);
+ * record.to_string()       // {"level":"info","line":3}
+ * ```
+ *
+ * Objects are a Vec of pairs rather than a HashMap, so keys come out in the
+ * order they went in, and the same value always prints the same text.
+ */
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object<K: Into<String>, I: IntoIterator<Item = (K, Json)>>(pairs: I) -> Json {
+        Json::Object(pairs.into_iter().map(|(key, value)| (key.into(), value)).collect())
+    }
+
+    pub fn array<T: Into<Json>, I: IntoIterator<Item = T>>(items: I) -> Json {
+        Json::Array(items.into_iter().map(Into::into).collect())
+    }
+
+    // the same text as `to_string`, laid out over several lines, indented
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, depth: usize) {
+        let indent = |depth: usize| "  ".repeat(depth);
+        match self {
+            Json::Array(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&indent(depth + 1));
+                    item.write_pretty(out, depth + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                out.push_str(&indent(depth));
+                out.push(']');
+            }
+            Json::Object(pairs) if !pairs.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    out.push_str(&format!("{}{}: ", indent(depth + 1), quote(key)));
+                    value.write_pretty(out, depth + 1);
+                    out.push_str(if i + 1 < pairs.len() { ",\n" } else { "\n" });
+                }
+                out.push_str(&indent(depth));
+                out.push('}');
+            }
+            // everything else looks the same either way
+            other => out.push_str(&other.to_string()),
+        }
+    }
+}
+
+// A JSON string: quoted, with quotes, backslashes and control characters
+// escaped. Everything else, unicode included, can go in as it is.
+pub fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            // JSON has no NaN or infinity, so those become null
+            Json::Number(n) if !n.is_finite() => write!(f, "null"),
+            Json::Number(n) => write!(f, "{}", n),
+            Json::String(s) => write!(f, "{}", quote(s)),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(pairs) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}:{}", quote(key), value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Json {
+        Json::Bool(b)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(n: f64) -> Json {
+        Json::Number(n)
+    }
+}
+
+impl From<i32> for Json {
+    fn from(n: i32) -> Json {
+        Json::Number(n.into())
+    }
+}
+
+impl From<u32> for Json {
+    fn from(n: u32) -> Json {
+        Json::Number(n.into())
+    }
+}
+
+// (big enough usizes lose precision in an f64, just as they would in
+// JavaScript, which is where JSON's numbers come from)
+impl From<usize> for Json {
+    fn from(n: usize) -> Json {
+        Json::Number(n as f64)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Json {
+        Json::String(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Json {
+        Json::String(s)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(option: Option<T>) -> Json {
+        option.map_or(Json::Null, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::from(true).to_string(), "true");
+        assert_eq!(Json::from(42).to_string(), "42");
+        assert_eq!(Json::from(-2.5).to_string(), "-2.5");
+        assert_eq!(Json::from(f64::NAN).to_string(), "null");
+        assert_eq!(Json::from(None::<i32>).to_string(), "null");
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(quote("say \"hi\"\n"), "\"say \\\"hi\\\"\\n\"");
+        assert_eq!(quote("C:\\temp\ttab"), "\"C:\\\\temp\\ttab\"");
+        assert_eq!(quote("bell\u{7}"), "\"bell\\u0007\"");
+        assert_eq!(quote("नमस्ते"), "\"नमस्ते\"");
+    }
+
+    #[test]
+    fn containers_keep_their_order() {
+        let value = Json::object([
+            ("name", Json::from("vectors")),
+            ("tags", Json::array(["a", "b"])),
+            ("empty", Json::Array(Vec::new())),
+        ]);
+        assert_eq!(value.to_string(), r#"{"name":"vectors","tags":["a","b"],"empty":[]}"#);
+    }
+
+    #[test]
+    fn pretty_printing_indents() {
+        let value = Json::object([("list", Json::array([1, 2])), ("none", Json::Object(Vec::new()))]);
+        assert_eq!(value.pretty(), "{\n  \"list\": [\n    1,\n    2\n  ],\n  \"none\": {}\n}");
+    }
+}
@@ -0,0 +1,49 @@
+/*!
+ * Little helpers that kept getting hand-rolled, chapter after chapter.
+ *
+ * 11_modules and 12_collections each had their own copy of the same
+ * random-string function, and every demo in 12_collections declared its
+ * own `let divider = "///////////";`. Now they live here, once, and the
+ * chapters depend on this crate the same way they depend on rand:
+ *
+ * ```text
+ * [dependencies]
+ * common = { path = "../common" }
+ * ```
+ *
+ * A `path` dependency points at a crate sitting right there on disk,
+ * instead of one downloaded from crates.io. Everything else works the
+ * same: only `pub` items are visible from outside, and callers write
+ * `common::random_id(8)` or `use common::print_divider;`.
+ *
+ * The modules are public, but the everyday functions are also re-exported
+ * from the top, so nobody has to remember which module each one lives in.
+ *
+ * The logging macros (`info!`, `debug!` and the rest, from `log.rs`) are
+ * at the top already, because that is where `#[macro_export]` puts them:
+ * `use common::info;` and then `info!("...")`, like `println!`.
+ */
+pub mod config;
+pub mod demos;
+pub mod display;
+pub mod failures;
+pub mod fuzz;
+pub mod golden;
+pub mod i18n;
+pub mod ids;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod random;
+pub mod timing;
+
+pub use config::Config;
+pub use demos::Demo;
+pub use display::{divider, print_divider, DIVIDER};
+pub use i18n::{Catalog, Messages};
+pub use ids::{random_id, random_id_from};
+pub use input::{prompt, prompt_from};
+pub use json::Json;
+pub use log::{capture, Filter, Format, Level};
+pub use random::{with_rng, Rng, SeededRng, ThreadRng};
+pub use timing::{time, timed};
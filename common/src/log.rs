@@ -0,0 +1,472 @@
+/*!
+ * A tiny logging facade, in the spirit of the `log` crate.
+ *
+ * Demos call `info!`, `debug!` and friends instead of `println!`. Every
+ * message has a *level* and a *target* (the module it came from, unless the
+ * call names one), and a single filter decides which messages get through.
+ * The filter is read from the `DEMO_LOG` environment variable, which has the
+ * same shape as `RUST_LOG` for the real `env_logger` crate:
+ *
+ * ```text
+ * DEMO_LOG=off                        # silence everything
+ * DEMO_LOG=debug                      # debug and everything above it
+ * DEMO_LOG=warn,collections=trace     # quiet, except for one crate
+ * DEMO_LOG=modules::spam=off          # everything, except one module
+ * ```
+ *
+ * With no `DEMO_LOG` at all, the filter is `info`. Info messages print just
+ * as `println!` would have, so the demos look the same as they always did;
+ * the other levels get a `[LEVEL target]` prefix. (A program whose main
+ * calls `configure` also takes a `level` from examples.toml's `[log]`
+ * section, under DEMO_LOG: see ./config.rs.)
+ *
+ * Messages go to stdout unless `set_output` swaps in another writer. Tests
+ * use `capture` instead, which collects what the current thread logs into a
+ * String, so tests running side by side never see each other's messages.
+ *
+ * For other programs to read, `set_format(Format::Json)` turns every message
+ * into one JSON object per line (see ./json.rs), which is what a program's
+ * `--format json` flag does:
+ *
+ * ```text
+ * {"level":"info","target":"collections::vectors","message":"Empty vector (type i32): []"}
+ * ```
+ */
+use crate::config::{self, layered, Config};
+use crate::json::Json;
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::{Mutex, RwLock};
+
+// the environment variable that `Filter::from_env` reads
+pub const ENV_VAR: &str = "DEMO_LOG";
+
+// Most severe first, so "at least this important" is `level <= max`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level '{}'", s)),
+        }
+    }
+}
+
+// the most verbose level a filter lets through, where None means "off"
+fn parse_max(s: &str) -> Result<Option<Level>, String> {
+    if s.eq_ignore_ascii_case("off") {
+        Ok(None)
+    } else {
+        s.parse().map(Some)
+    }
+}
+
+// Which messages to keep: a default, plus overrides for particular targets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub fn new(default: Option<Level>) -> Filter {
+        Filter { default, targets: Vec::new() }
+    }
+
+    pub fn off() -> Filter {
+        Filter::new(None)
+    }
+
+    // a comma-separated list of `level` and `target=level` entries
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::new(Some(Level::Info));
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, max)) => {
+                    filter.targets.push((target.trim().to_string(), parse_max(max.trim())?))
+                }
+                None => filter.default = parse_max(entry)?,
+            }
+        }
+        Ok(filter)
+    }
+
+    // DEMO_LOG if it is set (and makes sense), otherwise plain `info`
+    pub fn from_env() -> Filter {
+        match env::var(ENV_VAR) {
+            Ok(spec) => Filter::parse(&spec).unwrap_or_else(|e| {
+                eprintln!("ignoring {}={:?}: {}", ENV_VAR, spec, e);
+                Filter::new(Some(Level::Info))
+            }),
+            Err(_) => Filter::new(Some(Level::Info)),
+        }
+    }
+
+    // The most specific matching target wins, so `modules::spam=off` beats
+    // `modules=debug`. A target covers its submodules, but `spam` does not
+    // cover `spammer`: matches have to end on a `::` boundary.
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .filter(|(t, _)| {
+                target == t || (target.starts_with(t.as_str()) && target[t.len()..].starts_with("::"))
+            })
+            .max_by_key(|(t, _)| t.len())
+            .map_or(self.default, |(_, max)| *max);
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+// how each message is written out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format '{}' (try text or json)", s)),
+        }
+    }
+}
+
+// Take `--format <text|json>` out of a command line, so whatever reads the
+// rest of it never sees the flag. No flag means Text.
+pub fn take_format_flag(args: &mut Vec<String>) -> Result<Format, String> {
+    Ok(take_format(args)?.unwrap_or(Format::Text))
+}
+
+fn take_format(args: &mut Vec<String>) -> Result<Option<Format>, String> {
+    match config::take_flag(args, "--format") {
+        Ok(value) => value.map(|value| value.parse()).transpose(),
+        Err(_) => Err(String::from("--format needs a value (text or json)")),
+    }
+}
+
+// Every log setting, in layers (see ./config.rs): `info` and Text to start
+// with, then the [log] section of examples.toml, then DEMO_LOG and --format.
+// A bad DEMO_LOG is only a warning, as it always was, but a bad setting in
+// the file is an error.
+fn settings(config: &Config, env_spec: Option<String>, args: &mut Vec<String>) -> Result<(Filter, Format), String> {
+    let file_filter = match config.string("log", "level")? {
+        Some(spec) => Some(Filter::parse(spec).map_err(|e| format!("log.level in {}: {}", config.source(), e))?),
+        None => None,
+    };
+    let env_filter = env_spec.and_then(|spec| {
+        Filter::parse(&spec).map_err(|e| eprintln!("ignoring {}={:?}: {}", ENV_VAR, spec, e)).ok()
+    });
+    let filter = layered(Filter::new(Some(Level::Info)), file_filter, env_filter);
+
+    let file_format = match config.string("log", "format")? {
+        Some(format) => Some(format.parse().map_err(|e| format!("log.format in {}: {}", config.source(), e))?),
+        None => None,
+    };
+    let format = layered(Format::Text, file_format, take_format(args)?);
+    Ok((filter, format))
+}
+
+// For main.rs: work out the settings, use them for the rest of the run, and
+// return the format (for anything else the program formats, like a listing)
+pub fn configure(config: &Config, args: &mut Vec<String>) -> Result<Format, String> {
+    let (filter, format) = settings(config, env::var(ENV_VAR).ok(), args)?;
+    set_filter(filter);
+    set_format(format);
+    Ok(format)
+}
+
+static FORMAT: RwLock<Format> = RwLock::new(Format::Text);
+
+// None until the first message, when DEMO_LOG gets read
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+// None means stdout
+static OUTPUT: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+thread_local! {
+    // Some(..) while this thread is inside `capture`
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// replace the filter (whatever DEMO_LOG said) for the rest of the run
+pub fn set_filter(filter: Filter) {
+    *FILTER.write().unwrap() = Some(filter);
+}
+
+// send everything to `writer` from now on, instead of stdout
+pub fn set_output(writer: Box<dyn Write + Send>) {
+    *OUTPUT.lock().unwrap() = Some(writer);
+}
+
+pub fn set_format(format: Format) {
+    *FORMAT.write().unwrap() = format;
+}
+
+pub fn format() -> Format {
+    *FORMAT.read().unwrap()
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    if let Some(filter) = FILTER.read().unwrap().as_ref() {
+        return filter.enabled(level, target);
+    }
+    FILTER
+        .write()
+        .unwrap()
+        .get_or_insert_with(Filter::from_env)
+        .enabled(level, target)
+}
+
+// Info messages are printed bare, to match the println! they replaced
+fn format_line(format: Format, level: Level, target: &str, args: fmt::Arguments) -> String {
+    match (format, level) {
+        (Format::Json, _) => Json::object([
+            ("level", Json::from(level.to_string().to_lowercase())),
+            ("target", Json::from(target)),
+            ("message", Json::from(args.to_string())),
+        ])
+        .to_string(),
+        (Format::Text, Level::Info) => format!("{}", args),
+        (Format::Text, _) => format!("[{} {}] {}", level, target, args),
+    }
+}
+
+// What the macros call. You can call it directly, but the macros fill in
+// the target for you and skip the formatting when nothing would print.
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    if !enabled(level, target) {
+        return;
+    }
+    let line = format_line(format(), level, target, args);
+
+    let captured = CAPTURED.with(|c| match c.borrow_mut().as_mut() {
+        Some(buffer) => {
+            buffer.push_str(&line);
+            buffer.push('\n');
+            true
+        }
+        None => false,
+    });
+    if captured {
+        return;
+    }
+
+    // A failed write is dropped on the floor rather than panicking the way
+    // println! does, so `cargo run | head` doesn't end in a stack trace.
+    match OUTPUT.lock().unwrap().as_mut() {
+        Some(writer) => {
+            let _ = writeln!(writer, "{}", line);
+        }
+        None => {
+            let _ = writeln!(io::stdout().lock(), "{}", line);
+        }
+    }
+}
+
+// Run `f`, and return whatever this thread logged meanwhile (one line per
+// message) instead of printing it. Only messages that pass the filter count.
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, String) {
+    let outer = CAPTURED.with(|c| c.borrow_mut().replace(String::new()));
+    let result = f();
+    let logged = CAPTURED.with(|c| std::mem::replace(&mut *c.borrow_mut(), outer));
+    (result, logged.unwrap_or_default())
+}
+
+// `log!(Level::Warn, "...")`, or `log!(target: "name", Level::Warn, "...")`
+#[macro_export]
+macro_rules! log {
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        let target = $target;
+        if $crate::log::enabled(level, target) {
+            $crate::log::log(level, target, format_args!($($arg)+));
+        }
+    }};
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log!(target: module_path!(), $level, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::log::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::log::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::log::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::log::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log!(target: $target, $crate::log::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_parse_and_order() {
+        assert_eq!("Warn".parse(), Ok(Level::Warn));
+        assert!("loud".parse::<Level>().is_err());
+        assert!(Level::Error < Level::Trace);
+        assert_eq!(Level::Debug.to_string(), "DEBUG");
+    }
+
+    #[test]
+    fn default_level_applies_everywhere() {
+        let filter = Filter::parse("warn").unwrap();
+        assert!(filter.enabled(Level::Error, "anything"));
+        assert!(filter.enabled(Level::Warn, "anything"));
+        assert!(!filter.enabled(Level::Info, "anything"));
+        assert!(!Filter::off().enabled(Level::Error, "anything"));
+        // an empty spec is the same as no spec: info
+        assert_eq!(Filter::parse("").unwrap(), Filter::new(Some(Level::Info)));
+    }
+
+    #[test]
+    fn most_specific_target_wins() {
+        let filter = Filter::parse("warn, modules=debug, modules::spam=off").unwrap();
+        assert!(filter.enabled(Level::Debug, "modules"));
+        assert!(filter.enabled(Level::Debug, "modules::things"));
+        assert!(!filter.enabled(Level::Error, "modules::spam"));
+        assert!(!filter.enabled(Level::Error, "modules::spam::eggs"));
+        assert!(!filter.enabled(Level::Info, "modulesque"));
+    }
+
+    #[test]
+    fn bad_specs_are_errors() {
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("modules=loud").is_err());
+    }
+
+    #[test]
+    fn json_lines_are_records() {
+        let line = format_line(Format::Json, Level::Warn, "demo", format_args!("say \"{}\"", "hi"));
+        assert_eq!(line, r#"{"level":"warn","target":"demo","message":"say \"hi\""}"#);
+        assert_eq!(format_line(Format::Text, Level::Warn, "demo", format_args!("hi")), "[WARN demo] hi");
+    }
+
+    #[test]
+    fn the_format_flag_is_taken_out() {
+        let mut args: Vec<String> = ["runner", "--format", "json", "12"].iter().map(|a| a.to_string()).collect();
+        assert_eq!(take_format_flag(&mut args), Ok(Format::Json));
+        assert_eq!(args, ["runner", "12"]);
+        assert_eq!(take_format_flag(&mut args), Ok(Format::Text));
+        assert!(take_format_flag(&mut vec![String::from("--format")]).is_err());
+        assert!(take_format_flag(&mut vec![String::from("--format"), String::from("xml")]).is_err());
+    }
+
+    #[test]
+    fn settings_come_in_layers() {
+        let file = Config::parse("[log]\nlevel = \"warn\"\nformat = \"json\"").unwrap();
+        let args = |list: &[&str]| list.iter().map(|a| a.to_string()).collect::<Vec<String>>();
+
+        // nothing anywhere: the defaults
+        let (filter, format) = settings(&Config::default(), None, &mut args(&["x"])).unwrap();
+        assert!(filter.enabled(Level::Info, "x") && !filter.enabled(Level::Debug, "x"));
+        assert_eq!(format, Format::Text);
+
+        // the file
+        let (filter, format) = settings(&file, None, &mut args(&["x"])).unwrap();
+        assert!(!filter.enabled(Level::Info, "x"));
+        assert_eq!(format, Format::Json);
+
+        // DEMO_LOG and --format, over the file
+        let (filter, format) = settings(&file, Some(String::from("debug")), &mut args(&["x", "--format", "text"])).unwrap();
+        assert!(filter.enabled(Level::Debug, "x"));
+        assert_eq!(format, Format::Text);
+
+        // a DEMO_LOG that makes no sense falls through to the file
+        let (filter, _) = settings(&file, Some(String::from("loud")), &mut args(&["x"])).unwrap();
+        assert!(!filter.enabled(Level::Info, "x"));
+    }
+
+    #[test]
+    fn bad_settings_in_the_file_are_errors() {
+        let bad = |text: &str| settings(&Config::parse(text).unwrap(), None, &mut Vec::new()).unwrap_err();
+        assert_eq!(bad("[log]\nlevel = \"loud\""), "log.level in examples.toml: unknown log level 'loud'");
+        assert!(bad("[log]\nformat = \"xml\"").starts_with("log.format in examples.toml: unknown format"));
+        assert!(bad("[log]\nlevel = 3").contains("should be a string"));
+    }
+
+    #[test]
+    fn capture_collects_formatted_lines() {
+        let ((), logged) = capture(|| {
+            crate::info!("plain {}", 1);
+            crate::warn!(target: "demo", "careful");
+        });
+        assert_eq!(logged, "plain 1\n[WARN demo] careful\n");
+    }
+
+    #[test]
+    fn captures_nest() {
+        let (inner, outer) = capture(|| {
+            crate::info!("outer");
+            capture(|| crate::info!("inner")).1
+        });
+        assert_eq!(inner, "inner\n");
+        assert_eq!(outer, "outer\n");
+    }
+}
@@ -0,0 +1,148 @@
+/*!
+ * One random number generator for the demos, which can be made predictable.
+ *
+ * Normally it's seeded from the operating system, like `rand::thread_rng()`,
+ * and every run is different. Set `DEMO_SEED` to a number and it's seeded
+ * from that instead, so the same seed always gives the same "random" run:
+ *
+ * ```text
+ * DEMO_SEED=42 cargo run -p branches     # same number every time
+ * ```
+ *
+ * That's what lets the golden-output tests (each chapter's tests/golden.rs)
+ * compare a whole run's output against a file.
+ *
+ * There's one generator per thread, the way `thread_rng` works, and
+ * `with_rng` lends it out for the length of a closure.
+ *
+ * Code that wants randomness can also take it as an argument, as anything
+ * that implements this module's `Rng` trait: `ThreadRng` for the generator
+ * above, or a `SeededRng` of its own. A test hands in a `SeededRng`, and
+ * knows exactly what it's going to get, with no environment variables:
+ *
+ * ```text
+ * pub fn pick_number(rng: &mut impl Rng) -> i32 { rng.range(0, 10) }
+ * pick_number(&mut ThreadRng)          // in the demo
+ * pick_number(&mut SeededRng::new(7))  // in a test, the same every time
+ * ```
+ *
+ * The trait only has what the chapters use, which also means a chapter can
+ * depend on `common` instead of on rand and its much bigger `Rng` trait.
+ */
+use rand::distributions::Alphanumeric;
+use rand::rngs::StdRng;
+use rand::{FromEntropy, Rng as _, SeedableRng};
+use std::cell::RefCell;
+use std::env;
+
+// the environment variable that seeds the generator
+pub const ENV_VAR: &str = "DEMO_SEED";
+
+// DEMO_SEED if it's set (and a number), otherwise the operating system
+fn from_env() -> StdRng {
+    match env::var(ENV_VAR) {
+        Ok(seed) => match seed.trim().parse() {
+            Ok(seed) => StdRng::seed_from_u64(seed),
+            Err(_) => {
+                eprintln!("ignoring {}={:?}: not a number", ENV_VAR, seed);
+                StdRng::from_entropy()
+            }
+        },
+        Err(_) => StdRng::from_entropy(),
+    }
+}
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(from_env());
+}
+
+// `common::with_rng(|rng| rng.gen_range(1, 101))`
+pub fn with_rng<T, F: FnOnce(&mut StdRng) -> T>(f: F) -> T {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+// restart this thread's generator from `seed`, whatever DEMO_SEED said
+pub fn reseed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+pub trait Rng {
+    // from `low` up to, but not including, `high`: range(1, 101) is 1 to 100
+    fn range(&mut self, low: i32, high: i32) -> i32;
+
+    // one ASCII letter or digit
+    fn alphanumeric(&mut self) -> char;
+}
+
+// This thread's generator (DEMO_SEED's, or the operating system's), just as
+// `with_rng` would lend it out. It has no state of its own, so any number of
+// these can be made, and they all draw from the one generator.
+pub struct ThreadRng;
+
+impl Rng for ThreadRng {
+    fn range(&mut self, low: i32, high: i32) -> i32 {
+        with_rng(|rng| rng.gen_range(low, high))
+    }
+
+    fn alphanumeric(&mut self) -> char {
+        with_rng(|rng| rng.sample(Alphanumeric))
+    }
+}
+
+// A generator of its own, started from `seed`, which ignores DEMO_SEED
+pub struct SeededRng(StdRng);
+
+impl SeededRng {
+    pub fn new(seed: u64) -> SeededRng {
+        SeededRng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Rng for SeededRng {
+    fn range(&mut self, low: i32, high: i32) -> i32 {
+        self.0.gen_range(low, high)
+    }
+
+    fn alphanumeric(&mut self) -> char {
+        self.0.sample(Alphanumeric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw() -> Vec<i32> {
+        (0..5).map(|_| ThreadRng.range(0, 1000)).collect()
+    }
+
+    #[test]
+    fn a_seed_replays_the_same_numbers() {
+        reseed(7);
+        let first = draw();
+        reseed(7);
+        assert_eq!(draw(), first);
+        // and the generator moves on between calls
+        assert_ne!(draw(), first);
+    }
+
+    #[test]
+    fn seeded_rngs_replay_without_the_environment() {
+        let draw = |rng: &mut SeededRng| (0..5).map(|_| rng.range(1, 7)).collect::<Vec<i32>>();
+        let first = draw(&mut SeededRng::new(7));
+        assert_eq!(draw(&mut SeededRng::new(7)), first);
+        assert!(first.iter().all(|n| (1..7).contains(n)));
+        assert_ne!(draw(&mut SeededRng::new(8)), first);
+    }
+
+    // a seed gives the same numbers whichever kind of Rng it's in
+    #[test]
+    fn both_kinds_agree() {
+        reseed(3);
+        let mut seeded = SeededRng::new(3);
+        for _ in 0..5 {
+            assert_eq!(ThreadRng.range(0, 100), seeded.range(0, 100));
+            assert_eq!(ThreadRng.alphanumeric(), seeded.alphanumeric());
+        }
+    }
+}
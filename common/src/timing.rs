@@ -0,0 +1,41 @@
+/*!
+ * How long did that take?
+ *
+ * Both helpers take a closure, run it once, and hand back whatever it
+ * returned, so they can wrap an existing expression without changing what
+ * it evaluates to. Timings from a debug build are only good for rough
+ * comparisons; use `cargo run --release` before believing any numbers.
+ */
+use std::time::{Duration, Instant};
+
+// run `f`, and return its result along with the elapsed time
+pub fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// the same, but log the time (with a label) instead of returning it
+pub fn timed<T, F: FnOnce() -> T>(label: &str, f: F) -> T {
+    let (result, elapsed) = time(f);
+    crate::info!("{} took {:?}", label, elapsed);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn passes_the_result_through() {
+        assert_eq!(time(|| 6 * 7).0, 42);
+        assert_eq!(timed("sum", || (1..=10).sum::<i32>()), 55);
+    }
+
+    #[test]
+    fn measures_at_least_the_time_spent() {
+        let ((), elapsed) = time(|| thread::sleep(Duration::from_millis(20)));
+        assert!(elapsed >= Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,8 @@
+// Any text as a guess: a Guess or a GuessError, never a panic
+#![no_main]
+use errors::Guess;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = text.parse::<Guess>();
+});
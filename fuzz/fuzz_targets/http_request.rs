@@ -0,0 +1,8 @@
+// Any bytes as a request: an HttpRequest or a ParseError, never a panic
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use web_server::http::HttpRequest;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = HttpRequest::parse(bytes);
+});
@@ -0,0 +1,10 @@
+// Any text as an address, and whatever parses prints back the same
+#![no_main]
+use enums::ip::IpAddr;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(addr) = IpAddr::parse(text) {
+        assert_eq!(IpAddr::parse(&addr.to_string()), Ok(addr));
+    }
+});
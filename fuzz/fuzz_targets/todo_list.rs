@@ -0,0 +1,10 @@
+// Any text as a todo.txt, and whatever parses writes back out the same
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use todo::store;
+
+fuzz_target!(|text: &str| {
+    if let Ok(items) = store::parse(text) {
+        assert_eq!(store::parse(&store::format(&items)), Ok(items));
+    }
+});
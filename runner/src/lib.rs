@@ -0,0 +1,373 @@
+/*!
+ * The registry behind the runner: one entry per chapter.
+ *
+ * Each chapter's demos used to live in its own `main()`, and a `main()`
+ * can't be called from anywhere else. Now each chapter has a lib.rs with a
+ * `pub fn demo()` instead (its main.rs just calls that), so this crate can
+ * depend on all of them and keep a table of plain function pointers.
+ *
+ * `demo: fn()` is a function pointer, as in 26_advanced_functions: every
+ * chapter's demo has the same signature, so they all fit in one array.
+ *
+ * Each chapter also exports a `DEMOS` table of its own (see
+ * common/src/demos.rs), listing the pieces of its demo that can run one at
+ * a time. That's what the interactive menu (`runner --menu`) shows under
+ * each chapter.
+ */
+use common::{Demo, Json};
+
+pub mod menu;
+pub mod tui;
+pub mod verify;
+
+pub struct Chapter {
+    // the directory name, e.g. "10_enums"
+    pub dir: &'static str,
+    // the directory name without its number, e.g. "enums"
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub demo: fn(),
+    // the chapter's own table of demos, each of which can run by itself
+    pub demos: &'static [Demo],
+}
+
+impl Chapter {
+    // the leading digits of the directory name
+    pub fn number(&self) -> u32 {
+        let digits: String = self.dir.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().unwrap_or(0)
+    }
+
+    // one of this chapter's demos, by name
+    pub fn find_demo(&self, name: &str) -> Option<&'static Demo> {
+        common::demos::find(self.demos, name)
+    }
+
+    // A chapter whose every demo wants stdin (the guessing game) has to
+    // run in the terminal, too, not just the demos themselves.
+    pub fn is_interactive(&self) -> bool {
+        self.demos.iter().all(|demo| demo.interactive)
+    }
+}
+
+pub const CHAPTERS: &[Chapter] = &[
+    Chapter {
+        dir: "01_hello_cargo",
+        name: "hello_cargo",
+        summary: "Hello, world! (and the cargo commands)",
+        demo: hello_cargo::demo,
+        demos: hello_cargo::DEMOS,
+    },
+    Chapter {
+        dir: "02_guessing_game",
+        name: "guessing_game",
+        summary: "The guessing game (interactive)",
+        demo: guessing_game::demo,
+        demos: guessing_game::DEMOS,
+    },
+    Chapter {
+        dir: "03_variables",
+        name: "variables",
+        summary: "Variables, mutability and shadowing",
+        demo: variables::demo,
+        demos: variables::DEMOS,
+    },
+    Chapter {
+        dir: "04_data_types",
+        name: "data_types",
+        summary: "Scalar and compound data types",
+        demo: data_types::demo,
+        demos: data_types::DEMOS,
+    },
+    Chapter {
+        dir: "05_functions",
+        name: "functions",
+        summary: "Functions, statements and expressions",
+        demo: functions::demo,
+        demos: functions::DEMOS,
+    },
+    Chapter {
+        dir: "06_branches",
+        name: "branches",
+        summary: "if, loops and other control flow",
+        demo: branches::demo,
+        demos: branches::DEMOS,
+    },
+    Chapter {
+        dir: "07_ownership",
+        name: "ownership",
+        summary: "Ownership, moves and borrowing",
+        demo: ownership::demo,
+        demos: ownership::DEMOS,
+    },
+    Chapter {
+        dir: "08_slices",
+        name: "slices",
+        summary: "String and array slices",
+        demo: slices::demo,
+        demos: slices::DEMOS,
+    },
+    Chapter {
+        dir: "09_structs",
+        name: "structs",
+        summary: "Structs and their methods",
+        demo: structs::demo,
+        demos: structs::DEMOS,
+    },
+    Chapter {
+        dir: "10_enums",
+        name: "enums",
+        summary: "Enums and pattern matching",
+        demo: enums::demo,
+        demos: enums::DEMOS,
+    },
+    Chapter {
+        dir: "11_modules",
+        name: "modules",
+        summary: "Modules, paths and visibility",
+        demo: modules::demo,
+        demos: modules::DEMOS,
+    },
+    Chapter {
+        dir: "12_collections",
+        name: "collections",
+        summary: "Vec, String and HashMap",
+        demo: collections::demo,
+        demos: collections::DEMOS,
+    },
+    Chapter {
+        dir: "13_errors",
+        name: "errors",
+        summary: "panic! and Result",
+        demo: errors::demo,
+        demos: errors::DEMOS,
+    },
+    Chapter {
+        dir: "14_generics",
+        name: "generics",
+        summary: "Generic types and functions",
+        demo: generics::demo,
+        demos: generics::DEMOS,
+    },
+    Chapter {
+        dir: "15_traits",
+        name: "traits",
+        summary: "Traits and trait bounds",
+        demo: traits_lib::demo,
+        demos: traits_lib::DEMOS,
+    },
+    Chapter {
+        dir: "16_lifetimes",
+        name: "lifetimes",
+        summary: "Lifetimes and the borrow checker",
+        demo: lifetimes::demo,
+        demos: lifetimes::DEMOS,
+    },
+    Chapter {
+        dir: "17_testing",
+        name: "testing",
+        summary: "Writing tests (try `cargo test -p testing`)",
+        demo: testing_lib::demo,
+        demos: testing_lib::DEMOS,
+    },
+    Chapter {
+        dir: "18_minigrep",
+        name: "minigrep",
+        summary: "The minigrep project, searching a poem",
+        demo: minigrep::demo,
+        demos: minigrep::DEMOS,
+    },
+    Chapter {
+        dir: "19_closures",
+        name: "closures",
+        summary: "Closures and the Fn traits",
+        demo: closures::demo,
+        demos: closures::DEMOS,
+    },
+    Chapter {
+        dir: "20_iterators",
+        name: "iterators",
+        summary: "Iterators and their adaptors",
+        demo: iterators::demo,
+        demos: iterators::DEMOS,
+    },
+    Chapter {
+        dir: "21_smart_pointers",
+        name: "smart_pointers",
+        summary: "Box, Rc, RefCell and Weak",
+        demo: smart_pointers::demo,
+        demos: smart_pointers::DEMOS,
+    },
+    Chapter {
+        dir: "22_concurrency",
+        name: "concurrency",
+        summary: "Threads, channels and shared state",
+        demo: concurrency::demo,
+        demos: concurrency::DEMOS,
+    },
+    Chapter {
+        dir: "23_async",
+        name: "async",
+        summary: "async/await with a home-made executor",
+        demo: async_await::demo,
+        demos: async_await::DEMOS,
+    },
+    Chapter {
+        dir: "24_blog",
+        name: "blog",
+        summary: "The state pattern, as trait objects and as types",
+        demo: blog::demo,
+        demos: blog::DEMOS,
+    },
+    Chapter {
+        dir: "25_advanced_traits",
+        name: "advanced_traits",
+        summary: "Associated types, operators and newtypes",
+        demo: advanced_traits::demo,
+        demos: advanced_traits::DEMOS,
+    },
+    Chapter {
+        dir: "26_advanced_functions",
+        name: "advanced_functions",
+        summary: "Function pointers and returned closures",
+        demo: advanced_functions::demo,
+        demos: advanced_functions::DEMOS,
+    },
+    Chapter {
+        dir: "27_macros",
+        name: "macros",
+        summary: "Declarative macros",
+        demo: macros::demo,
+        demos: macros::DEMOS,
+    },
+    Chapter {
+        dir: "28_web_server",
+        name: "web_server",
+        summary: "The multi-threaded web server",
+        demo: web_server::demo,
+        demos: web_server::DEMOS,
+    },
+    Chapter {
+        dir: "29_unsafe",
+        name: "unsafe",
+        summary: "Raw pointers, FFI and static mut",
+        demo: unsafe_rust::demo,
+        demos: unsafe_rust::DEMOS,
+    },
+    Chapter {
+        dir: "30_patterns",
+        name: "patterns",
+        summary: "Where patterns appear, and refutability",
+        demo: patterns::demo,
+        demos: patterns::DEMOS,
+    },
+];
+
+// Look a chapter up the way a person would type it: "10", "10_enums" or
+// "enums" all find the same chapter. Case doesn't matter.
+pub fn find(query: &str) -> Option<&'static Chapter> {
+    let query = query.trim().to_lowercase();
+    if let Ok(number) = query.parse::<u32>() {
+        return CHAPTERS.iter().find(|chapter| chapter.number() == number);
+    }
+    CHAPTERS.iter().find(|chapter| chapter.dir == query || chapter.name == query)
+}
+
+// the text printed by `--list`: one line per chapter
+// the same list, for programs to read: `runner --list --format json`
+pub fn listing_json() -> Json {
+    Json::array(CHAPTERS.iter().map(|chapter| {
+        Json::object([
+            ("dir", Json::from(chapter.dir)),
+            ("name", Json::from(chapter.name)),
+            ("summary", Json::from(chapter.summary)),
+            ("interactive", Json::from(chapter.is_interactive())),
+            (
+                "demos",
+                Json::array(chapter.demos.iter().map(|demo| {
+                    Json::object([
+                        ("name", Json::from(demo.name)),
+                        ("summary", Json::from(demo.summary)),
+                        ("interactive", Json::from(demo.interactive)),
+                    ])
+                })),
+            ),
+        ])
+    }))
+}
+
+pub fn listing() -> String {
+    let width = CHAPTERS.iter().map(|chapter| chapter.dir.len()).max().unwrap_or(0);
+    CHAPTERS
+        .iter()
+        .map(|chapter| format!("{:<width$}  {}", chapter.dir, chapter.summary, width = width))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chapters_are_numbered_in_order() {
+        for (i, chapter) in CHAPTERS.iter().enumerate() {
+            assert_eq!(chapter.number(), i as u32 + 1, "{}", chapter.dir);
+        }
+    }
+
+    #[test]
+    fn every_spelling_finds_the_same_chapter() {
+        for query in ["10", "010", "10_enums", "enums", " Enums "].iter() {
+            assert_eq!(find(query).map(|chapter| chapter.dir), Some("10_enums"), "{:?}", query);
+        }
+        assert_eq!(find("1").map(|chapter| chapter.dir), Some("01_hello_cargo"));
+        assert_eq!(find("unsafe").map(|chapter| chapter.dir), Some("29_unsafe"));
+    }
+
+    #[test]
+    fn the_json_listing_has_every_chapter() {
+        let json = listing_json().to_string();
+        assert!(json.starts_with(r#"[{"dir":"01_hello_cargo","name":"hello_cargo","#), "{}", json);
+        assert!(json.contains(r#"{"name":"guess","summary":"Guess a number between 1 and 100","interactive":true}"#));
+        match listing_json() {
+            Json::Array(chapters) => assert_eq!(chapters.len(), CHAPTERS.len()),
+            other => panic!("not an array: {}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_chapters_are_none() {
+        assert!(find("99").is_none());
+        assert!(find("0").is_none());
+        assert!(find("pancakes").is_none());
+        assert!(find("").is_none());
+    }
+
+    #[test]
+    fn every_chapter_has_demos_with_distinct_names() {
+        for chapter in CHAPTERS {
+            assert!(!chapter.demos.is_empty(), "{}", chapter.dir);
+            for (i, demo) in chapter.demos.iter().enumerate() {
+                assert_eq!(chapter.find_demo(demo.name).map(|found| found.name), Some(demo.name));
+                assert!(chapter.demos[..i].iter().all(|other| other.name != demo.name), "{}", demo.name);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_demos_within_a_chapter() {
+        let chapter = find("12").unwrap();
+        assert_eq!(chapter.find_demo("Strings").map(|demo| demo.name), Some("strings"));
+        assert!(chapter.find_demo("enums").is_none());
+        assert!(find("2").unwrap().is_interactive());
+        assert!(!find("10").unwrap().is_interactive());
+    }
+
+    #[test]
+    fn listing_has_a_line_per_chapter() {
+        let listing = listing();
+        assert_eq!(listing.lines().count(), CHAPTERS.len());
+        assert!(listing.lines().next().unwrap().starts_with("01_hello_cargo"));
+    }
+}
@@ -0,0 +1,146 @@
+/**
+ * One binary to run any chapter's demo, from the top of the workspace:
+ *
+ *      cargo run -p runner -- --list
+ *      cargo run -p runner -- 10
+ *      cargo run -p runner -- enums
+ *      cargo run -p runner -- enums coins     # just one of its demos
+ *      cargo run -p runner -- --menu          # browse, then pick one
+ *      cargo run -p runner -- verify 04       # check your exercise answers
+ *
+ * Add `--format json` for output other programs can read: the list, the
+ * verify reports, and the messages of the chapters that log through
+ * common (11, 12 and 13; the rest print plain text with println!).
+ *
+ * An examples.toml can set that format (and the log level) for every run,
+ * and what to do when there are no arguments at all:
+ *
+ *      [runner]
+ *      default = "--menu"
+ *
+ * The same output as `cd 10_enums && cargo run`, without the `cd`.
+ */
+use common::log::{self, Format};
+use common::Config;
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage: runner <chapter> [demo] | --list | --menu | verify [chapter]\n\
+                     (a chapter can be given as 10, 10_enums or enums; add --format json\n\
+                     for JSON output)";
+
+// Settings from examples.toml (see common/src/config.rs): the log level and
+// format, and, under [runner], `default`, the arguments to use when there
+// aren't any, such as "--menu"
+fn configure(args: &mut Vec<String>) -> Result<Format, String> {
+    let config = Config::load()?;
+    let format = log::configure(&config, args)?;
+    if args.is_empty() {
+        if let Some(default) = config.string("runner", "default")? {
+            args.extend(default.split_whitespace().map(String::from));
+        }
+    }
+    Ok(format)
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = configure(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let query = match args.first() {
+        Some(query) => query,
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    if query == "--list" {
+        match format {
+            Format::Text => println!("{}", runner::listing()),
+            Format::Json => println!("{}", runner::listing_json().pretty()),
+        }
+        return;
+    }
+
+    if query == "--menu" {
+        if let Err(e) = runner::tui::run() {
+            eprintln!("The menu needs a terminal: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if query == "verify" {
+        verify(args.get(1), format);
+        return;
+    }
+
+    let chapter = match runner::find(query) {
+        Some(chapter) => chapter,
+        None => {
+            eprintln!("No chapter called '{}'. Try --list to see them all.", query);
+            process::exit(1);
+        }
+    };
+
+    match args.get(1) {
+        None => (chapter.demo)(),
+        Some(name) => match chapter.find_demo(name) {
+            Some(demo) => (demo.run)(),
+            None => {
+                let names: Vec<&str> = chapter.demos.iter().map(|demo| demo.name).collect();
+                eprintln!("{} has no demo called '{}'. Try one of: {}", chapter.dir, name, names.join(", "));
+                process::exit(1);
+            }
+        },
+    }
+}
+
+// Check one chapter's exercises, or every chapter that has some. Exits with
+// 1 unless they all pass, so scripts can tell too.
+fn verify(query: Option<&String>, format: Format) {
+    let chapters = match query {
+        None => runner::verify::with_exercises(),
+        Some(query) => match runner::find(query) {
+            Some(chapter) if runner::verify::has_exercises(chapter) => vec![chapter],
+            found => {
+                let dirs: Vec<&str> = runner::verify::with_exercises().iter().map(|c| c.dir).collect();
+                match found {
+                    Some(chapter) => eprintln!("{} has no exercises.", chapter.dir),
+                    None => eprintln!("No chapter called '{}'.", query),
+                }
+                eprintln!("These chapters have exercises: {}", dirs.join(", "));
+                process::exit(1);
+            }
+        },
+    };
+
+    let mut all_passed = true;
+    let mut reports = Vec::new();
+    for chapter in chapters {
+        match runner::verify::verify(chapter) {
+            Ok(report) => {
+                match format {
+                    Format::Text => println!("{}", report.render()),
+                    Format::Json => reports.push(report.to_json()),
+                }
+                all_passed &= report.all_passed();
+            }
+            Err(e) => {
+                eprintln!("Couldn't run cargo for {}: {}", chapter.dir, e);
+                all_passed = false;
+            }
+        }
+    }
+    // one array at the end, so the whole of stdout is a single JSON value
+    if format == Format::Json {
+        println!("{}", common::Json::Array(reports).pretty());
+    }
+    if !all_passed {
+        process::exit(1);
+    }
+}
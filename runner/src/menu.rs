@@ -0,0 +1,210 @@
+/*!
+ * What the interactive menu knows, with none of the terminal plumbing.
+ *
+ * `Menu` is the list of chapters (and, under each chapter that has more
+ * than one, its demos) plus which line is selected and which line is at the
+ * top of the screen. `Pager` is a demo's captured output plus how far down
+ * it has been scrolled. Both are plain data with plain methods, so the tests
+ * below can drive them without a terminal; ./tui.rs draws them and feeds
+ * them keys.
+ */
+use crate::Chapter;
+use common::Demo;
+
+// one line of the menu
+#[derive(Clone, Copy)]
+pub enum Entry {
+    Chapter(&'static Chapter),
+    Demo(&'static Chapter, &'static Demo),
+}
+
+impl Entry {
+    pub fn label(&self) -> String {
+        match self {
+            Entry::Chapter(chapter) => chapter.dir.to_string(),
+            Entry::Demo(_, demo) => format!("  {}", demo.name),
+        }
+    }
+
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Entry::Chapter(chapter) => chapter.summary,
+            Entry::Demo(_, demo) => demo.summary,
+        }
+    }
+
+    // the arguments that make the runner binary run just this entry
+    pub fn args(&self) -> Vec<&'static str> {
+        match self {
+            Entry::Chapter(chapter) => vec![chapter.dir],
+            Entry::Demo(chapter, demo) => vec![chapter.dir, demo.name],
+        }
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        match self {
+            Entry::Chapter(chapter) => chapter.is_interactive(),
+            Entry::Demo(_, demo) => demo.interactive,
+        }
+    }
+}
+
+// Move `position` by `delta`, without going past either end of `len` items
+fn step(position: usize, delta: isize, len: usize) -> usize {
+    let last = len.saturating_sub(1) as isize;
+    (position as isize + delta).clamp(0, last) as usize
+}
+
+pub struct Menu {
+    pub entries: Vec<Entry>,
+    pub selected: usize,
+    pub top: usize,
+}
+
+impl Menu {
+    pub fn new(chapters: &'static [Chapter]) -> Menu {
+        let mut entries = Vec::new();
+        for chapter in chapters {
+            entries.push(Entry::Chapter(chapter));
+            // a lone demo *is* the chapter, so it doesn't get a line of its own
+            if chapter.demos.len() > 1 {
+                entries.extend(chapter.demos.iter().map(|demo| Entry::Demo(chapter, demo)));
+            }
+        }
+        Menu { entries, selected: 0, top: 0 }
+    }
+
+    pub fn selected(&self) -> Entry {
+        self.entries[self.selected]
+    }
+
+    pub fn move_by(&mut self, delta: isize) {
+        self.selected = step(self.selected, delta, self.entries.len());
+    }
+
+    pub fn move_to_end(&mut self) {
+        self.selected = self.entries.len().saturating_sub(1);
+    }
+
+    // Scroll just far enough that the selected line is among the `height`
+    // lines on screen, then say which entries those are.
+    pub fn visible(&mut self, height: usize) -> &[Entry] {
+        let height = height.max(1);
+        if self.selected < self.top {
+            self.top = self.selected;
+        } else if self.selected >= self.top + height {
+            self.top = self.selected + 1 - height;
+        }
+        let end = (self.top + height).min(self.entries.len());
+        &self.entries[self.top..end]
+    }
+}
+
+pub struct Pager {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub top: usize,
+}
+
+impl Pager {
+    pub fn new(title: String, text: &str) -> Pager {
+        Pager { title, lines: text.lines().map(String::from).collect(), top: 0 }
+    }
+
+    // Scrolling stops once the last line is at the bottom of the screen,
+    // rather than scrolling the text up and away into blankness.
+    pub fn scroll_by(&mut self, delta: isize, height: usize) {
+        let last_top = self.lines.len().saturating_sub(height.max(1));
+        self.top = step(self.top, delta, last_top + 1);
+    }
+
+    pub fn scroll_to_end(&mut self, height: usize) {
+        self.top = self.lines.len().saturating_sub(height.max(1));
+    }
+
+    pub fn visible(&self, height: usize) -> &[String] {
+        let end = (self.top + height).min(self.lines.len());
+        &self.lines[self.top..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find, CHAPTERS};
+
+    fn labels(entries: &[Entry]) -> Vec<String> {
+        entries.iter().map(Entry::label).collect()
+    }
+
+    #[test]
+    fn chapters_with_several_demos_list_them() {
+        let menu = Menu::new(CHAPTERS);
+        let labels = labels(&menu.entries);
+        let at = labels.iter().position(|label| label == "12_collections").unwrap();
+        assert_eq!(
+            labels[at + 1..at + 13],
+            [
+                "  vectors", "  strings", "  hashmaps", "  directory", "  wordplay", "  lru", "  scheduler", "  aggregates", "  sorting",
+                "  template", "  diff", "13_errors",
+            ]
+        );
+        // but a chapter with a single demo is just the chapter
+        assert_eq!(labels[..2], ["01_hello_cargo", "02_guessing_game"]);
+    }
+
+    #[test]
+    fn entries_know_how_to_run_themselves() {
+        let chapter = find("12").unwrap();
+        let demo = chapter.find_demo("strings").unwrap();
+        assert_eq!(Entry::Chapter(chapter).args(), ["12_collections"]);
+        assert_eq!(Entry::Demo(chapter, demo).args(), ["12_collections", "strings"]);
+        assert_eq!(Entry::Demo(chapter, demo).summary(), demo.summary);
+        assert!(Entry::Chapter(find("2").unwrap()).is_interactive());
+    }
+
+    #[test]
+    fn selection_stays_in_bounds() {
+        let mut menu = Menu::new(CHAPTERS);
+        menu.move_by(-5);
+        assert_eq!(menu.selected, 0);
+        menu.move_by(3);
+        assert_eq!(menu.selected, 3);
+        menu.move_by(10_000);
+        assert_eq!(menu.selected, menu.entries.len() - 1);
+        assert_eq!(menu.selected().label(), "  let_else");
+    }
+
+    #[test]
+    fn the_window_follows_the_selection() {
+        let mut menu = Menu::new(CHAPTERS);
+        assert_eq!(menu.visible(5).len(), 5);
+        menu.move_by(7);
+        assert_eq!(labels(menu.visible(5))[4], menu.selected().label());
+        assert_eq!(menu.top, 3);
+        // moving back up inside the window doesn't scroll it
+        menu.move_by(-2);
+        menu.visible(5);
+        assert_eq!(menu.top, 3);
+        menu.move_to_end();
+        assert_eq!(menu.visible(5).len(), 5);
+        assert_eq!(menu.top, menu.entries.len() - 5);
+    }
+
+    #[test]
+    fn the_pager_stops_at_the_last_screenful() {
+        let text: Vec<String> = (1..=10).map(|n| n.to_string()).collect();
+        let mut pager = Pager::new(String::from("numbers"), &text.join("\n"));
+        assert_eq!(pager.visible(4), ["1", "2", "3", "4"]);
+        pager.scroll_by(100, 4);
+        assert_eq!(pager.visible(4), ["7", "8", "9", "10"]);
+        pager.scroll_by(-1, 4);
+        assert_eq!(pager.top, 5);
+        // text shorter than the screen never scrolls at all
+        let mut short = Pager::new(String::from("short"), "one\ntwo");
+        short.scroll_by(1, 4);
+        assert_eq!(short.top, 0);
+        short.scroll_to_end(4);
+        assert_eq!(short.visible(4), ["one", "two"]);
+    }
+}
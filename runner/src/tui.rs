@@ -0,0 +1,223 @@
+/*!
+ * `runner --menu`: browse the chapters and their demos, and run one.
+ *
+ * The terminal work (reading single keys, the alternate screen that puts
+ * your shell back the way it was afterwards) comes from the crossterm
+ * crate. Everything the screen *shows* lives in ./menu.rs.
+ *
+ * Picking an entry runs it in a second copy of this very binary, as
+ * `runner <chapter> [demo]`, and collects what it prints. That's simpler
+ * and sturdier than trying to intercept our own stdout, and a demo that
+ * panics (13_errors, say, with a line uncommented) can only take down the
+ * copy. Interactive demos are the exception: they need a human at the
+ * keyboard, so the menu steps aside and lets them have the terminal.
+ */
+use crate::menu::{Entry, Menu, Pager};
+use crate::CHAPTERS;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+// the lines that aren't list or text: a title above, help below
+const CHROME: u16 = 2;
+
+const MENU_HELP: &str = "up/down/pgup/pgdn move   enter run   q quit";
+const PAGER_HELP: &str = "up/down/pgup/pgdn scroll   q back to the menu";
+
+// What a key means to us. Vim's j and k work too, for the people who will
+// reach for them no matter what.
+enum Key {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Enter,
+    Back,
+}
+
+fn read_key() -> io::Result<Key> {
+    loop {
+        if let Event::Key(key) = event::read()? {
+            // some terminals report releases too; act on presses only
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let key = match key.code {
+                KeyCode::Up | KeyCode::Char('k') => Key::Up,
+                KeyCode::Down | KeyCode::Char('j') => Key::Down,
+                KeyCode::PageUp => Key::PageUp,
+                KeyCode::PageDown | KeyCode::Char(' ') => Key::PageDown,
+                KeyCode::Home | KeyCode::Char('g') => Key::Home,
+                KeyCode::End | KeyCode::Char('G') => Key::End,
+                KeyCode::Enter => Key::Enter,
+                KeyCode::Esc | KeyCode::Char('q') => Key::Back,
+                _ => continue,
+            };
+            return Ok(key);
+        }
+    }
+}
+
+// Raw mode plus the alternate screen, for as long as this value lives.
+// Undoing it all in `drop` means the terminal gets put back even when we
+// leave by `?` or by panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+
+    // hand the real terminal over to `f` for a while
+    fn suspend<T>(&self, f: impl FnOnce() -> io::Result<T>) -> io::Result<T> {
+        execute!(io::stdout(), Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+        let result = f();
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        result
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// the rows available for the list (or the text), and the width of a line
+fn body_size() -> io::Result<(usize, usize)> {
+    let (width, height) = terminal::size()?;
+    Ok((height.saturating_sub(CHROME).max(1) as usize, width as usize))
+}
+
+fn fit(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+fn draw(title: &str, rows: &[(String, bool)], help: &str) -> io::Result<()> {
+    let (height, width) = body_size()?;
+    let mut out = io::stdout();
+    queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+    queue!(out, SetAttribute(Attribute::Bold), Print(fit(title, width)), SetAttribute(Attribute::Reset))?;
+    for (i, (row, highlighted)) in rows.iter().enumerate() {
+        queue!(out, MoveTo(0, i as u16 + 1))?;
+        if *highlighted {
+            queue!(out, SetAttribute(Attribute::Reverse))?;
+        }
+        queue!(out, Print(fit(row, width)), SetAttribute(Attribute::Reset))?;
+    }
+    queue!(out, MoveTo(0, height as u16 + 1), SetAttribute(Attribute::Dim), Print(fit(help, width)))?;
+    queue!(out, SetAttribute(Attribute::Reset))?;
+    out.flush()
+}
+
+fn draw_menu(menu: &mut Menu) -> io::Result<()> {
+    let (height, _) = body_size()?;
+    let width = menu.entries.iter().map(|entry| entry.label().len()).max().unwrap_or(0);
+    // (copied out, because `visible` borrows the menu to scroll it)
+    let visible: Vec<Entry> = menu.visible(height).to_vec();
+    let rows: Vec<(String, bool)> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let note = if entry.is_interactive() { " (interactive)" } else { "" };
+            let row = format!("{:<width$}  {}{}", entry.label(), entry.summary(), note, width = width);
+            (row, menu.top + i == menu.selected)
+        })
+        .collect();
+    draw("Rust Book Examples", &rows, MENU_HELP)
+}
+
+fn draw_pager(pager: &Pager) -> io::Result<()> {
+    let (height, _) = body_size()?;
+    let rows: Vec<(String, bool)> = pager.visible(height).iter().map(|line| (line.clone(), false)).collect();
+    let shown = pager.top + rows.len();
+    let help = format!("{}   (lines {}-{} of {})", PAGER_HELP, pager.top + 1, shown, pager.lines.len());
+    draw(&pager.title, &rows, &help)
+}
+
+// a fresh copy of this binary, told to run just `entry`
+fn runner_for(entry: &Entry) -> io::Result<Command> {
+    let mut command = Command::new(env::current_exe()?);
+    command.args(entry.args());
+    Ok(command)
+}
+
+// run `entry` with its output collected, and wrap that up in a Pager
+fn capture(entry: &Entry) -> io::Result<Pager> {
+    let output = runner_for(entry)?.stdin(Stdio::null()).output()?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    let errors = String::from_utf8_lossy(&output.stderr);
+    if !errors.is_empty() {
+        text.push_str("\n--- stderr ---\n");
+        text.push_str(&errors);
+    }
+    let status = if output.status.success() { String::from("ok") } else { output.status.to_string() };
+    let title = format!("{} {}: {}", entry.args().join(" "), entry.summary(), status);
+    Ok(Pager::new(title, &text))
+}
+
+// run `entry` in the ordinary terminal, then wait for Enter before going back
+fn run_in_terminal(entry: &Entry) -> io::Result<()> {
+    println!("--- {} ---", entry.args().join(" "));
+    runner_for(entry)?.status()?;
+    print!("\n(press Enter to go back to the menu) ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(())
+}
+
+fn page(pager: &mut Pager) -> io::Result<()> {
+    loop {
+        draw_pager(pager)?;
+        let (height, _) = body_size()?;
+        match read_key()? {
+            Key::Up => pager.scroll_by(-1, height),
+            Key::Down => pager.scroll_by(1, height),
+            Key::PageUp => pager.scroll_by(-(height as isize), height),
+            Key::PageDown => pager.scroll_by(height as isize, height),
+            Key::Home => pager.top = 0,
+            Key::End => pager.scroll_to_end(height),
+            Key::Enter => {}
+            Key::Back => return Ok(()),
+        }
+    }
+}
+
+pub fn run() -> io::Result<()> {
+    let mut menu = Menu::new(CHAPTERS);
+    let screen = Screen::enter()?;
+    loop {
+        draw_menu(&mut menu)?;
+        let (height, _) = body_size()?;
+        match read_key()? {
+            Key::Up => menu.move_by(-1),
+            Key::Down => menu.move_by(1),
+            Key::PageUp => menu.move_by(-(height as isize)),
+            Key::PageDown => menu.move_by(height as isize),
+            Key::Home => menu.selected = 0,
+            Key::End => menu.move_to_end(),
+            Key::Enter => {
+                let entry = menu.selected();
+                if entry.is_interactive() {
+                    screen.suspend(|| run_in_terminal(&entry))?;
+                } else {
+                    page(&mut capture(&entry)?)?;
+                }
+            }
+            Key::Back => return Ok(()),
+        }
+    }
+}
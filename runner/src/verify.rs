@@ -0,0 +1,189 @@
+/*!
+ * `runner verify [chapter]`: which of the exercises pass yet?
+ *
+ * A chapter with exercises has a `src/exercises.rs` full of `todo!()`s for
+ * you to replace, and a `tests/exercises.rs` with one test per exercise,
+ * both behind the chapter's `exercises` feature. Verifying a chapter is just
+ *
+ * ```text
+ * cargo test --manifest-path <chapter>/Cargo.toml --features exercises --test exercises
+ * ```
+ *
+ * with the results read back out of libtest's `test <name> ... ok` lines.
+ * It has to be cargo, and not a function call like the demos: the whole
+ * point is to compile the code you just edited.
+ */
+use crate::{Chapter, CHAPTERS};
+use common::Json;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+
+// every chapter directory sits next to this crate's own
+fn chapter_dir(chapter: &Chapter) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(chapter.dir)
+}
+
+pub fn has_exercises(chapter: &Chapter) -> bool {
+    chapter_dir(chapter).join("tests").join("exercises.rs").is_file()
+}
+
+pub fn with_exercises() -> Vec<&'static Chapter> {
+    CHAPTERS.iter().filter(|chapter| has_exercises(chapter)).collect()
+}
+
+pub struct Report {
+    pub chapter: &'static Chapter,
+    // each exercise's name, and whether its test passed
+    pub results: Vec<(String, bool)>,
+    // what the compiler said, when there were no test results at all
+    pub build_errors: Option<String>,
+}
+
+impl Report {
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|(_, passed)| *passed).count()
+    }
+
+    pub fn all_passed(&self) -> bool {
+        self.build_errors.is_none() && self.passed() == self.results.len()
+    }
+
+    pub fn render(&self) -> String {
+        if let Some(errors) = &self.build_errors {
+            return format!("{}: doesn't compile yet\n{}", self.chapter.dir, errors.trim_end());
+        }
+        let mut lines = vec![format!(
+            "{}: {} of {} exercises pass",
+            self.chapter.dir,
+            self.passed(),
+            self.results.len()
+        )];
+        for (name, passed) in &self.results {
+            lines.push(format!("    {:<8}{}", if *passed { "ok" } else { "not yet" }, name));
+        }
+        lines.join("\n")
+    }
+
+    // the same, for programs to read: `runner verify --format json`
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("chapter", Json::from(self.chapter.dir)),
+            ("passed", Json::from(self.passed())),
+            ("total", Json::from(self.results.len())),
+            (
+                "results",
+                Json::array(self.results.iter().map(|(name, passed)| {
+                    Json::object([("name", Json::from(name.as_str())), ("passed", Json::from(*passed))])
+                })),
+            ),
+            ("build_errors", Json::from(self.build_errors.clone())),
+        ])
+    }
+}
+
+// Pick the `test <name> ... ok` and `test <name> ... FAILED` lines out of
+// libtest's output. The tests run in parallel, so sort them by name.
+pub fn parse_results(output: &str) -> Vec<(String, bool)> {
+    let mut results: Vec<(String, bool)> = output
+        .lines()
+        .filter_map(|line| {
+            let (name, outcome) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+            match outcome {
+                "ok" => Some((name.to_string(), true)),
+                "FAILED" => Some((name.to_string(), false)),
+                _ => None,
+            }
+        })
+        .collect();
+    results.sort();
+    results
+}
+
+pub fn verify(chapter: &'static Chapter) -> io::Result<Report> {
+    // when we were started by `cargo run`, CARGO says which cargo that was
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let output = Command::new(cargo)
+        .arg("test")
+        .arg("--manifest-path")
+        .arg(chapter_dir(chapter).join("Cargo.toml"))
+        .args(["--features", "exercises", "--test", "exercises", "--color", "never"])
+        .output()?;
+
+    let results = parse_results(&String::from_utf8_lossy(&output.stdout));
+    let build_errors = if results.is_empty() && !output.status.success() {
+        Some(String::from_utf8_lossy(&output.stderr).into_owned())
+    } else {
+        None
+    };
+    Ok(Report { chapter, results, build_errors })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find;
+
+    const OUTPUT: &str = "\
+running 3 tests
+test swap ... ok
+test average ... FAILED
+test last_of ... ok
+
+failures:
+
+---- average stdout ----
+thread 'average' panicked at src/exercises.rs:28:5:
+not yet implemented
+
+test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+";
+
+    #[test]
+    fn reads_libtest_output() {
+        assert_eq!(
+            parse_results(OUTPUT),
+            vec![
+                (String::from("average"), false),
+                (String::from("last_of"), true),
+                (String::from("swap"), true),
+            ]
+        );
+        assert!(parse_results("error[E0308]: mismatched types").is_empty());
+    }
+
+    #[test]
+    fn reports_count_the_passes() {
+        let report = Report {
+            chapter: find("04").unwrap(),
+            results: parse_results(OUTPUT),
+            build_errors: None,
+        };
+        assert_eq!(report.passed(), 2);
+        assert!(!report.all_passed());
+        assert_eq!(
+            report.render(),
+            "04_data_types: 2 of 3 exercises pass\n    not yet average\n    ok      last_of\n    ok      swap"
+        );
+        assert_eq!(
+            report.to_json().to_string(),
+            concat!(
+                r#"{"chapter":"04_data_types","passed":2,"total":3,"results":["#,
+                r#"{"name":"average","passed":false},{"name":"last_of","passed":true},{"name":"swap","passed":true}],"#,
+                r#""build_errors":null}"#
+            )
+        );
+    }
+
+    #[test]
+    fn only_some_chapters_have_exercises() {
+        assert!(has_exercises(find("04").unwrap()));
+        assert!(!has_exercises(find("01").unwrap()));
+        let dirs: Vec<&str> = with_exercises().iter().map(|chapter| chapter.dir).collect();
+        assert_eq!(
+            dirs,
+            ["04_data_types", "06_branches", "08_slices", "12_collections", "14_generics", "20_iterators"]
+        );
+    }
+}
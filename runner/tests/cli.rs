@@ -0,0 +1,85 @@
+/**
+ * The runner binary, run for real: these are the same command lines the
+ * `--menu` screen builds when it runs a chapter or a single demo.
+ *
+ * Cargo builds the binary before running integration tests, and tells us
+ * where it put it in the CARGO_BIN_EXE_<name> variable.
+ */
+use std::process::{Command, Output};
+
+fn runner(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(args)
+        .env_remove("DEMO_LOG")
+        // no examples.toml either, whatever the checkout has lying around
+        .env("DEMO_CONFIG", "")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn a_single_demo_runs_alone() {
+    let output = runner(&["12", "strings"]);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("String Demonstration Begins"), "{}", text);
+    assert!(!text.contains("Vector Demonstration Begins"), "{}", text);
+}
+
+#[test]
+fn a_whole_chapter_runs_every_demo() {
+    let text = stdout(&runner(&["collections"]));
+    assert!(text.contains("Vector Demonstration Begins"), "{}", text);
+    assert!(text.contains("String Demonstration Begins"), "{}", text);
+}
+
+#[test]
+fn unknown_demos_list_the_real_ones() {
+    let output = runner(&["12", "nope"]);
+    assert!(!output.status.success());
+    let errors = String::from_utf8_lossy(&output.stderr);
+    assert!(errors.contains("vectors, strings, hashmaps"), "{}", errors);
+}
+
+#[test]
+fn json_output_is_one_record_per_message() {
+    let text = stdout(&runner(&["13", "--format", "json"]));
+    assert!(text.lines().count() > 1, "{}", text);
+    // the target is the module that logged it: errors, or errors::strategies
+    for line in text.lines() {
+        assert!(line.starts_with(r#"{"level":"info","target":"errors"#), "{}", line);
+        assert!(line.contains(r#"","message":""#), "{}", line);
+        assert!(line.ends_with("\"}"), "{}", line);
+    }
+}
+
+#[test]
+fn unknown_formats_are_refused() {
+    let output = runner(&["--format", "yaml", "--list"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown format 'yaml'"));
+}
+
+// examples.toml's settings, under the command line's
+#[test]
+fn a_config_file_sets_the_defaults() {
+    let path = std::env::temp_dir().join(format!("runner-cli-{}.toml", std::process::id()));
+    std::fs::write(&path, "[log]\nformat = \"json\"\n\n[runner]\ndefault = \"--list\"\n").unwrap();
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_runner")).args(args).env("DEMO_CONFIG", &path).output().unwrap()
+    };
+
+    // no arguments means the file's, and its format too
+    let text = stdout(&run(&[]));
+    assert!(text.starts_with('['), "{}", text);
+    assert!(text.contains("\"10_enums\""), "{}", text);
+
+    // but the command line still wins
+    let text = stdout(&run(&["--list", "--format", "text"]));
+    assert!(!text.starts_with('['), "{}", text);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,223 @@
+/*!
+ * Temperatures in Celsius, Fahrenheit and Kelvin, and the conversions
+ * between them.
+ *
+ * Every conversion goes through Celsius, so there are two formulas per
+ * scale instead of one for every pair:
+ *
+ * ```text
+ * F = C * 9/5 + 32        C = (F - 32) * 5/9
+ * K = C + 273.15          C = K - 273.15
+ * ```
+ *
+ * Text like "32F", "-40 c" or "273.15K" turns into a Temperature through
+ * the standard `FromStr` trait, which is what makes `"32F".parse()` work,
+ * the same way `"32".parse()` gives a number in the guessing game.
+ */
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 3] = [Scale::Celsius, Scale::Fahrenheit, Scale::Kelvin];
+
+    pub fn letter(self) -> char {
+        match self {
+            Scale::Celsius => 'C',
+            Scale::Fahrenheit => 'F',
+            Scale::Kelvin => 'K',
+        }
+    }
+
+    // either case, since nobody types a capital on purpose at a prompt
+    pub fn from_letter(letter: char) -> Option<Scale> {
+        Scale::ALL.iter().copied().find(|scale| scale.letter() == letter.to_ascii_uppercase())
+    }
+}
+
+// the coldest anything can be, in Celsius (0 K)
+pub const ABSOLUTE_ZERO: f64 = -273.15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    pub degrees: f64,
+    pub scale: Scale,
+}
+
+impl Temperature {
+    pub fn new(degrees: f64, scale: Scale) -> Temperature {
+        Temperature { degrees, scale }
+    }
+
+    pub fn celsius(&self) -> f64 {
+        match self.scale {
+            Scale::Celsius => self.degrees,
+            Scale::Fahrenheit => (self.degrees - 32.0) * 5.0 / 9.0,
+            Scale::Kelvin => self.degrees + ABSOLUTE_ZERO,
+        }
+    }
+
+    pub fn to(&self, scale: Scale) -> Temperature {
+        let celsius = self.celsius();
+        let degrees = match scale {
+            Scale::Celsius => celsius,
+            Scale::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            Scale::Kelvin => celsius - ABSOLUTE_ZERO,
+        };
+        Temperature::new(degrees, scale)
+    }
+
+    // the same temperature in each of the other scales
+    pub fn conversions(&self) -> Vec<Temperature> {
+        Scale::ALL.iter().filter(|&&scale| scale != self.scale).map(|&scale| self.to(scale)).collect()
+    }
+}
+
+// To two decimal places at most, and none at all when they'd be zeros:
+// 32°F is 0°C, and 0°F is -17.78°C. Kelvin has no degree sign.
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // + 0.0 turns the -0 that rounding can leave behind into a plain 0
+        let degrees = (self.degrees * 100.0).round() / 100.0 + 0.0;
+        match self.scale {
+            Scale::Kelvin => write!(f, "{} K", degrees),
+            scale => write!(f, "{}°{}", degrees, scale.letter()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseTemperatureError {
+    Empty,
+    // the text, when its last letter isn't C, F or K
+    NoScale(String),
+    // the number part, when it isn't one
+    BadNumber(String),
+    BelowAbsoluteZero,
+}
+
+impl fmt::Display for ParseTemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseTemperatureError::Empty => write!(f, "there's no temperature there"),
+            ParseTemperatureError::NoScale(text) => write!(f, "{:?} needs a scale at the end: C, F or K", text),
+            ParseTemperatureError::BadNumber(text) => write!(f, "{:?} isn't a number", text),
+            ParseTemperatureError::BelowAbsoluteZero => write!(f, "nothing is colder than absolute zero (0 K)"),
+        }
+    }
+}
+
+impl Error for ParseTemperatureError {}
+
+// A number, then a scale letter, with a space or a degree sign allowed in
+// between: "32F", "32 f", "32°F" and " -40.5C " all parse.
+impl FromStr for Temperature {
+    type Err = ParseTemperatureError;
+
+    fn from_str(text: &str) -> Result<Temperature, ParseTemperatureError> {
+        let text = text.trim();
+        let letter = text.chars().last().ok_or(ParseTemperatureError::Empty)?;
+        let scale = Scale::from_letter(letter).ok_or_else(|| ParseTemperatureError::NoScale(text.to_string()))?;
+
+        let number = text[..text.len() - letter.len_utf8()].trim_end().trim_end_matches('°').trim_end();
+        let degrees: f64 = number.parse().map_err(|_| ParseTemperatureError::BadNumber(number.to_string()))?;
+        // "NaN" and "inf" parse as f64s too, but aren't temperatures
+        if !degrees.is_finite() {
+            return Err(ParseTemperatureError::BadNumber(number.to_string()));
+        }
+
+        let temperature = Temperature::new(degrees, scale);
+        if temperature.celsius() < ABSOLUTE_ZERO {
+            return Err(ParseTemperatureError::BelowAbsoluteZero);
+        }
+        Ok(temperature)
+    }
+}
+
+// One line of the conversation in main.rs: the conversions, or what was
+// wrong with the input.
+pub fn answer(input: &str) -> String {
+    match input.parse::<Temperature>() {
+        Ok(temperature) => {
+            let others: Vec<String> = temperature.conversions().iter().map(|t| t.to_string()).collect();
+            format!("{} is {}", temperature, others.join(", or "))
+        }
+        Err(e) => format!("Sorry, {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn known_temperatures() {
+        let freezing = Temperature::new(0.0, Scale::Celsius);
+        assert!(close(freezing.to(Scale::Fahrenheit).degrees, 32.0));
+        assert!(close(freezing.to(Scale::Kelvin).degrees, 273.15));
+        let boiling = Temperature::new(212.0, Scale::Fahrenheit);
+        assert!(close(boiling.celsius(), 100.0));
+        // where the two scales cross
+        assert!(close(Temperature::new(-40.0, Scale::Celsius).to(Scale::Fahrenheit).degrees, -40.0));
+    }
+
+    #[test]
+    fn round_trips_come_back_where_they_started() {
+        for &degrees in &[-273.15, -40.0, 0.0, 36.6, 98.6, 1000.0] {
+            for &from in &Scale::ALL {
+                for &via in &Scale::ALL {
+                    let start = Temperature::new(degrees, from);
+                    let back = start.to(via).to(from);
+                    assert_eq!(back.scale, from);
+                    assert!(close(back.degrees, degrees), "{} via {:?} came back as {}", start, via, back);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("32F".parse(), Ok(Temperature::new(32.0, Scale::Fahrenheit)));
+        assert_eq!(" -40.5 c ".parse(), Ok(Temperature::new(-40.5, Scale::Celsius)));
+        assert_eq!("100°C".parse(), Ok(Temperature::new(100.0, Scale::Celsius)));
+        assert_eq!("0K".parse(), Ok(Temperature::new(0.0, Scale::Kelvin)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |text: &str| text.parse::<Temperature>().unwrap_err();
+        assert_eq!(parse("  "), ParseTemperatureError::Empty);
+        assert_eq!(parse("32"), ParseTemperatureError::NoScale(String::from("32")));
+        assert_eq!(parse("warmF"), ParseTemperatureError::BadNumber(String::from("warm")));
+        assert_eq!(parse("F"), ParseTemperatureError::BadNumber(String::new()));
+        assert_eq!(parse("NaNC"), ParseTemperatureError::BadNumber(String::from("NaN")));
+        assert_eq!(parse("-1K"), ParseTemperatureError::BelowAbsoluteZero);
+        assert_eq!(parse("-300C"), ParseTemperatureError::BelowAbsoluteZero);
+        assert_eq!(parse("32é"), ParseTemperatureError::NoScale(String::from("32é")));
+    }
+
+    #[test]
+    fn display_rounds_and_trims() {
+        assert_eq!(Temperature::new(32.0, Scale::Fahrenheit).to_string(), "32°F");
+        assert_eq!(Temperature::new(0.0, Scale::Fahrenheit).to(Scale::Celsius).to_string(), "-17.78°C");
+        assert_eq!(Temperature::new(-0.001, Scale::Celsius).to_string(), "0°C");
+        assert_eq!(Temperature::new(0.0, Scale::Celsius).to(Scale::Kelvin).to_string(), "273.15 K");
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(answer("32F"), "32°F is 0°C, or 273.15 K");
+        assert_eq!(answer("hot"), "Sorry, \"hot\" needs a scale at the end: C, F or K");
+    }
+}
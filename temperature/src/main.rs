@@ -0,0 +1,15 @@
+// Ask for temperatures until the input runs out (Ctrl-D) or an empty line,
+// and answer each one with the other two scales (see lib.rs).
+fn main() {
+    println!("Temperatures like 32F, 100C or 0K, one per line (an empty line quits)");
+    loop {
+        match common::prompt("> ") {
+            Ok(Some(line)) if !line.trim().is_empty() => println!("{}", temperature::answer(&line)),
+            Ok(_) => break,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
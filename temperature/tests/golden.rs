@@ -0,0 +1,14 @@
+/**
+ * A session's whole output, checked against tests/golden/ (the how and why
+ * are in common/src/golden.rs).
+ */
+use common::golden;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn session() {
+    let input = "32F\n100 c\n0K\n-40°C\n98.6f\nwarm\n-5K\n\nnever read\n";
+    let output = golden::output(&mut golden::command(env!("CARGO_BIN_EXE_temperature"), DIR), input);
+    golden::check(DIR, "session", &output);
+}
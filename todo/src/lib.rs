@@ -0,0 +1,236 @@
+/*!
+ * A to-do list on the command line:
+ *
+ * ```text
+ * todo add feed the frog      Added 1: feed the frog
+ * todo list                     1 [ ] feed the frog
+ * todo done 1                 Done 1: feed the frog
+ * todo remove 1               Removed 1: feed the frog
+ * ```
+ *
+ * The list is kept in `todo.txt` in the current directory, or wherever the
+ * TODO_FILE environment variable points (see ./store.rs for what's in it).
+ *
+ * Each item keeps its id for good: removing item 2 doesn't make item 3 the
+ * new item 2, which would be a nasty surprise for `todo done 3` typed a
+ * moment later. A new item gets one more than the biggest id in the list.
+ *
+ * Everything that can go wrong is a `TodoError`, which main.rs prints and
+ * turns into an exit status. A file with bad lines in it is never written
+ * over: the list might be the only copy of something, so `todo` reports
+ * every bad line and leaves the fixing to you.
+ */
+use crossterm::style::Stylize;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// reading and writing the file
+pub mod store;
+use store::ParseError;
+
+pub const ENV_VAR: &str = "TODO_FILE";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Todo,
+    Done,
+}
+
+impl Status {
+    // how the file (and `todo list`) shows it
+    pub fn mark(self) -> &'static str {
+        match self {
+            Status::Todo => "[ ]",
+            Status::Done => "[x]",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    pub status: Status,
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Add(String),
+    List,
+    Done(u32),
+    Remove(u32),
+}
+
+const USAGE: &str = "usage: todo add <text> | list | done <id> | remove <id>";
+
+impl Command {
+    // args[0] is the program's name, as in minigrep. `add` takes the rest
+    // of the line as its text, so the text needn't be quoted.
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let usage = |problem: &str| format!("{} ({})", problem, USAGE);
+        let id = |arg: Option<&String>| -> Result<u32, String> {
+            let arg = arg.ok_or_else(|| usage("which item?"))?;
+            arg.parse().map_err(|_| usage(&format!("{:?} isn't an id", arg)))
+        };
+        match args.get(1).map(String::as_str) {
+            Some("add") => {
+                let text = args[2..].join(" ");
+                if text.trim().is_empty() {
+                    Err(usage("nothing to add"))
+                } else if text.contains('\n') {
+                    Err(String::from("a to-do is one line of text"))
+                } else {
+                    Ok(Command::Add(text.trim().to_string()))
+                }
+            }
+            Some("list") => Ok(Command::List),
+            Some("done") => Ok(Command::Done(id(args.get(2))?)),
+            Some("remove") => Ok(Command::Remove(id(args.get(2))?)),
+            Some(other) => Err(usage(&format!("unknown command {:?}", other))),
+            None => Err(usage("no command")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TodoError {
+    Io(io::Error),
+    // every bad line in the file
+    Corrupt(Vec<ParseError>),
+    NoSuchItem(u32),
+    // the biggest id there is is taken, so a new item has none to get
+    OutOfIds,
+}
+
+impl fmt::Display for TodoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TodoError::Io(e) => write!(f, "{}", e),
+            TodoError::Corrupt(errors) => {
+                write!(f, "the list has {} bad line(s), and won't be changed until they're fixed:", errors.len())?;
+                for e in errors {
+                    write!(f, "\n    {}", e)?;
+                }
+                Ok(())
+            }
+            TodoError::NoSuchItem(id) => write!(f, "there's no item {}", id),
+            TodoError::OutOfIds => write!(f, "item {} is the biggest id there is, so there's none left to add with", u32::MAX),
+        }
+    }
+}
+
+impl Error for TodoError {}
+
+// so that `?` can turn an io::Error into a TodoError on its own
+impl From<io::Error> for TodoError {
+    fn from(e: io::Error) -> TodoError {
+        TodoError::Io(e)
+    }
+}
+
+// TODO_FILE, or todo.txt right here
+pub fn path() -> PathBuf {
+    env::var_os(ENV_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from("todo.txt"))
+}
+
+// Color only for a person: a file or a pipe would get the escape codes as
+// junk in the text. NO_COLOR (https://no-color.org) turns it off regardless.
+pub fn use_color() -> bool {
+    use std::io::IsTerminal;
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none()
+}
+
+// One item, as `todo list` shows it: done in green, still to do in yellow
+pub fn render(item: &Item, color: bool) -> String {
+    let mark = match (color, item.status) {
+        (false, status) => status.mark().to_string(),
+        (true, Status::Todo) => item.status.mark().yellow().to_string(),
+        (true, Status::Done) => item.status.mark().green().to_string(),
+    };
+    format!("{:>3} {} {}", item.id, mark, item.text)
+}
+
+// Load the list from `path`, do `command` to it, and save it if it changed.
+// What to print comes back as the Ok value.
+pub fn run(command: &Command, path: &Path, color: bool) -> Result<String, TodoError> {
+    let mut items = store::parse(&store::read(path)?).map_err(TodoError::Corrupt)?;
+    let position = |items: &[Item], id: u32| items.iter().position(|item| item.id == id).ok_or(TodoError::NoSuchItem(id));
+
+    let message = match command {
+        Command::Add(text) => {
+            // (checked: a file can already hold u32::MAX, with nothing after it)
+            let biggest = items.iter().map(|item| item.id).max().unwrap_or(0);
+            let id = biggest.checked_add(1).ok_or(TodoError::OutOfIds)?;
+            items.push(Item { id, status: Status::Todo, text: text.clone() });
+            format!("Added {}: {}", id, text)
+        }
+        Command::List => {
+            if items.is_empty() {
+                return Ok(String::from("Nothing to do!"));
+            }
+            let lines: Vec<String> = items.iter().map(|item| render(item, color)).collect();
+            let done = items.iter().filter(|item| item.status == Status::Done).count();
+            return Ok(format!("{}\n({} of {} done)", lines.join("\n"), done, items.len()));
+        }
+        Command::Done(id) => {
+            let index = position(&items, *id)?;
+            items[index].status = Status::Done;
+            format!("Done {}: {}", id, items[index].text)
+        }
+        Command::Remove(id) => {
+            let removed = items.remove(position(&items, *id)?);
+            format!("Removed {}: {}", id, removed.text)
+        }
+    };
+    store::write(path, &items)?;
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(Command::parse(&args(&["todo", "add", "feed", "the", "frog"])), Ok(Command::Add(String::from("feed the frog"))));
+        assert_eq!(Command::parse(&args(&["todo", "list", "extra"])), Ok(Command::List));
+        assert_eq!(Command::parse(&args(&["todo", "done", "3"])), Ok(Command::Done(3)));
+        assert_eq!(Command::parse(&args(&["todo", "remove", "12"])), Ok(Command::Remove(12)));
+    }
+
+    #[test]
+    fn bad_commands() {
+        for bad in [&["todo"][..], &["todo", "add"], &["todo", "add", " "], &["todo", "done"], &["todo", "done", "x"], &["todo", "frog"]] {
+            let error = Command::parse(&args(bad)).unwrap_err();
+            assert!(error.ends_with(&format!("({})", USAGE)), "{:?}: {}", bad, error);
+        }
+        assert!(Command::parse(&args(&["todo", "add", "two\nlines"])).is_err());
+    }
+
+    #[test]
+    fn rendering() {
+        let item = Item { id: 4, status: Status::Done, text: String::from("feed the frog") };
+        assert_eq!(render(&item, false), "  4 [x] feed the frog");
+        // the same words, with escape codes around the mark
+        let colored = render(&item, true);
+        assert!(colored.contains("\u{1b}[") && colored.ends_with("[x]\u{1b}[39m feed the frog"), "{:?}", colored);
+    }
+
+    #[test]
+    fn corrupt_lists_say_which_lines() {
+        let error = TodoError::Corrupt(store::parse("1 [ ] a\n1 [ ] b\nc\n").unwrap_err());
+        assert_eq!(
+            error.to_string(),
+            "the list has 2 bad line(s), and won't be changed until they're fixed:\n    \
+             line 2: id 1 is used more than once\n    \
+             line 3: expected an id, a status and some text"
+        );
+    }
+}
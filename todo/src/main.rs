@@ -0,0 +1,24 @@
+// Parse the command, run it against the list file, and print what it says
+// (see lib.rs). Like minigrep: 2 for a command we couldn't make sense of,
+// 1 for one that went wrong, and 0 when it worked.
+use std::env;
+use std::process;
+
+use todo::Command;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let command = Command::parse(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+
+    let path = todo::path();
+    match todo::run(&command, &path, todo::use_color()) {
+        Ok(message) => println!("{}", message),
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/*!
+ * The file the list lives in: one item per line, as plain text, so that
+ * it can be read (and fixed) in any editor.
+ *
+ * ```text
+ * 1 [x] feed the frog
+ * 2 [ ] tell your name the livelong day
+ * ```
+ *
+ * An id, a status (`[ ]` to do, `[x]` done) and the text, each separated by
+ * one space. Blank lines are skipped.
+ *
+ * A line that doesn't look like that is a `ParseError`, which says which
+ * line and what's wrong with it. Loading carries on past a bad line, so
+ * that *every* bad line is reported at once, rather than one per attempt.
+ */
+use crate::{Item, Status};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseErrorKind {
+    // no space after the id, or nothing after it
+    Truncated,
+    BadId(String),
+    BadStatus(String),
+    // the id and status are fine, but there's nothing to do
+    NoText,
+    // the same id as an earlier line
+    DuplicateId(u32),
+}
+
+// what's wrong, and on which line (counting from 1, as editors do)
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ParseErrorKind::Truncated => write!(f, "expected an id, a status and some text"),
+            ParseErrorKind::BadId(id) => write!(f, "{:?} isn't an id (ids are whole numbers from 1)", id),
+            ParseErrorKind::BadStatus(status) => write!(f, "{:?} isn't a status (expected [ ] or [x])", status),
+            ParseErrorKind::NoText => write!(f, "the item has no text"),
+            ParseErrorKind::DuplicateId(id) => write!(f, "id {} is used more than once", id),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// one line of the file, as an Item (the line number is just for the error)
+pub fn parse_line(number: usize, line: &str) -> Result<Item, ParseError> {
+    let error = |kind| ParseError { line: number, kind };
+    let (id, rest) = line.split_once(' ').ok_or_else(|| error(ParseErrorKind::Truncated))?;
+    let id = match id.parse::<u32>() {
+        Ok(id) if id > 0 => id,
+        _ => return Err(error(ParseErrorKind::BadId(id.to_string()))),
+    };
+    // the status is 3 characters, but they might not be 3 *bytes*, so
+    // `get` rather than slicing, which would panic mid-character
+    let status = match rest.get(..3) {
+        Some("[ ]") => Status::Todo,
+        Some("[x]") => Status::Done,
+        _ => return Err(error(ParseErrorKind::BadStatus(rest.chars().take(3).collect()))),
+    };
+    let text = rest[3..].trim();
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::NoText));
+    }
+    Ok(Item { id, status, text: text.to_string() })
+}
+
+// Every item in `contents`, or every line that's wrong with it
+pub fn parse(contents: &str) -> Result<Vec<Item>, Vec<ParseError>> {
+    let mut items: Vec<Item> = Vec::new();
+    let mut errors = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_line(index + 1, line) {
+            Ok(item) if items.iter().any(|earlier| earlier.id == item.id) => {
+                errors.push(ParseError { line: index + 1, kind: ParseErrorKind::DuplicateId(item.id) });
+            }
+            Ok(item) => items.push(item),
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(errors)
+    }
+}
+
+// the other direction: what `parse` reads back in
+pub fn format(items: &[Item]) -> String {
+    items.iter().map(|item| format!("{} {} {}\n", item.id, item.status.mark(), item.text)).collect()
+}
+
+// A file that isn't there yet is an empty list: the first `add` makes it
+pub fn read(path: &Path) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        result => result,
+    }
+}
+
+// Write to a file next door and rename it over the old one, so a crash
+// halfway through leaves the old list, not half of the new one
+pub fn write(path: &Path, items: &[Item]) -> io::Result<()> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".new");
+    fs::write(&temporary, format(items))?;
+    fs::rename(&temporary, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: u32, status: Status, text: &str) -> Item {
+        Item { id, status, text: text.to_string() }
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        let items = vec![item(1, Status::Done, "feed the frog"), item(7, Status::Todo, "écrire  une lettre")];
+        let text = format(&items);
+        assert_eq!(text, "1 [x] feed the frog\n7 [ ] écrire  une lettre\n");
+        assert_eq!(parse(&text).unwrap(), items);
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        assert_eq!(parse("\n1 [ ] a\n   \n").unwrap(), vec![item(1, Status::Todo, "a")]);
+        assert_eq!(parse("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn each_kind_of_bad_line() {
+        let kind = |line| parse_line(1, line).unwrap_err().kind;
+        assert_eq!(kind("1"), ParseErrorKind::Truncated);
+        assert_eq!(kind("one [ ] a"), ParseErrorKind::BadId(String::from("one")));
+        assert_eq!(kind("0 [ ] a"), ParseErrorKind::BadId(String::from("0")));
+        assert_eq!(kind("1 [?] a"), ParseErrorKind::BadStatus(String::from("[?]")));
+        // not a char boundary at byte 3: an error, not a panic
+        assert_eq!(kind("1 éé a"), ParseErrorKind::BadStatus(String::from("éé ")));
+        assert_eq!(kind("1 [x]   "), ParseErrorKind::NoText);
+    }
+
+    #[test]
+    fn every_bad_line_is_reported() {
+        let errors = parse("1 [ ] fine\nrubbish\n2 [ ] fine\n1 [x] again\n").unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ParseError { line: 2, kind: ParseErrorKind::Truncated },
+                ParseError { line: 4, kind: ParseErrorKind::DuplicateId(1) },
+            ]
+        );
+        assert_eq!(errors[1].to_string(), "line 4: id 1 is used more than once");
+    }
+}
@@ -0,0 +1,136 @@
+/**
+ * `todo`, run for real, each test in a fresh directory of its own under
+ * the system's temp dir, so they can run side by side without sharing (or
+ * clobbering) a todo.txt.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+
+// A new, empty directory for the test called `name`. Leftovers from an
+// earlier run that failed halfway are cleared out first.
+fn scratch(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("todo-cli-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// run `todo` in `dir`, with no TODO_FILE unless the test sets one
+fn todo(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_todo"))
+        .args(args)
+        .current_dir(dir)
+        .env_remove("TODO_FILE")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn add_list_done_remove() {
+    let dir = scratch("round");
+    assert_eq!(stdout(&todo(&dir, &["list"])), "Nothing to do!\n");
+    assert_eq!(stdout(&todo(&dir, &["add", "feed", "the", "frog"])), "Added 1: feed the frog\n");
+    assert_eq!(stdout(&todo(&dir, &["add", "write a poem"])), "Added 2: write a poem\n");
+    assert_eq!(stdout(&todo(&dir, &["done", "1"])), "Done 1: feed the frog\n");
+    // not a terminal, so no colors
+    assert_eq!(stdout(&todo(&dir, &["list"])), "  1 [x] feed the frog\n  2 [ ] write a poem\n(1 of 2 done)\n");
+    assert_eq!(fs::read_to_string(dir.join("todo.txt")).unwrap(), "1 [x] feed the frog\n2 [ ] write a poem\n");
+
+    assert_eq!(stdout(&todo(&dir, &["remove", "1"])), "Removed 1: feed the frog\n");
+    assert_eq!(stdout(&todo(&dir, &["list"])), "  2 [ ] write a poem\n(0 of 1 done)\n");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn ids_are_never_reused_while_bigger_ones_remain() {
+    let dir = scratch("ids");
+    for text in ["a", "b", "c"].iter() {
+        todo(&dir, &["add", text]);
+    }
+    todo(&dir, &["remove", "2"]);
+    assert_eq!(stdout(&todo(&dir, &["add", "d"])), "Added 4: d\n");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn todo_file_picks_the_file() {
+    let dir = scratch("env");
+    let file = dir.join("elsewhere.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_todo"))
+        .args(["add", "somewhere else"])
+        .current_dir(&dir)
+        .env("TODO_FILE", &file)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(fs::read_to_string(&file).unwrap(), "1 [ ] somewhere else\n");
+    assert!(!dir.join("todo.txt").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn missing_items_exit_one() {
+    let dir = scratch("missing");
+    todo(&dir, &["add", "only one"]);
+    let output = todo(&dir, &["done", "5"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), "todo.txt: there's no item 5\n");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn bad_commands_exit_two_with_usage() {
+    let dir = scratch("usage");
+    for args in [&[][..], &["frog"][..], &["done", "one"][..]] {
+        let output = todo(&dir, args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr(&output).contains("usage: todo add <text> | list | done <id> | remove <id>"), "{}", stderr(&output));
+    }
+    // and a bad command never creates the file
+    assert!(!dir.join("todo.txt").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn a_corrupt_file_is_reported_line_by_line_and_left_alone() {
+    let dir = scratch("corrupt");
+    let contents = "1 [ ] fine\n2 [?] not a status\nthree [ ] not an id\n";
+    fs::write(dir.join("todo.txt"), contents).unwrap();
+
+    let output = todo(&dir, &["add", "one more"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "todo.txt: the list has 2 bad line(s), and won't be changed until they're fixed:\n    \
+         line 2: \"[?]\" isn't a status (expected [ ] or [x])\n    \
+         line 3: \"three\" isn't an id (ids are whole numbers from 1)\n"
+    );
+    assert_eq!(fs::read_to_string(dir.join("todo.txt")).unwrap(), contents);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn running_out_of_ids_is_an_error_not_a_panic() {
+    let dir = scratch("out-of-ids");
+    let contents = "4294967295 [ ] the last id there is\n";
+    fs::write(dir.join("todo.txt"), contents).unwrap();
+    let output = todo(&dir, &["add", "one more"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "todo.txt: item 4294967295 is the biggest id there is, so there's none left to add with\n"
+    );
+    assert_eq!(fs::read_to_string(dir.join("todo.txt")).unwrap(), contents);
+    fs::remove_dir_all(&dir).unwrap();
+}
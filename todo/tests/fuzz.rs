@@ -0,0 +1,26 @@
+/**
+ * The list file's parser, fed thousands of made-up files (see
+ * common/src/fuzz.rs). A list someone has been editing by hand can have
+ * anything in it, and every bad line should come back as a ParseError
+ * saying which line, never as a panic.
+ */
+use common::fuzz;
+use todo::store;
+
+#[test]
+fn list_files() {
+    let corpus: &[&[u8]] = &[
+        b"1 [ ] feed the frog\n2 [x] tell your name\n",
+        b"3 [x] \xc3\xa9crire une lettre\n\n4 [ ] x\n",
+        b"1 [?] bad\nrubbish\n1 [ ] again\n",
+    ];
+    fuzz::check("store::parse", corpus, |bytes| {
+        let text = String::from_utf8_lossy(bytes);
+        let lines = text.lines().count();
+        match store::parse(&text) {
+            // and what it reads, it writes back out the same
+            Ok(items) => assert_eq!(store::parse(&store::format(&items)), Ok(items)),
+            Err(errors) => assert!(errors.iter().all(|e| e.line >= 1 && e.line <= lines), "{:?}", errors),
+        }
+    });
+}
@@ -0,0 +1,122 @@
+/*!
+ * Getting text and lists in and out of a WebAssembly module, by hand.
+ *
+ * A wasm function can only take and return numbers. Anything bigger has to
+ * travel through the module's memory, which JavaScript sees as one big
+ * byte array (`instance.exports.memory.buffer`). So this module owns two
+ * buffers, one for each direction:
+ *
+ * ```text
+ * const ptr = exports.input_buffer(bytes.length);  // room for the input...
+ * new Uint8Array(exports.memory.buffer, ptr, bytes.length).set(bytes);  // ...filled in
+ * exports.pig_latin();                             // reads the input, writes the output
+ * new Uint8Array(exports.memory.buffer, exports.output_ptr(), exports.output_len())
+ * ```
+ *
+ * Because Rust owns both buffers (they're plain Vecs), nothing here needs
+ * `unsafe`: JavaScript writes into memory we handed out, and we only ever
+ * read it back through the Vec. This is the glue that `wasm-bindgen`
+ * generates for you, written out so you can see there's no magic in it.
+ *
+ * A wasm module runs on a single thread, so thread_local is all the
+ * "global" we need, and it keeps the native tests from treading on each
+ * other, too.
+ */
+use std::cell::RefCell;
+
+thread_local! {
+    static INPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    static OUTPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+// Make room for `len` bytes of input, and say where JavaScript should put
+// them. The pointer is only good until the next call into the module.
+#[no_mangle]
+pub extern "C" fn input_buffer(len: usize) -> *mut u8 {
+    INPUT.with(|input| {
+        let mut input = input.borrow_mut();
+        input.clear();
+        input.resize(len, 0);
+        input.as_mut_ptr()
+    })
+}
+
+// where the last function that had something to say left it, and how long it is
+#[no_mangle]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.with(|output| output.borrow().as_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.with(|output| output.borrow().len())
+}
+
+// the input as text: JavaScript's TextEncoder always writes UTF-8, but
+// anyone can write any bytes, so anything that isn't becomes U+FFFD
+pub fn input_text() -> String {
+    INPUT.with(|input| String::from_utf8_lossy(&input.borrow()).into_owned())
+}
+
+// The input as a list of i32s, four little-endian bytes each (which is
+// what an Int32Array looks like in wasm's little-endian memory). A stray
+// byte or three at the end is ignored.
+pub fn input_numbers() -> Vec<i32> {
+    INPUT.with(|input| {
+        input
+            .borrow()
+            .chunks_exact(4)
+            .map(|bytes| i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect()
+    })
+}
+
+pub fn set_output(text: &str) {
+    OUTPUT.with(|output| {
+        let mut output = output.borrow_mut();
+        output.clear();
+        output.extend_from_slice(text.as_bytes());
+    });
+}
+
+// What JavaScript does, done from Rust, for the tests: fill the input
+// buffer, and read the output back as text.
+#[cfg(test)]
+pub fn set_input(bytes: &[u8]) {
+    INPUT.with(|input| *input.borrow_mut() = bytes.to_vec());
+}
+
+#[cfg(test)]
+pub fn output() -> String {
+    OUTPUT.with(|output| String::from_utf8(output.borrow().clone()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_comes_in_as_text_or_numbers() {
+        set_input("héllo".as_bytes());
+        assert_eq!(input_text(), "héllo");
+        let bytes: Vec<u8> = [1, -2, 300].iter().flat_map(|n: &i32| n.to_le_bytes().to_vec()).collect();
+        set_input(&bytes);
+        assert_eq!(input_numbers(), [1, -2, 300]);
+    }
+
+    #[test]
+    fn the_input_buffer_is_the_size_asked_for() {
+        let ptr = input_buffer(3);
+        assert!(!ptr.is_null());
+        assert_eq!(input_text(), "\0\0\0");
+        assert!(input_numbers().is_empty());
+    }
+
+    #[test]
+    fn output_is_replaced_each_time() {
+        set_output("first");
+        set_output("second");
+        assert_eq!(output(), "second");
+        assert_eq!(output_len(), 6);
+    }
+}
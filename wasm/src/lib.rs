@@ -0,0 +1,160 @@
+/*!
+ * Some chapters' logic, exported for a browser to call.
+ *
+ * None of this is new code: the roman numerals are 10_enums's, the guessing
+ * game is 02_guessing_game's (minus the terminal), and with the `exercises`
+ * feature, the statistics and pig latin are *your* answers to chapter 12's
+ * exercises. All this package adds is a set of `extern "C"` functions that
+ * a WebAssembly module can export, in the style of what `#[wasm_bindgen]`
+ * would generate, but by hand and without the dependency (./buffers.rs
+ * explains how text gets across).
+ *
+ * To build it and try it out, from the top of the workspace:
+ *
+ * ```text
+ * rustup target add wasm32-unknown-unknown
+ * cargo build -p wasm --target wasm32-unknown-unknown --release
+ * cp target/wasm32-unknown-unknown/release/wasm.wasm wasm/www/
+ * python3 -m http.server --directory wasm/www     # then open localhost:8000
+ * ```
+ *
+ * (A page loaded from a file:// URL isn't allowed to fetch the .wasm, hence
+ * the little web server. 28_web_server would do, if it served files.)
+ *
+ * `cargo test -p wasm` runs the same functions natively, so there's no
+ * need for a browser to check them.
+ */
+use enums::roman;
+use guessing_game::game::Game;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+pub mod buffers;
+use buffers::{input_text, set_output};
+
+// Roman numerals (10_enums/src/roman.rs)
+
+// Write `n` as a numeral. Either way the output holds something to show:
+// the numeral if it worked, and what went wrong if it didn't.
+#[no_mangle]
+pub extern "C" fn roman_to_numeral(n: u32) -> bool {
+    match roman::to_roman(n) {
+        Ok(numeral) => {
+            set_output(&numeral);
+            true
+        }
+        Err(e) => {
+            set_output(&e.to_string());
+            false
+        }
+    }
+}
+
+// Read the numeral in the input buffer. Zero has no numeral, so a zero
+// means it didn't parse, and the output says why.
+#[no_mangle]
+pub extern "C" fn roman_from_numeral() -> u32 {
+    match roman::from_roman(&input_text()) {
+        Ok(n) => {
+            set_output("");
+            n
+        }
+        Err(e) => {
+            set_output(&e.to_string());
+            0
+        }
+    }
+}
+
+// The guessing game (02_guessing_game/src/game.rs). The page picks the
+// secret number: JavaScript has its own Math.random, and a module with no
+// imports can't ask the operating system for randomness anyway.
+
+thread_local! {
+    static GAME: RefCell<Option<Game>> = const { RefCell::new(None) };
+}
+
+#[no_mangle]
+pub extern "C" fn game_new(secret: u32) {
+    GAME.with(|game| *game.borrow_mut() = Some(Game::new(secret)));
+}
+
+// -1 for too small, 1 for too big and 0 for a win. Guessing before
+// game_new panics, which the page sees as a RuntimeError.
+#[no_mangle]
+pub extern "C" fn game_guess(guess: u32) -> i32 {
+    GAME.with(|game| {
+        let mut game = game.borrow_mut();
+        match game.as_mut().expect("call game_new first").guess(guess) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn game_guesses() -> u32 {
+    GAME.with(|game| game.borrow().as_ref().map_or(0, Game::guesses))
+}
+
+// Chapter 12's exercises (12_collections/src/exercises.rs), which read a
+// list of i32s or a word from the input buffer. Median and mode have no
+// answer for an empty list, and JavaScript's word for that is NaN.
+
+#[cfg(feature = "exercises")]
+use collections::exercises;
+
+#[cfg(feature = "exercises")]
+#[no_mangle]
+pub extern "C" fn stats_median() -> f64 {
+    exercises::median(&buffers::input_numbers()).map_or(f64::NAN, f64::from)
+}
+
+#[cfg(feature = "exercises")]
+#[no_mangle]
+pub extern "C" fn stats_mode() -> f64 {
+    exercises::mode(&buffers::input_numbers()).map_or(f64::NAN, f64::from)
+}
+
+#[cfg(feature = "exercises")]
+#[no_mangle]
+pub extern "C" fn pig_latin() {
+    set_output(&exercises::pig_latin(&input_text()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::buffers::{output, set_input};
+    use super::*;
+
+    #[test]
+    fn numerals_both_ways() {
+        assert!(roman_to_numeral(1994));
+        assert_eq!(output(), "MCMXCIV");
+        set_input(b"mmxxiv");
+        assert_eq!(roman_from_numeral(), 2024);
+        assert_eq!(output(), "");
+    }
+
+    #[test]
+    fn numeral_errors_come_out_as_text() {
+        assert!(!roman_to_numeral(4000));
+        assert_eq!(output(), "4000 can't be written in Roman numerals (only 1 to 3999)");
+        set_input(b"IIII");
+        assert_eq!(roman_from_numeral(), 0);
+        assert!(!output().is_empty());
+    }
+
+    #[test]
+    fn a_game_to_the_end() {
+        game_new(37);
+        assert_eq!(game_guess(50), 1);
+        assert_eq!(game_guess(25), -1);
+        assert_eq!(game_guess(37), 0);
+        assert_eq!(game_guesses(), 3);
+        // a new game starts the count again
+        game_new(1);
+        assert_eq!(game_guesses(), 0);
+    }
+}
@@ -0,0 +1,111 @@
+// Loads wasm.wasm (built by `cargo build -p wasm --target
+// wasm32-unknown-unknown`, see ../src/lib.rs) and wires its exports up to
+// the forms in index.html.
+//
+// Text goes in through the module's input buffer and comes back out of its
+// output buffer (../src/buffers.rs). These few helpers are the JavaScript
+// half of that, and the only part of this file that knows about memory.
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+let wasm = null;
+
+function writeBytes(bytes) {
+  const ptr = wasm.input_buffer(bytes.length);
+  // take a fresh view every time: memory.buffer is replaced when it grows
+  new Uint8Array(wasm.memory.buffer, ptr, bytes.length).set(bytes);
+}
+
+function writeText(text) {
+  writeBytes(encoder.encode(text));
+}
+
+function writeNumbers(numbers) {
+  writeBytes(new Uint8Array(Int32Array.from(numbers).buffer));
+}
+
+function readText() {
+  return decoder.decode(new Uint8Array(wasm.memory.buffer, wasm.output_ptr(), wasm.output_len()));
+}
+
+function show(id, text) {
+  document.getElementById(id).textContent = text;
+}
+
+// Roman numerals
+
+function toRoman() {
+  const n = Number(document.getElementById("roman-number").value);
+  wasm.roman_to_numeral(n >>> 0);
+  show("roman-result", readText());
+}
+
+function fromRoman() {
+  writeText(document.getElementById("roman-numeral").value);
+  const n = wasm.roman_from_numeral();
+  show("roman-result", n === 0 ? readText() : String(n));
+}
+
+// The guessing game
+
+function newGame() {
+  wasm.game_new(Math.floor(Math.random() * 100) + 1);
+  show("game-result", "Guess the number!");
+}
+
+function guess() {
+  const n = Number(document.getElementById("game-guess").value);
+  if (!Number.isInteger(n) || n < 0) {
+    return; // just like the terminal version, which ignores non-numbers
+  }
+  const replies = { "-1": "Too small!", "1": "Too big!" };
+  const reply = wasm.game_guess(n);
+  show("game-result", replies[reply] || `You win! (in ${wasm.game_guesses()} guesses)`);
+}
+
+// Chapter 12's exercises, only in a module built with `--features exercises`
+
+function numbers() {
+  return document
+    .getElementById("stats-numbers")
+    .value.split(/[\s,]+/)
+    .filter((s) => s !== "")
+    .map(Number);
+}
+
+function stats() {
+  writeNumbers(numbers());
+  const median = wasm.stats_median();
+  writeNumbers(numbers());
+  const mode = wasm.stats_mode();
+  const text = (x) => (Number.isNaN(x) ? "none" : String(x));
+  show("stats-result", `median ${text(median)}, mode ${text(mode)}`);
+}
+
+function pigLatin() {
+  writeText(document.getElementById("pig-latin-word").value);
+  wasm.pig_latin();
+  show("pig-latin-result", readText());
+}
+
+async function load() {
+  // the module imports nothing, so the import object is empty
+  const { instance } = await WebAssembly.instantiateStreaming(fetch("wasm.wasm"), {});
+  wasm = instance.exports;
+
+  document.getElementById("to-roman").onclick = toRoman;
+  document.getElementById("from-roman").onclick = fromRoman;
+  document.getElementById("new-game").onclick = newGame;
+  document.getElementById("guess").onclick = guess;
+  newGame();
+
+  if (wasm.pig_latin) {
+    document.getElementById("stats").onclick = stats;
+    document.getElementById("pig-latin").onclick = pigLatin;
+  } else {
+    document.getElementById("exercises").hidden = true;
+  }
+}
+
+load().catch((e) => show("status", `Couldn't load wasm.wasm: ${e}`));